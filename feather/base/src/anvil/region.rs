@@ -116,18 +116,48 @@ pub struct LevelProperties {
 }
 
 /// Represents a block update scheduled for a specific time.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct ScheduledBlockUpdate {
     /// The identifier of the type of this block
     #[serde(rename = "i")]
     name: Cow<'static, str>,
-    // TODO are these global or chunk coordinates?
-    /// X coordinate
+    /// X coordinate in global space
     pub x: i32,
-    /// Y coordinate
+    /// Y coordinate in global space
     pub y: i32,
-    /// Z coordinate
+    /// Z coordinate in global space
     pub z: i32,
+    /// Ticks until the update runs
+    #[serde(rename = "t")]
+    #[serde(default)]
+    pub delay: i32,
+    /// Priority of the update; lower values run first
+    #[serde(rename = "p")]
+    #[serde(default)]
+    pub priority: i32,
+}
+
+impl ScheduledBlockUpdate {
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        (x, y, z): (i32, i32, i32),
+        delay: i32,
+        priority: i32,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            x,
+            y,
+            z,
+            delay,
+            priority,
+        }
+    }
+
+    /// Whether this update is for a liquid, which are saved separately
+    fn is_liquid(&self) -> bool {
+        matches!(&*self.name, "minecraft:water" | "minecraft:lava")
+    }
 }
 
 /// A block of sectors in a region file.
@@ -238,6 +268,8 @@ impl RegionHandle {
                 Biome::from_id(id as u32).ok_or(Error::InvalidBiomeId(id))?;
         }
 
+        chunk.set_inhabited_time(level.inhabited_time.max(0) as u64);
+
         // chunk.recalculate_heightmap();
 
         Ok((chunk, level.entities.clone(), level.block_entities.clone()))
@@ -261,6 +293,7 @@ impl RegionHandle {
         chunk: &Chunk,
         entities: &[EntityData],
         block_entities: &[BlockEntityData],
+        block_updates: &[ScheduledBlockUpdate],
     ) -> Result<(), Error> {
         let chunk_pos = chunk.position();
 
@@ -275,7 +308,7 @@ impl RegionHandle {
         }
 
         // Write chunk to `ChunkRoot` tag.
-        let root = chunk_to_chunk_root(chunk, entities, block_entities);
+        let root = chunk_to_chunk_root(chunk, entities, block_entities, block_updates);
 
         // Write to intermediate buffer, because we need to know the length.
         let mut buf = Vec::with_capacity(4096);
@@ -404,13 +437,18 @@ fn chunk_to_chunk_root(
     chunk: &Chunk,
     entities: &[EntityData],
     block_entities: &[BlockEntityData],
+    block_updates: &[ScheduledBlockUpdate],
 ) -> ChunkRoot {
+    let (liquid_updates, block_updates) = block_updates
+        .iter()
+        .cloned()
+        .partition(ScheduledBlockUpdate::is_liquid);
     ChunkRoot {
         level: ChunkLevel {
             x_pos: chunk.position().x,
             z_pos: chunk.position().z,
-            last_update: 0,    // TODO
-            inhabited_time: 0, // TODO
+            last_update: 0, // TODO
+            inhabited_time: chunk.inhabited_time() as i64,
             block_entities: block_entities.into(),
             sections: chunk
                 .sections()
@@ -445,8 +483,8 @@ fn chunk_to_chunk_root(
             entities: entities.into(),
             awaiting_block_updates: vec![vec![]; 16], // TODO
             awaiting_liquid_updates: vec![vec![]; 16], // TODO
-            scheduled_block_updates: vec![],          // TODO
-            scheduled_liquid_updates: vec![],
+            scheduled_block_updates: block_updates,
+            scheduled_liquid_updates: liquid_updates,
             post_processing: vec![vec![]; 16],
             worldgen_status: "postprocessed".into(),
         },
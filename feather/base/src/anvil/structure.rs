@@ -0,0 +1,77 @@
+//! Implements loading of the vanilla structure format,
+//! as written by structure blocks.
+//!
+//! <https://minecraft.gamepedia.com/Structure_block_file_format>
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+/// Represents the contents of a structure (`.nbt`) file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StructureData {
+    #[serde(rename = "DataVersion")]
+    #[serde(default)]
+    pub data_version: i32,
+    /// Size of the structure along the x, y and z axes.
+    pub size: Vec<i32>,
+    /// The distinct block states used by the structure.
+    pub palette: Vec<StructurePaletteEntry>,
+    pub blocks: Vec<StructureBlock>,
+}
+
+/// Represents a block state in a structure's palette.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StructurePaletteEntry {
+    /// The identifier of the type of this block, e.g. `minecraft:chest`
+    pub name: String,
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+}
+
+/// Represents a single block of a structure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructureBlock {
+    /// Position relative to the structure's origin.
+    pub pos: Vec<i32>,
+    /// Index of this block's state in the palette.
+    pub state: i32,
+    /// Data of the block entity at this block, if any,
+    /// without its position.
+    pub nbt: Option<HashMap<String, nbt::Value>>,
+}
+
+impl StructureData {
+    /// Reads a gzipped structure file.
+    pub fn load(reader: impl Read) -> anyhow::Result<Self> {
+        nbt::from_gzip_reader(reader).map_err(Into::into)
+    }
+
+    /// Writes this structure gzipped, as vanilla saves them.
+    pub fn save(&self, mut writer: impl Write) -> anyhow::Result<()> {
+        nbt::to_gzip_writer(&mut writer, self, None)?;
+        Ok(())
+    }
+
+    /// The structure's size as a tuple, or `None`
+    /// if the `size` tag is malformed.
+    pub fn dimensions(&self) -> Option<(i32, i32, i32)> {
+        match self.size.as_slice() {
+            [x, y, z] => Some((*x, *y, *z)),
+            _ => None,
+        }
+    }
+}
+
+impl StructureBlock {
+    /// The block's position as a tuple, or `None`
+    /// if the `pos` tag is malformed.
+    pub fn position(&self) -> Option<(i32, i32, i32)> {
+        match self.pos.as_slice() {
+            [x, y, z] => Some((*x, *y, *z)),
+            _ => None,
+        }
+    }
+}
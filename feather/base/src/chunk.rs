@@ -64,6 +64,8 @@ pub struct Chunk {
     heightmaps: HeightmapStore,
 
     position: ChunkPosition,
+
+    inhabited_time: u64,
 }
 
 impl Default for Chunk {
@@ -77,6 +79,7 @@ impl Default for Chunk {
             biomes: BiomeStore::default(),
             position: ChunkPosition::new(0, 0),
             heightmaps: HeightmapStore::new(),
+            inhabited_time: 0,
         }
     }
 }
@@ -112,6 +115,16 @@ impl Chunk {
         self.position = pos;
     }
 
+    /// Gets the total number of ticks players have spent in this chunk.
+    pub fn inhabited_time(&self) -> u64 {
+        self.inhabited_time
+    }
+
+    /// Sets the total number of ticks players have spent in this chunk.
+    pub fn set_inhabited_time(&mut self, inhabited_time: u64) {
+        self.inhabited_time = inhabited_time;
+    }
+
     /// Gets the block at the given position within this chunk.
     ///
     /// Returns `None` if the coordinates are out of bounds.
@@ -207,7 +220,7 @@ impl Chunk {
 
     pub fn set_block_light_at(&mut self, x: usize, y: usize, z: usize, light: u8) -> Option<()> {
         if let Some(section) = self.section_for_y_mut(y)? {
-            section.set_block_light_at(x, y, z, light)
+            section.set_block_light_at(x, y % SECTION_HEIGHT, z, light)
         } else {
             Some(())
         }
@@ -215,7 +228,7 @@ impl Chunk {
 
     pub fn set_sky_light_at(&mut self, x: usize, y: usize, z: usize, light: u8) -> Option<()> {
         if let Some(section) = self.section_for_y_mut(y)? {
-            section.set_sky_light_at(x, y, z, light)
+            section.set_sky_light_at(x, y % SECTION_HEIGHT, z, light)
         } else {
             Some(())
         }
@@ -289,7 +289,11 @@ fn generate_table(blocks: &Blocks) -> TokenStream {
         fns.push(quote! {
             #[doc = #doc]
             pub fn #name(&self, kind: BlockKind, state: u16) -> Option<#property> {
-                let (offset_coefficient, stride) = self.#name[kind as u16 as usize];
+                // Kinds added after the block data have no properties
+                let (offset_coefficient, stride) = self.#name
+                    .get(kind as u16 as usize)
+                    .copied()
+                    .unwrap_or_default();
 
                 if offset_coefficient == 0 {
                     return None;
@@ -307,7 +311,10 @@ fn generate_table(blocks: &Blocks) -> TokenStream {
         fns.push(quote! {
             #[doc = #doc]
             pub fn #set(&self, kind: BlockKind, state: u16, value: #property) -> Option<u16> {
-                let (offset_coefficient, stride) = self.#name[kind as u16 as usize];
+                let (offset_coefficient, stride) = self.#name
+                    .get(kind as u16 as usize)
+                    .copied()
+                    .unwrap_or_default();
 
                 if offset_coefficient == 0 {
                     return None;
@@ -90,6 +90,10 @@ impl BlockId {
                     0
                 }
             }
+            BlockKind::RespawnAnchor => match self.charges().unwrap() {
+                0 => 0,
+                charges => (charges * 4 - 1) as u8,
+            },
             BlockKind::EndRod | BlockKind::Torch => 14,
             BlockKind::Furnace => 13,
             BlockKind::NetherPortal => 11,
@@ -7198,6 +7198,54 @@ impl BlockId {
         };
         block
     }
+    #[doc = "Returns an instance of `powder_snow` with default state values."]
+    pub fn powder_snow() -> Self {
+        let mut block = Self {
+            kind: BlockKind::PowderSnow,
+            state: 0,
+        };
+        block
+    }
+    #[doc = "Returns an instance of `sculk_sensor` with default state values."]
+    pub fn sculk_sensor() -> Self {
+        let mut block = Self {
+            kind: BlockKind::SculkSensor,
+            state: 0,
+        };
+        block
+    }
+    #[doc = "Returns an instance of `sculk` with default state values."]
+    pub fn sculk() -> Self {
+        let mut block = Self {
+            kind: BlockKind::Sculk,
+            state: 0,
+        };
+        block
+    }
+    #[doc = "Returns an instance of `sculk_vein` with default state values."]
+    pub fn sculk_vein() -> Self {
+        let mut block = Self {
+            kind: BlockKind::SculkVein,
+            state: 0,
+        };
+        block
+    }
+    #[doc = "Returns an instance of `sculk_catalyst` with default state values."]
+    pub fn sculk_catalyst() -> Self {
+        let mut block = Self {
+            kind: BlockKind::SculkCatalyst,
+            state: 0,
+        };
+        block
+    }
+    #[doc = "Returns an instance of `sculk_shrieker` with default state values."]
+    pub fn sculk_shrieker() -> Self {
+        let mut block = Self {
+            kind: BlockKind::SculkShrieker,
+            state: 0,
+        };
+        block
+    }
     pub fn age_0_1(self) -> Option<i32> {
         BLOCK_TABLE.age_0_1(self.kind, self.state)
     }
@@ -9404,6 +9452,12 @@ impl BlockId {
             BlockKind::ChiseledNetherBricks => "minecraft:chiseled_nether_bricks",
             BlockKind::CrackedNetherBricks => "minecraft:cracked_nether_bricks",
             BlockKind::QuartzBricks => "minecraft:quartz_bricks",
+            BlockKind::PowderSnow => "minecraft:powder_snow",
+            BlockKind::SculkSensor => "minecraft:sculk_sensor",
+            BlockKind::Sculk => "minecraft:sculk",
+            BlockKind::SculkVein => "minecraft:sculk_vein",
+            BlockKind::SculkCatalyst => "minecraft:sculk_catalyst",
+            BlockKind::SculkShrieker => "minecraft:sculk_shrieker",
         }
     }
     #[doc = "Returns a mapping from property name to property value for this block. Used to serialize blocks in vanilla world saves."]
@@ -10222,6 +10276,12 @@ impl BlockId {
             BlockKind::ChiseledNetherBricks => self.chiseled_nether_bricks_to_properties_map(),
             BlockKind::CrackedNetherBricks => self.cracked_nether_bricks_to_properties_map(),
             BlockKind::QuartzBricks => self.quartz_bricks_to_properties_map(),
+            BlockKind::PowderSnow => self.powder_snow_to_properties_map(),
+            BlockKind::SculkSensor => self.sculk_sensor_to_properties_map(),
+            BlockKind::Sculk => self.sculk_to_properties_map(),
+            BlockKind::SculkVein => self.sculk_vein_to_properties_map(),
+            BlockKind::SculkCatalyst => self.sculk_catalyst_to_properties_map(),
+            BlockKind::SculkShrieker => self.sculk_shrieker_to_properties_map(),
         }
     }
     fn air_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
@@ -19278,6 +19338,30 @@ impl BlockId {
         let mut map = BTreeMap::new();
         map
     }
+    fn powder_snow_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
+        let mut map = BTreeMap::new();
+        map
+    }
+    fn sculk_sensor_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
+        let mut map = BTreeMap::new();
+        map
+    }
+    fn sculk_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
+        let mut map = BTreeMap::new();
+        map
+    }
+    fn sculk_vein_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
+        let mut map = BTreeMap::new();
+        map
+    }
+    fn sculk_catalyst_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
+        let mut map = BTreeMap::new();
+        map
+    }
+    fn sculk_shrieker_to_properties_map(self) -> BTreeMap<&'static str, &'static str> {
+        let mut map = BTreeMap::new();
+        map
+    }
     #[doc = "Attempts to convert a block kind identifier (e.g. `minecraft::air`) and properties map to a `BlockId`."]
     pub fn from_identifier_and_properties(
         identifier: &str,
@@ -21083,6 +21167,18 @@ impl BlockId {
             "minecraft:quartz_bricks" => {
                 Self::quartz_bricks_from_identifier_and_properties(properties)
             }
+            "minecraft:powder_snow" => Self::powder_snow_from_identifier_and_properties(properties),
+            "minecraft:sculk_sensor" => {
+                Self::sculk_sensor_from_identifier_and_properties(properties)
+            }
+            "minecraft:sculk" => Self::sculk_from_identifier_and_properties(properties),
+            "minecraft:sculk_vein" => Self::sculk_vein_from_identifier_and_properties(properties),
+            "minecraft:sculk_catalyst" => {
+                Self::sculk_catalyst_from_identifier_and_properties(properties)
+            }
+            "minecraft:sculk_shrieker" => {
+                Self::sculk_shrieker_from_identifier_and_properties(properties)
+            }
             _ => None,
         }
     }
@@ -28851,6 +28947,34 @@ impl BlockId {
         let mut block = BlockId::quartz_bricks();
         Some(block)
     }
+    fn powder_snow_from_identifier_and_properties(map: &BTreeMap<String, String>) -> Option<Self> {
+        let mut block = BlockId::powder_snow();
+        Some(block)
+    }
+    fn sculk_sensor_from_identifier_and_properties(map: &BTreeMap<String, String>) -> Option<Self> {
+        let mut block = BlockId::sculk_sensor();
+        Some(block)
+    }
+    fn sculk_from_identifier_and_properties(map: &BTreeMap<String, String>) -> Option<Self> {
+        let mut block = BlockId::sculk();
+        Some(block)
+    }
+    fn sculk_vein_from_identifier_and_properties(map: &BTreeMap<String, String>) -> Option<Self> {
+        let mut block = BlockId::sculk_vein();
+        Some(block)
+    }
+    fn sculk_catalyst_from_identifier_and_properties(
+        map: &BTreeMap<String, String>,
+    ) -> Option<Self> {
+        let mut block = BlockId::sculk_catalyst();
+        Some(block)
+    }
+    fn sculk_shrieker_from_identifier_and_properties(
+        map: &BTreeMap<String, String>,
+    ) -> Option<Self> {
+        let mut block = BlockId::sculk_shrieker();
+        Some(block)
+    }
     #[doc = "Attempts to convert a block identifier to a block with default property values."]
     pub fn from_identifier(identifier: &str) -> Option<Self> {
         match identifier {
@@ -29639,6 +29763,12 @@ impl BlockId {
             "minecraft:chiseled_nether_bricks" => Some(Self::chiseled_nether_bricks()),
             "minecraft:cracked_nether_bricks" => Some(Self::cracked_nether_bricks()),
             "minecraft:quartz_bricks" => Some(Self::quartz_bricks()),
+            "minecraft:powder_snow" => Some(Self::powder_snow()),
+            "minecraft:sculk_sensor" => Some(Self::sculk_sensor()),
+            "minecraft:sculk" => Some(Self::sculk()),
+            "minecraft:sculk_vein" => Some(Self::sculk_vein()),
+            "minecraft:sculk_catalyst" => Some(Self::sculk_catalyst()),
+            "minecraft:sculk_shrieker" => Some(Self::sculk_shrieker()),
             _ => None,
         }
     }
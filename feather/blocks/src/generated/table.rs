@@ -97,7 +97,11 @@ pub struct BlockTable {
 impl BlockTable {
     #[doc = "Retrieves the `age_0_1` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_1(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_1[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_1
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -106,7 +110,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_1` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_1(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_1[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_1
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -116,7 +124,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `age_0_15` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_15(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_15[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_15
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -125,7 +137,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_15` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_15(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_15[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_15
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -135,7 +151,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `age_0_2` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_2(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_2[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_2
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -144,7 +164,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_2` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_2(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_2[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_2
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -154,7 +178,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `age_0_25` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_25(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_25[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_25
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -163,7 +191,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_25` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_25(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_25[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_25
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -173,7 +205,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `age_0_3` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_3(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_3[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_3
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -182,7 +218,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_3` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_3(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_3[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_3
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -192,7 +232,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `age_0_5` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_5(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_5[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_5
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -201,7 +245,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_5` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_5(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_5[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_5
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -211,7 +259,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `age_0_7` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn age_0_7(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.age_0_7[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_7
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -220,7 +272,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `age_0_7` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_age_0_7(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.age_0_7[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .age_0_7
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -230,7 +286,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `attached` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn attached(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.attached[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .attached
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -239,7 +299,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `attached` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_attached(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.attached[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .attached
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -249,7 +313,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `attachment` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn attachment(&self, kind: BlockKind, state: u16) -> Option<Attachment> {
-        let (offset_coefficient, stride) = self.attachment[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .attachment
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -258,7 +326,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `attachment` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_attachment(&self, kind: BlockKind, state: u16, value: Attachment) -> Option<u16> {
-        let (offset_coefficient, stride) = self.attachment[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .attachment
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -268,7 +340,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `axis_xyz` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn axis_xyz(&self, kind: BlockKind, state: u16) -> Option<AxisXyz> {
-        let (offset_coefficient, stride) = self.axis_xyz[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .axis_xyz
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -277,7 +353,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `axis_xyz` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_axis_xyz(&self, kind: BlockKind, state: u16, value: AxisXyz) -> Option<u16> {
-        let (offset_coefficient, stride) = self.axis_xyz[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .axis_xyz
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -287,7 +367,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `axis_xz` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn axis_xz(&self, kind: BlockKind, state: u16) -> Option<AxisXz> {
-        let (offset_coefficient, stride) = self.axis_xz[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .axis_xz
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -296,7 +380,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `axis_xz` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_axis_xz(&self, kind: BlockKind, state: u16, value: AxisXz) -> Option<u16> {
-        let (offset_coefficient, stride) = self.axis_xz[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .axis_xz
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -306,7 +394,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `bites` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn bites(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.bites[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .bites
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -315,7 +407,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `bites` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_bites(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.bites[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .bites
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -325,7 +421,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `bottom` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn bottom(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.bottom[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .bottom
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -334,7 +434,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `bottom` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_bottom(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.bottom[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .bottom
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -344,7 +448,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `cauldron_level` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn cauldron_level(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.cauldron_level[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .cauldron_level
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -353,7 +461,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `cauldron_level` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_cauldron_level(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.cauldron_level[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .cauldron_level
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -363,7 +475,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `charges` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn charges(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.charges[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .charges
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -372,7 +488,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `charges` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_charges(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.charges[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .charges
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -382,7 +502,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `chest_kind` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn chest_kind(&self, kind: BlockKind, state: u16) -> Option<ChestKind> {
-        let (offset_coefficient, stride) = self.chest_kind[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .chest_kind
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -391,7 +515,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `chest_kind` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_chest_kind(&self, kind: BlockKind, state: u16, value: ChestKind) -> Option<u16> {
-        let (offset_coefficient, stride) = self.chest_kind[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .chest_kind
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -401,7 +529,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `comparator_mode` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn comparator_mode(&self, kind: BlockKind, state: u16) -> Option<ComparatorMode> {
-        let (offset_coefficient, stride) = self.comparator_mode[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .comparator_mode
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -415,7 +547,11 @@ impl BlockTable {
         state: u16,
         value: ComparatorMode,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.comparator_mode[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .comparator_mode
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -425,7 +561,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `conditional` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn conditional(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.conditional[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .conditional
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -434,7 +574,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `conditional` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_conditional(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.conditional[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .conditional
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -444,7 +588,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `delay` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn delay(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.delay[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .delay
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -453,7 +601,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `delay` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_delay(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.delay[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .delay
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -463,7 +615,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `disarmed` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn disarmed(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.disarmed[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .disarmed
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -472,7 +628,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `disarmed` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_disarmed(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.disarmed[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .disarmed
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -482,7 +642,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `distance_0_7` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn distance_0_7(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.distance_0_7[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .distance_0_7
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -491,7 +655,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `distance_0_7` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_distance_0_7(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.distance_0_7[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .distance_0_7
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -501,7 +669,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `distance_1_7` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn distance_1_7(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.distance_1_7[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .distance_1_7
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -510,7 +682,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `distance_1_7` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_distance_1_7(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.distance_1_7[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .distance_1_7
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -520,7 +696,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `down` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn down(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.down[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .down
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -529,7 +709,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `down` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_down(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.down[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .down
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -539,7 +723,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `drag` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn drag(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.drag[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .drag
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -548,7 +736,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `drag` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_drag(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.drag[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .drag
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -558,7 +750,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `east_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn east_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.east_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .east_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -567,7 +763,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `east_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_east_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.east_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .east_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -577,7 +777,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `east_nlt` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn east_nlt(&self, kind: BlockKind, state: u16) -> Option<EastNlt> {
-        let (offset_coefficient, stride) = self.east_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .east_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -586,7 +790,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `east_nlt` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_east_nlt(&self, kind: BlockKind, state: u16, value: EastNlt) -> Option<u16> {
-        let (offset_coefficient, stride) = self.east_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .east_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -596,7 +804,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `east_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn east_wire(&self, kind: BlockKind, state: u16) -> Option<EastWire> {
-        let (offset_coefficient, stride) = self.east_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .east_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -605,7 +817,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `east_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_east_wire(&self, kind: BlockKind, state: u16, value: EastWire) -> Option<u16> {
-        let (offset_coefficient, stride) = self.east_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .east_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -615,7 +831,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `eggs` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn eggs(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.eggs[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .eggs
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -624,7 +844,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `eggs` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_eggs(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.eggs[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .eggs
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -634,7 +858,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `enabled` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn enabled(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.enabled[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .enabled
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -643,7 +871,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `enabled` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_enabled(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.enabled[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .enabled
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -653,7 +885,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `extended` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn extended(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.extended[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .extended
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -662,7 +898,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `extended` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_extended(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.extended[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .extended
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -672,7 +912,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `eye` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn eye(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.eye[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .eye
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -681,7 +925,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `eye` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_eye(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.eye[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .eye
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -691,7 +939,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `face` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn face(&self, kind: BlockKind, state: u16) -> Option<Face> {
-        let (offset_coefficient, stride) = self.face[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .face
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -700,7 +952,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `face` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_face(&self, kind: BlockKind, state: u16, value: Face) -> Option<u16> {
-        let (offset_coefficient, stride) = self.face[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .face
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -710,7 +966,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `facing_cardinal` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn facing_cardinal(&self, kind: BlockKind, state: u16) -> Option<FacingCardinal> {
-        let (offset_coefficient, stride) = self.facing_cardinal[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .facing_cardinal
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -724,7 +984,11 @@ impl BlockTable {
         state: u16,
         value: FacingCardinal,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.facing_cardinal[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .facing_cardinal
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -738,7 +1002,11 @@ impl BlockTable {
         kind: BlockKind,
         state: u16,
     ) -> Option<FacingCardinalAndDown> {
-        let (offset_coefficient, stride) = self.facing_cardinal_and_down[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .facing_cardinal_and_down
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -752,7 +1020,11 @@ impl BlockTable {
         state: u16,
         value: FacingCardinalAndDown,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.facing_cardinal_and_down[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .facing_cardinal_and_down
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -762,7 +1034,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `facing_cubic` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn facing_cubic(&self, kind: BlockKind, state: u16) -> Option<FacingCubic> {
-        let (offset_coefficient, stride) = self.facing_cubic[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .facing_cubic
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -771,7 +1047,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `facing_cubic` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_facing_cubic(&self, kind: BlockKind, state: u16, value: FacingCubic) -> Option<u16> {
-        let (offset_coefficient, stride) = self.facing_cubic[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .facing_cubic
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -781,7 +1061,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `half_top_bottom` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn half_top_bottom(&self, kind: BlockKind, state: u16) -> Option<HalfTopBottom> {
-        let (offset_coefficient, stride) = self.half_top_bottom[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .half_top_bottom
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -795,7 +1079,11 @@ impl BlockTable {
         state: u16,
         value: HalfTopBottom,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.half_top_bottom[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .half_top_bottom
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -805,7 +1093,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `half_upper_lower` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn half_upper_lower(&self, kind: BlockKind, state: u16) -> Option<HalfUpperLower> {
-        let (offset_coefficient, stride) = self.half_upper_lower[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .half_upper_lower
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -819,7 +1111,11 @@ impl BlockTable {
         state: u16,
         value: HalfUpperLower,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.half_upper_lower[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .half_upper_lower
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -829,7 +1125,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `hanging` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn hanging(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.hanging[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .hanging
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -838,7 +1138,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `hanging` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_hanging(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.hanging[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .hanging
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -848,7 +1152,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `has_book` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn has_book(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.has_book[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_book
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -857,7 +1165,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `has_book` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_has_book(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.has_book[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_book
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -867,7 +1179,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `has_bottle_0` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn has_bottle_0(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.has_bottle_0[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_bottle_0
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -876,7 +1192,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `has_bottle_0` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_has_bottle_0(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.has_bottle_0[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_bottle_0
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -886,7 +1206,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `has_bottle_1` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn has_bottle_1(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.has_bottle_1[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_bottle_1
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -895,7 +1219,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `has_bottle_1` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_has_bottle_1(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.has_bottle_1[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_bottle_1
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -905,7 +1233,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `has_bottle_2` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn has_bottle_2(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.has_bottle_2[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_bottle_2
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -914,7 +1246,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `has_bottle_2` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_has_bottle_2(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.has_bottle_2[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_bottle_2
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -924,7 +1260,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `has_record` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn has_record(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.has_record[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_record
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -933,7 +1273,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `has_record` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_has_record(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.has_record[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .has_record
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -943,7 +1287,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `hatch` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn hatch(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.hatch[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .hatch
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -952,7 +1300,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `hatch` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_hatch(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.hatch[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .hatch
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -962,7 +1314,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `hinge` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn hinge(&self, kind: BlockKind, state: u16) -> Option<Hinge> {
-        let (offset_coefficient, stride) = self.hinge[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .hinge
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -971,7 +1327,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `hinge` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_hinge(&self, kind: BlockKind, state: u16, value: Hinge) -> Option<u16> {
-        let (offset_coefficient, stride) = self.hinge[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .hinge
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -981,7 +1341,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `honey_level` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn honey_level(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.honey_level[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .honey_level
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -990,7 +1354,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `honey_level` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_honey_level(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.honey_level[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .honey_level
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1000,7 +1368,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `in_wall` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn in_wall(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.in_wall[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .in_wall
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1009,7 +1381,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `in_wall` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_in_wall(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.in_wall[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .in_wall
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1019,7 +1395,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `instrument` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn instrument(&self, kind: BlockKind, state: u16) -> Option<Instrument> {
-        let (offset_coefficient, stride) = self.instrument[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .instrument
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1028,7 +1408,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `instrument` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_instrument(&self, kind: BlockKind, state: u16, value: Instrument) -> Option<u16> {
-        let (offset_coefficient, stride) = self.instrument[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .instrument
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1038,7 +1422,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `inverted` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn inverted(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.inverted[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .inverted
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1047,7 +1435,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `inverted` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_inverted(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.inverted[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .inverted
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1057,7 +1449,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `layers` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn layers(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.layers[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .layers
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1066,7 +1462,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `layers` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_layers(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.layers[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .layers
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1076,7 +1476,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `leaves` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn leaves(&self, kind: BlockKind, state: u16) -> Option<Leaves> {
-        let (offset_coefficient, stride) = self.leaves[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .leaves
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1085,7 +1489,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `leaves` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_leaves(&self, kind: BlockKind, state: u16, value: Leaves) -> Option<u16> {
-        let (offset_coefficient, stride) = self.leaves[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .leaves
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1095,7 +1503,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `level_0_8` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn level_0_8(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.level_0_8[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .level_0_8
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1104,7 +1516,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `level_0_8` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_level_0_8(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.level_0_8[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .level_0_8
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1114,7 +1530,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `lit` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn lit(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.lit[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .lit
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1123,7 +1543,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `lit` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_lit(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.lit[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .lit
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1133,7 +1557,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `locked` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn locked(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.locked[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .locked
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1142,7 +1570,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `locked` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_locked(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.locked[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .locked
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1152,7 +1584,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `moisture` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn moisture(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.moisture[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .moisture
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1161,7 +1597,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `moisture` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_moisture(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.moisture[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .moisture
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1171,7 +1611,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `north_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn north_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.north_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .north_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1180,7 +1624,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `north_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_north_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.north_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .north_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1190,7 +1638,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `north_nlt` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn north_nlt(&self, kind: BlockKind, state: u16) -> Option<NorthNlt> {
-        let (offset_coefficient, stride) = self.north_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .north_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1199,7 +1651,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `north_nlt` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_north_nlt(&self, kind: BlockKind, state: u16, value: NorthNlt) -> Option<u16> {
-        let (offset_coefficient, stride) = self.north_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .north_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1209,7 +1665,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `north_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn north_wire(&self, kind: BlockKind, state: u16) -> Option<NorthWire> {
-        let (offset_coefficient, stride) = self.north_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .north_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1218,7 +1678,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `north_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_north_wire(&self, kind: BlockKind, state: u16, value: NorthWire) -> Option<u16> {
-        let (offset_coefficient, stride) = self.north_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .north_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1228,7 +1692,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `note` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn note(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.note[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .note
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1237,7 +1705,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `note` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_note(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.note[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .note
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1247,7 +1719,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `occupied` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn occupied(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.occupied[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .occupied
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1256,7 +1732,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `occupied` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_occupied(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.occupied[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .occupied
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1266,7 +1746,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `open` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn open(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.open[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .open
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1275,7 +1759,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `open` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_open(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.open[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .open
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1285,7 +1773,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `orientation` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn orientation(&self, kind: BlockKind, state: u16) -> Option<Orientation> {
-        let (offset_coefficient, stride) = self.orientation[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .orientation
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1294,7 +1786,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `orientation` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_orientation(&self, kind: BlockKind, state: u16, value: Orientation) -> Option<u16> {
-        let (offset_coefficient, stride) = self.orientation[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .orientation
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1304,7 +1800,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `part` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn part(&self, kind: BlockKind, state: u16) -> Option<Part> {
-        let (offset_coefficient, stride) = self.part[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .part
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1313,7 +1813,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `part` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_part(&self, kind: BlockKind, state: u16, value: Part) -> Option<u16> {
-        let (offset_coefficient, stride) = self.part[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .part
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1323,7 +1827,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `persistent` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn persistent(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.persistent[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .persistent
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1332,7 +1840,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `persistent` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_persistent(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.persistent[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .persistent
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1342,7 +1854,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `pickles` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn pickles(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.pickles[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .pickles
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1351,7 +1867,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `pickles` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_pickles(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.pickles[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .pickles
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1361,7 +1881,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `piston_kind` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn piston_kind(&self, kind: BlockKind, state: u16) -> Option<PistonKind> {
-        let (offset_coefficient, stride) = self.piston_kind[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .piston_kind
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1370,7 +1894,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `piston_kind` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_piston_kind(&self, kind: BlockKind, state: u16, value: PistonKind) -> Option<u16> {
-        let (offset_coefficient, stride) = self.piston_kind[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .piston_kind
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1380,7 +1908,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `power` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn power(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.power[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .power
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1389,7 +1921,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `power` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_power(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.power[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .power
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1399,7 +1935,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `powered` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn powered(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.powered[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .powered
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1408,7 +1948,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `powered` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_powered(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.powered[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .powered
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1418,7 +1962,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `powered_rail_shape` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn powered_rail_shape(&self, kind: BlockKind, state: u16) -> Option<PoweredRailShape> {
-        let (offset_coefficient, stride) = self.powered_rail_shape[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .powered_rail_shape
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1432,7 +1980,11 @@ impl BlockTable {
         state: u16,
         value: PoweredRailShape,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.powered_rail_shape[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .powered_rail_shape
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1442,7 +1994,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `rail_shape` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn rail_shape(&self, kind: BlockKind, state: u16) -> Option<RailShape> {
-        let (offset_coefficient, stride) = self.rail_shape[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .rail_shape
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1451,7 +2007,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `rail_shape` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_rail_shape(&self, kind: BlockKind, state: u16, value: RailShape) -> Option<u16> {
-        let (offset_coefficient, stride) = self.rail_shape[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .rail_shape
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1461,7 +2021,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `rotation` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn rotation(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.rotation[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .rotation
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1470,7 +2034,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `rotation` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_rotation(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.rotation[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .rotation
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1480,7 +2048,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `short` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn short(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.short[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .short
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1489,7 +2061,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `short` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_short(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.short[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .short
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1499,7 +2075,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `signal_fire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn signal_fire(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.signal_fire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .signal_fire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1508,7 +2088,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `signal_fire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_signal_fire(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.signal_fire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .signal_fire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1518,7 +2102,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `slab_kind` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn slab_kind(&self, kind: BlockKind, state: u16) -> Option<SlabKind> {
-        let (offset_coefficient, stride) = self.slab_kind[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .slab_kind
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1527,7 +2115,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `slab_kind` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_slab_kind(&self, kind: BlockKind, state: u16, value: SlabKind) -> Option<u16> {
-        let (offset_coefficient, stride) = self.slab_kind[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .slab_kind
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1537,7 +2129,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `snowy` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn snowy(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.snowy[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .snowy
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1546,7 +2142,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `snowy` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_snowy(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.snowy[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .snowy
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1556,7 +2156,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `south_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn south_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.south_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .south_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1565,7 +2169,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `south_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_south_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.south_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .south_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1575,7 +2183,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `south_nlt` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn south_nlt(&self, kind: BlockKind, state: u16) -> Option<SouthNlt> {
-        let (offset_coefficient, stride) = self.south_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .south_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1584,7 +2196,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `south_nlt` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_south_nlt(&self, kind: BlockKind, state: u16, value: SouthNlt) -> Option<u16> {
-        let (offset_coefficient, stride) = self.south_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .south_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1594,7 +2210,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `south_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn south_wire(&self, kind: BlockKind, state: u16) -> Option<SouthWire> {
-        let (offset_coefficient, stride) = self.south_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .south_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1603,7 +2223,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `south_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_south_wire(&self, kind: BlockKind, state: u16, value: SouthWire) -> Option<u16> {
-        let (offset_coefficient, stride) = self.south_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .south_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1613,7 +2237,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `stage` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn stage(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.stage[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .stage
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1622,7 +2250,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `stage` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_stage(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.stage[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .stage
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1632,7 +2264,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `stairs_shape` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn stairs_shape(&self, kind: BlockKind, state: u16) -> Option<StairsShape> {
-        let (offset_coefficient, stride) = self.stairs_shape[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .stairs_shape
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1641,7 +2277,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `stairs_shape` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_stairs_shape(&self, kind: BlockKind, state: u16, value: StairsShape) -> Option<u16> {
-        let (offset_coefficient, stride) = self.stairs_shape[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .stairs_shape
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1651,7 +2291,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `structure_block_mode` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn structure_block_mode(&self, kind: BlockKind, state: u16) -> Option<StructureBlockMode> {
-        let (offset_coefficient, stride) = self.structure_block_mode[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .structure_block_mode
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1665,7 +2309,11 @@ impl BlockTable {
         state: u16,
         value: StructureBlockMode,
     ) -> Option<u16> {
-        let (offset_coefficient, stride) = self.structure_block_mode[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .structure_block_mode
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1675,7 +2323,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `triggered` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn triggered(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.triggered[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .triggered
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1684,7 +2336,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `triggered` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_triggered(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.triggered[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .triggered
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1694,7 +2350,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `unstable` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn unstable(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.unstable[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .unstable
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1703,7 +2363,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `unstable` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_unstable(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.unstable[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .unstable
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1713,7 +2377,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `up` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn up(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.up[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .up
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1722,7 +2390,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `up` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_up(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.up[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .up
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1732,7 +2404,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `water_level` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn water_level(&self, kind: BlockKind, state: u16) -> Option<i32> {
-        let (offset_coefficient, stride) = self.water_level[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .water_level
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1741,7 +2417,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `water_level` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_water_level(&self, kind: BlockKind, state: u16, value: i32) -> Option<u16> {
-        let (offset_coefficient, stride) = self.water_level[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .water_level
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1751,7 +2431,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `waterlogged` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn waterlogged(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.waterlogged[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .waterlogged
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1760,7 +2444,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `waterlogged` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_waterlogged(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.waterlogged[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .waterlogged
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1770,7 +2458,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `west_connected` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn west_connected(&self, kind: BlockKind, state: u16) -> Option<bool> {
-        let (offset_coefficient, stride) = self.west_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .west_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1779,7 +2471,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `west_connected` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_west_connected(&self, kind: BlockKind, state: u16, value: bool) -> Option<u16> {
-        let (offset_coefficient, stride) = self.west_connected[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .west_connected
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1789,7 +2485,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `west_nlt` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn west_nlt(&self, kind: BlockKind, state: u16) -> Option<WestNlt> {
-        let (offset_coefficient, stride) = self.west_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .west_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1798,7 +2498,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `west_nlt` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_west_nlt(&self, kind: BlockKind, state: u16, value: WestNlt) -> Option<u16> {
-        let (offset_coefficient, stride) = self.west_nlt[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .west_nlt
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1808,7 +2512,11 @@ impl BlockTable {
     }
     #[doc = "Retrieves the `west_wire` value for the given block kind with the given state value.\n        Returns the value of the property, or `None` if it does not exist."]
     pub fn west_wire(&self, kind: BlockKind, state: u16) -> Option<WestWire> {
-        let (offset_coefficient, stride) = self.west_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .west_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -1817,7 +2525,11 @@ impl BlockTable {
     }
     #[doc = "Updates the state value for the given block kind such that its `west_wire` value is updated. Returns the new state,\n        or `None` if the block does not have this property."]
     pub fn set_west_wire(&self, kind: BlockKind, state: u16, value: WestWire) -> Option<u16> {
-        let (offset_coefficient, stride) = self.west_wire[kind as u16 as usize];
+        let (offset_coefficient, stride) = self
+            .west_wire
+            .get(kind as u16 as usize)
+            .copied()
+            .unwrap_or_default();
         if offset_coefficient == 0 {
             return None;
         }
@@ -89,8 +89,27 @@ impl BlockId {
     }
 
     /// Returns the vanilla state ID for this block.
+    ///
+    /// Blocks added after the supported version have no ID of their
+    /// own and use the ID of a similar block clients know.
     pub fn vanilla_id(self) -> u16 {
-        VANILLA_ID_TABLE[self.kind as u16 as usize][self.state as usize]
+        match VANILLA_ID_TABLE.get(self.kind as u16 as usize) {
+            Some(ids) => ids[self.state as usize],
+            None => self.stand_in().vanilla_id(),
+        }
+    }
+
+    /// The block sent to clients in place of a block added
+    /// after the supported version.
+    fn stand_in(self) -> BlockId {
+        match self.kind {
+            BlockKind::PowderSnow => BlockId::snow_block(),
+            BlockKind::SculkSensor
+            | BlockKind::Sculk
+            | BlockKind::SculkCatalyst
+            | BlockKind::SculkShrieker => BlockId::black_concrete(),
+            _ => BlockId::air(),
+        }
     }
 
     /*
@@ -226,4 +245,12 @@ mod tests {
         assert_eq!(block.layers(), Some(1));
         assert_eq!(block.to_properties_map()["layers"], "1");
     }
+
+    #[test]
+    fn newer_blocks_are_sent_as_a_stand_in() {
+        let block = BlockId::powder_snow();
+        assert_eq!(block.vanilla_id(), BlockId::snow_block().vanilla_id());
+        assert_eq!(block.waterlogged(), None);
+        assert_eq!(BlockId::from_identifier(block.identifier()), Some(block));
+    }
 }
@@ -0,0 +1,121 @@
+//! Saving changed chunks to disk, periodically and when the server
+//! shuts down.
+//!
+//! Chunks are marked dirty in the
+//! [`BlockWorldIntegration`](libcraft_blocks::BlockWorldIntegration)
+//! when their blocks change, and only dirty chunks are written.
+
+use base::ChunkPosition;
+use ecs::{SysResult, SystemExecutor, SystemStage};
+
+use crate::{chunk::save, Game};
+
+/// Ticks between autosaves, unless configured otherwise: five minutes
+pub const DEFAULT_INTERVAL: u64 = 6000;
+
+/// How often changed chunks are saved, in ticks.
+/// Zero disables autosaving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutosaveInterval(pub u64);
+
+impl Default for AutosaveInterval {
+    fn default() -> Self {
+        AutosaveInterval(DEFAULT_INTERVAL)
+    }
+}
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(AutosaveInterval::default());
+    systems.add_system_to_stage(SystemStage::Cleanup, autosave);
+}
+
+/// Queues the loaded chunks that changed since they were last saved
+/// to be written to disk, skipping the rest.
+///
+/// Returns the positions of the saved chunks.
+pub fn save_dirty_chunks(game: &mut Game) -> Vec<ChunkPosition> {
+    let mut saved: Vec<ChunkPosition> = game
+        .block_integration
+        .take_dirty_chunks()
+        .into_iter()
+        .filter(|pos| {
+            let data = save::chunk_save_data(game, *pos);
+            game.world.save_chunk(*pos, data)
+        })
+        .collect();
+    saved.sort_by_key(|pos| (pos.x, pos.z));
+    saved
+}
+
+/// Saves all changed chunks before the server shuts down,
+/// waiting until they have been written.
+pub fn save_on_shutdown(game: &mut Game) {
+    let saved = save_dirty_chunks(game);
+    game.world.flush_chunk_saves();
+    log::info!("Saved {} chunks before shutting down", saved.len());
+}
+
+fn autosave(game: &mut Game) -> SysResult {
+    let interval = game
+        .resources
+        .get::<AutosaveInterval>()
+        .map_or(DEFAULT_INTERVAL, |interval| interval.0);
+    if interval == 0 || game.tick_count == 0 || game.tick_count % interval != 0 {
+        return Ok(());
+    }
+
+    let saved = save_dirty_chunks(game);
+    if !saved.is_empty() {
+        log::debug!("Autosaved {} chunks", saved.len());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryInto, sync::Arc};
+
+    use base::{BlockId, BlockPosition, Chunk};
+    use worldgen::VoidWorldGenerator;
+
+    use crate::World;
+
+    use super::*;
+
+    #[test]
+    fn autosave_only_writes_dirty_chunks() {
+        let mut game = Game::new();
+        let dir = tempfile::tempdir().unwrap();
+        game.world = World::with_gen_and_path(Arc::new(VoidWorldGenerator), dir.path());
+        for x in 0..2 {
+            game.world
+                .chunk_map_mut()
+                .insert_chunk(Chunk::new(ChunkPosition::new(x, 0)));
+        }
+        game.insert_resource(AutosaveInterval(20));
+        let dirty = |game: &Game, x| {
+            game.block_integration
+                .is_chunk_dirty(ChunkPosition::new(x, 0))
+        };
+
+        let pos = BlockPosition::new(20, 64, 4).try_into().unwrap();
+        game.set_block(pos, BlockId::stone());
+        assert!(dirty(&game, 1));
+        assert!(!dirty(&game, 0));
+        assert_eq!(save_dirty_chunks(&mut game), vec![ChunkPosition::new(1, 0)]);
+        // Chunks stay clean until changed again
+        assert!(save_dirty_chunks(&mut game).is_empty());
+
+        // Nothing is saved between autosaves
+        game.set_block(pos, BlockId::air());
+        game.tick_count = 19;
+        autosave(&mut game).unwrap();
+        assert!(dirty(&game, 1));
+
+        game.tick_count = 20;
+        autosave(&mut game).unwrap();
+        assert!(!dirty(&game, 1));
+        // Finish writing before the directory is removed
+        game.world.flush_chunk_saves();
+    }
+}
@@ -1,27 +1,176 @@
-use base::biome::Biome;
-use base::chunk::BiomeStore;
-use ecs::{Entity, IntoQuery, SysResult, SystemExecutor};
-use quill_common::components::Position;
+use base::{Biome, EntityKind, ParticleKind, Position, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use quill_common::entities::Player;
 use std::collections::HashMap;
+use std::convert::TryInto;
 
 use crate::Game;
-use crate::entities::spawning::EntitySpawnManager;
 
 /// Handles integration of biomes with other game systems
 pub struct BiomeIntegration {
     /// Maps entity types to the biomes they can spawn in
     entity_spawn_biomes: HashMap<EntityKind, Vec<Biome>>,
     /// Maps biomes to their ambient sound events
-    biome_ambient_sounds: HashMap<Biome, SoundEvent>,
+    biome_ambient_sounds: HashMap<Biome, &'static str>,
+    /// Maps biomes to the particles shown around players in them
+    biome_ambient_particles: HashMap<Biome, AmbientParticles>,
     /// Maps biomes to their climate characteristics
     biome_climate: HashMap<Biome, BiomeClimate>,
 }
 
+/// Cosmetic particles a biome shows around the players in it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmbientParticles {
+    pub kind: ParticleKind,
+    /// Ticks between each emission
+    pub interval: u64,
+    pub count: i32,
+    /// How far around the player the particles scatter
+    pub spread: f32,
+}
+
 /// Climate characteristics of a biome
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct BiomeClimate {
     temperature: f32,
     humidity: f32,
-    precipitation: bool,
+    precipitation: Precipitation,
+}
+
+/// What falls in a biome when it rains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precipitation {
+    None,
+    Rain,
+    Snow,
+}
+
+/// Biomes with climate data, and what the world generator can pick
+const CORE_BIOMES: [Biome; 22] = [
+    Biome::Ocean,
+    Biome::DeepOcean,
+    Biome::WarmOcean,
+    Biome::FrozenOcean,
+    Biome::River,
+    Biome::FrozenRiver,
+    Biome::Beach,
+    Biome::SnowyBeach,
+    Biome::Plains,
+    Biome::Forest,
+    Biome::BirchForest,
+    Biome::DarkForest,
+    Biome::Taiga,
+    Biome::SnowyTaiga,
+    Biome::SnowyTundra,
+    Biome::IceSpikes,
+    Biome::Mountains,
+    Biome::Swamp,
+    Biome::Jungle,
+    Biome::Savanna,
+    Biome::Desert,
+    Biome::Badlands,
+];
+
+/// The climate of one of the core biomes, with the
+/// temperature and humidity Minecraft gives it
+fn core_climate(biome: Biome) -> Option<BiomeClimate> {
+    use Precipitation::{Rain, Snow};
+    let (temperature, humidity, precipitation) = match biome {
+        Biome::Ocean | Biome::DeepOcean | Biome::WarmOcean | Biome::River => (0.5, 0.5, Rain),
+        Biome::FrozenOcean | Biome::FrozenRiver => (0.0, 0.5, Snow),
+        Biome::Beach => (0.8, 0.4, Rain),
+        Biome::SnowyBeach => (0.05, 0.3, Snow),
+        Biome::Plains => (0.8, 0.4, Rain),
+        Biome::Forest | Biome::DarkForest => (0.7, 0.8, Rain),
+        Biome::BirchForest => (0.6, 0.6, Rain),
+        Biome::Taiga => (0.25, 0.8, Rain),
+        Biome::SnowyTaiga => (-0.5, 0.4, Snow),
+        Biome::SnowyTundra | Biome::IceSpikes => (0.0, 0.5, Snow),
+        Biome::Mountains => (0.2, 0.3, Rain),
+        Biome::Swamp => (0.8, 0.9, Rain),
+        Biome::Jungle => (0.95, 0.9, Rain),
+        Biome::Savanna => (1.2, 0.0, Precipitation::None),
+        Biome::Desert | Biome::Badlands => (2.0, 0.0, Precipitation::None),
+        _ => return None,
+    };
+    Some(BiomeClimate {
+        temperature,
+        humidity,
+        precipitation,
+    })
+}
+
+/// Picks the biome for a climate. World generation uses it
+/// through [`worldgen::ClimateBiomes::with_selector`].
+///
+/// Each value is in `-1.0..=1.0`: cold to hot, dry to wet, and
+/// deep ocean to far inland.
+pub fn biome_for_climate(temperature: f32, humidity: f32, continentalness: f32) -> Biome {
+    if continentalness < -0.5 {
+        return match temperature {
+            t if t < -0.45 => Biome::FrozenOcean,
+            t if t > 0.45 => Biome::WarmOcean,
+            _ => Biome::DeepOcean,
+        };
+    }
+    if continentalness < -0.2 {
+        return if temperature < -0.45 {
+            Biome::FrozenOcean
+        } else {
+            Biome::Ocean
+        };
+    }
+    if continentalness < -0.15 {
+        return if temperature < -0.45 {
+            Biome::SnowyBeach
+        } else {
+            Biome::Beach
+        };
+    }
+    if continentalness > 0.7 && temperature < 0.45 {
+        return Biome::Mountains;
+    }
+
+    match temperature {
+        // Frozen
+        t if t < -0.45 => match humidity {
+            h if h < -0.6 => Biome::IceSpikes,
+            h if h < 0.2 => Biome::SnowyTundra,
+            _ => Biome::SnowyTaiga,
+        },
+        // Cold
+        t if t < -0.15 => match humidity {
+            h if h < -0.2 => Biome::Plains,
+            _ => Biome::Taiga,
+        },
+        // Temperate
+        t if t < 0.15 => match humidity {
+            h if h < -0.3 => Biome::Plains,
+            h if h < 0.1 => Biome::Forest,
+            h if h < 0.4 => Biome::BirchForest,
+            h if h < 0.6 => Biome::DarkForest,
+            _ => Biome::Swamp,
+        },
+        // Warm
+        t if t < 0.45 => match humidity {
+            h if h < -0.35 => Biome::Savanna,
+            h if h < 0.3 => Biome::Plains,
+            _ => Biome::Jungle,
+        },
+        // Hot
+        _ => match humidity {
+            h if h < -0.5 => Biome::Badlands,
+            h if h < 0.0 => Biome::Desert,
+            h if h < 0.4 => Biome::Savanna,
+            _ => Biome::Jungle,
+        },
+    }
+}
+
+/// Returns what falls in a biome when it rains. Biomes
+/// without climate data get rain.
+pub fn precipitation_type(biome: Biome) -> Precipitation {
+    core_climate(biome).map_or(Precipitation::Rain, |climate| climate.precipitation)
 }
 
 impl BiomeIntegration {
@@ -30,11 +179,13 @@ impl BiomeIntegration {
         let mut integration = Self {
             entity_spawn_biomes: HashMap::new(),
             biome_ambient_sounds: HashMap::new(),
+            biome_ambient_particles: HashMap::new(),
             biome_climate: HashMap::new(),
         };
         
         integration.initialize_spawn_rules();
         integration.initialize_sound_mappings();
+        integration.initialize_particle_mappings();
         integration.initialize_climate_data();
         
         integration
@@ -42,22 +193,16 @@ impl BiomeIntegration {
     
     /// Initializes the entity spawning rules for each biome
     fn initialize_spawn_rules(&mut self) {
-        // Axolotl spawn rules
-        self.entity_spawn_biomes.insert(
-            EntityKind::Axolotl,
-            vec![Biome::LushCaves],
-        );
-        
         // Goat spawn rules
         self.entity_spawn_biomes.insert(
             EntityKind::Goat,
-            vec![Biome::FrozenPeaks, Biome::JaggedPeaks, Biome::SnowySlopes, Biome::MountainGrove],
+            vec![Biome::Mountains, Biome::SnowyMountains, Biome::WoodedMountains, Biome::GravellyMountains],
         );
         
         // Glow Squid spawn rules
         self.entity_spawn_biomes.insert(
             EntityKind::GlowSquid,
-            vec![Biome::LushCaves, Biome::DeepDark, Biome::DeepOcean],
+            vec![Biome::DeepOcean, Biome::DeepColdOcean, Biome::DeepLukewarmOcean],
         );
         
         // Additional entity spawn rules would be added here
@@ -65,32 +210,47 @@ impl BiomeIntegration {
     
     /// Initializes ambient sound mappings for biomes
     fn initialize_sound_mappings(&mut self) {
-        // Implementation for biome sound mappings
+        let sounds = [
+            (Biome::NetherWastes, "minecraft:ambient.nether_wastes.loop"),
+            (Biome::CrimsonForest, "minecraft:ambient.crimson_forest.loop"),
+            (Biome::WarpedForest, "minecraft:ambient.warped_forest.loop"),
+            (Biome::SoulSandValley, "minecraft:ambient.soul_sand_valley.loop"),
+            (Biome::BasaltDeltas, "minecraft:ambient.basalt_deltas.loop"),
+        ];
+        self.biome_ambient_sounds.extend(sounds.iter().copied());
+    }
+    
+    /// Initializes ambient particle mappings for biomes
+    fn initialize_particle_mappings(&mut self) {
+        let particles = [
+            (Biome::WarpedForest, ParticleKind::WarpedSpore, 2, 4, 8.0),
+            (Biome::CrimsonForest, ParticleKind::CrimsonSpore, 2, 4, 8.0),
+            (Biome::SoulSandValley, ParticleKind::Ash, 4, 3, 8.0),
+            (Biome::BasaltDeltas, ParticleKind::WhiteAsh, 2, 6, 8.0),
+            (Biome::MushroomFields, ParticleKind::Mycelium, 10, 2, 6.0),
+        ];
+        for (biome, kind, interval, count, spread) in particles.iter().copied() {
+            self.biome_ambient_particles.insert(
+                biome,
+                AmbientParticles { kind, interval, count, spread },
+            );
+        }
     }
     
     /// Initializes climate data for biomes
     fn initialize_climate_data(&mut self) {
-        // Implementation for biome climate characteristics
+        for biome in CORE_BIOMES.iter().copied() {
+            if let Some(climate) = core_climate(biome) {
+                self.biome_climate.insert(biome, climate);
+            }
+        }
     }
+
     
     /// Gets the biome at a specific position in the world
     pub fn get_biome_at_position(&self, game: &Game, pos: Position) -> Option<Biome> {
-        let block_pos = BlockPosition::from_position(pos);
-        let chunk_pos = ChunkPosition::from_block_pos(block_pos);
-        
-        if let Some(chunk) = game.world.chunk_at(chunk_pos) {
-            let biome_store = chunk.biomes();
-            let relative_pos = block_pos.relative_to_chunk();
-            
-            // Convert to biome coordinates (biomes are stored at lower resolution)
-            let biome_x = relative_pos.x as usize / BIOME_SAMPLE_RATE;
-            let biome_y = relative_pos.y as usize / BIOME_SAMPLE_RATE;
-            let biome_z = relative_pos.z as usize / BIOME_SAMPLE_RATE;
-            
-            return Some(biome_store.get(biome_x, biome_y, biome_z));
-        }
-        
-        None
+        let block_pos: ValidBlockPosition = pos.block().try_into().ok()?;
+        game.world.biome_at(block_pos)
     }
     
     /// Determines if an entity can spawn at the given position based on biome
@@ -105,10 +265,15 @@ impl BiomeIntegration {
     }
     
     /// Gets the ambient sound for a specific biome
-    pub fn get_biome_ambient_sound(&self, biome: Biome) -> Option<SoundEvent> {
+    pub fn get_biome_ambient_sound(&self, biome: Biome) -> Option<&'static str> {
         self.biome_ambient_sounds.get(&biome).copied()
     }
     
+    /// Gets the ambient particles for a specific biome
+    pub fn get_biome_ambient_particles(&self, biome: Biome) -> Option<AmbientParticles> {
+        self.biome_ambient_particles.get(&biome).copied()
+    }
+    
     /// Gets the temperature of a biome adjusted for height
     pub fn get_adjusted_temperature(&self, biome: Biome, height: i32) -> f32 {
         if let Some(climate) = self.biome_climate.get(&biome) {
@@ -124,39 +289,92 @@ impl BiomeIntegration {
         
         0.5 // Default moderate temperature
     }
-    
-    /// Updates entity states based on the biome they're in
-    pub fn update_entity_states(&self, game: &mut Game) -> SysResult {
-        for (entity, (position,)) in game.ecs.query::<(&Position,)>().iter() {
-            if let Some(biome) = self.get_biome_at_position(game, *position) {
-                // Apply biome-specific effects to entities
-                self.apply_biome_effects(game, entity, biome, *position);
+}
+
+impl Default for BiomeIntegration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(BiomeIntegration::new());
+    systems.add_system(emit_ambient_particles);
+}
+
+/// Emits the ambient particles of each player's biome around them,
+/// on that biome's interval
+fn emit_ambient_particles(game: &mut Game) -> SysResult {
+    let mut emissions = Vec::new();
+    {
+        let integration = game.resources.get::<BiomeIntegration>()?;
+        for (_, (_, position)) in game.ecs.query::<(&Player, &Position)>().iter() {
+            let particles = integration
+                .get_biome_at_position(game, *position)
+                .and_then(|biome| integration.get_biome_ambient_particles(biome));
+            if let Some(particles) = particles {
+                if game.tick_count % particles.interval == 0 {
+                    emissions.push((particles, *position));
+                }
             }
         }
-        
-        Ok(())
     }
     
-    /// Apply biome-specific effects to an entity
-    fn apply_biome_effects(&self, game: &mut Game, entity: Entity, biome: Biome, pos: Position) {
-        // Implementation for biome-specific entity effects
-        match biome {
-            Biome::Desert => {
-                // Apply desert effects (e.g., drying, thirst, etc.)
-            },
-            Biome::SnowyTaiga | Biome::FrozenOcean | Biome::FrozenRiver => {
-                // Apply cold biome effects (e.g., freezing, slowdown, etc.)
-            },
-            Biome::Swamp => {
-                // Apply swamp effects (e.g., slowdown, poison resistance for certain mobs)
-            },
-            // Other biome-specific effects
-            _ => {}
-        }
+    for (particles, position) in emissions {
+        game.emit_particle(particles.kind, position, particles.count, particles.spread);
     }
+    Ok(())
 }
 
-pub fn register(systems: &mut SystemExecutor<Game>) {
-    let biome_integration = BiomeIntegration::new();
-    systems.add_system_with_data(biome_integration.update_entity_states, biome_integration);
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use base::{chunk::BiomeStore, position, Chunk, ChunkPosition};
+
+    use super::*;
+
+    #[test]
+    fn players_see_ambient_particles_of_their_biome() {
+        let mut game = Game::new();
+        let mut chunk = Chunk::new(ChunkPosition::new(0, 0));
+        *chunk.biomes_mut() = BiomeStore::new(Biome::WarpedForest);
+        game.world.chunk_map_mut().insert_chunk(chunk);
+        game.ecs.spawn((Player, position!(8.0, 64.0, 8.0)));
+        game.insert_resource(BiomeIntegration::new());
+
+        let ambient = BiomeIntegration::new()
+            .get_biome_ambient_particles(Biome::WarpedForest)
+            .unwrap();
+        let mut emitted = Vec::new();
+        for tick in 1..=ambient.interval * 3 {
+            game.tick_count = tick;
+            emit_ambient_particles(&mut game).unwrap();
+            emitted.extend(game.outbound_effects.drain_particles());
+        }
+
+        assert_eq!(emitted.len(), 3);
+        assert!(emitted.iter().all(|particle| particle.kind == ParticleKind::WarpedSpore
+            && particle.count == ambient.count
+            && particle.position == position!(8.0, 64.0, 8.0)));
+    }
+
+    #[test]
+    fn climate_picks_biomes_and_precipitation() {
+        assert_eq!(biome_for_climate(0.9, -0.3, 0.3), Biome::Desert);
+        assert_eq!(biome_for_climate(-0.8, 0.0, 0.3), Biome::SnowyTundra);
+        assert_eq!(precipitation_type(biome_for_climate(-0.8, 0.5, 0.3)), Precipitation::Snow);
+        assert_eq!(precipitation_type(biome_for_climate(-0.8, 0.0, -0.9)), Precipitation::Snow);
+
+        assert_eq!(precipitation_type(Biome::Desert), Precipitation::None);
+        assert_eq!(precipitation_type(Biome::Plains), Precipitation::Rain);
+        let raining = crate::Weather {
+            raining: true,
+            thundering: false,
+        };
+        assert_eq!(raining.precipitation_in(Biome::Desert), Precipitation::None);
+        assert_eq!(raining.precipitation_in(Biome::SnowyTaiga), Precipitation::Snow);
+
+        let integration = BiomeIntegration::new();
+        assert!(integration.get_adjusted_temperature(Biome::Desert, 64) > 1.0);
+        assert!(integration.get_adjusted_temperature(Biome::SnowyTundra, 64) < 0.15);
+    }
+}
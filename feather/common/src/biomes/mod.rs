@@ -1,13 +1,16 @@
+//! Biome-driven behaviour: climate, precipitation, ambient
+//! particles and the effects biomes have on the mobs in them.
+
 pub mod integration;
 pub mod entity_interaction;
 
-pub use integration::BiomeIntegration;
+pub use integration::{biome_for_climate, precipitation_type, BiomeIntegration, Precipitation};
 pub use entity_interaction::BiomeEntityInteraction;
 
 use ecs::SystemExecutor;
 use crate::Game;
 
-pub fn register(systems: &mut SystemExecutor<Game>) {
-    integration::register(systems);
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    integration::register(game, systems);
     entity_interaction::register(systems);
-}
\ No newline at end of file
+}
@@ -0,0 +1,86 @@
+//! Publishes block entity changes to the rest of the game
+//! and stores the items held by container block entities.
+
+use std::collections::HashMap;
+
+use base::{BlockPosition, Inventory, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::BlockEntityKind;
+
+use crate::Game;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(BlockInventories::default());
+    systems.add_system(publish_block_entity_changes);
+}
+
+/// Moves the `BlockEntityChanged` events queued by the block entity
+/// manager into the ECS, where systems updating comparators and
+/// clients can observe them, and marks their chunks as needing a save.
+fn publish_block_entity_changes(game: &mut Game) -> SysResult {
+    for change in game.block_entities.drain_changes() {
+        let (x, y, z) = change.pos;
+        game.block_integration
+            .mark_chunk_dirty(BlockPosition::new(x, y, z).chunk());
+        game.ecs.insert_event(change);
+    }
+    Ok(())
+}
+
+/// Inventories of container block entities, such as chests,
+/// hoppers and furnaces, keyed by position.
+#[derive(Debug, Default)]
+pub struct BlockInventories(HashMap<ValidBlockPosition, (BlockEntityKind, Inventory)>);
+
+impl BlockInventories {
+    /// Gets a handle to the inventory of the `kind` of container at `pos`,
+    /// without creating one if it was never accessed.
+    pub fn get(&self, pos: ValidBlockPosition, kind: &BlockEntityKind) -> Option<Inventory> {
+        match self.0.get(&pos) {
+            Some((inventory_kind, inventory)) if inventory_kind == kind => {
+                Some(inventory.new_handle())
+            }
+            _ => None,
+        }
+    }
+}
+
+fn new_inventory(kind: &BlockEntityKind) -> Option<Inventory> {
+    match kind {
+        BlockEntityKind::Chest => Some(Inventory::chest()),
+        BlockEntityKind::Furnace => Some(Inventory::furnace()),
+        BlockEntityKind::Hopper => Some(Inventory::hopper()),
+        _ => None,
+    }
+}
+
+/// Gets a handle to the inventory of the container block entity at `pos`,
+/// creating an empty one the first time it is accessed.
+///
+/// Returns `None` if there is no container at `pos`.
+pub fn block_inventory(game: &Game, pos: ValidBlockPosition) -> Option<Inventory> {
+    let mut inventories = game
+        .resources
+        .get_mut::<BlockInventories>()
+        .expect("Failed to get the block inventories");
+    let kind = match game.block_entities.get((pos.x(), pos.y(), pos.z())) {
+        Some(entity) => &entity.kind,
+        None => {
+            // The container may have been removed since its inventory was created
+            inventories.0.remove(&pos);
+            return None;
+        }
+    };
+    match inventories.0.get(&pos) {
+        Some((inventory_kind, inventory)) if inventory_kind == kind => {
+            return Some(inventory.new_handle())
+        }
+        _ => {}
+    }
+
+    let inventory = new_inventory(kind)?;
+    inventories
+        .0
+        .insert(pos, (kind.clone(), inventory.new_handle()));
+    Some(inventory)
+}
@@ -0,0 +1,57 @@
+//! Scheduled and random block ticks.
+
+use std::convert::TryInto;
+
+use base::{Inventory, Item, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use quill_common::events::BlockPlacementEvent;
+
+use crate::{
+    entities::player::{held_item, HotbarSlot},
+    Game, GameRules,
+};
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems
+        .add_system(run_block_ticks)
+        .add_system(use_bone_meal);
+}
+
+fn run_block_ticks(game: &mut Game) -> SysResult {
+    // Keep random ticking in step with the `randomTickSpeed` rule
+    if let Ok(rules) = game.resources.get::<GameRules>() {
+        game.block_integration
+            .tick_executor_mut()
+            .set_random_tick_speed(rules.random_tick_speed);
+    }
+    game.run_block_ticks();
+    Ok(())
+}
+
+/// Applies the bone meal players use on blocks, consuming it
+/// when the block accepted it.
+fn use_bone_meal(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockPlacementEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockPlacementEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let mut held = held_item(&inventory, hotbar_slot, event.hand);
+        if held.item_kind() != Some(Item::BoneMeal) {
+            continue;
+        }
+        let pos: ValidBlockPosition = match event.location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        if game.apply_bone_meal(pos) {
+            let _ = held.try_take(1);
+        }
+    }
+    Ok(())
+}
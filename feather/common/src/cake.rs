@@ -0,0 +1,114 @@
+//! Cakes, which players eat a bite at a time by using them.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{BlockId, BlockKind, Gamemode, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::events::BlockInteractEvent;
+
+use crate::{hunger::Hunger, interactable::InteractableRegistry, Game};
+
+/// Bites taken out of a cake before the last one finishes it
+const MAX_BITES: u8 = 6;
+/// Food restored by a bite of cake
+const BITE_FOOD: u8 = 2;
+/// Saturation modifier of a bite of cake
+const BITE_SATURATION: f32 = 0.1;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry")
+        .register(BlockKind::Cake);
+    systems.add_system(eat_cakes);
+}
+
+/// Takes a bite of the cake at `pos` for `player`, if they can eat.
+/// The last bite removes the cake.
+///
+/// Returns whether the player ate.
+pub fn eat_cake(game: &mut Game, pos: ValidBlockPosition, player: Entity) -> SysResult<bool> {
+    let mut properties = match game.block_properties(pos) {
+        Some(properties) if properties.kind() == BlockKind::Cake => properties,
+        _ => bail!("no cake at {:?}", pos),
+    };
+    {
+        let creative = game
+            .ecs
+            .get::<Gamemode>(player)
+            .map_or(false, |gamemode| *gamemode == Gamemode::Creative);
+        let mut hunger = game.ecs.get_mut::<Hunger>(player)?;
+        if !hunger.is_hungry() && !creative {
+            return Ok(false);
+        }
+        hunger.eat(BITE_FOOD, BITE_SATURATION);
+    }
+
+    let bites: u8 = properties
+        .get("bites")
+        .and_then(|bites| bites.parse().ok())
+        .unwrap_or(0);
+    if bites >= MAX_BITES {
+        game.set_block(pos, BlockId::air());
+    } else {
+        properties.set("bites", &(bites + 1).to_string());
+        game.set_block_properties(pos, &properties);
+    }
+    Ok(true)
+}
+
+fn eat_cakes(game: &mut Game) -> SysResult {
+    let uses: Vec<(Entity, ValidBlockPosition)> = game
+        .ecs
+        .query::<&BlockInteractEvent>()
+        .iter()
+        .filter_map(|(player, event)| Some((player, event.location.try_into().ok()?)))
+        .collect();
+
+    for (player, pos) in uses {
+        if game.block(pos).map(|block| block.kind()) == Some(BlockKind::Cake) {
+            eat_cake(game, pos, player)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockPosition, ChunkPosition};
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    #[test]
+    fn last_bite_removes_cake() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        let mut cake = BlockProperties::new(BlockKind::Cake);
+        cake.set("bites", "0");
+        assert!(game.set_block_properties(pos, &cake));
+
+        let player = game.ecs.spawn((
+            Hunger {
+                food: 0,
+                saturation: 0.0,
+                exhaustion: 0.0,
+            },
+            Gamemode::Survival,
+        ));
+        for bite in 1..=MAX_BITES + 1 {
+            assert_eq!(game.block(pos).unwrap().kind(), BlockKind::Cake);
+            assert!(eat_cake(&mut game, pos, player).unwrap());
+            assert_eq!(
+                game.ecs.get::<Hunger>(player).unwrap().food,
+                bite * BITE_FOOD
+            );
+        }
+        assert_eq!(game.block(pos).unwrap().kind(), BlockKind::Air);
+        assert!(eat_cake(&mut game, pos, player).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+//! Cauldrons, which hold water.
+//!
+//! The water level and its dye are stored in the cauldron's block entity,
+//! and the `level` block state mirrors the level for clients.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{position, BlockKind, Inventory, Item, ItemStack, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_items::InventorySlot;
+use quill_common::events::BlockInteractEvent;
+
+use crate::{
+    dye::DyeColor,
+    entities::{
+        item,
+        player::{held_item, HotbarSlot},
+    },
+    interactable::InteractableRegistry,
+    Game,
+};
+
+/// Level of a full cauldron
+pub const MAX_LEVEL: u8 = 3;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry")
+        .register(BlockKind::Cauldron);
+    systems.add_system(use_cauldrons);
+}
+
+/// The potion held by water bottles
+const WATER_POTION: &str = "minecraft:water";
+
+fn is_water_potion(held: &InventorySlot) -> bool {
+    held.option_ref()
+        .map_or(false, |stack| stack.potion() == Some(WATER_POTION))
+}
+
+fn is_leather_armor(item: Item) -> bool {
+    matches!(
+        item,
+        Item::LeatherHelmet
+            | Item::LeatherChestplate
+            | Item::LeatherLeggings
+            | Item::LeatherBoots
+            | Item::LeatherHorseArmor
+    )
+}
+
+fn check_cauldron(game: &Game, pos: ValidBlockPosition) -> SysResult {
+    match game.block(pos) {
+        Some(block) if block.kind() == BlockKind::Cauldron => Ok(()),
+        _ => bail!("no cauldron at {:?}", pos),
+    }
+}
+
+/// The level of the water in the cauldron at `pos`.
+pub fn water_level(game: &Game, pos: ValidBlockPosition) -> SysResult<u8> {
+    check_cauldron(game, pos)?;
+    Ok(game
+        .block_entities
+        .get((pos.x(), pos.y(), pos.z()))
+        .and_then(|entity| entity.data.get_int("level"))
+        .map_or(0, |level| level as u8))
+}
+
+/// Fills the cauldron at `pos` with water up to `level`, or empties it.
+/// Emptying a cauldron also washes out its dye.
+pub fn set_water_level(game: &mut Game, pos: ValidBlockPosition, level: u8) -> SysResult {
+    check_cauldron(game, pos)?;
+    let level = level.min(MAX_LEVEL);
+    if let Some(mut properties) = game.block_properties(pos) {
+        properties.set("level", &level.to_string());
+        game.set_block_properties(pos, &properties);
+    }
+    if let Some(mut entity) = game.block_entities.get_mut((pos.x(), pos.y(), pos.z())) {
+        entity.data.set_int("level", i32::from(level));
+        if level == 0 {
+            entity.data.set_int("color", -1);
+        }
+    }
+    Ok(())
+}
+
+fn dye_in(game: &Game, pos: ValidBlockPosition) -> Option<u32> {
+    game.block_entities
+        .get((pos.x(), pos.y(), pos.z()))
+        .and_then(|entity| entity.data.get_int("color"))
+        .filter(|color| *color >= 0)
+        .map(|color| color as u32)
+}
+
+/// The signal read by a comparator next to the cauldron at `pos`.
+pub fn comparator_output(game: &Game, pos: ValidBlockPosition) -> u8 {
+    water_level(game, pos).unwrap_or(0)
+}
+
+/// Swaps one item of the held stack for `product`, dropping
+/// the product on the cauldron if the stack had more items.
+fn exchange(
+    game: &mut Game,
+    pos: ValidBlockPosition,
+    held: &mut InventorySlot,
+    product: ItemStack,
+) {
+    if held.count() == 1 {
+        *held = InventorySlot::Filled(product);
+    } else {
+        let _ = held.try_take(1);
+        let position = position!(
+            f64::from(pos.x()) + 0.5,
+            f64::from(pos.y()) + 1.0,
+            f64::from(pos.z()) + 0.5
+        );
+        item::spawn_item(game, position, product);
+    }
+}
+
+/// Uses the held item on the cauldron at `pos`: buckets and bottles fill
+/// or empty it, dyes color its water and leather armor dipped in the water
+/// takes on its color, or is washed clean.
+///
+/// Returns whether the item was used.
+pub fn use_item(
+    game: &mut Game,
+    pos: ValidBlockPosition,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let water_level = water_level(game, pos)?;
+    let item = match held.item_kind() {
+        Some(item) => item,
+        None => return Ok(false),
+    };
+
+    if item == Item::WaterBucket {
+        if water_level == MAX_LEVEL {
+            return Ok(false);
+        }
+        set_water_level(game, pos, MAX_LEVEL)?;
+        exchange(game, pos, held, ItemStack::new(Item::Bucket, 1)?);
+    } else if item == Item::Bucket {
+        if water_level != MAX_LEVEL {
+            return Ok(false);
+        }
+        set_water_level(game, pos, 0)?;
+        exchange(game, pos, held, ItemStack::new(Item::WaterBucket, 1)?);
+    } else if item == Item::GlassBottle && water_level > 0 {
+        set_water_level(game, pos, water_level - 1)?;
+        let mut bottle = ItemStack::new(Item::Potion, 1)?;
+        bottle.set_potion(Some(WATER_POTION.to_owned()));
+        exchange(game, pos, held, bottle);
+    } else if item == Item::Potion && is_water_potion(held) && water_level < MAX_LEVEL {
+        set_water_level(game, pos, water_level + 1)?;
+        exchange(game, pos, held, ItemStack::new(Item::GlassBottle, 1)?);
+    } else if let Some(color) = DyeColor::from_dye(item).filter(|_| water_level > 0) {
+        if let Some(mut entity) = game.block_entities.get_mut((pos.x(), pos.y(), pos.z())) {
+            entity.data.set_int("color", color.rgb() as i32);
+        }
+        let _ = held.try_take(1);
+    } else if is_leather_armor(item) && water_level > 0 {
+        let color = dye_in(game, pos);
+        let armor = match held.option_mut() {
+            Some(armor) => armor,
+            None => return Ok(false),
+        };
+        if armor.color() == color {
+            return Ok(false);
+        }
+        armor.set_color(color);
+        set_water_level(game, pos, water_level - 1)?;
+    } else {
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+fn use_cauldrons(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockInteractEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockInteractEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let pos: ValidBlockPosition = match event.location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        if check_cauldron(game, pos).is_ok() {
+            let mut held = held_item(&inventory, hotbar_slot, event.hand);
+            use_item(game, pos, &mut held)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockPosition, ChunkPosition};
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    fn level_state(game: &Game, pos: ValidBlockPosition) -> String {
+        game.block_properties(pos)
+            .unwrap()
+            .get("level")
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn buckets_fill_and_bottles_drain() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        let mut cauldron = BlockProperties::new(BlockKind::Cauldron);
+        cauldron.set("level", "0");
+        assert!(game.set_block_properties(pos, &cauldron));
+        assert_eq!(water_level(&game, pos).unwrap(), 0);
+
+        let mut held = InventorySlot::new(Item::WaterBucket, 1);
+        assert!(use_item(&mut game, pos, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::Bucket, 1));
+        assert_eq!(water_level(&game, pos).unwrap(), MAX_LEVEL);
+        assert_eq!(level_state(&game, pos), "3");
+        assert_eq!(comparator_output(&game, pos), 3);
+
+        let mut held = InventorySlot::new(Item::GlassBottle, 1);
+        assert!(use_item(&mut game, pos, &mut held).unwrap());
+        assert_eq!(held.item_kind(), Some(Item::Potion));
+        assert!(is_water_potion(&held));
+        assert_eq!(water_level(&game, pos).unwrap(), 2);
+        assert_eq!(level_state(&game, pos), "2");
+        assert_eq!(comparator_output(&game, pos), 2);
+
+        // Only full cauldrons can be scooped up with a bucket
+        let mut bucket = InventorySlot::new(Item::Bucket, 1);
+        assert!(!use_item(&mut game, pos, &mut bucket).unwrap());
+
+        // Water bottles pour back in, other potions don't
+        assert!(use_item(&mut game, pos, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::GlassBottle, 1));
+        assert_eq!(water_level(&game, pos).unwrap(), MAX_LEVEL);
+        set_water_level(&mut game, pos, 1).unwrap();
+        let mut held = InventorySlot::new(Item::Potion, 1);
+        held.option_mut()
+            .unwrap()
+            .set_potion(Some("minecraft:swiftness".to_owned()));
+        assert!(!use_item(&mut game, pos, &mut held).unwrap());
+        assert_eq!(water_level(&game, pos).unwrap(), 1);
+    }
+}
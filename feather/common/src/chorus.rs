@@ -0,0 +1,104 @@
+//! Chorus fruit, which teleports whoever eats it, and the chorus
+//! plants it grows on.
+//!
+//! Chorus flowers grow with random ticks, and a chorus plant that loses
+//! its support breaks along with everything growing from it.
+
+use base::{Item, Position};
+use ecs::{Entity, SysResult, SystemExecutor};
+use rand::Rng;
+
+use crate::{events::FoodEatenEvent, teleport, Game};
+
+/// Blocks away along each axis that chorus fruit can teleport its eater
+pub const TELEPORT_RANGE: f64 = 8.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(teleport_chorus_fruit_eaters);
+}
+
+/// Teleports `player`, who just ate a chorus fruit, to a random
+/// spot nearby.
+///
+/// Returns where the player ended up, or `None` if they stayed put.
+pub fn eat_chorus_fruit(
+    game: &mut Game,
+    player: Entity,
+    rng: &mut impl Rng,
+) -> SysResult<Option<Position>> {
+    let origin = *game.ecs.get::<Position>(player)?;
+    let destination = teleport::random_teleport(game, player, TELEPORT_RANGE, rng)?;
+    if destination.is_some() {
+        game.emit_sound("item.chorus_fruit.teleport", origin, 1.0, 1.0);
+    }
+    Ok(destination)
+}
+
+fn teleport_chorus_fruit_eaters(game: &mut Game) -> SysResult {
+    let eaters: Vec<Entity> = game
+        .ecs
+        .query::<&FoodEatenEvent>()
+        .iter()
+        .filter(|(_, event)| event.food == Item::ChorusFruit)
+        .map(|(player, _)| player)
+        .collect();
+    for player in eaters {
+        game.with_rng(|game, rng| eat_chorus_fruit(game, player, rng))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockId, BlockKind, BlockPosition, Chunk, ChunkPosition};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn chunk(game: &mut Game) {
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+    }
+
+    #[test]
+    fn chorus_fruit_teleports_its_eater() {
+        let mut game = Game::new();
+        chunk(&mut game);
+        for x in 0..16 {
+            for z in 0..16 {
+                let pos = BlockPosition::new(x, 63, z).try_into().unwrap();
+                game.set_block(pos, BlockId::end_stone());
+            }
+        }
+        let player = game.ecs.spawn((position!(8.0, 64.0, 8.0),));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let destination = eat_chorus_fruit(&mut game, player, &mut rng)
+            .unwrap()
+            .unwrap();
+        assert_eq!(*game.ecs.get::<Position>(player).unwrap(), destination);
+        assert!((destination.x - 8.0).abs() <= TELEPORT_RANGE);
+        assert!((destination.z - 8.0).abs() <= TELEPORT_RANGE);
+        assert_eq!(destination.y, 64.0);
+    }
+
+    #[test]
+    fn breaking_a_chorus_plant_breaks_what_grows_on_it() {
+        let mut game = Game::new();
+        chunk(&mut game);
+        let pos = |y| BlockPosition::new(4, y, 4).try_into().unwrap();
+        game.set_block(pos(63), BlockId::end_stone());
+        game.set_block(pos(64), BlockId::chorus_plant());
+        game.set_block(pos(65), BlockId::chorus_plant());
+        game.set_block(pos(66), BlockId::chorus_flower());
+
+        game.break_block(pos(64));
+        for y in 64..=66 {
+            assert_eq!(game.block(pos(y)).unwrap().kind(), BlockKind::Air);
+        }
+        assert_eq!(game.block(pos(63)).unwrap().kind(), BlockKind::EndStone);
+    }
+}
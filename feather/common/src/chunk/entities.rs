@@ -57,6 +57,9 @@ fn update_chunk_entities(game: &mut Game) -> SysResult {
         if position.chunk() != *old_chunk {
             game.chunk_entities
                 .update(entity, Some(*old_chunk), new_chunk);
+            // Both chunks save a different set of entities now
+            game.block_integration.mark_chunk_dirty(*old_chunk);
+            game.block_integration.mark_chunk_dirty(new_chunk);
             events.push((
                 entity,
                 ChunkCrossEvent {
@@ -78,6 +81,7 @@ fn update_chunk_entities(game: &mut Game) -> SysResult {
     {
         let chunk = position.chunk();
         game.chunk_entities.update(entity, None, chunk);
+        game.block_integration.mark_chunk_dirty(chunk);
         insertions.push((entity, chunk));
     }
     // Add ChunkPosition component to new entities
@@ -92,6 +96,7 @@ fn update_chunk_entities(game: &mut Game) -> SysResult {
         .iter()
     {
         game.chunk_entities.remove_entity(entity, chunk);
+        game.block_integration.mark_chunk_dirty(chunk);
     }
 
     Ok(())
@@ -12,7 +12,11 @@ use ecs::{Entity, SysResult, SystemExecutor};
 use quill_common::events::EntityRemoveEvent;
 use utils::vec_remove_item;
 
-use crate::{chunk::worker::LoadRequest, events::ViewUpdateEvent, Game};
+use crate::{
+    chunk::{save, worker::LoadRequest},
+    events::ViewUpdateEvent,
+    Game,
+};
 
 pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
     game.insert_resource(ChunkLoadState::default());
@@ -153,7 +157,10 @@ fn unload_chunks(game: &mut Game, state: &mut ChunkLoadState) -> SysResult {
             continue;
         }
 
-        game.world.unload_chunk(unload.pos)?;
+        let data = save::chunk_save_data(game, unload.pos);
+        game.world.unload_chunk(unload.pos, data)?;
+        // Unloading saved the chunk
+        game.block_integration.mark_chunk_clean(unload.pos);
     }
     game.world.cache.purge_unused();
     Ok(())
@@ -1,4 +1,5 @@
 pub mod cache;
 pub mod entities;
 pub mod loading;
+pub mod save;
 pub mod worker;
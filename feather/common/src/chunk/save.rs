@@ -0,0 +1,276 @@
+//! Gathers what is saved along with a chunk's blocks: its entities,
+//! block entities and scheduled block updates.
+
+use std::convert::TryInto;
+
+use base::{
+    anvil::{
+        block_entity::{self, BlockEntityBase, BlockEntityData},
+        entity::{
+            AnimalData, ArrowEntityData, BaseEntityData, EntityData, ItemData, ItemEntityData,
+        },
+        player::InventorySlot,
+        region::ScheduledBlockUpdate,
+    },
+    vec3, BlockPosition, ChunkPosition, EntityKind, ItemStack, Position,
+};
+use ecs::Entity;
+use libcraft_blocks::{BlockEntity, BlockEntityKind};
+use quill_common::{
+    components::{Health, Velocity},
+    events::EntityRemoveEvent,
+};
+
+use crate::{block_entities::BlockInventories, Game};
+
+/// Health of a dropped item, which can be destroyed by damage
+const ITEM_HEALTH: i16 = 5;
+
+/// The data saved with a chunk besides its blocks.
+#[derive(Debug, Default)]
+pub struct ChunkSaveData {
+    pub entities: Vec<EntityData>,
+    pub block_entities: Vec<BlockEntityData>,
+    pub block_updates: Vec<ScheduledBlockUpdate>,
+}
+
+/// Collects the data to save with the chunk at `pos`.
+///
+/// Players are saved separately, and entities and block entities
+/// the world format can't store yet are left out.
+pub fn chunk_save_data(game: &Game, pos: ChunkPosition) -> ChunkSaveData {
+    let entities = game
+        .chunk_entities
+        .entities_in_chunk(pos)
+        .iter()
+        .filter(|entity| game.ecs.get::<EntityRemoveEvent>(**entity).is_err())
+        .filter_map(|entity| entity_data(game, *entity))
+        .collect();
+
+    let block_entities = game
+        .block_entities
+        .iter()
+        .filter(|entity| {
+            let (x, y, z) = entity.position;
+            BlockPosition::new(x, y, z).chunk() == pos
+        })
+        .filter_map(|entity| block_entity_data(game, entity))
+        .collect();
+
+    let block_updates = game
+        .block_integration
+        .scheduled_updates_in(pos)
+        .into_iter()
+        .map(|update| {
+            ScheduledBlockUpdate::new(
+                format!("minecraft:{}", update.kind.name()),
+                (
+                    update.position.x(),
+                    update.position.y(),
+                    update.position.z(),
+                ),
+                update.delay as i32,
+                update.priority,
+            )
+        })
+        .collect();
+
+    ChunkSaveData {
+        entities,
+        block_entities,
+        block_updates,
+    }
+}
+
+fn entity_data(game: &Game, entity: Entity) -> Option<EntityData> {
+    let kind = *game.ecs.get::<EntityKind>(entity).ok()?;
+    let position = *game.ecs.get::<Position>(entity).ok()?;
+    let velocity = game
+        .ecs
+        .get::<Velocity>(entity)
+        .map_or_else(|_| Velocity::default(), |velocity| *velocity);
+    let base = BaseEntityData::new(position, vec3(velocity.x, velocity.y, velocity.z));
+    let animal = |base| match game.ecs.get::<Health>(entity) {
+        Ok(health) => AnimalData::new(base, health.current),
+        Err(_) => AnimalData {
+            base,
+            ..Default::default()
+        },
+    };
+
+    Some(match kind {
+        EntityKind::Item => {
+            let stack = game.ecs.get::<ItemStack>(entity).ok()?;
+            EntityData::Item(ItemEntityData {
+                entity: base,
+                item: ItemData::from(&*stack),
+                health: ITEM_HEALTH,
+                ..Default::default()
+            })
+        }
+        EntityKind::Arrow => EntityData::Arrow(ArrowEntityData {
+            entity: base,
+            critical: 0,
+        }),
+        EntityKind::Cow => EntityData::Cow(animal(base)),
+        EntityKind::Pig => EntityData::Pig(animal(base)),
+        EntityKind::Chicken => EntityData::Chicken(animal(base)),
+        EntityKind::Sheep => EntityData::Sheep(animal(base)),
+        EntityKind::Horse => EntityData::Horse(animal(base)),
+        EntityKind::Llama => EntityData::Llama(animal(base)),
+        EntityKind::Mooshroom => EntityData::Mooshroom(animal(base)),
+        EntityKind::Rabbit => EntityData::Rabbit(animal(base)),
+        EntityKind::Squid => EntityData::Squid(animal(base)),
+        EntityKind::Donkey => EntityData::Donkey(animal(base)),
+        _ => return None,
+    })
+}
+
+fn block_entity_data(game: &Game, entity: &BlockEntity) -> Option<BlockEntityData> {
+    let (x, y, z) = entity.position;
+    let data = &entity.data;
+    let int = |key: &str| data.get_int(key).unwrap_or_default();
+    let items = || container_items(game, entity);
+
+    let kind = match entity.kind {
+        BlockEntityKind::Chest => block_entity::BlockEntityKind::Chest {
+            items: items(),
+            loot_table: data.get_string("LootTable").cloned(),
+            loot_table_seed: data.get_int("LootTableSeed").map(i64::from),
+        },
+        BlockEntityKind::Furnace => block_entity::BlockEntityKind::Furnace {
+            items: items(),
+            burn_time: int("BurnTime") as i16,
+            cook_time: int("CookTime") as i16,
+            cook_time_total: int("CookTimeTotal") as i16,
+        },
+        BlockEntityKind::Hopper => block_entity::BlockEntityKind::Hopper {
+            items: items(),
+            transfer_cooldown: int("TransferCooldown"),
+        },
+        BlockEntityKind::Dispenser => block_entity::BlockEntityKind::Dispenser { items: items() },
+        BlockEntityKind::Dropper => block_entity::BlockEntityKind::Dropper { items: items() },
+        BlockEntityKind::Comparator => block_entity::BlockEntityKind::Comparator {
+            output_signal: int("OutputSignal"),
+        },
+        BlockEntityKind::Bed => block_entity::BlockEntityKind::Bed,
+        BlockEntityKind::EnchantingTable => block_entity::BlockEntityKind::EnchantingTable,
+        BlockEntityKind::DaylightDetector => block_entity::BlockEntityKind::DaylightDetector,
+        BlockEntityKind::EndPortal => block_entity::BlockEntityKind::EndPortal,
+        _ => return None,
+    };
+    Some(BlockEntityData {
+        base: BlockEntityBase { x, y, z },
+        kind,
+    })
+}
+
+/// The filled slots of a container block entity's inventory.
+fn container_items(game: &Game, entity: &BlockEntity) -> Vec<InventorySlot> {
+    let (x, y, z) = entity.position;
+    let pos = match BlockPosition::new(x, y, z).try_into() {
+        Ok(pos) => pos,
+        Err(_) => return Vec::new(),
+    };
+    let inventory = game
+        .resources
+        .get::<BlockInventories>()
+        .ok()
+        .and_then(|inventories| inventories.get(pos, &entity.kind));
+    let inventory = match inventory {
+        Some(inventory) => inventory,
+        None => return Vec::new(),
+    };
+    inventory
+        .to_vec()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(slot, item)| {
+            item.into_option()
+                .map(|stack| InventorySlot::from_inventory_index(slot as i8, &stack))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, Area, BlockId, BlockKind, Item, ValidBlockPosition};
+    use libcraft_blocks::create_block_entity;
+    use quill_common::{entities::Player, events::EntityCreateEvent};
+
+    use crate::{block_entities, chunk, entities::item, test_support::TestGame};
+
+    use super::*;
+
+    #[test]
+    fn saves_the_contents_of_its_chunk() {
+        let mut test = TestGame::builder()
+            .chunks(1)
+            .register(|game, systems| {
+                game.add_entity_spawn_callback(crate::entities::add_entity_components);
+                block_entities::register(game, systems);
+                chunk::entities::register(systems);
+            })
+            .build();
+        let game = &mut test.game;
+
+        let stack = ItemStack::new(Item::Diamond, 3).unwrap();
+        item::spawn_item(game, position!(4.5, 64.0, 4.5), stack);
+        item::spawn_item(
+            game,
+            position!(20.5, 64.0, 4.5),
+            ItemStack::new(Item::Stick, 1).unwrap(),
+        );
+        let player = game
+            .ecs
+            .spawn((Player, EntityKind::Player, position!(5.5, 64.0, 5.5)));
+        game.ecs
+            .insert_entity_event(player, EntityCreateEvent)
+            .unwrap();
+
+        let chest: ValidBlockPosition = BlockPosition::new(2, 64, 2).try_into().unwrap();
+        game.set_block(chest, BlockId::chest());
+        game.block_entities.set(
+            (2, 64, 2),
+            create_block_entity(BlockKind::Chest, (2, 64, 2)).unwrap(),
+        );
+        let inventory = block_entities::block_inventory(game, chest).unwrap();
+        *inventory.item(Area::Storage, 4).unwrap() =
+            libcraft_items::InventorySlot::Filled(ItemStack::new(Item::Apple, 2).unwrap());
+
+        let water: ValidBlockPosition = BlockPosition::new(3, 64, 3).try_into().unwrap();
+        game.block_integration
+            .schedule_block_update(water, BlockKind::Water, 5, 0);
+        test.step();
+
+        let data = chunk_save_data(&test.game, ChunkPosition::new(0, 0));
+        assert_eq!(data.entities.len(), 1);
+        match &data.entities[0] {
+            EntityData::Item(item) => {
+                assert_eq!(item.item.item, "diamond");
+                assert_eq!(item.item.count, 3);
+            }
+            other => panic!("saved {:?} instead of the dropped item", other),
+        }
+
+        assert_eq!(data.block_entities.len(), 1);
+        match &data.block_entities[0].kind {
+            block_entity::BlockEntityKind::Chest { items, .. } => {
+                assert_eq!(items.len(), 1);
+                assert_eq!(items[0].slot, 4);
+                assert_eq!(items[0].count, 2);
+            }
+            other => panic!("saved {:?} instead of the chest", other),
+        }
+
+        assert_eq!(
+            data.block_updates,
+            vec![ScheduledBlockUpdate::new(
+                "minecraft:water",
+                (3, 64, 3),
+                5,
+                0
+            )]
+        );
+    }
+}
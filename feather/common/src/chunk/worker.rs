@@ -2,7 +2,7 @@ use std::{path::PathBuf, sync::Arc};
 
 use anyhow::bail;
 use base::{
-    anvil::{block_entity::BlockEntityData, entity::EntityData},
+    anvil::{block_entity::BlockEntityData, entity::EntityData, region::ScheduledBlockUpdate},
     Chunk, ChunkHandle, ChunkPosition,
 };
 use flume::{Receiver, Sender};
@@ -37,6 +37,7 @@ pub struct SaveRequest {
     pub chunk: ChunkHandle,
     pub entities: Vec<EntityData>,
     pub block_entities: Vec<BlockEntityData>,
+    pub block_updates: Vec<ScheduledBlockUpdate>,
 }
 
 #[derive(Debug)]
@@ -44,6 +45,8 @@ pub struct SaveRequest {
 pub enum WorkerRequest {
     Load(LoadRequest),
     Save(SaveRequest),
+    /// Answered once all requests sent before it are handled.
+    Flush(Sender<()>),
 }
 pub struct ChunkWorker {
     generator: Arc<dyn WorldGenerator>,
@@ -112,4 +115,11 @@ impl ChunkWorker {
     pub fn queue_chunk_save(&mut self, req: SaveRequest) {
         self.send_req.send(WorkerRequest::Save(req)).unwrap()
     }
+
+    /// Blocks until all queued chunk saves have been written.
+    pub fn flush(&mut self) {
+        let (send_done, recv_done) = flume::bounded(1);
+        self.send_req.send(WorkerRequest::Flush(send_done)).unwrap();
+        let _ = recv_done.recv();
+    }
 }
@@ -0,0 +1,823 @@
+//! Damage dealt by weapons and reduced by armor.
+//!
+//! Players hit the entities they attack and shoot arrows from bows,
+//! while hostile mobs hit players within their reach. All damage to
+//! entities should go through [`apply_damage`].
+
+use base::{Area, EntityKind, Gamemode, Inventory, Item, ItemStack, Position};
+use ecs::{Entity, SysResult, SystemExecutor, SystemStage};
+use libcraft_core::{Hand, InteractionType};
+use libcraft_items::EnchantmentKind;
+use quill_common::{
+    components::{Health, Velocity},
+    entity_init::EntityInit,
+    events::InteractEntityEvent,
+};
+
+use crate::{
+    difficulty::Difficulty,
+    entities::{
+        equipment::{self, Equipment, EquipmentSlot},
+        experience_orb,
+        player::{held_item, HotbarSlot},
+    },
+    events::EntityDeathEvent,
+    fire,
+    physics::Bounds,
+    status_effects::{StatusEffectKind, StatusEffects},
+    Game,
+};
+
+/// Damage dealt by an attack with an empty hand
+pub const FIST_DAMAGE: f32 = 1.0;
+/// Damage dealt by an arrow shot from a bow without power
+pub const ARROW_DAMAGE: f32 = 2.0;
+/// Ticks after taking damage in which an entity
+/// only takes damage exceeding the last
+pub const INVULNERABLE_TICKS: u32 = 10;
+/// Most protection enchantments can reduce damage by, out of 25
+const MAX_PROTECTION: f32 = 20.0;
+/// Blocks per tick an arrow leaves a fully drawn bow at
+const ARROW_SPEED: f64 = 3.0;
+/// Height of a player's eyes, where their arrows are shot from
+const EYE_HEIGHT: f64 = 1.62;
+/// Distance from which mobs can hit their target
+const MOB_REACH: f64 = 2.0;
+/// Ticks between each hit of a mob's melee attack
+const MOB_ATTACK_INTERVAL: u64 = 20;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems
+        .add_system_to_stage(SystemStage::Combat, count_down_invulnerability)
+        .add_system_to_stage(SystemStage::Combat, attack_entities)
+        .add_system_to_stage(SystemStage::Combat, mob_melee_attacks)
+        .add_system_to_stage(SystemStage::Combat, hit_entities_with_arrows);
+}
+
+/// A flying arrow, which hurts the first entity it hits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShotArrow {
+    /// Damage dealt at a speed of one block per tick
+    pub damage: f32,
+    pub shooter: Option<Entity>,
+}
+
+/// What hurt an entity, which decides what protects against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageType {
+    Generic,
+    Attack,
+    Projectile,
+    Explosion,
+    /// Standing in fire
+    InFire,
+    /// Burning after being set on fire
+    Fire,
+    Lava,
+    Fall,
+    Drowning,
+    Suffocation,
+    Freezing,
+    Magic,
+    Poison,
+    Wither,
+    Starvation,
+    Void,
+}
+
+impl DamageType {
+    /// Whether armor does nothing against the damage.
+    pub fn bypasses_armor(self) -> bool {
+        matches!(
+            self,
+            DamageType::Fire
+                | DamageType::Fall
+                | DamageType::Drowning
+                | DamageType::Suffocation
+                | DamageType::Freezing
+                | DamageType::Magic
+                | DamageType::Poison
+                | DamageType::Wither
+                | DamageType::Starvation
+                | DamageType::Void
+        )
+    }
+
+    /// Whether protection enchantments and resistance
+    /// do nothing against the damage.
+    pub fn bypasses_magic(self) -> bool {
+        matches!(self, DamageType::Starvation | DamageType::Void)
+    }
+
+    /// Whether the damage ignores invulnerability. Damage over time
+    /// comes at its own pace, so it neither waits for invulnerability
+    /// to run out nor makes the entity invulnerable.
+    pub fn bypasses_invulnerability(self) -> bool {
+        matches!(
+            self,
+            DamageType::Poison | DamageType::Wither | DamageType::Starvation | DamageType::Void
+        )
+    }
+
+    pub fn is_fire(self) -> bool {
+        matches!(
+            self,
+            DamageType::InFire | DamageType::Fire | DamageType::Lava
+        )
+    }
+}
+
+/// Whether the mob is undead, taking extra damage from smite.
+pub fn is_undead(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Zombie
+            | EntityKind::Husk
+            | EntityKind::Drowned
+            | EntityKind::ZombieVillager
+            | EntityKind::ZombifiedPiglin
+            | EntityKind::ZombieHorse
+            | EntityKind::Zoglin
+            | EntityKind::Skeleton
+            | EntityKind::Stray
+            | EntityKind::WitherSkeleton
+            | EntityKind::SkeletonHorse
+            | EntityKind::Wither
+            | EntityKind::Phantom
+    )
+}
+
+/// Whether the mob is an arthropod, taking extra damage from bane of arthropods.
+pub fn is_arthropod(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Spider
+            | EntityKind::CaveSpider
+            | EntityKind::Silverfish
+            | EntityKind::Endermite
+            | EntityKind::Bee
+    )
+}
+
+/// Damage dealt by a weapon before enchantments.
+pub fn weapon_damage(weapon: Option<Item>) -> f32 {
+    match weapon {
+        Some(Item::WoodenSword) | Some(Item::GoldenSword) => 4.0,
+        Some(Item::StoneSword) => 5.0,
+        Some(Item::IronSword) => 6.0,
+        Some(Item::DiamondSword) => 7.0,
+        Some(Item::NetheriteSword) => 8.0,
+        Some(Item::WoodenAxe) | Some(Item::GoldenAxe) => 7.0,
+        Some(Item::StoneAxe) | Some(Item::IronAxe) | Some(Item::DiamondAxe) => 9.0,
+        Some(Item::NetheriteAxe) => 10.0,
+        Some(Item::Trident) => 9.0,
+        _ => FIST_DAMAGE,
+    }
+}
+
+/// Damage added by the weapon's enchantments against a `target` mob.
+pub fn enchantment_damage(weapon: &ItemStack, target: EntityKind) -> f32 {
+    let enchantments = weapon.enchantments();
+    let level = |enchantment| f32::from(enchantments.get(enchantment));
+    let mut damage = 0.0;
+    if enchantments.has(EnchantmentKind::Sharpness) {
+        damage += 0.5 * level(EnchantmentKind::Sharpness) + 0.5;
+    }
+    if is_undead(target) {
+        damage += 2.5 * level(EnchantmentKind::Smite);
+    }
+    if is_arthropod(target) {
+        damage += 2.5 * level(EnchantmentKind::BaneOfArthropods);
+    }
+    damage
+}
+
+/// Damage dealt by a melee attack with `weapon` against a `target` mob.
+pub fn attack_damage(weapon: Option<&ItemStack>, target: EntityKind) -> f32 {
+    weapon_damage(weapon.map(ItemStack::item))
+        + weapon.map_or(0.0, |weapon| enchantment_damage(weapon, target))
+}
+
+/// Ticks a target hit with `weapon` burns for, from fire aspect.
+pub fn fire_aspect_ticks(weapon: &ItemStack) -> u32 {
+    80 * u32::from(weapon.enchantments().get(EnchantmentKind::FireAspect))
+}
+
+/// Damage dealt by a mob with `base_damage` attacking a `target` mob,
+/// adding the weapon in its main hand.
+pub fn mob_attack_damage(base_damage: f32, equipment: &Equipment, target: EntityKind) -> f32 {
+    let weapon = equipment.main_hand.as_ref();
+    base_damage + attack_damage(weapon, target) - FIST_DAMAGE
+}
+
+/// Damage dealt by an arrow shot from `bow`. Power adds to it.
+pub fn arrow_damage(bow: Option<&ItemStack>) -> f32 {
+    let power = bow.map_or(0, |bow| bow.enchantments().get(EnchantmentKind::Power));
+    if power == 0 {
+        return ARROW_DAMAGE;
+    }
+    ARROW_DAMAGE + 0.5 * f32::from(power) + 0.5
+}
+
+/// Base damage of the melee attack of hostile mobs,
+/// or `None` if the mob doesn't attack players on sight.
+pub fn mob_base_damage(kind: EntityKind) -> Option<f32> {
+    Some(match kind {
+        EntityKind::Zombie
+        | EntityKind::Husk
+        | EntityKind::Drowned
+        | EntityKind::ZombieVillager => 3.0,
+        EntityKind::WitherSkeleton => 4.0,
+        EntityKind::Vindicator => 5.0,
+        EntityKind::Zoglin => 6.0,
+        EntityKind::PiglinBrute => 7.0,
+        _ => return None,
+    })
+}
+
+/// Damage left of `damage` after it hits someone wearing
+/// `armor` points of armor with `toughness`. Strong hits get
+/// through armor more easily, unless the armor is tough.
+pub fn damage_after_armor(damage: f32, armor: f32, toughness: f32) -> f32 {
+    let reduction = (armor - 4.0 * damage / (toughness + 8.0))
+        .max(armor / 5.0)
+        .min(20.0);
+    damage * (1.0 - reduction / 25.0)
+}
+
+/// Protection given by the enchantments on `armor` against `damage_type`,
+/// with each point taking away 4% of the damage.
+pub fn protection_points<'a>(
+    armor: impl IntoIterator<Item = &'a ItemStack>,
+    damage_type: DamageType,
+) -> f32 {
+    let mut points = 0;
+    for piece in armor {
+        let enchantments = piece.enchantments();
+        points += u32::from(enchantments.get(EnchantmentKind::Protection));
+        let (specific, factor) = match damage_type {
+            DamageType::InFire | DamageType::Fire | DamageType::Lava => {
+                (EnchantmentKind::FireProtection, 2)
+            }
+            DamageType::Explosion => (EnchantmentKind::BlastProtection, 2),
+            DamageType::Projectile => (EnchantmentKind::ProjectileProtection, 2),
+            DamageType::Fall => (EnchantmentKind::FeatherFalling, 3),
+            _ => continue,
+        };
+        points += u32::from(enchantments.get(specific)) * factor;
+    }
+    (points as f32).min(MAX_PROTECTION)
+}
+
+/// The armor worn by `entity`: the armor in its equipment,
+/// or in the armor slots of a player's inventory.
+pub fn worn_armor(game: &Game, entity: Entity) -> Vec<ItemStack> {
+    if let Ok(equipment) = game.ecs.get::<Equipment>(entity) {
+        return [
+            EquipmentSlot::Head,
+            EquipmentSlot::Chest,
+            EquipmentSlot::Legs,
+            EquipmentSlot::Feet,
+        ]
+        .iter()
+        .filter_map(|slot| equipment.get(*slot).cloned())
+        .collect();
+    }
+    if let Ok(inventory) = game.ecs.get::<Inventory>(entity) {
+        return [Area::Helmet, Area::Chestplate, Area::Leggings, Area::Boots]
+            .iter()
+            .filter_map(|area| inventory.item(*area, 0))
+            .filter_map(|slot| slot.option_ref().cloned())
+            .collect();
+    }
+    Vec::new()
+}
+
+/// Hurts `target` with `amount` of `damage_type`, dealt by the `source`
+/// entity if there is one. Returns the damage actually taken.
+///
+/// Armor, protection enchantments and resistance reduce the damage unless
+/// the damage type bypasses them. Players take damage from mobs scaled by
+/// the difficulty. For [`INVULNERABLE_TICKS`] after being hurt, entities
+/// ignore damage no higher than the last and only take the difference
+/// from higher damage.
+pub fn apply_damage(
+    game: &mut Game,
+    target: Entity,
+    source: Option<Entity>,
+    amount: f32,
+    damage_type: DamageType,
+) -> SysResult<f32> {
+    let health = match game.ecs.get::<Health>(target) {
+        Ok(health) => *health,
+        Err(_) => return Ok(0.0),
+    };
+    let invulnerable_gamemode = game.ecs.get::<Gamemode>(target).map_or(false, |gamemode| {
+        matches!(*gamemode, Gamemode::Creative | Gamemode::Spectator)
+    });
+    if invulnerable_gamemode && damage_type != DamageType::Void {
+        return Ok(0.0);
+    }
+    let effect_level = |kind| {
+        game.ecs
+            .get::<StatusEffects>(target)
+            .ok()
+            .map_or(0, |effects| {
+                effects
+                    .get(kind)
+                    .map_or(0, |effect| effect.amplifier.saturating_add(1))
+            })
+    };
+    if damage_type.is_fire() && effect_level(StatusEffectKind::FireResistance) > 0 {
+        return Ok(0.0);
+    }
+
+    let mut amount = amount;
+    let kind_of = |entity| game.ecs.get::<EntityKind>(entity).ok().map(|kind| *kind);
+    let mob_source = source
+        .and_then(kind_of)
+        .map_or(false, |kind| kind != EntityKind::Player);
+    if mob_source && kind_of(target) == Some(EntityKind::Player) {
+        let difficulty = game
+            .resources
+            .get::<Difficulty>()
+            .map_or(Difficulty::default(), |difficulty| *difficulty);
+        amount = difficulty.scale_damage(amount);
+    }
+
+    let ignores_invulnerability = damage_type.bypasses_invulnerability();
+    let invulnerable = !ignores_invulnerability && health.is_invulnerable();
+    if invulnerable && amount <= health.last_damage_amount {
+        return Ok(0.0);
+    }
+    let mut damage = if invulnerable {
+        amount - health.last_damage_amount
+    } else {
+        amount
+    };
+
+    let armor = worn_armor(game, target);
+    if !damage_type.bypasses_armor() {
+        let points: f32 = armor
+            .iter()
+            .map(|piece| equipment::armor_points(piece.item()))
+            .sum();
+        let toughness: f32 = armor
+            .iter()
+            .map(|piece| equipment::armor_toughness(piece.item()))
+            .sum();
+        damage = damage_after_armor(damage, points, toughness);
+    }
+    if !damage_type.bypasses_magic() {
+        let resistance = f32::from(effect_level(StatusEffectKind::Resistance));
+        damage *= (1.0 - 0.2 * resistance).max(0.0);
+        damage *= 1.0 - protection_points(&armor, damage_type) / 25.0;
+    }
+    if damage <= 0.0 {
+        return Ok(0.0);
+    }
+
+    let mut health = game.ecs.get_mut::<Health>(target)?;
+    let was_alive = health.current > 0.0;
+    health.current -= damage;
+    if !ignores_invulnerability {
+        if !invulnerable {
+            health.invulnerable_ticks = INVULNERABLE_TICKS;
+        }
+        health.last_damage_amount = amount;
+    }
+    let killed = was_alive && health.current <= 0.0;
+    drop(health);
+
+    if killed {
+        let experience = match kind_of(target) {
+            Some(kind) => game.with_rng(|_, rng| experience_orb::mob_experience(kind, rng)),
+            None => 0,
+        };
+        let death = EntityDeathEvent {
+            experience,
+            killer: source,
+            weapon: source.and_then(|source| main_hand_item(game, source)),
+        };
+        game.ecs.insert_entity_event(target, death)?;
+    }
+    Ok(damage)
+}
+
+/// Shoots an arrow from `bow` where `shooter` is looking.
+pub fn shoot_arrow(game: &mut Game, shooter: Entity, bow: Option<&ItemStack>) -> SysResult<Entity> {
+    let mut position = *game.ecs.get::<Position>(shooter)?;
+    let direction = position.direction() * ARROW_SPEED;
+    position.y += EYE_HEIGHT;
+
+    let mut builder = game.create_entity_builder(position, EntityInit::Arrow);
+    builder
+        .add(Velocity {
+            x: direction.x,
+            y: direction.y,
+            z: direction.z,
+        })
+        .add(ShotArrow {
+            damage: arrow_damage(bow),
+            shooter: Some(shooter),
+        });
+    Ok(game.spawn_entity(builder))
+}
+
+/// The entity with health that has the id of `target`.
+fn find_target(game: &Game, target: u64) -> Option<(Entity, EntityKind)> {
+    game.ecs
+        .query::<(&EntityKind, &Health)>()
+        .iter()
+        .find(|(entity, _)| u64::from(entity.id()) == target)
+        .map(|(entity, (kind, _))| (entity, *kind))
+}
+
+/// Hurts the entities players attack with the weapon in their
+/// main hand, setting them on fire if it has fire aspect.
+fn attack_entities(game: &mut Game) -> SysResult {
+    let attacks: Vec<(Entity, u64, Option<ItemStack>)> = game
+        .ecs
+        .query::<(&InteractEntityEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .filter(|(_, (event, _, _))| matches!(event.ty, InteractionType::Attack))
+        .map(|(player, (event, inventory, hotbar_slot))| {
+            let weapon = held_item(inventory, *hotbar_slot, Hand::Main)
+                .option_ref()
+                .cloned();
+            (player, event.target.0, weapon)
+        })
+        .collect();
+
+    for (player, target, weapon) in attacks {
+        let (target, kind) = match find_target(game, target) {
+            Some(target) => target,
+            None => continue,
+        };
+        let damage = attack_damage(weapon.as_ref(), kind);
+        let dealt = apply_damage(game, target, Some(player), damage, DamageType::Attack)?;
+        if let (Some(weapon), true) = (weapon, dealt > 0.0) {
+            fire::ignite(game, target, fire_aspect_ticks(&weapon))?;
+        }
+    }
+    Ok(())
+}
+
+/// Lets hostile mobs hit the closest player within their reach
+/// with the weapon in their main hand.
+fn mob_melee_attacks(game: &mut Game) -> SysResult {
+    if game.tick_count % MOB_ATTACK_INTERVAL != 0 {
+        return Ok(());
+    }
+    let players: Vec<(Entity, Position)> = game
+        .ecs
+        .query::<(&EntityKind, &Position, &Health)>()
+        .iter()
+        .filter(|(_, (kind, _, health))| **kind == EntityKind::Player && health.current > 0.0)
+        .map(|(player, (_, position, _))| (player, *position))
+        .collect();
+    let mut attacks = Vec::new();
+    for (mob, (kind, position, equipment)) in game
+        .ecs
+        .query::<(&EntityKind, &Position, Option<&Equipment>)>()
+        .iter()
+    {
+        let base_damage = match mob_base_damage(*kind) {
+            Some(damage) => damage,
+            None => continue,
+        };
+        let target = players
+            .iter()
+            .map(|(player, target)| (*player, target.distance_to(*position)))
+            .filter(|(_, distance)| *distance <= MOB_REACH)
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+        if let Some((player, _)) = target {
+            let damage = equipment.map_or(base_damage, |equipment| {
+                mob_attack_damage(base_damage, equipment, EntityKind::Player)
+            });
+            attacks.push((mob, player, damage));
+        }
+    }
+
+    for (mob, player, damage) in attacks {
+        apply_damage(game, player, Some(mob), damage, DamageType::Attack)?;
+    }
+    Ok(())
+}
+
+/// Hurts the entities arrows flew into this tick, more so the faster
+/// the arrow, and removes the arrows.
+fn hit_entities_with_arrows(game: &mut Game) -> SysResult {
+    let arrows: Vec<(Entity, ShotArrow, Position, Velocity)> = game
+        .ecs
+        .query::<(&ShotArrow, &Position, &Velocity)>()
+        .iter()
+        .map(|(arrow, (shot, position, velocity))| (arrow, *shot, *position, *velocity))
+        .collect();
+    if arrows.is_empty() {
+        return Ok(());
+    }
+    let targets: Vec<(Entity, Bounds)> = game
+        .ecs
+        .query::<(&EntityKind, &Position, &Health)>()
+        .iter()
+        .map(|(entity, (kind, position, _))| (entity, Bounds::of_entity(*kind, *position)))
+        .collect();
+
+    for (arrow, shot, position, velocity) in arrows {
+        let speed =
+            (velocity.x * velocity.x + velocity.y * velocity.y + velocity.z * velocity.z).sqrt();
+        if speed < 0.1 {
+            continue;
+        }
+        // Check along the path the arrow flew this tick
+        let steps = (speed * 4.0).ceil() as u32;
+        let hit = (0..=steps)
+            .map(|step| {
+                let back = f64::from(step) / f64::from(steps);
+                (
+                    position.x - velocity.x * back,
+                    position.y - velocity.y * back,
+                    position.z - velocity.z * back,
+                )
+            })
+            .find_map(|(x, y, z)| {
+                targets.iter().find(|(entity, bounds)| {
+                    Some(*entity) != shot.shooter
+                        && bounds.min.x <= x
+                        && x <= bounds.max.x
+                        && bounds.min.y <= y
+                        && y <= bounds.max.y
+                        && bounds.min.z <= z
+                        && z <= bounds.max.z
+                })
+            })
+            .map(|(entity, _)| *entity);
+
+        if let Some(target) = hit {
+            let damage = (speed as f32 * shot.damage).ceil();
+            apply_damage(game, target, shot.shooter, damage, DamageType::Projectile)?;
+            game.remove_entity(arrow)?;
+        }
+    }
+    Ok(())
+}
+
+/// The item `entity` holds in its main hand: the weapon
+/// in its equipment, or the selected item of a player.
+pub fn main_hand_item(game: &Game, entity: Entity) -> Option<ItemStack> {
+    if let Ok(equipment) = game.ecs.get::<Equipment>(entity) {
+        return equipment.main_hand.clone();
+    }
+    let inventory = game.ecs.get::<Inventory>(entity).ok()?;
+    let hotbar_slot = game.ecs.get::<HotbarSlot>(entity).ok()?;
+    let held = held_item(&inventory, *hotbar_slot, Hand::Main);
+    held.option_ref().cloned()
+}
+
+fn count_down_invulnerability(game: &mut Game) -> SysResult {
+    for (_, health) in game.ecs.query::<&mut Health>().iter() {
+        health.invulnerable_ticks = health.invulnerable_ticks.saturating_sub(1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use libcraft_items::InventorySlot;
+    use quill_common::{events::EntityRemoveEvent, EntityId};
+
+    use super::*;
+
+    fn armored(game: &mut Game, armor: Equipment) -> Entity {
+        game.ecs
+            .spawn((position!(0.0, 64.0, 0.0), Health::new(20.0), armor))
+    }
+
+    #[test]
+    fn smite_only_hurts_undead_more() {
+        let mut sword = ItemStack::new(Item::IronSword, 1).unwrap();
+        assert_eq!(attack_damage(Some(&sword), EntityKind::Zombie), 6.0);
+        sword.set_enchantment_level(EnchantmentKind::Smite, 2);
+        assert_eq!(attack_damage(Some(&sword), EntityKind::Zombie), 11.0);
+        assert_eq!(attack_damage(Some(&sword), EntityKind::Cow), 6.0);
+        sword.set_enchantment_level(EnchantmentKind::Sharpness, 1);
+        assert_eq!(attack_damage(Some(&sword), EntityKind::Cow), 7.0);
+        assert_eq!(attack_damage(None, EntityKind::Cow), FIST_DAMAGE);
+    }
+
+    #[test]
+    fn armor_reduces_damage_to_mobs() {
+        let mut equipment = Equipment::default();
+        let unarmored =
+            damage_after_armor(6.0, equipment.armor_points(), equipment.armor_toughness());
+        assert_eq!(unarmored, 6.0);
+
+        equipment.helmet = ItemStack::new(Item::IronHelmet, 1).ok();
+        equipment.set(
+            EquipmentSlot::Chest,
+            ItemStack::new(Item::DiamondChestplate, 1).ok(),
+        );
+        assert_eq!(equipment.armor_points(), 10.0);
+        let armored =
+            damage_after_armor(6.0, equipment.armor_points(), equipment.armor_toughness());
+        assert!(armored < unarmored, "{} damage through armor", armored);
+
+        equipment.main_hand = ItemStack::new(Item::IronSword, 1).ok();
+        assert_eq!(mob_attack_damage(3.0, &equipment, EntityKind::Player), 8.0);
+    }
+
+    #[test]
+    fn armor_reduces_physical_damage() {
+        let mut game = Game::new();
+        let bare = armored(&mut game, Equipment::default());
+        let iron = armored(
+            &mut game,
+            Equipment {
+                chestplate: ItemStack::new(Item::IronChestplate, 1).ok(),
+                ..Default::default()
+            },
+        );
+
+        let attack = |game: &mut Game, target| {
+            apply_damage(game, target, None, 8.0, DamageType::Attack).unwrap()
+        };
+        assert_eq!(attack(&mut game, bare), 8.0);
+        assert!(attack(&mut game, iron) < 8.0);
+        assert_eq!(game.ecs.get::<Health>(bare).unwrap().current, 12.0);
+
+        // Falls go straight through armor
+        let equipment = game.ecs.get::<Equipment>(iron).unwrap().clone();
+        let fall = armored(&mut game, equipment);
+        assert_eq!(
+            apply_damage(&mut game, fall, None, 8.0, DamageType::Fall).unwrap(),
+            8.0
+        );
+    }
+
+    #[test]
+    fn fire_protection_reduces_fire_damage() {
+        let mut game = Game::new();
+        let mut boots = ItemStack::new(Item::LeatherBoots, 1).unwrap();
+        boots.set_enchantment_level(EnchantmentKind::FireProtection, 4);
+        let equipment = Equipment {
+            boots: Some(boots),
+            ..Default::default()
+        };
+        let burning = armored(&mut game, equipment.clone());
+        let attacked = armored(&mut game, equipment);
+        let bare = armored(&mut game, Equipment::default());
+
+        let burnt = apply_damage(&mut game, burning, None, 4.0, DamageType::Fire).unwrap();
+        assert_eq!(burnt, 4.0 * (1.0 - 8.0 / 25.0));
+        let hit = apply_damage(&mut game, attacked, None, 4.0, DamageType::Attack).unwrap();
+        assert!(hit > burnt, "fire protection reduced an attack to {}", hit);
+        assert_eq!(
+            apply_damage(&mut game, bare, None, 4.0, DamageType::Fire).unwrap(),
+            4.0
+        );
+    }
+
+    #[test]
+    fn invulnerability_prevents_rapid_hits() {
+        let mut game = Game::new();
+        let target = armored(&mut game, Equipment::default());
+        assert_eq!(
+            apply_damage(&mut game, target, None, 3.0, DamageType::Attack).unwrap(),
+            3.0
+        );
+        assert_eq!(
+            apply_damage(&mut game, target, None, 3.0, DamageType::Attack).unwrap(),
+            0.0
+        );
+        assert_eq!(game.ecs.get::<Health>(target).unwrap().current, 17.0);
+
+        for _ in 0..INVULNERABLE_TICKS {
+            count_down_invulnerability(&mut game).unwrap();
+        }
+        assert_eq!(
+            apply_damage(&mut game, target, None, 3.0, DamageType::Attack).unwrap(),
+            3.0
+        );
+    }
+
+    #[test]
+    fn only_the_larger_hit_counts_while_invulnerable() {
+        let mut game = Game::new();
+        let weak_first = armored(&mut game, Equipment::default());
+        let strong_first = armored(&mut game, Equipment::default());
+        for (target, hits) in [(weak_first, [2.0, 5.0]), (strong_first, [5.0, 2.0])].iter() {
+            for hit in hits.iter() {
+                apply_damage(&mut game, *target, None, *hit, DamageType::Attack).unwrap();
+            }
+            let health = *game.ecs.get::<Health>(*target).unwrap();
+            assert_eq!(health.current, 15.0);
+            assert_eq!(health.last_damage_amount, 5.0);
+        }
+    }
+
+    #[test]
+    fn players_attack_with_their_weapon() {
+        let mut game = Game::new();
+        let inventory = Inventory::player();
+        let mut sword = ItemStack::new(Item::IronSword, 1).unwrap();
+        sword.set_enchantment_level(EnchantmentKind::FireAspect, 1);
+        *inventory.item(Area::Hotbar, 0).unwrap() = InventorySlot::Filled(sword);
+        let player = game.ecs.spawn((
+            EntityKind::Player,
+            position!(0.0, 64.0, 0.0),
+            inventory,
+            HotbarSlot::new(0),
+        ));
+        let cow = game.ecs.spawn((
+            EntityKind::Cow,
+            position!(1.0, 64.0, 0.0),
+            Health::new(10.0),
+        ));
+
+        let attack = InteractEntityEvent {
+            target: EntityId(u64::from(cow.id())),
+            ty: InteractionType::Attack,
+            target_pos: None,
+            hand: None,
+            sneaking: false,
+        };
+        game.ecs.insert_entity_event(player, attack).unwrap();
+        attack_entities(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<Health>(cow).unwrap().current, 4.0);
+        assert_eq!(
+            game.ecs.get::<fire::Burning>(cow).unwrap().ticks_remaining,
+            80
+        );
+    }
+
+    #[test]
+    fn arrows_hurt_what_they_fly_into() {
+        let mut game = Game::new();
+        let zombie = game.ecs.spawn((
+            EntityKind::Zombie,
+            position!(0.0, 64.0, 2.0),
+            Health::new(20.0),
+        ));
+        let arrow = game.ecs.spawn((
+            position!(0.0, 65.0, 2.5),
+            Velocity {
+                x: 0.0,
+                y: 0.0,
+                z: 3.0,
+            },
+            ShotArrow {
+                damage: arrow_damage(None),
+                shooter: None,
+            },
+        ));
+
+        hit_entities_with_arrows(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<Health>(zombie).unwrap().current, 14.0);
+        assert!(game.ecs.get::<EntityRemoveEvent>(arrow).is_ok());
+    }
+
+    #[test]
+    fn mobs_hit_players_in_reach() {
+        let mut game = Game::new();
+        game.insert_resource(Difficulty::Normal);
+        let player = game.ecs.spawn((
+            EntityKind::Player,
+            position!(0.0, 64.0, 0.0),
+            Health::new(20.0),
+        ));
+        let equipment = Equipment {
+            main_hand: ItemStack::new(Item::IronSword, 1).ok(),
+            ..Default::default()
+        };
+        game.ecs
+            .spawn((EntityKind::Zombie, position!(1.5, 64.0, 0.0), equipment));
+        game.ecs
+            .spawn((EntityKind::Zombie, position!(10.0, 64.0, 0.0)));
+
+        mob_melee_attacks(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<Health>(player).unwrap().current, 12.0);
+    }
+
+    #[test]
+    fn killing_blow_records_the_weapon() {
+        let mut game = Game::new();
+        let sword = ItemStack::new(Item::IronSword, 1).unwrap();
+        let killer = game.ecs.spawn((
+            EntityKind::Zombie,
+            Equipment {
+                main_hand: Some(sword.clone()),
+                ..Default::default()
+            },
+        ));
+        let cow = game.ecs.spawn((EntityKind::Cow, Health::new(4.0)));
+
+        apply_damage(&mut game, cow, Some(killer), 2.0, DamageType::Attack).unwrap();
+        assert!(game.ecs.get::<EntityDeathEvent>(cow).is_err());
+        apply_damage(&mut game, cow, Some(killer), 5.0, DamageType::Attack).unwrap();
+        let death = game.ecs.get::<EntityDeathEvent>(cow).unwrap();
+        assert_eq!(death.killer, Some(killer));
+        assert_eq!(death.weapon, Some(sword));
+    }
+}
@@ -0,0 +1,232 @@
+//! Composters, which turn plant matter into bone meal.
+//!
+//! Each compostable item has a chance to raise the fill level. Shortly after
+//! filling up, the composter is ready and using it yields a piece of bone meal.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{position, BlockKind, Inventory, Item, ItemStack, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::events::BlockInteractEvent;
+use rand::Rng;
+
+use crate::{
+    entities::{
+        item,
+        player::{held_item, HotbarSlot},
+    },
+    interactable::InteractableRegistry,
+    Game,
+};
+
+/// Level at which a composter stops accepting items
+const FULL_LEVEL: u8 = 7;
+/// Level of a composter ready to yield bone meal
+const READY_LEVEL: u8 = 8;
+/// Game ticks it takes a full composter to become ready
+const READY_TICKS: u32 = 20;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry")
+        .register(BlockKind::Composter);
+    systems.add_system(use_composters);
+}
+
+/// The chance that composting the item raises a composter's level,
+/// or `None` if the item cannot be composted.
+pub fn compost_chance(item: Item) -> Option<f64> {
+    let name = item.name();
+    let chance = match item {
+        Item::WheatSeeds
+        | Item::BeetrootSeeds
+        | Item::MelonSeeds
+        | Item::PumpkinSeeds
+        | Item::Kelp
+        | Item::DriedKelp
+        | Item::SweetBerries
+        | Item::Grass => 0.3,
+        Item::Cactus
+        | Item::MelonSlice
+        | Item::SugarCane
+        | Item::TallGrass
+        | Item::Vine
+        | Item::NetherSprouts => 0.5,
+        Item::Apple
+        | Item::Beetroot
+        | Item::Carrot
+        | Item::CocoaBeans
+        | Item::Potato
+        | Item::Wheat
+        | Item::Pumpkin
+        | Item::Melon
+        | Item::LilyPad
+        | Item::BrownMushroom
+        | Item::RedMushroom => 0.65,
+        Item::BakedPotato | Item::Bread | Item::Cookie | Item::HayBlock => 0.85,
+        Item::Cake | Item::PumpkinPie => 1.0,
+        _ if name.ends_with("_leaves") || name.ends_with("_sapling") => 0.3,
+        _ => return None,
+    };
+    Some(chance)
+}
+
+fn level_at(game: &Game, pos: ValidBlockPosition) -> SysResult<u8> {
+    match game.block_properties(pos) {
+        Some(properties) if properties.kind() == BlockKind::Composter => Ok(properties
+            .get("level")
+            .and_then(|level| level.parse().ok())
+            .unwrap_or(0)),
+        _ => bail!("no composter at {:?}", pos),
+    }
+}
+
+fn set_level(game: &mut Game, pos: ValidBlockPosition, level: u8) {
+    if let Some(mut properties) = game.block_properties(pos) {
+        properties.set("level", &level.to_string());
+        game.set_block_properties(pos, &properties);
+    }
+}
+
+/// The signal read by a comparator next to the composter at `pos`,
+/// which is its fill level.
+pub fn comparator_output(game: &Game, pos: ValidBlockPosition) -> u8 {
+    level_at(game, pos).unwrap_or(0)
+}
+
+/// Adds an item to the composter at `pos`, rolling its chance to raise
+/// the level. The first item into an empty composter always counts.
+/// A composter filled this way becomes ready a second later.
+///
+/// Returns whether the item was accepted; the caller consumes it if so.
+pub fn compost(
+    game: &mut Game,
+    pos: ValidBlockPosition,
+    item: Item,
+    rng: &mut impl Rng,
+) -> SysResult<bool> {
+    let level = level_at(game, pos)?;
+    let chance = match compost_chance(item) {
+        Some(chance) if level < FULL_LEVEL => chance,
+        _ => return Ok(false),
+    };
+    if level == 0 || rng.gen::<f64>() < chance {
+        set_level(game, pos, level + 1);
+        if level + 1 == FULL_LEVEL {
+            game.block_integration.tick_executor_mut().schedule_tick(
+                (pos.x(), pos.y(), pos.z()),
+                BlockKind::Composter,
+                READY_TICKS,
+                0,
+            );
+        }
+    }
+    Ok(true)
+}
+
+/// Empties the ready composter at `pos`, dropping a piece
+/// of bone meal on top of it.
+///
+/// Returns the dropped item, or `None` if the composter is not ready.
+pub fn take_bone_meal(game: &mut Game, pos: ValidBlockPosition) -> SysResult<Option<Entity>> {
+    if level_at(game, pos)? != READY_LEVEL {
+        return Ok(None);
+    }
+    set_level(game, pos, 0);
+    let position = position!(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 1.0,
+        f64::from(pos.z()) + 0.5
+    );
+    let bone_meal = ItemStack::new(Item::BoneMeal, 1)?;
+    Ok(Some(item::spawn_item(game, position, bone_meal)))
+}
+
+/// Fills composters with the items players use on them
+/// and empties ready ones.
+fn use_composters(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockInteractEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockInteractEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let pos: ValidBlockPosition = match event.location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let level = match level_at(game, pos) {
+            Ok(level) => level,
+            Err(_) => continue,
+        };
+        if level == READY_LEVEL {
+            take_bone_meal(game, pos)?;
+            continue;
+        }
+
+        let mut held = held_item(&inventory, hotbar_slot, event.hand);
+        if let Some(item) = held.item_kind() {
+            if game.with_rng(|game, rng| compost(game, pos, item, rng))? {
+                let _ = held.try_take(1);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockPosition, ChunkPosition};
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    #[test]
+    fn full_composter_yields_bone_meal() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        let mut composter = BlockProperties::new(BlockKind::Composter);
+        composter.set("level", "0");
+        assert!(game.set_block_properties(pos, &composter));
+        assert!(!compost(&mut game, pos, Item::Stone, &mut rand::thread_rng()).unwrap());
+        assert_eq!(take_bone_meal(&mut game, pos).unwrap(), None);
+
+        // Pumpkin pie always raises the level
+        for level in 1..FULL_LEVEL {
+            assert!(compost(&mut game, pos, Item::PumpkinPie, &mut rand::thread_rng()).unwrap());
+            assert_eq!(comparator_output(&game, pos), level);
+        }
+        assert!(compost(&mut game, pos, Item::PumpkinPie, &mut rand::thread_rng()).unwrap());
+        assert_eq!(comparator_output(&game, pos), FULL_LEVEL);
+        assert!(!compost(&mut game, pos, Item::PumpkinPie, &mut rand::thread_rng()).unwrap());
+        assert_eq!(take_bone_meal(&mut game, pos).unwrap(), None);
+
+        // The compost finishes a second later
+        for _ in 1..READY_TICKS {
+            game.run_block_ticks();
+        }
+        assert_eq!(comparator_output(&game, pos), FULL_LEVEL);
+        game.run_block_ticks();
+        assert_eq!(comparator_output(&game, pos), READY_LEVEL);
+        assert_eq!(
+            game.block_properties(pos).unwrap().get("level").unwrap(),
+            "8"
+        );
+
+        let bone_meal = take_bone_meal(&mut game, pos).unwrap().unwrap();
+        assert_eq!(
+            *game.ecs.get::<ItemStack>(bone_meal).unwrap(),
+            ItemStack::new(Item::BoneMeal, 1).unwrap()
+        );
+        assert_eq!(comparator_output(&game, pos), 0);
+    }
+}
@@ -0,0 +1,265 @@
+//! Conduits, which power up players in the water around them.
+//!
+//! A conduit works while it is submerged and framed by at least
+//! 16 prismarine or sea lantern blocks. Once the frame is complete,
+//! it also attacks the closest hostile mob swimming nearby.
+
+use std::{cmp::Ordering, convert::TryInto};
+
+use anyhow::bail;
+use base::{position, BlockId, BlockKind, BlockPosition, EntityKind, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_blocks::BlockEntityKind;
+use quill_common::entities::Player;
+
+use crate::{
+    combat::{self, DamageType},
+    entities,
+    status_effects::{self, StatusEffect, StatusEffectKind},
+    Game,
+};
+
+/// Ticks between updates of conduits
+const UPDATE_INTERVAL: u64 = 40;
+/// Frame blocks a conduit needs to activate
+pub const MIN_FRAME_BLOCKS: usize = 16;
+/// Frame blocks in a complete frame
+pub const FULL_FRAME_BLOCKS: usize = 42;
+/// Ticks of Conduit Power given on each update
+const EFFECT_DURATION: u32 = 260;
+/// Distance within which a fully framed conduit attacks hostile mobs
+const ATTACK_RANGE: f64 = 8.0;
+/// Damage dealt to hostile mobs on each update
+const ATTACK_DAMAGE: f32 = 4.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_conduits);
+}
+
+fn is_frame_block(kind: BlockKind) -> bool {
+    matches!(
+        kind,
+        BlockKind::Prismarine
+            | BlockKind::PrismarineBricks
+            | BlockKind::DarkPrismarine
+            | BlockKind::SeaLantern
+    )
+}
+
+fn is_water(block: BlockId) -> bool {
+    block.kind() == BlockKind::Water || block.waterlogged() == Some(true)
+}
+
+fn is_water_at(game: &Game, position: Position) -> bool {
+    let pos: Option<ValidBlockPosition> = position.block().try_into().ok();
+    pos.and_then(|pos| game.block(pos)).map_or(false, is_water)
+}
+
+fn offset(pos: ValidBlockPosition, dx: i32, dy: i32, dz: i32) -> Option<ValidBlockPosition> {
+    BlockPosition::new(pos.x() + dx, pos.y() + dy, pos.z() + dz)
+        .try_into()
+        .ok()
+}
+
+/// Whether the block `(dx, dy, dz)` away from a conduit is part of its
+/// frame: the three 5×5 rings around it along each axis.
+fn in_frame(dx: i32, dy: i32, dz: i32) -> bool {
+    let (ax, ay, az) = (dx.abs(), dy.abs(), dz.abs());
+    (dx == 0 && (ay == 2 || az == 2))
+        || (dy == 0 && (ax == 2 || az == 2))
+        || (dz == 0 && (ax == 2 || ay == 2))
+}
+
+/// The number of frame blocks around the conduit at `pos`.
+pub fn frame_size(game: &Game, pos: ValidBlockPosition) -> usize {
+    let mut count = 0;
+    for dx in -2..=2 {
+        for dy in -2..=2 {
+            for dz in -2..=2 {
+                if !in_frame(dx, dy, dz) {
+                    continue;
+                }
+                let block = offset(pos, dx, dy, dz).and_then(|pos| game.block(pos));
+                if block.map_or(false, |block| is_frame_block(block.kind())) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Whether the conduit at `pos` and the 3×3×3 area around it are under water.
+pub fn is_submerged(game: &Game, pos: ValidBlockPosition) -> bool {
+    (-1..=1).all(|dx| {
+        (-1..=1).all(|dy| {
+            (-1..=1).all(|dz| {
+                offset(pos, dx, dy, dz)
+                    .and_then(|pos| game.block(pos))
+                    .map_or(false, is_water)
+            })
+        })
+    })
+}
+
+/// The distance within which a conduit with `frame_size`
+/// frame blocks gives Conduit Power: 16 blocks for every 7.
+pub fn power_range(frame_size: usize) -> f64 {
+    (frame_size / 7 * 16) as f64
+}
+
+/// Updates the conduit at `pos`, giving Conduit Power to players in the
+/// water within range and attacking a hostile mob if the frame is complete.
+///
+/// Returns whether the conduit is active.
+pub fn update_conduit(game: &mut Game, pos: ValidBlockPosition) -> SysResult<bool> {
+    match game.block(pos) {
+        Some(block) if block.kind() == BlockKind::Conduit => {}
+        _ => bail!("no conduit at {:?}", pos),
+    }
+    let frame_size = frame_size(game, pos);
+    if frame_size < MIN_FRAME_BLOCKS || !is_submerged(game, pos) {
+        return Ok(false);
+    }
+
+    let center = position!(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 0.5,
+        f64::from(pos.z()) + 0.5
+    );
+    let range = power_range(frame_size);
+    let players: Vec<Entity> = game
+        .ecs
+        .query::<(&Player, &Position)>()
+        .iter()
+        .filter(|(_, (_, position))| {
+            position.distance_to(center) <= range && is_water_at(game, **position)
+        })
+        .map(|(player, _)| player)
+        .collect();
+    for player in players {
+        let effect = StatusEffect::new(StatusEffectKind::ConduitPower, 0, EFFECT_DURATION);
+        status_effects::apply_effect(game, player, effect)?;
+    }
+
+    if frame_size >= FULL_FRAME_BLOCKS {
+        let target = game
+            .ecs
+            .query::<(&EntityKind, &Position)>()
+            .iter()
+            .filter(|(_, (kind, position))| {
+                entities::is_monster(**kind)
+                    && position.distance_to(center) <= ATTACK_RANGE
+                    && is_water_at(game, **position)
+            })
+            .map(|(entity, (_, position))| (entity, position.distance_to(center)))
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(entity, _)| entity);
+        if let Some(target) = target {
+            combat::apply_damage(game, target, None, ATTACK_DAMAGE, DamageType::Magic)?;
+            game.emit_sound("block.conduit.attack.target", center, 1.0, 1.0);
+        }
+    }
+    Ok(true)
+}
+
+fn update_conduits(game: &mut Game) -> SysResult {
+    if game.tick_count % UPDATE_INTERVAL != 0 {
+        return Ok(());
+    }
+
+    let conduits: Vec<(i32, i32, i32)> = game
+        .block_entities
+        .iter()
+        .filter(|entity| entity.kind == BlockEntityKind::Conduit)
+        .map(|entity| entity.position)
+        .collect();
+    for (x, y, z) in conduits {
+        if let Ok(pos) = BlockPosition::new(x, y, z).try_into() {
+            update_conduit(game, pos)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{Chunk, ChunkPosition};
+    use quill_common::components::Health;
+
+    use crate::status_effects::StatusEffects;
+
+    use super::*;
+
+    fn underwater_conduit(game: &mut Game) -> ValidBlockPosition {
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        for x in 0..16 {
+            for y in 60..70 {
+                for z in 0..16 {
+                    let pos = BlockPosition::new(x, y, z).try_into().unwrap();
+                    game.set_block(pos, BlockId::water());
+                }
+            }
+        }
+        let pos: ValidBlockPosition = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        game.set_block(pos, BlockId::conduit());
+        pos
+    }
+
+    fn build_frame(game: &mut Game, pos: ValidBlockPosition, blocks: usize) {
+        let mut placed = 0;
+        for dx in -2..=2 {
+            for dy in -2..=2 {
+                for dz in -2..=2 {
+                    if placed < blocks && in_frame(dx, dy, dz) {
+                        let frame = offset(pos, dx, dy, dz).unwrap();
+                        game.set_block(frame, BlockId::prismarine_bricks());
+                        placed += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn framed_conduit_powers_players_and_attacks_mobs() {
+        let mut game = Game::new();
+        let pos = underwater_conduit(&mut game);
+        let player = game.ecs.spawn((Player, position!(10.5, 62.0, 10.5)));
+        let drowned = game.ecs.spawn((
+            EntityKind::Drowned,
+            position!(6.5, 61.0, 8.5),
+            Health::new(20.0),
+        ));
+
+        build_frame(&mut game, pos, MIN_FRAME_BLOCKS - 1);
+        assert_eq!(frame_size(&game, pos), MIN_FRAME_BLOCKS - 1);
+        assert!(!update_conduit(&mut game, pos).unwrap());
+        assert!(game.ecs.get::<StatusEffects>(player).is_err());
+
+        build_frame(&mut game, pos, FULL_FRAME_BLOCKS);
+        assert_eq!(frame_size(&game, pos), FULL_FRAME_BLOCKS);
+        assert!(update_conduit(&mut game, pos).unwrap());
+        assert!(game
+            .ecs
+            .get::<StatusEffects>(player)
+            .unwrap()
+            .has(StatusEffectKind::ConduitPower));
+        assert_eq!(game.ecs.get::<Health>(drowned).unwrap().current, 16.0);
+
+        // The next attack comes once the mob can be hurt again
+        game.ecs
+            .get_mut::<Health>(drowned)
+            .unwrap()
+            .invulnerable_ticks = 0;
+        assert!(update_conduit(&mut game, pos).unwrap());
+        assert_eq!(game.ecs.get::<Health>(drowned).unwrap().current, 12.0);
+
+        // A conduit out of the water does nothing
+        let above = offset(pos, 0, 1, 0).unwrap();
+        game.set_block(above, BlockId::air());
+        assert!(!update_conduit(&mut game, pos).unwrap());
+    }
+}
@@ -0,0 +1,242 @@
+//! The difficulty of the world, which scales how dangerous mobs are
+//! and decides whether monsters exist at all.
+
+use ahash::AHashSet;
+use base::{BlockPosition, ChunkPosition, EntityKind, Position};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::{entities::Player, events::EntityRemoveEvent};
+
+use crate::{entities, Game, WorldTime};
+
+/// Ticks of world age after which regional difficulty starts to rise
+const WORLD_AGE_GRACE: f32 = 72_000.0;
+/// Ticks of world age over which regional difficulty reaches its maximum
+const WORLD_AGE_SCALE: f32 = 1_440_000.0;
+/// Ticks players must spend in a chunk for it to reach its maximum difficulty
+const INHABITED_TIME_SCALE: f32 = 3_600_000.0;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(Difficulty::default());
+    systems
+        .add_system(remove_monsters_on_peaceful)
+        .add_system(count_inhabited_time);
+}
+
+/// How hard the game is, numbered by its protocol id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    Peaceful,
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Difficulty::Normal
+    }
+}
+
+impl Difficulty {
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Difficulty::Peaceful),
+            1 => Some(Difficulty::Easy),
+            2 => Some(Difficulty::Normal),
+            3 => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+
+    /// Whether monsters are kept out of the world.
+    pub fn is_peaceful(self) -> bool {
+        self == Difficulty::Peaceful
+    }
+
+    /// The damage a mob attack of `amount` deals to players.
+    pub fn scale_damage(self, amount: f32) -> f32 {
+        match self {
+            Difficulty::Peaceful => 0.0,
+            Difficulty::Easy => (amount / 2.0 + 1.0).min(amount),
+            Difficulty::Normal => amount,
+            Difficulty::Hard => amount * 1.5,
+        }
+    }
+
+    /// Health starving players cannot drop below. Players do not
+    /// starve at all on peaceful and starve to death on hard.
+    pub fn starvation_floor(self) -> f32 {
+        match self {
+            Difficulty::Peaceful => 20.0,
+            Difficulty::Easy => 10.0,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.0,
+        }
+    }
+
+    /// The regional difficulty of a chunk players have spent
+    /// `inhabited_time` ticks in, from 0 up to 6.75 on hard.
+    ///
+    /// It rises as the world ages, as players stay in the chunk
+    /// and with the size of the moon.
+    pub fn regional_difficulty(self, inhabited_time: u64, time: &WorldTime) -> f32 {
+        if self.is_peaceful() {
+            return 0.0;
+        }
+        let hard = self == Difficulty::Hard;
+
+        let world_age = ((time.world_age as f32 - WORLD_AGE_GRACE) / WORLD_AGE_SCALE)
+            .max(0.0)
+            .min(1.0)
+            * 0.25;
+        let mut local =
+            (inhabited_time as f32 / INHABITED_TIME_SCALE).min(1.0) * if hard { 1.0 } else { 0.75 };
+        local += (time.moon_size() * 0.25).min(world_age);
+        if self == Difficulty::Easy {
+            local *= 0.5;
+        }
+        f32::from(self.id()) * (0.75 + world_age + local)
+    }
+}
+
+/// Regional difficulty mapped onto 0 to 1: 0 below 2 and 1 above 4.
+pub fn clamped_regional_difficulty(regional_difficulty: f32) -> f32 {
+    ((regional_difficulty - 2.0) / 2.0).max(0.0).min(1.0)
+}
+
+/// The regional difficulty at `pos`, given the world's difficulty,
+/// its age and the time players have spent in the chunk.
+pub fn regional_difficulty_at(game: &Game, pos: BlockPosition) -> f32 {
+    let difficulty = game
+        .resources
+        .get::<Difficulty>()
+        .map_or(Difficulty::default(), |difficulty| *difficulty);
+    let time = game
+        .resources
+        .get::<WorldTime>()
+        .map_or(WorldTime::default(), |time| *time);
+    let inhabited_time = game
+        .world
+        .chunk_map()
+        .chunk_at(pos.chunk())
+        .map_or(0, |chunk| chunk.inhabited_time());
+    difficulty.regional_difficulty(inhabited_time, &time)
+}
+
+/// Counts the time players spend in each chunk.
+fn count_inhabited_time(game: &mut Game) -> SysResult {
+    let chunks: AHashSet<ChunkPosition> = game
+        .ecs
+        .query::<(&Player, &Position)>()
+        .iter()
+        .map(|(_, (_, position))| position.chunk())
+        .collect();
+    for pos in chunks {
+        if let Some(mut chunk) = game.world.chunk_map().chunk_at_mut(pos) {
+            let inhabited_time = chunk.inhabited_time();
+            chunk.set_inhabited_time(inhabited_time + 1);
+        }
+    }
+    Ok(())
+}
+
+/// Removes all monsters while the game is on peaceful.
+fn remove_monsters_on_peaceful(game: &mut Game) -> SysResult {
+    if !game.resources.get::<Difficulty>()?.is_peaceful() {
+        return Ok(());
+    }
+    let monsters: Vec<Entity> = game
+        .ecs
+        .query::<&EntityKind>()
+        .iter()
+        .filter(|(_, kind)| entities::is_monster(**kind))
+        .map(|(entity, _)| entity)
+        .filter(|entity| game.ecs.get::<EntityRemoveEvent>(*entity).is_err())
+        .collect();
+    for monster in monsters {
+        game.remove_entity(monster)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BiomeId, BlockKind, BlockPosition};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::entities::spawning::{
+        register_default_spawn_rules, EntitySpawnManager, SpawnAttempt,
+    };
+
+    use super::*;
+
+    #[test]
+    fn peaceful_removes_and_stops_monsters() {
+        let mut game = Game::new();
+        game.insert_resource(Difficulty::Normal);
+        let zombie = game.ecs.spawn((EntityKind::Zombie,));
+        let sheep = game.ecs.spawn((EntityKind::Sheep,));
+        remove_monsters_on_peaceful(&mut game).unwrap();
+        assert!(game.ecs.get::<EntityRemoveEvent>(zombie).is_err());
+
+        *game.resources.get_mut::<Difficulty>().unwrap() = Difficulty::Peaceful;
+        remove_monsters_on_peaceful(&mut game).unwrap();
+        assert!(game.ecs.get::<EntityRemoveEvent>(zombie).is_ok());
+        assert!(game.ecs.get::<EntityRemoveEvent>(sheep).is_err());
+
+        let mut manager = EntitySpawnManager::new();
+        register_default_spawn_rules(&mut manager);
+        let view = |(_, y, _): (i32, i32, i32)| {
+            let kind = if y < 64 {
+                BlockKind::GrassBlock
+            } else {
+                BlockKind::Air
+            };
+            Some((kind, kind.default_properties()))
+        };
+        let mut spawned = Vec::new();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..200 {
+            let attempt = SpawnAttempt {
+                biome: BiomeId::Plains,
+                chunk_pos: (0, 0),
+                block_view: &view,
+                light_getter: |_: BlockPosition| 0,
+                difficulty: Difficulty::Peaceful,
+            };
+            manager.try_spawn_in_chunk(attempt, &mut rng, |group| spawned.push(group.kind));
+        }
+        assert!(!spawned.is_empty());
+        assert!(spawned.iter().all(|kind| !entities::is_monster(*kind)));
+    }
+
+    #[test]
+    fn easy_halves_damage_plus_one() {
+        assert_eq!(Difficulty::Easy.scale_damage(6.0), 4.0);
+        assert_eq!(Difficulty::Easy.scale_damage(1.0), 1.0);
+        assert_eq!(Difficulty::Hard.scale_damage(6.0), 9.0);
+        assert_eq!(Difficulty::Peaceful.scale_damage(6.0), 0.0);
+    }
+
+    #[test]
+    fn regional_difficulty_grows_with_time() {
+        let fresh = WorldTime::default();
+        let old = WorldTime {
+            world_age: 2_000_000,
+            day_time: 2_000_000,
+        };
+        assert_eq!(Difficulty::Peaceful.regional_difficulty(0, &old), 0.0);
+        assert_eq!(Difficulty::Normal.regional_difficulty(0, &fresh), 1.5);
+        assert!(
+            Difficulty::Hard.regional_difficulty(3_600_000, &old)
+                > Difficulty::Hard.regional_difficulty(0, &old)
+        );
+        assert_eq!(clamped_regional_difficulty(1.5), 0.0);
+        assert_eq!(clamped_regional_difficulty(6.75), 1.0);
+    }
+}
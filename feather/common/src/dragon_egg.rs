@@ -0,0 +1,190 @@
+//! Dragon eggs, which teleport away from players who try to use them.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{BlockId, BlockKind, BlockPosition, ParticleKind, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::events::BlockInteractEvent;
+use rand::Rng;
+
+use crate::{entities::falling_block, interactable::InteractableRegistry, Game};
+
+/// Tries at finding an empty spot before the egg stays put
+const ATTEMPTS: usize = 1000;
+/// Blocks away along the x and z axes the egg can teleport
+const HORIZONTAL_RANGE: i32 = 16;
+/// Blocks away along the y axis the egg can teleport
+const VERTICAL_RANGE: i32 = 8;
+/// Portal particles left along the egg's path
+const TRAIL_PARTICLES: usize = 16;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry")
+        .register(BlockKind::DragonEgg);
+    systems.add_system(teleport_used_dragon_eggs);
+}
+
+fn is_empty(game: &Game, pos: BlockPosition) -> bool {
+    pos.try_into()
+        .ok()
+        .and_then(|pos| game.block(pos))
+        .map_or(false, BlockId::is_air)
+}
+
+/// Finds where the egg at `origin` will land after teleporting to `target`,
+/// dropping through air like other falling blocks.
+fn landing_spot(
+    game: &Game,
+    origin: ValidBlockPosition,
+    target: BlockPosition,
+) -> Option<ValidBlockPosition> {
+    let origin: BlockPosition = origin.into();
+    if !is_empty(game, target) {
+        return None;
+    }
+    let mut pos = target;
+    loop {
+        let below = pos.down();
+        if below != origin && !is_empty(game, below) {
+            break;
+        }
+        // Eggs falling out of the world are lost, so look elsewhere
+        below.try_into().ok().and_then(|below| game.block(below))?;
+        pos = below;
+    }
+    if pos == origin {
+        return None;
+    }
+    pos.try_into().ok()
+}
+
+fn center(pos: BlockPosition) -> Position {
+    Position {
+        x: f64::from(pos.x) + 0.5,
+        y: f64::from(pos.y) + 0.5,
+        z: f64::from(pos.z) + 0.5,
+        ..Default::default()
+    }
+}
+
+/// Teleports the dragon egg at `pos` to a random empty spot nearby,
+/// from where it falls to the ground as a falling block.
+///
+/// Returns the falling egg, or `None` if no spot was found
+/// and it stayed where it was.
+pub fn teleport_egg(
+    game: &mut Game,
+    pos: ValidBlockPosition,
+    rng: &mut impl Rng,
+) -> SysResult<Option<Entity>> {
+    let egg = match game.block(pos) {
+        Some(block) if block.kind() == BlockKind::DragonEgg => block,
+        _ => bail!("no dragon egg at {:?}", pos),
+    };
+
+    for _ in 0..ATTEMPTS {
+        let target = BlockPosition::new(
+            pos.x() + rng.gen_range(0..HORIZONTAL_RANGE) - rng.gen_range(0..HORIZONTAL_RANGE),
+            pos.y() + rng.gen_range(0..VERTICAL_RANGE) - rng.gen_range(0..VERTICAL_RANGE),
+            pos.z() + rng.gen_range(0..HORIZONTAL_RANGE) - rng.gen_range(0..HORIZONTAL_RANGE),
+        );
+        // Only teleport where the egg has something to land on
+        let target: ValidBlockPosition = match target.try_into() {
+            Ok(valid) if landing_spot(game, pos, target).is_some() => valid,
+            _ => continue,
+        };
+
+        game.set_block(pos, BlockId::air());
+        let falling = falling_block::spawn_falling_block(game, target, egg);
+
+        // Trail portal particles from where the egg was to where it appeared
+        let (from, to) = (center(pos.into()), center(target.into()));
+        for i in 0..TRAIL_PARTICLES {
+            let t = i as f64 / (TRAIL_PARTICLES - 1) as f64;
+            let position = Position {
+                x: from.x + (to.x - from.x) * t,
+                y: from.y + (to.y - from.y) * t,
+                z: from.z + (to.z - from.z) * t,
+                ..from
+            };
+            game.emit_particle(ParticleKind::Portal, position, 8, 0.5);
+        }
+        return Ok(Some(falling));
+    }
+    Ok(None)
+}
+
+fn teleport_used_dragon_eggs(game: &mut Game) -> SysResult {
+    let eggs: Vec<ValidBlockPosition> = game
+        .ecs
+        .query::<&BlockInteractEvent>()
+        .iter()
+        .filter_map(|(_, event)| event.location.try_into().ok())
+        .collect();
+    for pos in eggs {
+        if game.block(pos).map(BlockId::kind) == Some(BlockKind::DragonEgg) {
+            game.with_rng(|game, rng| teleport_egg(game, pos, rng))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::ChunkPosition;
+    use quill_common::entities::FallingBlock;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{entities, physics, test_support::TestGame};
+
+    use super::*;
+
+    #[test]
+    fn used_dragon_eggs_teleport_and_fall() {
+        let mut test = TestGame::builder()
+            .chunk(ChunkPosition::new(0, 0))
+            .register(|game, systems| {
+                game.add_entity_spawn_callback(entities::add_entity_components);
+                physics::register(systems);
+                falling_block::register(systems);
+            })
+            .build();
+        test.fill(
+            BlockPosition::new(0, 63, 0),
+            BlockPosition::new(15, 63, 15),
+            BlockId::obsidian(),
+        );
+        let pos: ValidBlockPosition = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        test.game.set_block(pos, BlockId::dragon_egg());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let egg = teleport_egg(&mut test.game, pos, &mut rng)
+            .unwrap()
+            .unwrap();
+        test.assert_block(pos.into(), BlockKind::Air);
+        test.assert_component(egg, BlockId::dragon_egg());
+
+        let eggs = |test: &TestGame| -> Vec<BlockPosition> {
+            (0..16)
+                .flat_map(|x| (0..16).map(move |z| BlockPosition::new(x, 64, z)))
+                .filter(|pos| {
+                    let pos: ValidBlockPosition = (*pos).try_into().unwrap();
+                    test.game.block(pos).unwrap().kind() == BlockKind::DragonEgg
+                })
+                .collect()
+        };
+        assert!(eggs(&test).is_empty());
+
+        // The egg falls onto the floor wherever it appeared
+        test.step_n(2 * VERTICAL_RANGE as u32 + 20);
+        test.assert_no_component::<FallingBlock>(egg);
+        let landed = eggs(&test);
+        assert_eq!(landed.len(), 1);
+        assert_ne!(landed[0], pos.into());
+        assert!((landed[0].x - pos.x()).abs() < HORIZONTAL_RANGE);
+        assert!((landed[0].z - pos.z()).abs() < HORIZONTAL_RANGE);
+    }
+}
@@ -0,0 +1,103 @@
+//! The sixteen dye colors, which color wool, collars and more.
+
+use base::Item;
+
+/// A dye color, numbered by its protocol id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DyeColor {
+    White,
+    Orange,
+    Magenta,
+    LightBlue,
+    Yellow,
+    Lime,
+    Pink,
+    Gray,
+    LightGray,
+    Cyan,
+    Purple,
+    Blue,
+    Brown,
+    Green,
+    Red,
+    Black,
+}
+
+impl Default for DyeColor {
+    fn default() -> Self {
+        DyeColor::White
+    }
+}
+
+impl DyeColor {
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// The color of a dye item.
+    pub fn from_dye(dye: Item) -> Option<Self> {
+        Some(match dye {
+            Item::WhiteDye => DyeColor::White,
+            Item::OrangeDye => DyeColor::Orange,
+            Item::MagentaDye => DyeColor::Magenta,
+            Item::LightBlueDye => DyeColor::LightBlue,
+            Item::YellowDye => DyeColor::Yellow,
+            Item::LimeDye => DyeColor::Lime,
+            Item::PinkDye => DyeColor::Pink,
+            Item::GrayDye => DyeColor::Gray,
+            Item::LightGrayDye => DyeColor::LightGray,
+            Item::CyanDye => DyeColor::Cyan,
+            Item::PurpleDye => DyeColor::Purple,
+            Item::BlueDye => DyeColor::Blue,
+            Item::BrownDye => DyeColor::Brown,
+            Item::GreenDye => DyeColor::Green,
+            Item::RedDye => DyeColor::Red,
+            Item::BlackDye => DyeColor::Black,
+            _ => return None,
+        })
+    }
+
+    /// The RGB color leather armor and cauldron water take on with this dye.
+    pub fn rgb(self) -> u32 {
+        match self {
+            DyeColor::White => 0xF9FFFE,
+            DyeColor::Orange => 0xF9801D,
+            DyeColor::Magenta => 0xC74EBD,
+            DyeColor::LightBlue => 0x3AB3DA,
+            DyeColor::Yellow => 0xFED83D,
+            DyeColor::Lime => 0x80C71F,
+            DyeColor::Pink => 0xF38BAA,
+            DyeColor::Gray => 0x474F52,
+            DyeColor::LightGray => 0x9D9D97,
+            DyeColor::Cyan => 0x169C9C,
+            DyeColor::Purple => 0x8932B8,
+            DyeColor::Blue => 0x3C44AA,
+            DyeColor::Brown => 0x835432,
+            DyeColor::Green => 0x5E7C16,
+            DyeColor::Red => 0xB02E26,
+            DyeColor::Black => 0x1D1D21,
+        }
+    }
+
+    /// The wool block of this color.
+    pub fn wool(self) -> Item {
+        match self {
+            DyeColor::White => Item::WhiteWool,
+            DyeColor::Orange => Item::OrangeWool,
+            DyeColor::Magenta => Item::MagentaWool,
+            DyeColor::LightBlue => Item::LightBlueWool,
+            DyeColor::Yellow => Item::YellowWool,
+            DyeColor::Lime => Item::LimeWool,
+            DyeColor::Pink => Item::PinkWool,
+            DyeColor::Gray => Item::GrayWool,
+            DyeColor::LightGray => Item::LightGrayWool,
+            DyeColor::Cyan => Item::CyanWool,
+            DyeColor::Purple => Item::PurpleWool,
+            DyeColor::Blue => Item::BlueWool,
+            DyeColor::Brown => Item::BrownWool,
+            DyeColor::Green => Item::GreenWool,
+            DyeColor::Red => Item::RedWool,
+            DyeColor::Black => Item::BlackWool,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Dyes, used on sheep to color their wool and on
+//! tamed wolves and cats to color their collars.
+
+use base::EntityKind;
+use ecs::{Entity, SysResult};
+use libcraft_items::InventorySlot;
+use quill_common::components::Tameable;
+use uuid::Uuid;
+
+use crate::{
+    dye::DyeColor,
+    entities::{
+        sheep::{self, SheepColor},
+        taming::CollarColor,
+    },
+    entity_interaction::EntityInteractions,
+    events::EntityMetadataChangeEvent,
+    Game,
+};
+
+pub fn register(game: &mut Game) {
+    game.resources
+        .get_mut::<EntityInteractions>()
+        .expect("Failed to get the entity interactions")
+        .register(EntityKind::Sheep, None, dye)
+        .register(EntityKind::Wolf, None, dye)
+        .register(EntityKind::Cat, None, dye);
+}
+
+/// Dyes `target` with the `held` dye. Only the owner
+/// of a wolf or cat can dye its collar.
+///
+/// Returns whether the target changed color.
+pub fn dye(
+    game: &mut Game,
+    player: Entity,
+    target: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let color = match held.item_kind().and_then(DyeColor::from_dye) {
+        Some(color) => color,
+        None => return Ok(false),
+    };
+
+    let kind = *game.ecs.get::<EntityKind>(target)?;
+    match kind {
+        EntityKind::Sheep => {
+            if *game.ecs.get::<SheepColor>(target)? == SheepColor(color) {
+                return Ok(false);
+            }
+            game.ecs.insert(target, SheepColor(color))?;
+            sheep::update_wool(game, target)?;
+        }
+        EntityKind::Wolf | EntityKind::Cat => {
+            let owner = *game.ecs.get::<Uuid>(player)?;
+            let owned = game
+                .ecs
+                .get::<Tameable>(target)
+                .map_or(false, |tameable| tameable.is_owned_by(owner));
+            let collar = CollarColor(color);
+            if !owned || *game.ecs.get::<CollarColor>(target)? == collar {
+                return Ok(false);
+            }
+            game.ecs.insert(target, collar)?;
+            if let Some(metadata) = collar.metadata(kind) {
+                game.ecs
+                    .insert_entity_event(target, EntityMetadataChangeEvent { metadata })?;
+            }
+        }
+        _ => return Ok(false),
+    }
+
+    let _ = held.try_take(1);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{metadata::MetaEntry, position, Item, ItemStack};
+    use quill_common::entities::Sheep;
+
+    use crate::{entities, shearing};
+
+    use super::*;
+
+    #[test]
+    fn dyed_sheep_grow_colored_wool() {
+        let mut game = Game::new();
+        game.add_entity_spawn_callback(entities::add_entity_components);
+        let player = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Uuid::from_u128(1)));
+        let sheep = game.ecs.spawn((
+            Sheep,
+            EntityKind::Sheep,
+            SheepColor::default(),
+            position!(1.5, 64.0, 0.5),
+        ));
+
+        let mut held = InventorySlot::new(Item::BlueDye, 2);
+        assert!(dye(&mut game, player, sheep, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::BlueDye, 1));
+        assert_eq!(
+            *game.ecs.get::<SheepColor>(sheep).unwrap(),
+            SheepColor(DyeColor::Blue)
+        );
+        assert_eq!(
+            game.ecs
+                .get::<EntityMetadataChangeEvent>(sheep)
+                .unwrap()
+                .metadata
+                .get(sheep::indices::WOOL),
+            Some(MetaEntry::Byte(DyeColor::Blue.id() as i8))
+        );
+
+        // Already blue
+        assert!(!dye(&mut game, player, sheep, &mut held).unwrap());
+
+        let mut shears = InventorySlot::new(Item::Shears, 1);
+        assert!(shearing::shear(&mut game, player, sheep, &mut shears).unwrap());
+        let wool: Vec<ItemStack> = game
+            .ecs
+            .query::<&ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.clone())
+            .collect();
+        assert_eq!(wool.len(), 1);
+        assert!(wool[0].has_same_type(&ItemStack::new(Item::BlueWool, 1).unwrap()));
+    }
+}
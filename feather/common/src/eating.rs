@@ -0,0 +1,287 @@
+//! Players eating and drinking food items.
+//!
+//! A player starts eating when they use a food item and finishes once
+//! they have held it long enough, restoring hunger and triggering a
+//! `FoodEatenEvent`.
+
+use base::{Gamemode, Inventory, Item, ItemStack, Position};
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_core::Hand;
+use libcraft_items::InventorySlot;
+
+use crate::{
+    entities::{
+        item,
+        player::{held_item, HotbarSlot},
+    },
+    events::FoodEatenEvent,
+    hunger::Hunger,
+    Game,
+};
+
+/// Ticks it takes to eat most food
+pub const EAT_TICKS: u32 = 32;
+/// Ticks it takes to eat dried kelp
+const FAST_EAT_TICKS: u32 = 16;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(finish_eating);
+}
+
+/// A player in the middle of eating the item in `hand`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eating {
+    pub hand: Hand,
+    pub food: Item,
+    pub ticks_remaining: u32,
+}
+
+/// Food points and saturation modifier restored by eating `item`,
+/// or `None` if it can't be eaten.
+pub fn food_values(item: Item) -> Option<(u8, f32)> {
+    Some(match item {
+        Item::Apple => (4, 0.3),
+        Item::BakedPotato => (5, 0.6),
+        Item::Beef => (3, 0.3),
+        Item::Beetroot => (1, 0.6),
+        Item::BeetrootSoup => (6, 0.6),
+        Item::Bread => (5, 0.6),
+        Item::Carrot => (3, 0.6),
+        Item::Chicken => (2, 0.3),
+        Item::ChorusFruit => (4, 0.3),
+        Item::Cod => (2, 0.1),
+        Item::CookedBeef => (8, 0.8),
+        Item::CookedChicken => (6, 0.6),
+        Item::CookedCod => (5, 0.6),
+        Item::CookedMutton => (6, 0.8),
+        Item::CookedPorkchop => (8, 0.8),
+        Item::CookedRabbit => (5, 0.6),
+        Item::CookedSalmon => (6, 0.8),
+        Item::Cookie => (2, 0.1),
+        Item::DriedKelp => (1, 0.3),
+        Item::EnchantedGoldenApple => (4, 1.2),
+        Item::GoldenApple => (4, 1.2),
+        Item::GoldenCarrot => (6, 1.2),
+        Item::HoneyBottle => (6, 0.1),
+        Item::MelonSlice => (2, 0.3),
+        Item::MushroomStew => (6, 0.6),
+        Item::Mutton => (2, 0.3),
+        Item::PoisonousPotato => (2, 0.3),
+        Item::Porkchop => (3, 0.3),
+        Item::Potato => (1, 0.3),
+        Item::Pufferfish => (1, 0.1),
+        Item::PumpkinPie => (8, 0.3),
+        Item::Rabbit => (3, 0.3),
+        Item::RabbitStew => (10, 0.6),
+        Item::RottenFlesh => (4, 0.1),
+        Item::Salmon => (2, 0.1),
+        Item::SpiderEye => (2, 0.8),
+        Item::SuspiciousStew => (6, 0.6),
+        Item::SweetBerries => (2, 0.1),
+        Item::TropicalFish => (1, 0.1),
+        _ => return None,
+    })
+}
+
+/// Whether `item` can be eaten by a player who isn't hungry.
+fn always_edible(item: Item) -> bool {
+    matches!(
+        item,
+        Item::GoldenApple | Item::EnchantedGoldenApple | Item::ChorusFruit
+    )
+}
+
+/// The item left behind once `food` has been eaten.
+fn container(food: Item) -> Option<Item> {
+    match food {
+        Item::BeetrootSoup | Item::MushroomStew | Item::RabbitStew | Item::SuspiciousStew => {
+            Some(Item::Bowl)
+        }
+        Item::HoneyBottle => Some(Item::GlassBottle),
+        _ => None,
+    }
+}
+
+/// The food `player` holds in `hand`.
+fn held_food(game: &Game, player: Entity, hand: Hand) -> SysResult<Option<Item>> {
+    let inventory = game.ecs.get::<Inventory>(player)?;
+    let hotbar_slot = *game.ecs.get::<HotbarSlot>(player)?;
+    let held = held_item(&inventory, hotbar_slot, hand).item_kind();
+    Ok(held.filter(|item| food_values(*item).is_some()))
+}
+
+/// Starts `player` eating the food in `hand`.
+///
+/// Returns whether they started eating. Players who aren't
+/// hungry can only eat a few kinds of food.
+pub fn start_eating(game: &mut Game, player: Entity, hand: Hand) -> SysResult<bool> {
+    let food = match held_food(game, player, hand)? {
+        Some(food) => food,
+        None => return Ok(false),
+    };
+    let hungry = game
+        .ecs
+        .get::<Hunger>(player)
+        .map_or(false, |hunger| hunger.is_hungry());
+    let creative = game
+        .ecs
+        .get::<Gamemode>(player)
+        .map_or(false, |gamemode| *gamemode == Gamemode::Creative);
+    if !hungry && !creative && !always_edible(food) {
+        return Ok(false);
+    }
+
+    let ticks_remaining = match food {
+        Item::DriedKelp => FAST_EAT_TICKS,
+        _ => EAT_TICKS,
+    };
+    game.ecs.insert(
+        player,
+        Eating {
+            hand,
+            food,
+            ticks_remaining,
+        },
+    )?;
+    Ok(true)
+}
+
+/// Stops `player` eating, as when they let go of the use button.
+pub fn stop_eating(game: &mut Game, player: Entity) {
+    let _ = game.ecs.remove::<Eating>(player);
+}
+
+/// Counts down eating players and feeds those who are done.
+fn finish_eating(game: &mut Game) -> SysResult {
+    let mut finished = Vec::new();
+    for (player, eating) in game.ecs.query::<&mut Eating>().iter() {
+        eating.ticks_remaining = eating.ticks_remaining.saturating_sub(1);
+        if eating.ticks_remaining == 0 {
+            finished.push((player, *eating));
+        }
+    }
+
+    for (player, eating) in finished {
+        stop_eating(game, player);
+        // The player switched to another item before finishing
+        if held_food(game, player, eating.hand)? != Some(eating.food) {
+            continue;
+        }
+        eat(game, player, eating)?;
+    }
+    Ok(())
+}
+
+/// Uses up the food `player` finished eating and feeds them.
+fn eat(game: &mut Game, player: Entity, eating: Eating) -> SysResult {
+    let creative = game
+        .ecs
+        .get::<Gamemode>(player)
+        .map_or(false, |gamemode| *gamemode == Gamemode::Creative);
+    let mut leftover = None;
+    if !creative {
+        let inventory = game.ecs.get::<Inventory>(player)?;
+        let hotbar_slot = *game.ecs.get::<HotbarSlot>(player)?;
+        let mut slot = held_item(&inventory, hotbar_slot, eating.hand);
+        let _ = slot.try_take(1);
+        if let Some(container) = container(eating.food) {
+            let container = ItemStack::new(container, 1)?;
+            if slot.count() == 0 {
+                *slot = InventorySlot::Filled(container);
+            } else {
+                leftover = Some(container);
+            }
+        }
+    }
+    if let Some(leftover) = leftover {
+        let position = *game.ecs.get::<Position>(player)?;
+        item::spawn_item(game, position, leftover);
+    }
+
+    if let (Some((food, saturation_modifier)), Ok(mut hunger)) =
+        (food_values(eating.food), game.ecs.get_mut::<Hunger>(player))
+    {
+        hunger.eat(food, saturation_modifier);
+    }
+    game.ecs
+        .insert_entity_event(player, FoodEatenEvent { food: eating.food })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, Area};
+
+    use super::*;
+
+    fn player_holding(game: &mut Game, stack: ItemStack, food: u8) -> Entity {
+        let inventory = Inventory::player();
+        *inventory.item(Area::Hotbar, 0).unwrap() = InventorySlot::Filled(stack);
+        let hunger = Hunger {
+            food,
+            ..Hunger::default()
+        };
+        game.ecs.spawn((
+            position!(0.0, 64.0, 0.0),
+            inventory,
+            HotbarSlot::new(0),
+            Gamemode::Survival,
+            hunger,
+        ))
+    }
+
+    fn held(game: &Game, player: Entity) -> InventorySlot {
+        let inventory = game.ecs.get::<Inventory>(player).unwrap();
+        let held = inventory.item(Area::Hotbar, 0).unwrap().clone();
+        held
+    }
+
+    #[test]
+    fn finishing_a_meal_feeds_the_player() {
+        let mut game = Game::new();
+        let bread = ItemStack::new(Item::Bread, 2).unwrap();
+        let player = player_holding(&mut game, bread, 10);
+
+        assert!(start_eating(&mut game, player, Hand::Main).unwrap());
+        for _ in 1..EAT_TICKS {
+            finish_eating(&mut game).unwrap();
+        }
+        assert!(game.ecs.get::<FoodEatenEvent>(player).is_err());
+        finish_eating(&mut game).unwrap();
+
+        assert_eq!(
+            game.ecs.get::<FoodEatenEvent>(player).unwrap().food,
+            Item::Bread
+        );
+        assert_eq!(game.ecs.get::<Hunger>(player).unwrap().food, 15);
+        assert_eq!(held(&game, player).item_kind(), Some(Item::Bread));
+        assert_eq!(held(&game, player).count(), 1);
+        assert!(game.ecs.get::<Eating>(player).is_err());
+    }
+
+    #[test]
+    fn full_players_only_eat_some_foods() {
+        let mut game = Game::new();
+        let stew = ItemStack::new(Item::MushroomStew, 1).unwrap();
+        let full = player_holding(&mut game, stew, 20);
+        assert!(!start_eating(&mut game, full, Hand::Main).unwrap());
+
+        let fruit = ItemStack::new(Item::ChorusFruit, 1).unwrap();
+        let player = player_holding(&mut game, fruit, 20);
+        assert!(start_eating(&mut game, player, Hand::Main).unwrap());
+    }
+
+    #[test]
+    fn stews_leave_a_bowl() {
+        let mut game = Game::new();
+        let stew = ItemStack::new(Item::RabbitStew, 1).unwrap();
+        let player = player_holding(&mut game, stew, 5);
+
+        start_eating(&mut game, player, Hand::Main).unwrap();
+        for _ in 0..EAT_TICKS {
+            finish_eating(&mut game).unwrap();
+        }
+        assert_eq!(game.ecs.get::<Hunger>(player).unwrap().food, 15);
+        assert_eq!(held(&game, player).item_kind(), Some(Item::Bowl));
+    }
+}
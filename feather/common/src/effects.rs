@@ -0,0 +1,95 @@
+//! Particles and sounds for players to see and hear. They are
+//! buffered on the [`Game`](crate::Game) during a tick and sent
+//! out by the server.
+
+use base::{ParticleKind, Position};
+
+/// Particles shown around a position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParticleEmission {
+    pub kind: ParticleKind,
+    pub position: Position,
+    pub count: i32,
+    /// How far the particles scatter from `position` on each axis.
+    pub spread: f32,
+}
+
+/// A sound played at a position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundEmission {
+    /// Namespaced identifier of the sound, like `block.note_block.harp`.
+    pub sound: String,
+    pub position: Position,
+    pub volume: f32,
+    pub pitch: f32,
+}
+
+/// Particles and sounds emitted since they were last drained.
+#[derive(Debug, Default)]
+pub struct OutboundEffects {
+    particles: Vec<ParticleEmission>,
+    sounds: Vec<SoundEmission>,
+}
+
+impl OutboundEffects {
+    pub fn push_particle(&mut self, particle: ParticleEmission) {
+        self.particles.push(particle);
+    }
+
+    pub fn push_sound(&mut self, sound: SoundEmission) {
+        self.sounds.push(sound);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty() && self.sounds.is_empty()
+    }
+
+    /// Takes the buffered particles, leaving none behind.
+    pub fn drain_particles(&mut self) -> impl Iterator<Item = ParticleEmission> + '_ {
+        self.particles.drain(..)
+    }
+
+    /// Takes the buffered sounds, leaving none behind.
+    pub fn drain_sounds(&mut self) -> impl Iterator<Item = SoundEmission> + '_ {
+        self.sounds.drain(..)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+
+    use crate::Game;
+
+    use super::*;
+
+    #[test]
+    fn emitted_effects_are_drained_once() {
+        let mut game = Game::new();
+        let position = position!(1.5, 64.0, -2.5);
+        game.emit_particle(ParticleKind::Flame, position, 8, 0.25);
+        game.emit_sound("block.fire.ambient", position, 0.5, 1.2);
+
+        let particles: Vec<_> = game.outbound_effects.drain_particles().collect();
+        assert_eq!(
+            particles,
+            vec![ParticleEmission {
+                kind: ParticleKind::Flame,
+                position,
+                count: 8,
+                spread: 0.25,
+            }]
+        );
+        let sounds: Vec<_> = game.outbound_effects.drain_sounds().collect();
+        assert_eq!(
+            sounds,
+            vec![SoundEmission {
+                sound: "block.fire.ambient".to_owned(),
+                position,
+                volume: 0.5,
+                pitch: 1.2,
+            }]
+        );
+        assert!(game.outbound_effects.is_empty());
+    }
+}
@@ -4,15 +4,58 @@
 //! It should export a `build_default(&mut EntityBuilder)` function to
 //! add default components for that entity.
 
-use ecs::EntityBuilder;
-use quill_common::{components::OnGround, entity_init::EntityInit};
+use base::{EntityKind, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{
+    components::{Health, OnGround, Velocity},
+    entity_init::EntityInit,
+};
 use uuid::Uuid;
 
 /// Adds default components shared between all entities.
 fn build_default(builder: &mut EntityBuilder) {
-    builder.add(Uuid::new_v4()).add(OnGround(true));
+    builder
+        .add(Uuid::new_v4())
+        .add(OnGround(true))
+        .add(Velocity::default());
 }
 
+/// Returns whether the entity is a monster, i.e. one that
+/// keeps nearby players from sleeping.
+pub fn is_monster(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Blaze
+            | EntityKind::CaveSpider
+            | EntityKind::Creeper
+            | EntityKind::Drowned
+            | EntityKind::ElderGuardian
+            | EntityKind::Enderman
+            | EntityKind::Endermite
+            | EntityKind::Evoker
+            | EntityKind::Giant
+            | EntityKind::Guardian
+            | EntityKind::Husk
+            | EntityKind::Illusioner
+            | EntityKind::Pillager
+            | EntityKind::Ravager
+            | EntityKind::Silverfish
+            | EntityKind::Skeleton
+            | EntityKind::Spider
+            | EntityKind::Stray
+            | EntityKind::Vex
+            | EntityKind::Vindicator
+            | EntityKind::Warden
+            | EntityKind::Witch
+            | EntityKind::Wither
+            | EntityKind::WitherSkeleton
+            | EntityKind::Zoglin
+            | EntityKind::Zombie
+            | EntityKind::ZombieVillager
+    )
+}
+
+pub mod aging;
 pub mod area_effect_cloud;
 pub mod armor_stand;
 pub mod arrow;
@@ -21,6 +64,7 @@ pub mod bat;
 pub mod bee;
 pub mod blaze;
 pub mod boat;
+pub mod breeding;
 pub mod cat;
 pub mod cave_spider;
 pub mod chest_minecart;
@@ -40,6 +84,7 @@ pub mod ender_dragon;
 pub mod ender_pearl;
 pub mod enderman;
 pub mod endermite;
+pub mod equipment;
 pub mod evoker;
 pub mod evoker_fangs;
 pub mod experience_bottle;
@@ -114,6 +159,7 @@ pub mod vex;
 pub mod villager;
 pub mod vindicator;
 pub mod wandering_trader;
+pub mod warden;
 pub mod witch;
 pub mod wither;
 pub mod wither_skeleton;
@@ -129,6 +175,10 @@ pub mod interactions;
 pub mod pathfinding;
 pub mod components;
 pub mod spawning;
+pub mod riding;
+pub mod taming;
+
+use crate::Game;
 
 pub fn add_entity_components(builder: &mut EntityBuilder, init: &EntityInit) {
     match init {
@@ -243,6 +293,7 @@ pub fn add_entity_components(builder: &mut EntityBuilder, init: &EntityInit) {
         EntityInit::Axolotl => axolotl::build_default(builder),
         EntityInit::Goat => goat::build_default(builder),
         EntityInit::GlowSquid => glow_squid::build_default(builder),
+        EntityInit::Warden => warden::build_default(builder),
     }
 }
 
@@ -251,6 +302,36 @@ pub fn register(systems: &mut SystemExecutor<Game>) {
     metadata::register(systems);
     interactions::register(systems);
     pathfinding::register(systems);
-    spawning::register(systems);
+    riding::register(systems);
+    boat::register(systems);
+    potion::register(systems);
+    area_effect_cloud::register(systems);
+    zombie::register(systems);
+    enderman::register(systems);
+    aging::register(systems);
+    sheep::register(systems);
+    creeper::register(systems);
+    fireball::register(systems);
+    wither::register(systems);
+    wither_skull::register(systems);
+    falling_block::register(systems);
     // Other registrations...
 }
+
+/// Converts `entity` into a new entity of another kind, like a mooshroom
+/// into a cow when sheared. The new entity keeps the position and health
+/// of the old one, which is removed.
+///
+/// Returns the new entity.
+pub fn convert(game: &mut Game, entity: Entity, into: EntityInit) -> SysResult<Entity> {
+    let position = *game.ecs.get::<Position>(entity)?;
+    let health = game.ecs.get::<Health>(entity).ok().map(|health| *health);
+
+    let mut builder = game.create_entity_builder(position, into);
+    if let Some(health) = health {
+        builder.add(health);
+    }
+    let converted = game.spawn_entity(builder);
+    game.remove_entity(entity)?;
+    Ok(converted)
+}
@@ -0,0 +1,56 @@
+//! Baby mobs and how they grow up.
+
+use ecs::{Entity, SysResult, SystemExecutor};
+
+use crate::Game;
+
+/// Ticks a baby animal takes to grow up
+pub const GROWING_TICKS: u32 = 24_000;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(grow_up);
+}
+
+/// Marks a baby mob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Baby;
+
+/// Ticks left until a baby grows up. Babies without it, like
+/// baby zombies, stay babies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrowingUp {
+    pub ticks_remaining: u32,
+}
+
+impl Default for GrowingUp {
+    fn default() -> Self {
+        Self {
+            ticks_remaining: GROWING_TICKS,
+        }
+    }
+}
+
+/// Brings the time until `baby` grows up forward by `ticks`.
+pub fn age_up(game: &mut Game, baby: Entity, ticks: u32) -> SysResult {
+    if let Ok(mut growing) = game.ecs.get_mut::<GrowingUp>(baby) {
+        growing.ticks_remaining = growing.ticks_remaining.saturating_sub(ticks);
+    }
+    Ok(())
+}
+
+/// Counts down the time until babies grow up, making them adults at the end.
+fn grow_up(game: &mut Game) -> SysResult {
+    let mut grown = Vec::new();
+    for (baby, growing) in game.ecs.query::<&mut GrowingUp>().iter() {
+        growing.ticks_remaining = growing.ticks_remaining.saturating_sub(1);
+        if growing.ticks_remaining == 0 {
+            grown.push(baby);
+        }
+    }
+
+    for adult in grown {
+        game.ecs.remove::<GrowingUp>(adult)?;
+        let _ = game.ecs.remove::<Baby>(adult);
+    }
+    Ok(())
+}
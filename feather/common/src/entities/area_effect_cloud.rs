@@ -1,6 +1,18 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::AreaEffectCloud;
+use std::collections::HashMap;
+
+use base::{EntityKind, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{components::Health, entities::AreaEffectCloud};
+
+use crate::{
+    status_effects::{self, StatusEffect},
+    Game,
+};
+
+/// Clouds smaller than this disappear
+const MIN_RADIUS: f32 = 0.5;
+/// How far above and below its center a cloud reaches
+const CLOUD_HEIGHT: f64 = 0.5;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
@@ -8,3 +20,147 @@ pub fn build_default(builder: &mut EntityBuilder) {
         .add(AreaEffectCloud)
         .add(EntityKind::AreaEffectCloud);
 }
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_area_effect_clouds);
+}
+
+/// The effect carried by an area effect cloud and how it evolves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AreaEffectCloudState {
+    pub radius: f32,
+    /// Remaining lifetime in ticks
+    pub duration: u32,
+    /// Radius change per tick (negative to shrink)
+    pub radius_per_tick: f32,
+    /// Radius change each time the effect is applied to an entity
+    pub radius_on_use: f32,
+    /// Ticks before the same entity can be affected again
+    pub reapplication_delay: u32,
+    pub effect: StatusEffect,
+    /// Tick at which each affected entity may be affected again
+    cooldowns: HashMap<Entity, u64>,
+}
+
+impl AreaEffectCloudState {
+    /// The cloud left behind by a lingering potion.
+    pub fn lingering(effect: StatusEffect) -> Self {
+        let radius = 3.0;
+        let duration = 600;
+        Self {
+            radius,
+            duration,
+            radius_per_tick: -radius / duration as f32,
+            radius_on_use: -0.5,
+            reapplication_delay: 20,
+            effect: StatusEffect {
+                // Lingering effects last a quarter as long as when drunk
+                duration: effect.duration / 4,
+                ..effect
+            },
+            cooldowns: HashMap::new(),
+        }
+    }
+
+    fn contains(&self, center: Position, pos: Position) -> bool {
+        let (dx, dz) = (pos.x - center.x, pos.z - center.z);
+        let radius = f64::from(self.radius);
+        dx * dx + dz * dz <= radius * radius && (pos.y - center.y).abs() <= CLOUD_HEIGHT
+    }
+}
+
+/// Applies cloud effects to entities inside them, then shrinks the clouds,
+/// despawning those that expired or became too small.
+fn update_area_effect_clouds(game: &mut Game) -> SysResult {
+    let tick = game.tick_count;
+    let clouds: Vec<Entity> = game
+        .ecs
+        .query::<&AreaEffectCloudState>()
+        .iter()
+        .filter(|(_, cloud)| cloud.duration > 0)
+        .map(|(entity, _)| entity)
+        .collect();
+
+    for cloud in clouds {
+        let center = *game.ecs.get::<Position>(cloud)?;
+        let mut state = game.ecs.get::<AreaEffectCloudState>(cloud)?.clone();
+
+        let targets: Vec<Entity> = game
+            .ecs
+            .query::<(&Position, &Health)>()
+            .iter()
+            .filter(|(entity, (pos, _))| *entity != cloud && state.contains(center, **pos))
+            .map(|(entity, _)| entity)
+            .collect();
+
+        state.cooldowns.retain(|_, ready_at| *ready_at > tick);
+        for target in targets {
+            if state.cooldowns.contains_key(&target) {
+                continue;
+            }
+            status_effects::apply_effect(game, target, state.effect)?;
+            state
+                .cooldowns
+                .insert(target, tick + u64::from(state.reapplication_delay));
+            state.radius += state.radius_on_use;
+        }
+
+        state.radius += state.radius_per_tick;
+        state.duration -= 1;
+        if state.duration == 0 || state.radius < MIN_RADIUS {
+            state.duration = 0;
+            game.remove_entity(cloud)?;
+        }
+        *game.ecs.get_mut::<AreaEffectCloudState>(cloud)? = state;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use quill_common::events::EntityRemoveEvent;
+
+    use crate::status_effects::{StatusEffectKind, StatusEffects};
+
+    use super::*;
+
+    #[test]
+    fn poison_cloud_poisons_and_shrinks_away() {
+        let mut game = Game::new();
+        let mut state =
+            AreaEffectCloudState::lingering(StatusEffect::new(StatusEffectKind::Poison, 0, 3600));
+        state.radius_on_use = 0.0;
+        let cloud = game.ecs.spawn((position!(0.0, 64.0, 0.0), state));
+        let inside = game
+            .ecs
+            .spawn((position!(1.0, 64.0, 1.0), Health::new(20.0)));
+        let outside = game
+            .ecs
+            .spawn((position!(5.0, 64.0, 0.0), Health::new(20.0)));
+
+        update_area_effect_clouds(&mut game).unwrap();
+        let poison = *game
+            .ecs
+            .get::<StatusEffects>(inside)
+            .unwrap()
+            .get(StatusEffectKind::Poison)
+            .unwrap();
+        assert_eq!(poison.duration, 900);
+        assert!(game.ecs.get::<StatusEffects>(outside).is_err());
+
+        let mut last_radius = game.ecs.get::<AreaEffectCloudState>(cloud).unwrap().radius;
+        for _ in 0..100 {
+            update_area_effect_clouds(&mut game).unwrap();
+            let radius = game.ecs.get::<AreaEffectCloudState>(cloud).unwrap().radius;
+            assert!(radius < last_radius);
+            last_radius = radius;
+        }
+
+        for _ in 0..600 {
+            update_area_effect_clouds(&mut game).unwrap();
+        }
+        assert!(game.ecs.get::<EntityRemoveEvent>(cloud).is_ok());
+    }
+}
@@ -1,8 +1,255 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
+use base::metadata::{MetaEntry, ToMetaEntry};
+use base::{EntityKind, EntityMetadata, ItemStack};
+use ecs::{Entity, EntityBuilder, SysResult};
 use quill_common::entities::ArmorStand;
 
+use crate::Game;
+
+pub use super::equipment::EquipmentSlot;
+
+/// Metadata indices of armor stands, see <https://wiki.vg/Entity_metadata#Armor_Stand>
+pub mod indices {
+    pub const FLAGS: u8 = 14;
+    pub const HEAD: u8 = 15;
+    pub const BODY: u8 = 16;
+    pub const LEFT_ARM: u8 = 17;
+    pub const RIGHT_ARM: u8 = 18;
+    pub const LEFT_LEG: u8 = 19;
+    pub const RIGHT_LEG: u8 = 20;
+}
+
+const FLAG_SMALL: u8 = 0x01;
+const FLAG_HAS_ARMS: u8 = 0x04;
+const FLAG_NO_BASE_PLATE: u8 = 0x08;
+const FLAG_MARKER: u8 = 0x10;
+
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(ArmorStand).add(EntityKind::ArmorStand);
+    builder
+        .add(ArmorStand)
+        .add(EntityKind::ArmorStand)
+        .add(ArmorStandState::default());
+}
+
+/// Rotation of a single limb, in degrees around each axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LimbRotation {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl LimbRotation {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl ToMetaEntry for LimbRotation {
+    fn to_meta_entry(&self) -> MetaEntry {
+        MetaEntry::Rotation(self.x, self.y, self.z)
+    }
+}
+
+/// The pose of an armor stand, as the rotation of each of its limbs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArmorStandPose {
+    pub head: LimbRotation,
+    pub body: LimbRotation,
+    pub left_arm: LimbRotation,
+    pub right_arm: LimbRotation,
+    pub left_leg: LimbRotation,
+    pub right_leg: LimbRotation,
+}
+
+impl Default for ArmorStandPose {
+    /// The pose of a freshly placed armor stand.
+    fn default() -> Self {
+        Self {
+            head: LimbRotation::default(),
+            body: LimbRotation::default(),
+            left_arm: LimbRotation::new(-10.0, 0.0, -10.0),
+            right_arm: LimbRotation::new(-15.0, 0.0, 10.0),
+            left_leg: LimbRotation::new(-1.0, 0.0, -1.0),
+            right_leg: LimbRotation::new(1.0, 0.0, 1.0),
+        }
+    }
+}
+
+/// Pose, display flags and equipment of an armor stand.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArmorStandState {
+    pub pose: ArmorStandPose,
+    pub small: bool,
+    pub show_arms: bool,
+    pub hide_base_plate: bool,
+    pub marker: bool,
+    equipment: [Option<ItemStack>; 6],
+}
+
+impl ArmorStandState {
+    /// The item in the given slot.
+    pub fn equipment(&self, slot: EquipmentSlot) -> Option<&ItemStack> {
+        self.equipment[slot.index()].as_ref()
+    }
+
+    /// Puts `item` in `slot`, returning the item that was there before.
+    pub fn set_equipment(
+        &mut self,
+        slot: EquipmentSlot,
+        item: Option<ItemStack>,
+    ) -> Option<ItemStack> {
+        std::mem::replace(&mut self.equipment[slot.index()], item)
+    }
+
+    /// Whether items can be placed in the given slot. Hands are
+    /// only usable when the armor stand shows its arms.
+    pub fn accepts(&self, slot: EquipmentSlot) -> bool {
+        !self.marker && (self.show_arms || !slot.is_hand())
+    }
+
+    /// The flags byte sent in the armor stand's metadata.
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.small {
+            flags |= FLAG_SMALL;
+        }
+        if self.show_arms {
+            flags |= FLAG_HAS_ARMS;
+        }
+        if self.hide_base_plate {
+            flags |= FLAG_NO_BASE_PLATE;
+        }
+        if self.marker {
+            flags |= FLAG_MARKER;
+        }
+        flags
+    }
+
+    /// Builds the entity metadata describing this armor stand's flags and pose.
+    pub fn metadata(&self) -> EntityMetadata {
+        EntityMetadata::entity_base()
+            .with(indices::FLAGS, self.flags())
+            .with(indices::HEAD, self.pose.head)
+            .with(indices::BODY, self.pose.body)
+            .with(indices::LEFT_ARM, self.pose.left_arm)
+            .with(indices::RIGHT_ARM, self.pose.right_arm)
+            .with(indices::LEFT_LEG, self.pose.left_leg)
+            .with(indices::RIGHT_LEG, self.pose.right_leg)
+    }
+}
+
+/// Handles a player using `held` on an armor stand.
+///
+/// A held item is swapped with whatever the stand wears in that item's
+/// slot. With an empty hand, the item in `clicked` is taken instead.
+pub fn interact(
+    game: &mut Game,
+    stand: Entity,
+    held: &mut Option<ItemStack>,
+    clicked: EquipmentSlot,
+) -> SysResult {
+    let mut state = game.ecs.get_mut::<ArmorStandState>(stand)?;
+    let slot = match held {
+        Some(stack) => EquipmentSlot::for_item(stack.item()),
+        None => clicked,
+    };
+    if !state.accepts(slot) {
+        return Ok(());
+    }
+
+    // Only a single item is put on the stand; the rest stays in hand
+    let (rest, equipped) = match held.take() {
+        Some(stack) if stack.count() > 1 => {
+            if state.equipment(slot).is_some() {
+                // No free hand to take the previously worn item
+                *held = Some(stack);
+                return Ok(());
+            }
+            let mut rest = stack.clone();
+            rest.remove(1)?;
+            (Some(rest), Some(stack.get_item()))
+        }
+        stack => (None, stack),
+    };
+
+    let previous = state.set_equipment(slot, equipped);
+    *held = rest.or(previous);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+
+    use super::*;
+
+    fn spawn_stand(game: &mut Game) -> Entity {
+        game.ecs
+            .spawn((position!(0.0, 64.0, 0.0), ArmorStandState::default()))
+    }
+
+    #[test]
+    fn equip_and_take_back_helmet() {
+        let mut game = Game::new();
+        let stand = spawn_stand(&mut game);
+
+        let helmet = ItemStack::new(Item::IronHelmet, 1).unwrap();
+        let mut held = Some(helmet.clone());
+        interact(&mut game, stand, &mut held, EquipmentSlot::Chest).unwrap();
+        assert_eq!(held, None);
+        assert_eq!(
+            game.ecs
+                .get::<ArmorStandState>(stand)
+                .unwrap()
+                .equipment(EquipmentSlot::Head),
+            Some(&helmet)
+        );
+
+        interact(&mut game, stand, &mut held, EquipmentSlot::Head).unwrap();
+        assert_eq!(held, Some(helmet));
+        assert!(game
+            .ecs
+            .get::<ArmorStandState>(stand)
+            .unwrap()
+            .equipment(EquipmentSlot::Head)
+            .is_none());
+    }
+
+    #[test]
+    fn hands_need_arms() {
+        let mut game = Game::new();
+        let stand = spawn_stand(&mut game);
+
+        let mut held = Some(ItemStack::new(Item::DiamondSword, 1).unwrap());
+        interact(&mut game, stand, &mut held, EquipmentSlot::MainHand).unwrap();
+        assert!(held.is_some());
+
+        game.ecs
+            .get_mut::<ArmorStandState>(stand)
+            .unwrap()
+            .show_arms = true;
+        interact(&mut game, stand, &mut held, EquipmentSlot::MainHand).unwrap();
+        assert!(held.is_none());
+    }
+
+    #[test]
+    fn pose_change_updates_rotations_and_metadata() {
+        let mut state = ArmorStandState::default();
+        state.pose.right_arm = LimbRotation::new(-90.0, 30.0, 0.0);
+        state.show_arms = true;
+        state.hide_base_plate = true;
+
+        assert_eq!(state.pose.right_arm, LimbRotation::new(-90.0, 30.0, 0.0));
+
+        let metadata = state.metadata();
+        assert_eq!(
+            metadata.get(indices::RIGHT_ARM),
+            Some(MetaEntry::Rotation(-90.0, 30.0, 0.0))
+        );
+        assert_eq!(
+            metadata.get(indices::FLAGS),
+            Some(MetaEntry::Byte((FLAG_HAS_ARMS | FLAG_NO_BASE_PLATE) as i8))
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
 use quill_common::entities::{Axolotl, AxolotlVariant, PlayDead};
-use quill_common::components::{WaterBreathing, Tameable, BucketPickupable};
+use quill_common::components::{Health, WaterBreathing, Tameable, BucketPickupable};
 use uuid::Uuid;
 
 /// Axolotl color variants
@@ -43,6 +43,7 @@ pub fn build_default(builder: &mut EntityBuilder) {
     builder
         .add(Axolotl)
         .add(EntityKind::Axolotl)
+        .add(Health::new(14.0))
         .add(AxolotlVariant::from(variant))
         .add(PlayDead { activated: false, timer: 0 })
         .add(WaterBreathing { air_ticks: 6000, max_air: 6000 })
@@ -1,8 +1,8 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Bat;
+use quill_common::{components::Health, entities::Bat};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Bat).add(EntityKind::Bat);
+    builder.add(Bat).add(EntityKind::Bat).add(Health::new(6.0));
 }
@@ -1,8 +1,8 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Bee;
+use quill_common::{components::Health, entities::Bee};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Bee).add(EntityKind::Bee);
+    builder.add(Bee).add(EntityKind::Bee).add(Health::new(10.0));
 }
@@ -1,5 +1,5 @@
 use base::{Position, EntityKind};
-use ecs::{Entity, IntoQuery, SysResult, SystemExecutor};
+use ecs::{Entity, IntoQuery, SysResult, SystemExecutor, SystemStage};
 use quill_common::entities::{Axolotl, PlayDead, Goat, RammingCooldown, GlowSquid, GlowIntensity};
 use quill_common::components::{Damage, Health, OnGround, WaterBreathing};
 
@@ -7,9 +7,9 @@ use crate::Game;
 
 pub fn register(systems: &mut SystemExecutor<Game>) {
     systems
-        .add_system(update_axolotl_behavior)
-        .add_system(update_goat_behavior)
-        .add_system(update_glow_squid_behavior);
+        .add_system_to_stage(SystemStage::Ai, update_axolotl_behavior)
+        .add_system_to_stage(SystemStage::Ai, update_goat_behavior)
+        .add_system_to_stage(SystemStage::Ai, update_glow_squid_behavior);
 }
 
 fn update_axolotl_behavior(game: &mut Game) -> SysResult {
@@ -31,8 +31,8 @@ fn update_axolotl_behavior(game: &mut Game) -> SysResult {
                 play_dead.activated = false;
                 // Heal axolotl when it recovers from playing dead
                 if let Ok(mut health) = game.ecs.get_mut::<Health>(entity) {
-                    health.current += health.max * 0.2; // Heal 20% of max health
-                    health.current = health.current.min(health.max); // Cap at max health
+                    let max = health.max;
+                    health.heal(max * 0.2); // Heal 20% of max health
                 }
             }
         }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Blaze;
+use quill_common::{components::Health, entities::Blaze};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Blaze).add(EntityKind::Blaze);
+    builder
+        .add(Blaze)
+        .add(EntityKind::Blaze)
+        .add(Health::new(20.0));
 }
@@ -1,8 +1,399 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Boat;
+use base::{BlockKind, EntityKind, Gamemode, Item, ItemStack, Position, Vec3d};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use libcraft_blocks::BlockView;
+use libcraft_core::InteractionType;
+use quill_common::{entities::Boat, events::InteractEntityEvent};
+
+use crate::{entities::item, Game};
+
+/// Downward acceleration of a boat in the air, in blocks/tick²
+const GRAVITY: f64 = 0.04;
+/// Fraction of the distance to the water surface a boat rises each tick
+const BUOYANCY: f64 = 0.2;
+/// Velocity retained each tick while in water or in the air
+const WATER_FRICTION: f64 = 0.9;
+/// Velocity retained each tick on ordinary ground
+const GROUND_FRICTION: f64 = 0.6;
+/// Acceleration from paddling forward
+const PADDLE_FORWARD: f64 = 0.04;
+/// Acceleration from paddling backward, or from a single paddle
+const PADDLE_WEAK: f64 = 0.005;
+/// Turn acceleration from a single paddle, in degrees/tick²
+const PADDLE_TURN: f32 = 1.0;
+/// Number of planks dropped when a boat breaks
+const BROKEN_PLANKS: u32 = 3;
+/// Number of sticks dropped when a boat breaks
+const BROKEN_STICKS: u32 = 2;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Boat).add(EntityKind::Boat);
+    builder
+        .add(Boat)
+        .add(EntityKind::Boat)
+        .add(BoatWood::Oak)
+        .add(BoatMotion::default())
+        .add(BoatControls::default());
+}
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems
+        .add_system(update_boats)
+        .add_system(break_attacked_boats);
+}
+
+/// The wood a boat is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoatWood {
+    Oak,
+    Spruce,
+    Birch,
+    Jungle,
+    Acacia,
+    DarkOak,
+}
+
+impl BoatWood {
+    /// The planks this boat breaks into.
+    pub fn planks(self) -> Item {
+        match self {
+            BoatWood::Oak => Item::OakPlanks,
+            BoatWood::Spruce => Item::SprucePlanks,
+            BoatWood::Birch => Item::BirchPlanks,
+            BoatWood::Jungle => Item::JunglePlanks,
+            BoatWood::Acacia => Item::AcaciaPlanks,
+            BoatWood::DarkOak => Item::DarkOakPlanks,
+        }
+    }
+}
+
+/// Velocity of a boat, in blocks/tick, and its turning speed in degrees/tick.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BoatMotion {
+    pub velocity: Vec3d,
+    pub yaw_velocity: f32,
+}
+
+/// Paddle input from the player driving a boat.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BoatControls {
+    pub left: bool,
+    pub right: bool,
+    pub forward: bool,
+    pub back: bool,
+}
+
+/// What a boat is currently resting on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BoatStatus {
+    /// In water whose surface is at the given height
+    InWater {
+        surface: f64,
+    },
+    /// On top of a solid block
+    OnGround(BlockKind),
+    InAir,
+}
+
+fn boat_status(view: &dyn BlockView, pos: Position) -> BoatStatus {
+    let x = pos.x.floor() as i32;
+    let z = pos.z.floor() as i32;
+    // A boat resting exactly on the surface counts as being in the water below it
+    let y = (pos.y - 1e-3).floor() as i32;
+
+    match view.kind_at((x, y, z)) {
+        Some(BlockKind::Water) => {
+            let mut top = y;
+            while view.kind_at((x, top + 1, z)) == Some(BlockKind::Water) {
+                top += 1;
+            }
+            BoatStatus::InWater {
+                surface: f64::from(top + 1),
+            }
+        }
+        Some(kind) if kind.solid() => BoatStatus::OnGround(kind),
+        _ => BoatStatus::InAir,
+    }
+}
+
+/// Fraction of its velocity a boat keeps each tick when sliding on a block.
+fn slipperiness(kind: BlockKind) -> f64 {
+    match kind {
+        BlockKind::Ice | BlockKind::PackedIce | BlockKind::FrostedIce => 0.98,
+        BlockKind::BlueIce => 0.989,
+        _ => GROUND_FRICTION,
+    }
+}
+
+/// Returns whether a boat moving to `pos` would run into the shore.
+fn blocked_at(view: &dyn BlockView, pos: Position) -> bool {
+    let block = (
+        pos.x.floor() as i32,
+        pos.y.floor() as i32,
+        pos.z.floor() as i32,
+    );
+    view.kind_at(block).map_or(false, |kind| kind.solid())
+}
+
+/// Advances a boat's motion and position by one tick.
+fn step_boat(
+    view: &dyn BlockView,
+    position: &mut Position,
+    motion: &mut BoatMotion,
+    controls: BoatControls,
+) {
+    let friction = match boat_status(view, *position) {
+        BoatStatus::InWater { surface } => {
+            motion.velocity.y = (surface - position.y) * BUOYANCY;
+            WATER_FRICTION
+        }
+        BoatStatus::OnGround(kind) => {
+            motion.velocity.y = 0.0;
+            slipperiness(kind)
+        }
+        BoatStatus::InAir => {
+            motion.velocity.y -= GRAVITY;
+            WATER_FRICTION
+        }
+    };
+    motion.velocity.x *= friction;
+    motion.velocity.z *= friction;
+    motion.yaw_velocity *= friction as f32;
+
+    let mut thrust = 0.0;
+    if controls.left {
+        motion.yaw_velocity -= PADDLE_TURN;
+    }
+    if controls.right {
+        motion.yaw_velocity += PADDLE_TURN;
+    }
+    if controls.left != controls.right && !controls.forward && !controls.back {
+        thrust += PADDLE_WEAK;
+    }
+    if controls.forward {
+        thrust += PADDLE_FORWARD;
+    }
+    if controls.back {
+        thrust -= PADDLE_WEAK;
+    }
+
+    position.yaw += motion.yaw_velocity;
+    let yaw = f64::from(position.yaw.to_radians());
+    motion.velocity.x += -yaw.sin() * thrust;
+    motion.velocity.z += yaw.cos() * thrust;
+
+    let mut target = *position + motion.velocity;
+    if blocked_at(view, target) {
+        motion.velocity.x = 0.0;
+        motion.velocity.z = 0.0;
+        target = *position + motion.velocity;
+    }
+    *position = target;
+}
+
+fn update_boats(game: &mut Game) -> SysResult {
+    for (_, (position, motion, controls)) in game
+        .ecs
+        .query::<(&mut Position, &mut BoatMotion, &BoatControls)>()
+        .iter()
+    {
+        step_boat(&*game, position, motion, *controls);
+    }
+    Ok(())
+}
+
+/// Destroys a boat, returning the planks and sticks it breaks into.
+///
+/// Passengers are dismounted by the riding system once the boat is gone.
+pub fn break_boat(game: &mut Game, boat: Entity) -> SysResult<Vec<ItemStack>> {
+    let wood = *game.ecs.get::<BoatWood>(boat)?;
+    game.remove_entity(boat)?;
+
+    Ok(vec![
+        ItemStack::new(wood.planks(), BROKEN_PLANKS)?,
+        ItemStack::new(Item::Stick, BROKEN_STICKS)?,
+    ])
+}
+
+/// Breaks the boats players hit, dropping their planks and sticks
+/// unless the player is in creative mode.
+fn break_attacked_boats(game: &mut Game) -> SysResult {
+    let attacks: Vec<(Entity, u64)> = game
+        .ecs
+        .query::<&InteractEntityEvent>()
+        .iter()
+        .filter(|(_, event)| matches!(event.ty, InteractionType::Attack))
+        .map(|(player, event)| (player, event.target.0))
+        .collect();
+
+    for (player, target) in attacks {
+        let boat = game
+            .ecs
+            .query::<(&BoatWood, &Position)>()
+            .iter()
+            .find(|(boat, _)| u64::from(boat.id()) == target)
+            .map(|(boat, (_, position))| (boat, *position));
+        let (boat, position) = match boat {
+            Some(boat) => boat,
+            // Not a boat, or already broken this tick
+            None => continue,
+        };
+
+        let drops = break_boat(game, boat)?;
+        let creative = game
+            .ecs
+            .get::<Gamemode>(player)
+            .map_or(false, |gamemode| *gamemode == Gamemode::Creative);
+        if !creative {
+            for stack in drops {
+                item::spawn_item(game, position, stack);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    /// A lake of water from y=60 to y=62, with stone below
+    fn lake(pos: (i32, i32, i32)) -> Option<(BlockKind, BlockProperties)> {
+        let kind = match pos.1 {
+            y if y < 60 => BlockKind::Stone,
+            y if y <= 62 => BlockKind::Water,
+            _ => BlockKind::Air,
+        };
+        Some((kind, kind.default_properties()))
+    }
+
+    #[test]
+    fn boat_floats_at_water_surface() {
+        let mut position = position!(0.5, 61.2, 0.5);
+        let mut motion = BoatMotion::default();
+        for _ in 0..100 {
+            step_boat(&lake, &mut position, &mut motion, BoatControls::default());
+        }
+        assert!((position.y - 63.0).abs() < 0.01, "boat at y={}", position.y);
+    }
+
+    #[test]
+    fn paddling_moves_boat_forward() {
+        let mut position = position!(0.5, 63.0, 0.5);
+        let mut motion = BoatMotion::default();
+        let controls = BoatControls {
+            forward: true,
+            ..Default::default()
+        };
+        for _ in 0..20 {
+            step_boat(&lake, &mut position, &mut motion, controls);
+        }
+
+        // Yaw 0 faces south (+Z)
+        assert!(position.z > 2.0, "boat at z={}", position.z);
+        assert!((position.x - 0.5).abs() < 1e-6);
+        assert!((position.y - 63.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn boat_stops_at_shore() {
+        let shore = |pos: (i32, i32, i32)| {
+            if pos.2 >= 3 && pos.1 <= 63 {
+                Some((BlockKind::Stone, BlockKind::Stone.default_properties()))
+            } else {
+                lake(pos)
+            }
+        };
+        let mut position = position!(0.5, 63.0, 0.5);
+        let mut motion = BoatMotion::default();
+        let controls = BoatControls {
+            forward: true,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            step_boat(&shore, &mut position, &mut motion, controls);
+        }
+        assert!(position.z < 3.0, "boat at z={}", position.z);
+    }
+
+    #[test]
+    fn boat_slides_further_on_ice() {
+        let ice = |pos: (i32, i32, i32)| {
+            let kind = if pos.1 < 63 {
+                BlockKind::Ice
+            } else {
+                BlockKind::Air
+            };
+            Some((kind, kind.default_properties()))
+        };
+        let stone = |pos: (i32, i32, i32)| {
+            let kind = if pos.1 < 63 {
+                BlockKind::Stone
+            } else {
+                BlockKind::Air
+            };
+            Some((kind, kind.default_properties()))
+        };
+        let slide = |view: &dyn BlockView| {
+            let mut position = position!(0.5, 63.0, 0.5);
+            let mut motion = BoatMotion {
+                velocity: Vec3d::new(0.0, 0.0, 0.5),
+                yaw_velocity: 0.0,
+            };
+            for _ in 0..40 {
+                step_boat(view, &mut position, &mut motion, BoatControls::default());
+            }
+            position.z
+        };
+
+        assert!(slide(&ice) > slide(&stone) * 2.0);
+    }
+
+    #[test]
+    fn hitting_boat_spawns_its_drops() {
+        let mut game = Game::new();
+        let boat = game.ecs.spawn((position!(0.0, 63.0, 0.0), BoatWood::Oak));
+        let player = game.ecs.spawn((Gamemode::Survival,));
+        game.ecs
+            .insert_entity_event(
+                player,
+                InteractEntityEvent {
+                    target: quill_common::EntityId(u64::from(boat.id())),
+                    ty: InteractionType::Attack,
+                    target_pos: None,
+                    hand: None,
+                    sneaking: false,
+                },
+            )
+            .unwrap();
+
+        break_attacked_boats(&mut game).unwrap();
+
+        assert!(game.ecs.get::<BoatWood>(boat).is_err());
+        let drops: Vec<ItemStack> = game
+            .ecs
+            .query::<&ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.clone())
+            .collect();
+        assert_eq!(drops.len(), 2);
+        assert!(drops.contains(&ItemStack::new(Item::OakPlanks, 3).unwrap()));
+        assert!(drops.contains(&ItemStack::new(Item::Stick, 2).unwrap()));
+    }
+
+    #[test]
+    fn broken_boat_drops_planks_and_sticks() {
+        let mut game = Game::new();
+        let boat = game.ecs.spawn((position!(0.0, 63.0, 0.0), BoatWood::Birch));
+        let drops = break_boat(&mut game, boat).unwrap();
+        assert_eq!(
+            drops,
+            vec![
+                ItemStack::new(Item::BirchPlanks, 3).unwrap(),
+                ItemStack::new(Item::Stick, 2).unwrap(),
+            ]
+        );
+    }
 }
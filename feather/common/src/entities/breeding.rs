@@ -0,0 +1,237 @@
+//! Feeding animals, which puts adults in love so they breed
+//! and heals tamed pets.
+
+use base::{EntityKind, Item, ParticleKind, Position};
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_items::InventorySlot;
+use quill_common::components::{Health, Tameable};
+
+use crate::{entity_interaction::EntityInteractions, Game};
+
+use super::aging::Baby;
+
+/// Ticks an animal stays in love after eating
+pub const IN_LOVE_TICKS: u32 = 600;
+/// Ticks before an animal can breed again
+pub const BREEDING_COOLDOWN_TICKS: u32 = 6000;
+
+/// Animals that can be fed to breed or heal them
+const FED_ANIMALS: &[EntityKind] = &[
+    EntityKind::Cow,
+    EntityKind::Mooshroom,
+    EntityKind::Sheep,
+    EntityKind::Goat,
+    EntityKind::Pig,
+    EntityKind::Chicken,
+    EntityKind::Wolf,
+    EntityKind::Cat,
+    EntityKind::Horse,
+    EntityKind::Donkey,
+    EntityKind::Rabbit,
+    EntityKind::Llama,
+    EntityKind::Fox,
+    EntityKind::Turtle,
+];
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    let mut interactions = game
+        .resources
+        .get_mut::<EntityInteractions>()
+        .expect("Failed to get the entity interactions");
+    for kind in FED_ANIMALS {
+        interactions.register(*kind, None, feed);
+    }
+    drop(interactions);
+    systems.add_system(cool_down);
+}
+
+/// An animal that breeds after eating its food.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breedable {
+    /// The items the animal eats
+    pub food: &'static [Item],
+    /// Ticks left for the animal to find a partner
+    pub in_love_ticks: u32,
+    /// Ticks until the animal can fall in love again
+    pub cooldown_ticks: u32,
+}
+
+impl Breedable {
+    pub fn new(food: &'static [Item]) -> Self {
+        Self {
+            food,
+            in_love_ticks: 0,
+            cooldown_ticks: 0,
+        }
+    }
+
+    pub fn is_food(&self, item: Item) -> bool {
+        self.food.contains(&item)
+    }
+
+    pub fn is_in_love(&self) -> bool {
+        self.in_love_ticks > 0
+    }
+
+    /// Starts the cooldown after the animal has bred.
+    pub fn bred(&mut self) {
+        self.in_love_ticks = 0;
+        self.cooldown_ticks = BREEDING_COOLDOWN_TICKS;
+    }
+}
+
+/// Health a tamed animal of `kind` regains by eating `food`.
+pub fn healing(kind: EntityKind, food: Item) -> Option<f32> {
+    let amount = match (kind, food) {
+        (EntityKind::Wolf, Item::CookedBeef) | (EntityKind::Wolf, Item::CookedPorkchop) => 8.0,
+        (EntityKind::Wolf, Item::CookedChicken) | (EntityKind::Wolf, Item::CookedMutton) => 6.0,
+        (EntityKind::Wolf, Item::CookedRabbit) => 5.0,
+        (EntityKind::Wolf, Item::RottenFlesh) => 4.0,
+        (EntityKind::Wolf, Item::Beef)
+        | (EntityKind::Wolf, Item::Porkchop)
+        | (EntityKind::Wolf, Item::Rabbit) => 3.0,
+        (EntityKind::Wolf, Item::Chicken) | (EntityKind::Wolf, Item::Mutton) => 2.0,
+        (EntityKind::Cat, Item::Cod) | (EntityKind::Cat, Item::Salmon) => 2.0,
+        (EntityKind::Horse, food) | (EntityKind::Donkey, food) => match food {
+            Item::HayBlock => 20.0,
+            Item::GoldenApple | Item::EnchantedGoldenApple => 10.0,
+            Item::GoldenCarrot => 4.0,
+            Item::Apple => 3.0,
+            Item::Wheat => 2.0,
+            Item::Sugar => 1.0,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(amount)
+}
+
+/// Feeds `animal` the `held` food. A hurt tamed pet is healed,
+/// while an adult ready to breed falls in love. Wolves and cats
+/// only take food once tamed.
+///
+/// Returns whether the food was eaten.
+pub fn feed(
+    game: &mut Game,
+    _player: Entity,
+    animal: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let food = match held.item_kind() {
+        Some(food) => food,
+        None => return Ok(false),
+    };
+    let kind = *game.ecs.get::<EntityKind>(animal)?;
+    let position = *game.ecs.get::<Position>(animal)?;
+    let tamed = game
+        .ecs
+        .get::<Tameable>(animal)
+        .map_or(false, |tameable| tameable.tamed);
+    if matches!(kind, EntityKind::Wolf | EntityKind::Cat) && !tamed {
+        return Ok(false);
+    }
+
+    if let Some(amount) = healing(kind, food).filter(|_| tamed) {
+        if let Ok(mut health) = game.ecs.get_mut::<Health>(animal) {
+            if health.current < health.max {
+                health.heal(amount);
+                drop(health);
+                let _ = held.try_take(1);
+                game.emit_sound("entity.generic.eat", position, 1.0, 1.0);
+                return Ok(true);
+            }
+        }
+    }
+
+    let baby = game.ecs.get::<Baby>(animal).is_ok();
+    let mut breedable = match game.ecs.get_mut::<Breedable>(animal) {
+        Ok(breedable) => breedable,
+        Err(_) => return Ok(false),
+    };
+    if baby || !breedable.is_food(food) || breedable.is_in_love() || breedable.cooldown_ticks > 0 {
+        return Ok(false);
+    }
+    breedable.in_love_ticks = IN_LOVE_TICKS;
+    drop(breedable);
+
+    let _ = held.try_take(1);
+    game.emit_particle(ParticleKind::Heart, position, 7, 0.5);
+    Ok(true)
+}
+
+/// Counts down the time animals stay in love and wait to breed again.
+fn cool_down(game: &mut Game) -> SysResult {
+    for (_, breedable) in game.ecs.query::<&mut Breedable>().iter() {
+        breedable.in_love_ticks = breedable.in_love_ticks.saturating_sub(1);
+        breedable.cooldown_ticks = breedable.cooldown_ticks.saturating_sub(1);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use quill_common::entities::{Cow, Wolf};
+    use uuid::Uuid;
+
+    use crate::entities::{cow, wolf};
+
+    use super::*;
+
+    #[test]
+    fn feeding_heals_hurt_tamed_wolves() {
+        let mut game = Game::new();
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let mut health = Health::new(20.0);
+        health.current = 10.0;
+        let mut tameable = Tameable::default();
+        tameable.tame(Uuid::from_u128(1));
+        let wolf = game.ecs.spawn((
+            Wolf,
+            EntityKind::Wolf,
+            position!(1.5, 64.0, 0.5),
+            health,
+            tameable,
+            Breedable::new(wolf::FOOD),
+        ));
+
+        let mut held = InventorySlot::new(Item::CookedBeef, 2);
+        assert!(feed(&mut game, player, wolf, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::CookedBeef, 1));
+        assert_eq!(game.ecs.get::<Health>(wolf).unwrap().current, 18.0);
+        assert!(!game.ecs.get::<Breedable>(wolf).unwrap().is_in_love());
+    }
+
+    #[test]
+    fn fed_cows_fall_in_love() {
+        let mut game = Game::new();
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let cows: Vec<Entity> = (0..2)
+            .map(|i| {
+                game.ecs.spawn((
+                    Cow,
+                    EntityKind::Cow,
+                    position!(f64::from(i) * 2.0, 64.0, 0.5),
+                    Breedable::new(cow::FOOD),
+                ))
+            })
+            .collect();
+
+        let mut held = InventorySlot::new(Item::Wheat, 3);
+        for animal in &cows {
+            assert!(feed(&mut game, player, *animal, &mut held).unwrap());
+            assert_eq!(
+                game.ecs.get::<Breedable>(*animal).unwrap().in_love_ticks,
+                IN_LOVE_TICKS
+            );
+        }
+        assert_eq!(held, InventorySlot::new(Item::Wheat, 1));
+
+        // Already in love
+        assert!(!feed(&mut game, player, cows[0], &mut held).unwrap());
+        // Resting after breeding
+        game.ecs.get_mut::<Breedable>(cows[1]).unwrap().bred();
+        assert!(!feed(&mut game, player, cows[1], &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::Wheat, 1));
+    }
+}
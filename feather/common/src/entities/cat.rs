@@ -1,8 +1,23 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Cat;
+use quill_common::{components::Health, entities::Cat};
+
+use super::{breeding::Breedable, taming::CollarColor};
+
+/// Metadata indices of cats, see <https://wiki.vg/Entity_metadata#Cat>
+pub mod indices {
+    pub const COLLAR_COLOR: u8 = 21;
+}
+
+/// Raw fish cats are bred and healed with
+pub const FOOD: &[Item] = &[Item::Cod, Item::Salmon];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Cat).add(EntityKind::Cat);
+    builder
+        .add(Cat)
+        .add(EntityKind::Cat)
+        .add(Health::new(10.0))
+        .add(CollarColor::default())
+        .add(Breedable::new(FOOD));
 }
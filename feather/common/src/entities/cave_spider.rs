@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::CaveSpider;
+use quill_common::{components::Health, entities::CaveSpider};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(CaveSpider).add(EntityKind::CaveSpider);
+    builder
+        .add(CaveSpider)
+        .add(EntityKind::CaveSpider)
+        .add(Health::new(12.0));
 }
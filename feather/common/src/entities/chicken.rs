@@ -1,8 +1,22 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Chicken;
+use quill_common::{components::Health, entities::Chicken};
+
+use super::breeding::Breedable;
+
+/// Seeds chickens are bred with
+pub const FOOD: &[Item] = &[
+    Item::WheatSeeds,
+    Item::MelonSeeds,
+    Item::PumpkinSeeds,
+    Item::BeetrootSeeds,
+];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Chicken).add(EntityKind::Chicken);
+    builder
+        .add(Chicken)
+        .add(EntityKind::Chicken)
+        .add(Health::new(4.0))
+        .add(Breedable::new(FOOD));
 }
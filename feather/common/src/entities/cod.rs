@@ -1,8 +1,8 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Cod;
+use quill_common::{components::Health, entities::Cod};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Cod).add(EntityKind::Cod);
+    builder.add(Cod).add(EntityKind::Cod).add(Health::new(3.0));
 }
@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Cow;
+use quill_common::{components::Health, entities::Cow};
+
+use super::breeding::Breedable;
+
+/// Cows are bred with wheat
+pub const FOOD: &[Item] = &[Item::Wheat];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Cow).add(EntityKind::Cow);
+    builder
+        .add(Cow)
+        .add(EntityKind::Cow)
+        .add(Health::new(10.0))
+        .add(Breedable::new(FOOD));
 }
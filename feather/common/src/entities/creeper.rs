@@ -1,8 +1,321 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Creeper;
+use base::{EntityKind, EntityMetadata, Gamemode, ItemStack, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{
+    components::Health,
+    entities::{Creeper, LightningBolt, Player},
+    events::{EntityCreateEvent, EntityRemoveEvent},
+};
+
+use crate::{events::EntityMetadataChangeEvent, explosion, loot, Game};
+
+use super::item;
+
+/// Metadata indices of creepers, see <https://wiki.vg/Entity_metadata#Creeper>
+pub mod indices {
+    pub const STATE: u8 = 15;
+    pub const CHARGED: u8 = 16;
+}
+
+/// Power of a creeper's explosion
+pub const EXPLOSION_POWER: f32 = 3.0;
+/// Power of a charged creeper's explosion
+pub const CHARGED_EXPLOSION_POWER: f32 = 6.0;
+/// Ticks a creeper's fuse burns before it explodes
+pub const FUSE_TICKS: u32 = 30;
+/// How close a player has to come for a creeper to light its fuse
+const IGNITE_DISTANCE: f64 = 3.0;
+/// How far a player has to get for a lit creeper to put its fuse out
+const DEFUSE_DISTANCE: f64 = 7.0;
+/// How close to a lightning strike a creeper has to be to be charged
+const LIGHTNING_REACH: f64 = 3.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems
+        .add_system(charge_creepers_struck_by_lightning)
+        .add_system(burn_fuses);
+}
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Creeper).add(EntityKind::Creeper);
+    builder
+        .add(Creeper)
+        .add(EntityKind::Creeper)
+        .add(Health::new(20.0));
+}
+
+/// The lit fuse of a creeper about to explode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fuse {
+    pub ticks_remaining: u32,
+}
+
+/// Marks a creeper charged by lightning. Its explosion is
+/// stronger, and a mob it kills drops its head.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Charged;
+
+/// Blows up `creeper`, removing it. The blast only destroys
+/// blocks while the `mobGriefing` rule is on.
+///
+/// Returns the entities hurt by the explosion.
+pub fn explode(game: &mut Game, creeper: Entity) -> SysResult<Vec<Entity>> {
+    let charged = game.ecs.get::<Charged>(creeper).is_ok();
+    let power = if charged {
+        CHARGED_EXPLOSION_POWER
+    } else {
+        EXPLOSION_POWER
+    };
+    let hurt = explosion::explode_entity(game, creeper, power)?;
+    if charged {
+        drop_mob_head(game, &hurt)?;
+    }
+    Ok(hurt)
+}
+
+/// Charges the creepers near lightning that just struck.
+fn charge_creepers_struck_by_lightning(game: &mut Game) -> SysResult {
+    let strikes: Vec<Position> = game
+        .ecs
+        .query::<(&LightningBolt, &EntityCreateEvent, &Position)>()
+        .iter()
+        .map(|(_, (_, _, position))| *position)
+        .collect();
+    if strikes.is_empty() {
+        return Ok(());
+    }
+
+    let struck: Vec<Entity> = game
+        .ecs
+        .query::<(&Creeper, &Position)>()
+        .iter()
+        .filter(|(_, (_, position))| {
+            strikes
+                .iter()
+                .any(|strike| strike.distance_to(**position) <= LIGHTNING_REACH)
+        })
+        .map(|(creeper, _)| creeper)
+        .collect();
+    for creeper in struck {
+        game.ecs.insert(creeper, Charged)?;
+        game.ecs.insert_entity_event(
+            creeper,
+            EntityMetadataChangeEvent {
+                metadata: EntityMetadata::new().with(indices::CHARGED, true),
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Lights the fuse of creepers a player comes close to and puts it out
+/// again if they get away. Creepers whose fuse burns down explode.
+fn burn_fuses(game: &mut Game) -> SysResult {
+    let players: Vec<Position> = game
+        .ecs
+        .query::<(&Player, &Position, Option<&Gamemode>)>()
+        .iter()
+        .filter(|(_, (_, _, gamemode))| {
+            !matches!(
+                gamemode,
+                Some(Gamemode::Creative) | Some(Gamemode::Spectator)
+            )
+        })
+        .map(|(_, (_, position, _))| *position)
+        .collect();
+    let creepers: Vec<(Entity, Position, Option<Fuse>)> = game
+        .ecs
+        .query::<(&Creeper, &Position, &Health, Option<&Fuse>)>()
+        .iter()
+        .filter(|(_, (_, _, health, _))| health.current > 0.0)
+        .map(|(creeper, (_, position, _, fuse))| (creeper, *position, fuse.copied()))
+        .filter(|(creeper, _, _)| game.ecs.get::<EntityRemoveEvent>(*creeper).is_err())
+        .collect();
+
+    for (creeper, position, fuse) in creepers {
+        let nearest = players
+            .iter()
+            .map(|player| player.distance_to(position))
+            .fold(f64::INFINITY, f64::min);
+        match fuse {
+            None if nearest <= IGNITE_DISTANCE => {
+                game.ecs.insert(
+                    creeper,
+                    Fuse {
+                        ticks_remaining: FUSE_TICKS,
+                    },
+                )?;
+                set_state(game, creeper, 1)?;
+                game.emit_sound("entity.creeper.primed", position, 1.0, 0.5);
+            }
+            None => {}
+            Some(_) if nearest > DEFUSE_DISTANCE => {
+                game.ecs.remove::<Fuse>(creeper)?;
+                set_state(game, creeper, -1)?;
+            }
+            Some(fuse) if fuse.ticks_remaining <= 1 => {
+                explode(game, creeper)?;
+            }
+            Some(fuse) => {
+                game.ecs.insert(
+                    creeper,
+                    Fuse {
+                        ticks_remaining: fuse.ticks_remaining - 1,
+                    },
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Shows a creeper swelling up with a lit fuse, with a `state`
+/// of 1, or idle, with -1.
+fn set_state(game: &mut Game, creeper: Entity, state: i32) -> SysResult {
+    game.ecs.insert_entity_event(
+        creeper,
+        EntityMetadataChangeEvent {
+            metadata: EntityMetadata::new().with(indices::STATE, state),
+        },
+    )?;
+    Ok(())
+}
+
+/// Drops the head of the first mob with one among those
+/// killed by a charged creeper. Each explosion drops one head at most.
+fn drop_mob_head(game: &mut Game, hurt: &[Entity]) -> SysResult {
+    for mob in hurt {
+        let killed = game
+            .ecs
+            .get::<Health>(*mob)
+            .map_or(false, |health| health.current <= 0.0);
+        let head = game
+            .ecs
+            .get::<EntityKind>(*mob)
+            .ok()
+            .and_then(|kind| loot::mob_head(*kind));
+        if let (true, Some(head)) = (killed, head) {
+            let position = *game.ecs.get::<Position>(*mob)?;
+            item::spawn_item(game, position, ItemStack::new(head, 1)?);
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockId, BlockPosition, Chunk, ChunkPosition, Item, ValidBlockPosition};
+    use crate::GameRules;
+
+    use super::*;
+
+    #[test]
+    fn creeper_spares_terrain_without_mob_griefing() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let ground: ValidBlockPosition = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        game.set_block(ground, BlockId::stone());
+        game.insert_resource(GameRules {
+            mob_griefing: false,
+            ..Default::default()
+        });
+
+        let creeper = game
+            .ecs
+            .spawn((EntityKind::Creeper, position!(8.5, 65.0, 8.5)));
+        let cow = game.ecs.spawn((
+            EntityKind::Cow,
+            position!(10.5, 65.0, 8.5),
+            Health::new(10.0),
+        ));
+        assert_eq!(explode(&mut game, creeper).unwrap(), vec![cow]);
+
+        assert!(game.ecs.get::<Health>(cow).unwrap().current < 10.0);
+        assert!(game.ecs.get::<EntityRemoveEvent>(creeper).is_ok());
+        assert_eq!(game.block(ground), Some(BlockId::stone()));
+    }
+
+    #[test]
+    fn charged_creeper_kills_drop_mob_heads() {
+        let mut game = Game::new();
+        game.add_entity_spawn_callback(crate::entities::add_entity_components);
+        let creeper = game
+            .ecs
+            .spawn((EntityKind::Creeper, Charged, position!(8.5, 65.0, 8.5)));
+        let zombie = game.ecs.spawn((
+            EntityKind::Zombie,
+            position!(9.5, 65.0, 8.5),
+            Health::new(20.0),
+        ));
+        let cow = game.ecs.spawn((
+            EntityKind::Cow,
+            position!(7.5, 65.0, 8.5),
+            Health::new(10.0),
+        ));
+        explode(&mut game, creeper).unwrap();
+        assert!(game.ecs.get::<Health>(zombie).unwrap().current <= 0.0);
+        assert!(game.ecs.get::<Health>(cow).unwrap().current <= 0.0);
+
+        let dropped: Vec<ItemStack> = game
+            .ecs
+            .query::<&ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.clone())
+            .collect();
+        assert_eq!(dropped, vec![ItemStack::new(Item::ZombieHead, 1).unwrap()]);
+    }
+
+    #[test]
+    fn creepers_explode_once_their_fuse_burns_down() {
+        let mut game = Game::new();
+        let creeper = game.ecs.spawn((
+            Creeper,
+            EntityKind::Creeper,
+            position!(8.5, 65.0, 8.5),
+            Health::new(20.0),
+        ));
+        let player = game.ecs.spawn((
+            Player,
+            EntityKind::Player,
+            position!(10.5, 65.0, 8.5),
+            Health::new(20.0),
+        ));
+
+        burn_fuses(&mut game).unwrap();
+        assert!(game.ecs.get::<Fuse>(creeper).is_ok());
+        for _ in 1..FUSE_TICKS {
+            burn_fuses(&mut game).unwrap();
+        }
+        assert!(game.ecs.get::<EntityRemoveEvent>(creeper).is_err());
+        burn_fuses(&mut game).unwrap();
+        assert!(game.ecs.get::<EntityRemoveEvent>(creeper).is_ok());
+        assert!(game.ecs.get::<Health>(player).unwrap().current < 20.0);
+    }
+
+    #[test]
+    fn lightning_charges_nearby_creepers() {
+        let mut game = Game::new();
+        let near = game
+            .ecs
+            .spawn((Creeper, EntityKind::Creeper, position!(1.0, 64.0, 0.0)));
+        let far = game
+            .ecs
+            .spawn((Creeper, EntityKind::Creeper, position!(10.0, 64.0, 0.0)));
+        let bolt = game.ecs.spawn((
+            LightningBolt,
+            EntityKind::LightningBolt,
+            position!(0.0, 64.0, 0.0),
+        ));
+        game.ecs
+            .insert_entity_event(bolt, EntityCreateEvent)
+            .unwrap();
+
+        charge_creepers_struck_by_lightning(&mut game).unwrap();
+        assert!(game.ecs.get::<Charged>(near).is_ok());
+        assert!(game.ecs.get::<Charged>(far).is_err());
+    }
 }
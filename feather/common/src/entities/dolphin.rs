@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Dolphin;
+use quill_common::{components::Health, entities::Dolphin};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Dolphin).add(EntityKind::Dolphin);
+    builder
+        .add(Dolphin)
+        .add(EntityKind::Dolphin)
+        .add(Health::new(10.0));
 }
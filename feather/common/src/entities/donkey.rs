@@ -1,8 +1,14 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Donkey;
+use quill_common::{components::Health, entities::Donkey};
+
+use super::{breeding::Breedable, horse};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Donkey).add(EntityKind::Donkey);
+    builder
+        .add(Donkey)
+        .add(EntityKind::Donkey)
+        .add(Health::new(20.0))
+        .add(Breedable::new(horse::FOOD));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Drowned;
+use quill_common::{components::Health, entities::Drowned};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Drowned).add(EntityKind::Drowned);
+    builder
+        .add(Drowned)
+        .add(EntityKind::Drowned)
+        .add(Health::new(20.0));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::ElderGuardian;
+use quill_common::{components::Health, entities::ElderGuardian};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(ElderGuardian).add(EntityKind::ElderGuardian);
+    builder
+        .add(ElderGuardian)
+        .add(EntityKind::ElderGuardian)
+        .add(Health::new(80.0));
 }
@@ -1,8 +1,197 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Enderman;
+use std::convert::TryInto;
+
+use base::{BlockId, BlockKind, BlockPosition, EntityKind, Position, ValidBlockPosition};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{components::Health, entities::Enderman};
+use rand::Rng;
+
+use crate::{game::properties_of, Game};
+
+/// Chance each tick for an enderman without a block to try picking one up
+const PICK_UP_CHANCE: f32 = 0.05;
+/// Chance each tick for an enderman to try putting its block down
+const PLACE_CHANCE: f32 = 0.0005;
+/// Farthest an enderman reaches for blocks horizontally
+const REACH: i32 = 2;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Enderman).add(EntityKind::Enderman);
+    builder
+        .add(Enderman)
+        .add(EntityKind::Enderman)
+        .add(Health::new(40.0));
+}
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(move_blocks);
+}
+
+/// The block an enderman is carrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CarriedBlock(pub BlockId);
+
+/// Whether endermen pick up blocks of this kind.
+pub fn can_carry(kind: BlockKind) -> bool {
+    matches!(
+        kind,
+        BlockKind::GrassBlock
+            | BlockKind::Dirt
+            | BlockKind::CoarseDirt
+            | BlockKind::Podzol
+            | BlockKind::Mycelium
+            | BlockKind::Sand
+            | BlockKind::RedSand
+            | BlockKind::Gravel
+            | BlockKind::Clay
+            | BlockKind::Pumpkin
+            | BlockKind::CarvedPumpkin
+            | BlockKind::Melon
+            | BlockKind::Tnt
+            | BlockKind::Cactus
+            | BlockKind::BrownMushroom
+            | BlockKind::RedMushroom
+            | BlockKind::Dandelion
+            | BlockKind::Poppy
+            | BlockKind::Cornflower
+            | BlockKind::LilyOfTheValley
+            | BlockKind::WitherRose
+            | BlockKind::CrimsonNylium
+            | BlockKind::WarpedNylium
+    )
+}
+
+/// Makes `enderman` pick up the block at `pos`, if it has
+/// its hands free and mobs are allowed to grief.
+///
+/// Returns whether it picked the block up.
+pub fn pick_up_block(
+    game: &mut Game,
+    enderman: Entity,
+    pos: ValidBlockPosition,
+) -> SysResult<bool> {
+    if !game.mob_can_grief() || game.ecs.get::<CarriedBlock>(enderman).is_ok() {
+        return Ok(false);
+    }
+    let block = match game.block(pos) {
+        Some(block) if can_carry(block.kind()) => block,
+        _ => return Ok(false),
+    };
+
+    game.break_block(pos);
+    game.ecs.insert(enderman, CarriedBlock(block))?;
+    Ok(true)
+}
+
+/// Makes `enderman` put the block it carries down at `pos`, if that
+/// is an empty spot on top of another block and mobs are allowed to grief.
+///
+/// Returns whether it put the block down.
+pub fn place_carried_block(
+    game: &mut Game,
+    enderman: Entity,
+    pos: ValidBlockPosition,
+) -> SysResult<bool> {
+    if !game.mob_can_grief() {
+        return Ok(false);
+    }
+    let carried = match game.ecs.get::<CarriedBlock>(enderman) {
+        Ok(carried) => carried.0,
+        Err(_) => return Ok(false),
+    };
+    let free = game.block(pos).map_or(false, |block| block.is_air());
+    let supported = BlockPosition::from(pos)
+        .down()
+        .try_into()
+        .ok()
+        .and_then(|below| game.block(below))
+        .map_or(false, |below| !below.is_air());
+    if !free || !supported {
+        return Ok(false);
+    }
+
+    if !game.place_block(pos, &properties_of(carried)) {
+        return Ok(false);
+    }
+    game.ecs.remove::<CarriedBlock>(enderman)?;
+    Ok(true)
+}
+
+/// Lets endermen now and then pick up a block near them
+/// or put down the one they carry.
+fn move_blocks(game: &mut Game) -> SysResult {
+    if !game.mob_can_grief() {
+        return Ok(());
+    }
+
+    let moves: Vec<(Entity, ValidBlockPosition, bool)> = game.with_rng(|game, rng| {
+        let mut moves = Vec::new();
+        for (enderman, (_, position)) in game.ecs.query::<(&Enderman, &Position)>().iter() {
+            let carrying = game.ecs.get::<CarriedBlock>(enderman).is_ok();
+            let chance = if carrying {
+                PLACE_CHANCE
+            } else {
+                PICK_UP_CHANCE
+            };
+            if rng.gen::<f32>() >= chance {
+                continue;
+            }
+            let origin = position.block();
+            let target = BlockPosition::new(
+                origin.x + rng.gen_range(-REACH..=REACH),
+                origin.y + rng.gen_range(0..=2),
+                origin.z + rng.gen_range(-REACH..=REACH),
+            );
+            if let Ok(target) = target.try_into() {
+                moves.push((enderman, target, carrying));
+            }
+        }
+        moves
+    });
+
+    for (enderman, target, carrying) in moves {
+        if carrying {
+            place_carried_block(game, enderman, target)?;
+        } else {
+            pick_up_block(game, enderman, target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, Chunk, ChunkPosition};
+
+    use crate::GameRules;
+
+    use super::*;
+
+    #[test]
+    fn enderman_leaves_blocks_without_mob_griefing() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let grass: ValidBlockPosition = BlockPosition::new(3, 64, 3).try_into().unwrap();
+        game.set_block(grass, BlockId::grass_block());
+        let enderman = game
+            .ecs
+            .spawn((Enderman, EntityKind::Enderman, position!(3.5, 65.0, 4.5)));
+
+        game.insert_resource(GameRules {
+            mob_griefing: false,
+            ..Default::default()
+        });
+        assert!(!pick_up_block(&mut game, enderman, grass).unwrap());
+        assert_eq!(game.block(grass), Some(BlockId::grass_block()));
+        assert!(game.ecs.get::<CarriedBlock>(enderman).is_err());
+
+        game.resources.get_mut::<GameRules>().unwrap().mob_griefing = true;
+        assert!(pick_up_block(&mut game, enderman, grass).unwrap());
+        assert!(game.block(grass).unwrap().is_air());
+        assert_eq!(
+            *game.ecs.get::<CarriedBlock>(enderman).unwrap(),
+            CarriedBlock(BlockId::grass_block())
+        );
+    }
 }
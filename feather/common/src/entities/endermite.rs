@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Endermite;
+use quill_common::{components::Health, entities::Endermite};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Endermite).add(EntityKind::Endermite);
+    builder
+        .add(Endermite)
+        .add(EntityKind::Endermite)
+        .add(Health::new(8.0));
 }
@@ -0,0 +1,207 @@
+//! Items worn and held by mobs.
+
+use base::{Item, ItemStack};
+use rand::Rng;
+
+/// Chance for a mob to drop each item it spawned with when killed
+pub const DEFAULT_DROP_CHANCE: f32 = 0.085;
+/// Drop chance added by each level of looting on the killing weapon
+const LOOTING_DROP_BONUS: f32 = 0.01;
+
+/// A slot an item can be worn or held in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EquipmentSlot {
+    MainHand,
+    OffHand,
+    Head,
+    Chest,
+    Legs,
+    Feet,
+}
+
+impl EquipmentSlot {
+    pub const ALL: [EquipmentSlot; 6] = [
+        EquipmentSlot::MainHand,
+        EquipmentSlot::OffHand,
+        EquipmentSlot::Head,
+        EquipmentSlot::Chest,
+        EquipmentSlot::Legs,
+        EquipmentSlot::Feet,
+    ];
+
+    /// The slot an item goes into when equipped.
+    pub fn for_item(item: Item) -> EquipmentSlot {
+        let name = item.name();
+        if name.ends_with("_helmet")
+            || name.ends_with("_head")
+            || name.ends_with("_skull")
+            || item == Item::CarvedPumpkin
+        {
+            EquipmentSlot::Head
+        } else if name.ends_with("_chestplate") || item == Item::Elytra {
+            EquipmentSlot::Chest
+        } else if name.ends_with("_leggings") {
+            EquipmentSlot::Legs
+        } else if name.ends_with("_boots") {
+            EquipmentSlot::Feet
+        } else if item == Item::Shield {
+            EquipmentSlot::OffHand
+        } else {
+            EquipmentSlot::MainHand
+        }
+    }
+
+    /// Whether the slot is one of the hands.
+    pub fn is_hand(self) -> bool {
+        matches!(self, EquipmentSlot::MainHand | EquipmentSlot::OffHand)
+    }
+
+    pub(crate) fn index(self) -> usize {
+        match self {
+            EquipmentSlot::MainHand => 0,
+            EquipmentSlot::OffHand => 1,
+            EquipmentSlot::Head => 2,
+            EquipmentSlot::Chest => 3,
+            EquipmentSlot::Legs => 4,
+            EquipmentSlot::Feet => 5,
+        }
+    }
+}
+
+/// Armor points given by a piece of armor.
+pub fn armor_points(item: Item) -> f32 {
+    match item {
+        Item::LeatherHelmet | Item::LeatherBoots => 1.0,
+        Item::LeatherLeggings => 2.0,
+        Item::LeatherChestplate => 3.0,
+        Item::GoldenBoots | Item::ChainmailBoots => 1.0,
+        Item::GoldenHelmet | Item::ChainmailHelmet | Item::TurtleHelmet => 2.0,
+        Item::GoldenLeggings => 3.0,
+        Item::GoldenChestplate | Item::ChainmailChestplate => 5.0,
+        Item::ChainmailLeggings => 4.0,
+        Item::IronHelmet | Item::IronBoots => 2.0,
+        Item::IronLeggings => 5.0,
+        Item::IronChestplate => 6.0,
+        Item::DiamondHelmet | Item::DiamondBoots => 3.0,
+        Item::NetheriteHelmet | Item::NetheriteBoots => 3.0,
+        Item::DiamondLeggings | Item::NetheriteLeggings => 6.0,
+        Item::DiamondChestplate | Item::NetheriteChestplate => 8.0,
+        _ => 0.0,
+    }
+}
+
+/// Armor toughness given by a piece of armor, which
+/// makes armor hold up better against strong hits.
+pub fn armor_toughness(item: Item) -> f32 {
+    match item {
+        Item::DiamondHelmet
+        | Item::DiamondChestplate
+        | Item::DiamondLeggings
+        | Item::DiamondBoots => 2.0,
+        Item::NetheriteHelmet
+        | Item::NetheriteChestplate
+        | Item::NetheriteLeggings
+        | Item::NetheriteBoots => 3.0,
+        _ => 0.0,
+    }
+}
+
+/// The items a mob wears and holds, along with
+/// the chance each one drops when the mob is killed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equipment {
+    pub main_hand: Option<ItemStack>,
+    pub off_hand: Option<ItemStack>,
+    pub helmet: Option<ItemStack>,
+    pub chestplate: Option<ItemStack>,
+    pub leggings: Option<ItemStack>,
+    pub boots: Option<ItemStack>,
+    drop_chances: [f32; 6],
+}
+
+impl Default for Equipment {
+    fn default() -> Self {
+        Self {
+            main_hand: None,
+            off_hand: None,
+            helmet: None,
+            chestplate: None,
+            leggings: None,
+            boots: None,
+            drop_chances: [DEFAULT_DROP_CHANCE; 6],
+        }
+    }
+}
+
+impl Equipment {
+    /// The item in the given slot.
+    pub fn get(&self, slot: EquipmentSlot) -> Option<&ItemStack> {
+        self.slot(slot).as_ref()
+    }
+
+    /// Puts `item` into the given slot, returning the item that was there.
+    pub fn set(&mut self, slot: EquipmentSlot, item: Option<ItemStack>) -> Option<ItemStack> {
+        std::mem::replace(self.slot_mut(slot), item)
+    }
+
+    /// Chance for the item in the given slot to drop on death.
+    /// Chances above 1 always drop, even without looting.
+    pub fn drop_chance(&self, slot: EquipmentSlot) -> f32 {
+        self.drop_chances[slot.index()]
+    }
+
+    pub fn set_drop_chance(&mut self, slot: EquipmentSlot, chance: f32) {
+        self.drop_chances[slot.index()] = chance;
+    }
+
+    /// The equipped items along with their slots.
+    pub fn iter(&self) -> impl Iterator<Item = (EquipmentSlot, &ItemStack)> + '_ {
+        EquipmentSlot::ALL
+            .iter()
+            .filter_map(move |&slot| self.get(slot).map(|item| (slot, item)))
+    }
+
+    /// Total armor points of the worn armor.
+    pub fn armor_points(&self) -> f32 {
+        self.iter().map(|(_, item)| armor_points(item.item())).sum()
+    }
+
+    /// Total armor toughness of the worn armor.
+    pub fn armor_toughness(&self) -> f32 {
+        self.iter()
+            .map(|(_, item)| armor_toughness(item.item()))
+            .sum()
+    }
+
+    /// Rolls which items drop when the mob is killed
+    /// by a weapon with `looting` levels of looting.
+    pub fn roll_drops(&self, looting: u8, rng: &mut impl Rng) -> Vec<ItemStack> {
+        let bonus = f32::from(looting) * LOOTING_DROP_BONUS;
+        self.iter()
+            .filter(|(slot, _)| rng.gen::<f32>() < self.drop_chance(*slot) + bonus)
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+
+    fn slot(&self, slot: EquipmentSlot) -> &Option<ItemStack> {
+        match slot {
+            EquipmentSlot::MainHand => &self.main_hand,
+            EquipmentSlot::OffHand => &self.off_hand,
+            EquipmentSlot::Head => &self.helmet,
+            EquipmentSlot::Chest => &self.chestplate,
+            EquipmentSlot::Legs => &self.leggings,
+            EquipmentSlot::Feet => &self.boots,
+        }
+    }
+
+    fn slot_mut(&mut self, slot: EquipmentSlot) -> &mut Option<ItemStack> {
+        match slot {
+            EquipmentSlot::MainHand => &mut self.main_hand,
+            EquipmentSlot::OffHand => &mut self.off_hand,
+            EquipmentSlot::Head => &mut self.helmet,
+            EquipmentSlot::Chest => &mut self.chestplate,
+            EquipmentSlot::Legs => &mut self.leggings,
+            EquipmentSlot::Feet => &mut self.boots,
+        }
+    }
+}
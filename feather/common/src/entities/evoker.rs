@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Evoker;
+use quill_common::{components::Health, entities::Evoker};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Evoker).add(EntityKind::Evoker);
+    builder
+        .add(Evoker)
+        .add(EntityKind::Evoker)
+        .add(Health::new(24.0));
 }
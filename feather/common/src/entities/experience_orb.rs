@@ -1,8 +1,109 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::ExperienceOrb;
+use base::{BlockKind, EntityKind, Position};
+use ecs::{Entity, EntityBuilder};
+use quill_common::{entities::ExperienceOrb, entity_init::EntityInit};
+use rand::Rng;
+
+use crate::Game;
+
+/// Experience values orbs are split into, largest first
+const ORB_SIZES: [u32; 11] = [2477, 1237, 617, 307, 149, 73, 37, 17, 7, 3, 1];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder.add(ExperienceOrb).add(EntityKind::ExperienceOrb);
 }
+
+/// The amount of experience an orb grants when collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExperienceValue(pub u32);
+
+/// Splits an experience amount into orb values, largest first.
+pub fn orb_values(mut amount: u32) -> Vec<u32> {
+    let mut values = Vec::new();
+    while amount > 0 {
+        let size = ORB_SIZES
+            .iter()
+            .copied()
+            .find(|size| *size <= amount)
+            .unwrap_or(1);
+        values.push(size);
+        amount -= size;
+    }
+    values
+}
+
+/// Rolls the experience dropped when mining a block.
+pub fn block_experience(kind: BlockKind, rng: &mut impl Rng) -> u32 {
+    match kind {
+        BlockKind::CoalOre => rng.gen_range(0..=2),
+        BlockKind::NetherGoldOre => rng.gen_range(0..=1),
+        BlockKind::DiamondOre | BlockKind::EmeraldOre => rng.gen_range(3..=7),
+        BlockKind::LapisOre | BlockKind::NetherQuartzOre => rng.gen_range(2..=5),
+        BlockKind::RedstoneOre => rng.gen_range(1..=5),
+        BlockKind::Spawner => rng.gen_range(15..=43),
+        _ => 0,
+    }
+}
+
+/// Rolls the experience dropped by a mob of `kind` when it dies.
+pub fn mob_experience(kind: EntityKind, rng: &mut impl Rng) -> u32 {
+    match kind {
+        EntityKind::Player
+        | EntityKind::Villager
+        | EntityKind::WanderingTrader
+        | EntityKind::IronGolem
+        | EntityKind::SnowGolem => 0,
+        EntityKind::ElderGuardian | EntityKind::Wither => 50,
+        EntityKind::Blaze | EntityKind::Guardian => 10,
+        kind if super::is_monster(kind) => 5,
+        _ => rng.gen_range(1..=3),
+    }
+}
+
+/// Spawns experience orbs at `position` granting `amount` experience in total.
+pub fn spawn_experience(game: &mut Game, position: Position, amount: u32) -> Vec<Entity> {
+    orb_values(amount)
+        .into_iter()
+        .map(|value| {
+            let mut builder = game.create_entity_builder(position, EntityInit::ExperienceOrb);
+            builder.add(ExperienceValue(value));
+            game.spawn_entity(builder)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+
+    use super::*;
+
+    #[test]
+    fn experience_splits_into_vanilla_orbs() {
+        let mut game = Game::new();
+        let orbs = spawn_experience(&mut game, position!(0.0, 64.0, 0.0), 25);
+
+        let values: Vec<u32> = orbs
+            .iter()
+            .map(|orb| game.ecs.get::<ExperienceValue>(*orb).unwrap().0)
+            .collect();
+        assert_eq!(values, vec![17, 7, 1]);
+        assert_eq!(values.iter().sum::<u32>(), 25);
+    }
+
+    #[test]
+    fn only_ores_drop_experience() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let xp = block_experience(BlockKind::DiamondOre, &mut rng);
+            assert!((3..=7).contains(&xp));
+        }
+        assert_eq!(block_experience(BlockKind::Stone, &mut rng), 0);
+    }
+
+    #[test]
+    fn no_orbs_for_zero_experience() {
+        assert!(orb_values(0).is_empty());
+        assert_eq!(orb_values(3000), vec![2477, 307, 149, 37, 17, 7, 3, 3]);
+    }
+}
@@ -1,8 +1,74 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::FallingBlock;
+use std::convert::TryInto;
+
+use base::{BlockId, BlockPosition, EntityKind, Position, ValidBlockPosition};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{
+    components::OnGround, entities::FallingBlock, entity_init::EntityInit,
+    events::EntityRemoveEvent,
+};
+
+use crate::Game;
+
+use super::item;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(land_falling_blocks);
+}
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(FallingBlock).add(EntityKind::FallingBlock);
+    builder
+        .add(FallingBlock)
+        .add(EntityKind::FallingBlock)
+        .add(OnGround(false));
+}
+
+/// Spawns `block` as a falling block at `pos`, from where
+/// it falls until it lands and is placed again.
+pub fn spawn_falling_block(game: &mut Game, pos: ValidBlockPosition, block: BlockId) -> Entity {
+    let position = Position {
+        x: f64::from(pos.x()) + 0.5,
+        y: f64::from(pos.y()),
+        z: f64::from(pos.z()) + 0.5,
+        ..Default::default()
+    };
+    let mut builder = game.create_entity_builder(position, EntityInit::FallingBlock);
+    builder.add(block);
+    game.spawn_entity(builder)
+}
+
+/// Places falling blocks that landed. Those landing in a block
+/// that isn't empty drop as an item instead.
+fn land_falling_blocks(game: &mut Game) -> SysResult {
+    let landed: Vec<(Entity, BlockId, Position)> = game
+        .ecs
+        .query::<(&FallingBlock, &BlockId, &Position, &OnGround)>()
+        .iter()
+        .filter(|(_, (_, _, _, on_ground))| on_ground.0)
+        .map(|(entity, (_, block, position, _))| (entity, *block, *position))
+        .filter(|(entity, _, _)| game.ecs.get::<EntityRemoveEvent>(*entity).is_err())
+        .collect();
+
+    for (entity, block, position) in landed {
+        game.remove_entity(entity)?;
+        // Allow for rounding errors in where physics stopped the block
+        let pos: Option<ValidBlockPosition> = BlockPosition::new(
+            position.x.floor() as i32,
+            (position.y + 1e-4).floor() as i32,
+            position.z.floor() as i32,
+        )
+        .try_into()
+        .ok();
+        match pos {
+            Some(pos) if game.block(pos).map_or(false, BlockId::is_air) => {
+                game.set_block(pos, block);
+            }
+            _ => {
+                if let Some(drop) = item::block_drop(block.kind()) {
+                    item::spawn_item(game, position, drop);
+                }
+            }
+        }
+    }
+    Ok(())
 }
@@ -1,8 +1,81 @@
 use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Fireball;
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{entities::Fireball, events::EntityRemoveEvent};
+
+use crate::{explosion, Game};
+
+/// Power of the explosion of a ghast's fireball
+pub const EXPLOSION_POWER: f32 = 1.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(explode_on_impact);
+}
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder.add(Fireball).add(EntityKind::Fireball);
 }
+
+/// Blows up the fireball where it hit, removing it. The blast
+/// only destroys blocks while the `mobGriefing` rule is on.
+pub fn explode(game: &mut Game, fireball: Entity) -> SysResult<Vec<Entity>> {
+    explosion::explode_entity(game, fireball, EXPLOSION_POWER)
+}
+
+/// Blows up fireballs that hit a block or anything but a ghast.
+fn explode_on_impact(game: &mut Game) -> SysResult {
+    let hit: Vec<Entity> = game
+        .ecs
+        .query::<&Fireball>()
+        .iter()
+        .map(|(fireball, _)| fireball)
+        .filter(|fireball| game.ecs.get::<EntityRemoveEvent>(*fireball).is_err())
+        .filter(|fireball| explosion::projectile_hit(game, *fireball, EntityKind::Ghast))
+        .collect();
+    for fireball in hit {
+        explode(game, fireball)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockPosition};
+    use libcraft_core::BlockFace;
+    use quill_common::components::Health;
+
+    use crate::events::ProjectileHitBlockEvent;
+
+    use super::*;
+
+    #[test]
+    fn fireballs_explode_when_they_hit_something() {
+        let mut game = Game::new();
+        let fireball = game
+            .ecs
+            .spawn((Fireball, EntityKind::Fireball, position!(0.0, 64.0, 0.0)));
+        let ghast = game.ecs.spawn((
+            EntityKind::Ghast,
+            position!(0.5, 64.0, 0.0),
+            Health::new(10.0),
+        ));
+        explode_on_impact(&mut game).unwrap();
+        assert!(game.ecs.get::<EntityRemoveEvent>(fireball).is_err());
+
+        game.ecs
+            .insert_entity_event(
+                fireball,
+                ProjectileHitBlockEvent {
+                    block: BlockPosition::new(0, 63, 0).try_into().unwrap(),
+                    face: BlockFace::Top,
+                    hit: position!(0.0, 64.0, 0.0),
+                },
+            )
+            .unwrap();
+        explode_on_impact(&mut game).unwrap();
+        assert!(game.ecs.get::<EntityRemoveEvent>(fireball).is_ok());
+        assert!(game.ecs.get::<Health>(ghast).unwrap().current < 10.0);
+    }
+}
@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Fox;
+use quill_common::{components::Health, entities::Fox};
+
+use super::breeding::Breedable;
+
+/// Foxes are bred with sweet berries
+pub const FOOD: &[Item] = &[Item::SweetBerries];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Fox).add(EntityKind::Fox);
+    builder
+        .add(Fox)
+        .add(EntityKind::Fox)
+        .add(Health::new(10.0))
+        .add(Breedable::new(FOOD));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Ghast;
+use quill_common::{components::Health, entities::Ghast};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Ghast).add(EntityKind::Ghast);
+    builder
+        .add(Ghast)
+        .add(EntityKind::Ghast)
+        .add(Health::new(10.0));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Giant;
+use quill_common::{components::Health, entities::Giant};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Giant).add(EntityKind::Giant);
+    builder
+        .add(Giant)
+        .add(EntityKind::Giant)
+        .add(Health::new(100.0));
 }
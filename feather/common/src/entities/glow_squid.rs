@@ -1,7 +1,7 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
 use quill_common::entities::{GlowSquid, GlowIntensity};
-use quill_common::components::DropsItem;
+use quill_common::components::{DropsItem, Health};
 use uuid::Uuid;
 
 pub fn build_default(builder: &mut EntityBuilder) {
@@ -10,6 +10,7 @@ pub fn build_default(builder: &mut EntityBuilder) {
     builder
         .add(GlowSquid)
         .add(EntityKind::GlowSquid)
+        .add(Health::new(10.0))
         .add(GlowIntensity { value: 1.0 })
         .add(DropsItem {
             item_type: "minecraft:glow_ink_sac",
@@ -1,9 +1,14 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
 use quill_common::entities::{Goat, GoatHorns, RammingCooldown};
-use quill_common::components::JumpStrength;
+use quill_common::components::{JumpStrength, Health};
 use uuid::Uuid;
 
+use super::breeding::Breedable;
+
+/// Goats are bred with wheat
+pub const FOOD: &[Item] = &[Item::Wheat];
+
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     
@@ -16,7 +21,9 @@ pub fn build_default(builder: &mut EntityBuilder) {
     builder
         .add(Goat { is_screaming })
         .add(EntityKind::Goat)
+        .add(Health::new(10.0))
         .add(GoatHorns { has_horns })
         .add(RammingCooldown { ticks: 0 })
-        .add(JumpStrength(0.8));
+        .add(JumpStrength(0.8))
+        .add(Breedable::new(FOOD));
 }
\ No newline at end of file
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Guardian;
+use quill_common::{components::Health, entities::Guardian};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Guardian).add(EntityKind::Guardian);
+    builder
+        .add(Guardian)
+        .add(EntityKind::Guardian)
+        .add(Health::new(30.0));
 }
@@ -1,8 +1,40 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Horse;
+use quill_common::{components::Health, entities::Horse};
+use rand::{Rng, RngCore};
+
+use super::breeding::Breedable;
+
+/// Number of horse coat colors
+pub const COAT_COLORS: u32 = 7;
+
+/// A horse's coat color, from 0 (white) to 6 (dark brown)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HorseVariant(pub u32);
+
+/// Golden food horses are bred with
+pub const FOOD: &[Item] = &[
+    Item::GoldenApple,
+    Item::EnchantedGoldenApple,
+    Item::GoldenCarrot,
+];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Horse).add(EntityKind::Horse);
+    builder
+        .add(Horse)
+        .add(EntityKind::Horse)
+        .add(Health::new(20.0))
+        .add(HorseVariant(roll_variant(&mut rand::thread_rng())))
+        .add(Breedable::new(FOOD));
+}
+
+/// Rolls a random coat color
+pub fn roll_variant(rng: &mut dyn RngCore) -> u32 {
+    rng.gen_range(0..COAT_COLORS)
+}
+
+/// Gives a horse being built the given coat color
+pub fn apply_variant(builder: &mut EntityBuilder, variant: u32) {
+    builder.add(HorseVariant(variant));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Husk;
+use quill_common::{components::Health, entities::Husk};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Husk).add(EntityKind::Husk);
+    builder
+        .add(Husk)
+        .add(EntityKind::Husk)
+        .add(Health::new(20.0));
 }
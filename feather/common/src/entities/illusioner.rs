@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Illusioner;
+use quill_common::{components::Health, entities::Illusioner};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Illusioner).add(EntityKind::Illusioner);
+    builder
+        .add(Illusioner)
+        .add(EntityKind::Illusioner)
+        .add(Health::new(32.0));
 }
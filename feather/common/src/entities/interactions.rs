@@ -1,7 +1,8 @@
-use base::{Position, EntityKind, Block, BlockPosition};
+use base::{Position, EntityKind, Block, BlockPosition, ParticleKind};
 use ecs::{Entity, IntoQuery, SysResult, SystemExecutor};
 use quill_common::entities::{Axolotl, PlayDead, Goat, RammingCooldown, GlowSquid, GlowIntensity};
 use quill_common::components::{Health, OnGround, WaterBreathing, Velocity};
+use crate::combat::{self, DamageType};
 use crate::Game;
 
 pub fn register(systems: &mut SystemExecutor<Game>) {
@@ -13,6 +14,7 @@ pub fn register(systems: &mut SystemExecutor<Game>) {
 
 /// Handles axolotl interactions with water and land
 fn update_axolotl_water_interactions(game: &mut Game) -> SysResult {
+    let mut drying = Vec::new();
     for (entity, (axolotl, position, velocity, water_breathing)) in game
         .ecs
         .query::<(&Axolotl, &Position, &mut Velocity, &mut WaterBreathing)>()
@@ -43,9 +45,7 @@ fn update_axolotl_water_interactions(game: &mut Game) -> SysResult {
             } else {
                 // Apply damage when out of water too long
                 if game.tick_count % 20 == 0 { // Once per second
-                    if let Ok(mut health) = game.ecs.get_mut::<Health>(entity) {
-                        health.current -= 1.0;
-                    }
+                    drying.push(entity);
                 }
             }
         }
@@ -59,6 +59,10 @@ fn update_axolotl_water_interactions(game: &mut Game) -> SysResult {
             }
         }
     }
+
+    for entity in drying {
+        combat::apply_damage(game, entity, None, 1.0, DamageType::Drowning)?;
+    }
     
     Ok(())
 }
@@ -120,6 +124,8 @@ fn update_goat_terrain_interactions(game: &mut Game) -> SysResult {
 
 /// Handles glow squid interactions with water and light
 fn update_glow_squid_water_interactions(game: &mut Game) -> SysResult {
+    let mut stranded = Vec::new();
+    let mut inked = Vec::new();
     for (entity, (glow_squid, position, velocity, glow_intensity)) in game
         .ecs
         .query::<(&GlowSquid, &Position, &mut Velocity, &mut GlowIntensity)>()
@@ -131,9 +137,7 @@ fn update_glow_squid_water_interactions(game: &mut Game) -> SysResult {
         if !in_water {
             // Glow squids can only survive in water
             if game.tick_count % 10 == 0 { // Every half second
-                if let Ok(mut health) = game.ecs.get_mut::<Health>(entity) {
-                    health.current -= 2.0;
-                }
+                stranded.push(entity);
             }
             
             // Reduced movement on land
@@ -161,10 +165,17 @@ fn update_glow_squid_water_interactions(game: &mut Game) -> SysResult {
         // Ink particles when attacked
         if let Ok(health) = game.ecs.get::<Health>(entity) {
             if health.current < health.max * 0.7 && game.tick_count % 40 == 0 {
-                spawn_glow_ink_particles(game, *position);
+                inked.push(*position);
             }
         }
     }
+
+    for entity in stranded {
+        combat::apply_damage(game, entity, None, 2.0, DamageType::Drowning)?;
+    }
+    for position in inked {
+        spawn_glow_ink_particles(game, position);
+    }
     
     Ok(())
 }
@@ -227,7 +238,7 @@ fn get_light_level(game: &Game, block_pos: BlockPosition) -> u8 {
     0
 }
 
-fn spawn_glow_ink_particles(game: &Game, position: Position) {
-    // Spawn glow ink particle effects
-    // Simplified version for demonstration
+fn spawn_glow_ink_particles(game: &mut Game, position: Position) {
+    game.emit_particle(ParticleKind::SquidInk, position, 30, 0.4);
+    game.emit_sound("entity.glow_squid.squirt", position, 1.0, 1.0);
 }
\ No newline at end of file
@@ -1,8 +1,103 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Item;
+use base::{BlockKind, EntityKind, Item as ItemKind, ItemStack, Position};
+use ecs::{Entity, EntityBuilder};
+use quill_common::{entities::Item, entity_init::EntityInit};
+
+use crate::Game;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder.add(Item).add(EntityKind::Item);
 }
+
+/// Spawns a dropped item entity holding `stack`.
+pub fn spawn_item(game: &mut Game, position: Position, stack: ItemStack) -> Entity {
+    let mut builder = game.create_entity_builder(position, EntityInit::Item);
+    builder.add(stack);
+    game.spawn_entity(builder)
+}
+
+/// The item dropped when a block is broken, if any.
+pub fn block_drop(kind: BlockKind) -> Option<ItemStack> {
+    let item = match kind {
+        BlockKind::Air | BlockKind::CaveAir | BlockKind::VoidAir => return None,
+        // Blocks placed from an item of a different name
+        BlockKind::RedstoneWire => ItemKind::Redstone,
+        BlockKind::Tripwire => ItemKind::String,
+        BlockKind::Wheat => ItemKind::WheatSeeds,
+        BlockKind::Carrots => ItemKind::Carrot,
+        BlockKind::Potatoes => ItemKind::Potato,
+        BlockKind::Beetroots => ItemKind::BeetrootSeeds,
+        // Wall-mounted variants drop their standing item
+        BlockKind::WallTorch => ItemKind::Torch,
+        BlockKind::SoulWallTorch => ItemKind::SoulTorch,
+        BlockKind::RedstoneWallTorch => ItemKind::RedstoneTorch,
+        BlockKind::OakWallSign => ItemKind::OakSign,
+        BlockKind::SpruceWallSign => ItemKind::SpruceSign,
+        BlockKind::BirchWallSign => ItemKind::BirchSign,
+        BlockKind::JungleWallSign => ItemKind::JungleSign,
+        BlockKind::AcaciaWallSign => ItemKind::AcaciaSign,
+        BlockKind::DarkOakWallSign => ItemKind::DarkOakSign,
+        BlockKind::CrimsonWallSign => ItemKind::CrimsonSign,
+        BlockKind::WarpedWallSign => ItemKind::WarpedSign,
+        BlockKind::WhiteWallBanner => ItemKind::WhiteBanner,
+        BlockKind::OrangeWallBanner => ItemKind::OrangeBanner,
+        BlockKind::MagentaWallBanner => ItemKind::MagentaBanner,
+        BlockKind::LightBlueWallBanner => ItemKind::LightBlueBanner,
+        BlockKind::YellowWallBanner => ItemKind::YellowBanner,
+        BlockKind::LimeWallBanner => ItemKind::LimeBanner,
+        BlockKind::PinkWallBanner => ItemKind::PinkBanner,
+        BlockKind::GrayWallBanner => ItemKind::GrayBanner,
+        BlockKind::LightGrayWallBanner => ItemKind::LightGrayBanner,
+        BlockKind::CyanWallBanner => ItemKind::CyanBanner,
+        BlockKind::PurpleWallBanner => ItemKind::PurpleBanner,
+        BlockKind::BlueWallBanner => ItemKind::BlueBanner,
+        BlockKind::BrownWallBanner => ItemKind::BrownBanner,
+        BlockKind::GreenWallBanner => ItemKind::GreenBanner,
+        BlockKind::RedWallBanner => ItemKind::RedBanner,
+        BlockKind::BlackWallBanner => ItemKind::BlackBanner,
+        BlockKind::SkeletonWallSkull => ItemKind::SkeletonSkull,
+        BlockKind::WitherSkeletonWallSkull => ItemKind::WitherSkeletonSkull,
+        BlockKind::ZombieWallHead => ItemKind::ZombieHead,
+        BlockKind::PlayerWallHead => ItemKind::PlayerHead,
+        BlockKind::CreeperWallHead => ItemKind::CreeperHead,
+        BlockKind::DragonWallHead => ItemKind::DragonHead,
+        BlockKind::TubeCoralWallFan => ItemKind::TubeCoralFan,
+        BlockKind::BrainCoralWallFan => ItemKind::BrainCoralFan,
+        BlockKind::BubbleCoralWallFan => ItemKind::BubbleCoralFan,
+        BlockKind::FireCoralWallFan => ItemKind::FireCoralFan,
+        BlockKind::HornCoralWallFan => ItemKind::HornCoralFan,
+        BlockKind::DeadTubeCoralWallFan => ItemKind::DeadTubeCoralFan,
+        BlockKind::DeadBrainCoralWallFan => ItemKind::DeadBrainCoralFan,
+        BlockKind::DeadBubbleCoralWallFan => ItemKind::DeadBubbleCoralFan,
+        BlockKind::DeadFireCoralWallFan => ItemKind::DeadFireCoralFan,
+        BlockKind::DeadHornCoralWallFan => ItemKind::DeadHornCoralFan,
+        _ => ItemKind::from_name(kind.name())?,
+    };
+    ItemStack::new(item, 1).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dropped(kind: BlockKind) -> Option<ItemKind> {
+        block_drop(kind).map(|stack| stack.item())
+    }
+
+    #[test]
+    fn blocks_drop_the_item_they_are_placed_from() {
+        assert_eq!(dropped(BlockKind::Stone), Some(ItemKind::Stone));
+        assert_eq!(dropped(BlockKind::WallTorch), Some(ItemKind::Torch));
+        assert_eq!(dropped(BlockKind::OakWallSign), Some(ItemKind::OakSign));
+        assert_eq!(dropped(BlockKind::RedstoneWire), Some(ItemKind::Redstone));
+        assert_eq!(dropped(BlockKind::Air), None);
+    }
+
+    #[test]
+    fn walls_drop_themselves() {
+        assert_eq!(
+            dropped(BlockKind::CobblestoneWall),
+            Some(ItemKind::CobblestoneWall)
+        );
+    }
+}
@@ -1,8 +1,268 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::ItemFrame;
+use std::mem;
+
+use base::{Direction, EntityKind, Inventory, Item, ItemStack, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use libcraft_blocks::BlockView;
+use libcraft_items::InventorySlot;
+use quill_common::{entities::ItemFrame, entity_init::EntityInit, events::BlockPlacementEvent};
+
+use super::painting::{facing_offset, wall_facing};
+use crate::{
+    entities::player::{held_item, HotbarSlot},
+    entity_interaction::EntityInteractions,
+    Game,
+};
+
+/// Number of 45 degree steps in a full turn of a framed item
+pub const ROTATIONS: u8 = 8;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(ItemFrame).add(EntityKind::ItemFrame);
+    builder
+        .add(ItemFrame)
+        .add(EntityKind::ItemFrame)
+        .add(FramedItem::default());
+}
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<EntityInteractions>()
+        .expect("Failed to get the entity interactions")
+        .register(EntityKind::ItemFrame, None, use_frame);
+    systems.add_system(hang_item_frames);
+}
+
+/// The direction a placed item frame faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameFacing(pub Direction);
+
+/// The item displayed in an item frame and its rotation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FramedItem {
+    item: Option<ItemStack>,
+    /// Rotation in 45 degree steps, in `0..ROTATIONS`
+    rotation: u8,
+}
+
+impl FramedItem {
+    pub fn item(&self) -> Option<&ItemStack> {
+        self.item.as_ref()
+    }
+
+    pub fn rotation(&self) -> u8 {
+        self.rotation
+    }
+
+    /// Puts a single item from `stack` into the frame, resetting its rotation.
+    ///
+    /// Returns the rest of the stack, or gives the stack back untouched
+    /// if the frame is already occupied.
+    pub fn insert(&mut self, stack: ItemStack) -> Option<ItemStack> {
+        if self.item.is_some() {
+            return Some(stack);
+        }
+
+        self.item = Some(stack.get_item());
+        self.rotation = 0;
+        let mut rest = stack;
+        match rest.remove(1) {
+            Ok(_) => Some(rest),
+            Err(_) => None,
+        }
+    }
+
+    /// Turns the framed item clockwise by 45 degrees.
+    ///
+    /// Returns whether there was an item to rotate.
+    pub fn rotate(&mut self) -> bool {
+        if self.item.is_none() {
+            return false;
+        }
+        self.rotation = (self.rotation + 1) % ROTATIONS;
+        true
+    }
+
+    /// Removes the item from the frame.
+    pub fn take(&mut self) -> Option<ItemStack> {
+        self.rotation = 0;
+        self.item.take()
+    }
+
+    /// The signal read by a comparator behind the frame: 0 when empty,
+    /// otherwise the rotation index plus one.
+    pub fn comparator_output(&self) -> u8 {
+        match self.item {
+            Some(_) => self.rotation + 1,
+            None => 0,
+        }
+    }
+}
+
+/// Handles a player using `held` on an item frame: an empty frame takes
+/// one item from the held stack, while an occupied frame is rotated.
+pub fn interact(game: &mut Game, frame: Entity, held: &mut Option<ItemStack>) -> SysResult {
+    let mut framed = game.ecs.get_mut::<FramedItem>(frame)?;
+    if framed.item().is_some() {
+        framed.rotate();
+    } else if let Some(stack) = held.take() {
+        *held = framed.insert(stack);
+    }
+    Ok(())
+}
+
+/// Interaction handler putting the `held` item into a frame, or rotating it.
+fn use_frame(
+    game: &mut Game,
+    _player: Entity,
+    frame: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let mut stack = mem::replace(held, InventorySlot::Empty).into_option();
+    interact(game, frame, &mut stack)?;
+    *held = stack.map_or(InventorySlot::Empty, InventorySlot::Filled);
+    Ok(true)
+}
+
+/// Whether an item frame can hang on the `facing` side of the block at `wall`.
+fn can_hang(view: &dyn BlockView, wall: (i32, i32, i32), facing: Direction) -> bool {
+    let (fx, fz) = facing_offset(facing);
+    let (x, y, z) = wall;
+    let backing = view.kind_at(wall).map_or(false, |kind| kind.solid());
+    let space = view
+        .kind_at((x + fx, y, z + fz))
+        .map_or(false, |kind| !kind.solid());
+    backing && space
+}
+
+/// Hangs an empty item frame on the `facing` side of the block at `wall`.
+///
+/// Returns `None` if the block can't hold the frame.
+pub fn place_item_frame(
+    game: &mut Game,
+    wall: (i32, i32, i32),
+    facing: Direction,
+) -> Option<Entity> {
+    if !can_hang(&*game, wall, facing) {
+        return None;
+    }
+    let (fx, fz) = facing_offset(facing);
+    let position = Position {
+        x: f64::from(wall.0 + fx) + 0.5,
+        y: f64::from(wall.1) + 0.5,
+        z: f64::from(wall.2 + fz) + 0.5,
+        pitch: 0.0,
+        yaw: 0.0,
+    };
+
+    let mut builder = game.create_entity_builder(position, EntityInit::ItemFrame);
+    builder.add(FrameFacing(facing));
+    Some(game.spawn_entity(builder))
+}
+
+/// Hangs the item frames players use on the side of a block.
+fn hang_item_frames(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockPlacementEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockPlacementEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let mut held = held_item(&inventory, hotbar_slot, event.hand);
+        if held.item_kind() != Some(Item::ItemFrame) {
+            continue;
+        }
+        let facing = match wall_facing(event.face) {
+            Some(facing) => facing,
+            None => continue,
+        };
+        let wall = (event.location.x, event.location.y, event.location.z);
+        if place_item_frame(game, wall, facing).is_some() {
+            let _ = held.try_take(1);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockId, BlockPosition, Chunk, ChunkPosition};
+
+    use super::*;
+
+    #[test]
+    fn rotating_framed_item_changes_comparator_output() {
+        let mut game = Game::new();
+        let frame = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), FramedItem::default()));
+        assert_eq!(
+            game.ecs
+                .get::<FramedItem>(frame)
+                .unwrap()
+                .comparator_output(),
+            0
+        );
+
+        let mut held = Some(ItemStack::new(Item::Diamond, 3).unwrap());
+        interact(&mut game, frame, &mut held).unwrap();
+        assert_eq!(held, Some(ItemStack::new(Item::Diamond, 2).unwrap()));
+        {
+            let framed = game.ecs.get::<FramedItem>(frame).unwrap();
+            assert_eq!(
+                framed.item(),
+                Some(&ItemStack::new(Item::Diamond, 1).unwrap())
+            );
+            assert_eq!(framed.rotation(), 0);
+            assert_eq!(framed.comparator_output(), 1);
+        }
+
+        for expected in 1..=10u8 {
+            interact(&mut game, frame, &mut held).unwrap();
+            let framed = game.ecs.get::<FramedItem>(frame).unwrap();
+            assert_eq!(framed.rotation(), expected % ROTATIONS);
+            assert_eq!(framed.comparator_output(), framed.rotation() + 1);
+        }
+        assert_eq!(held, Some(ItemStack::new(Item::Diamond, 2).unwrap()));
+    }
+
+    #[test]
+    fn last_item_is_consumed() {
+        let mut framed = FramedItem::default();
+        assert_eq!(
+            framed.insert(ItemStack::new(Item::Compass, 1).unwrap()),
+            None
+        );
+        assert!(framed.rotate());
+        assert_eq!(
+            framed.take(),
+            Some(ItemStack::new(Item::Compass, 1).unwrap())
+        );
+        assert_eq!(framed.comparator_output(), 0);
+        assert!(!framed.rotate());
+    }
+
+    #[test]
+    fn frames_hang_on_solid_walls_only() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let wall = BlockPosition::new(1, 64, 1).try_into().unwrap();
+        game.set_block(wall, BlockId::stone());
+
+        assert!(place_item_frame(&mut game, (2, 64, 1), Direction::South).is_none());
+        let frame = place_item_frame(&mut game, (1, 64, 1), Direction::South).unwrap();
+        assert_eq!(
+            *game.ecs.get::<FrameFacing>(frame).unwrap(),
+            FrameFacing(Direction::South)
+        );
+        let pos = *game.ecs.get::<Position>(frame).unwrap();
+        assert_eq!((pos.x, pos.y, pos.z), (1.5, 64.5, 2.5));
+    }
 }
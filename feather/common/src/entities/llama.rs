@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Llama;
+use quill_common::{components::Health, entities::Llama};
+
+use super::breeding::Breedable;
+
+/// Llamas are bred with hay bales
+pub const FOOD: &[Item] = &[Item::HayBlock];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Llama).add(EntityKind::Llama);
+    builder
+        .add(Llama)
+        .add(EntityKind::Llama)
+        .add(Health::new(22.0))
+        .add(Breedable::new(FOOD));
 }
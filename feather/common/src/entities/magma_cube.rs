@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::MagmaCube;
+use quill_common::{components::Health, entities::MagmaCube};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(MagmaCube).add(EntityKind::MagmaCube);
+    builder
+        .add(MagmaCube)
+        .add(EntityKind::MagmaCube)
+        .add(Health::new(4.0));
 }
@@ -1,8 +1,191 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Mooshroom;
+use base::{EntityKind, Item, ItemStack, ParticleKind, Position};
+use ecs::{Entity, EntityBuilder, SysResult};
+use libcraft_items::InventorySlot;
+use quill_common::{components::Health, entities::Mooshroom};
+
+use crate::{
+    entity_interaction::{self, EntityInteractions},
+    status_effects::{StatusEffect, StatusEffectKind},
+    Game,
+};
+
+use super::{aging::Baby, breeding::Breedable};
+
+/// Like cows, mooshrooms are bred with wheat
+pub const FOOD: &[Item] = &[Item::Wheat];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Mooshroom).add(EntityKind::Mooshroom);
+    builder
+        .add(Mooshroom)
+        .add(EntityKind::Mooshroom)
+        .add(Health::new(10.0))
+        .add(MooshroomVariant::default())
+        .add(Breedable::new(FOOD));
+}
+
+pub fn register(game: &mut Game) {
+    game.resources
+        .get_mut::<EntityInteractions>()
+        .expect("Failed to get the entity interactions")
+        .register(EntityKind::Mooshroom, Some(Item::Bowl), milk_stew)
+        .register(EntityKind::Mooshroom, None, feed_flower);
+}
+
+/// The kind of mushrooms growing on a mooshroom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MooshroomVariant {
+    Red,
+    Brown,
+}
+
+impl Default for MooshroomVariant {
+    fn default() -> Self {
+        MooshroomVariant::Red
+    }
+}
+
+impl MooshroomVariant {
+    /// The mushroom growing on mooshrooms of this variant
+    pub fn mushroom(self) -> Item {
+        match self {
+            MooshroomVariant::Red => Item::RedMushroom,
+            MooshroomVariant::Brown => Item::BrownMushroom,
+        }
+    }
+}
+
+/// The flower a brown mooshroom was last fed, which
+/// flavors the next stew milked from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FedFlower(pub Item);
+
+/// The effect of suspicious stew made with `flower`.
+pub fn stew_effect(flower: Item) -> Option<StatusEffect> {
+    let (kind, duration) = match flower {
+        Item::Allium => (StatusEffectKind::FireResistance, 80),
+        Item::AzureBluet => (StatusEffectKind::Blindness, 160),
+        Item::BlueOrchid | Item::Dandelion => (StatusEffectKind::Saturation, 7),
+        Item::Cornflower => (StatusEffectKind::JumpBoost, 120),
+        Item::LilyOfTheValley => (StatusEffectKind::Poison, 240),
+        Item::OxeyeDaisy => (StatusEffectKind::Regeneration, 160),
+        Item::Poppy => (StatusEffectKind::NightVision, 100),
+        Item::RedTulip | Item::OrangeTulip | Item::WhiteTulip | Item::PinkTulip => {
+            (StatusEffectKind::Weakness, 180)
+        }
+        Item::WitherRose => (StatusEffectKind::Wither, 160),
+        _ => return None,
+    };
+    Some(StatusEffect::new(kind, 0, duration))
+}
+
+/// Fills the `held` bowl with stew from an adult `mooshroom`. A brown
+/// mooshroom fed a flower gives suspicious stew with the flower's effect.
+///
+/// Returns whether the bowl was filled.
+pub fn milk_stew(
+    game: &mut Game,
+    player: Entity,
+    mooshroom: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    if game.ecs.get::<Baby>(mooshroom).is_ok() {
+        return Ok(false);
+    }
+
+    let stew = match game.ecs.remove::<FedFlower>(mooshroom) {
+        Ok(FedFlower(flower)) => {
+            let mut stew = ItemStack::new(Item::SuspiciousStew, 1)?;
+            stew.set_stew_effect(
+                stew_effect(flower).map(|effect| (effect.kind.id(), effect.duration)),
+            );
+            stew
+        }
+        Err(_) => ItemStack::new(Item::MushroomStew, 1)?,
+    };
+    entity_interaction::exchange(game, player, held, stew)?;
+
+    let position = *game.ecs.get::<Position>(mooshroom)?;
+    game.emit_sound("entity.mooshroom.milk", position, 1.0, 1.0);
+    Ok(true)
+}
+
+/// Feeds a brown `mooshroom` the `held` flower,
+/// unless it has already been fed one.
+///
+/// Returns whether the flower was eaten.
+pub fn feed_flower(
+    game: &mut Game,
+    _player: Entity,
+    mooshroom: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let flower = match held.item_kind().filter(|item| stew_effect(*item).is_some()) {
+        Some(flower) => flower,
+        None => return Ok(false),
+    };
+    let brown = game
+        .ecs
+        .get::<MooshroomVariant>(mooshroom)
+        .map_or(false, |variant| *variant == MooshroomVariant::Brown);
+    if !brown || game.ecs.get::<FedFlower>(mooshroom).is_ok() {
+        return Ok(false);
+    }
+
+    game.ecs.insert(mooshroom, FedFlower(flower))?;
+    let _ = held.try_take(1);
+
+    let position = *game.ecs.get::<Position>(mooshroom)?;
+    game.emit_particle(ParticleKind::Effect, position, 4, 0.5);
+    game.emit_sound("entity.mooshroom.eat", position, 2.0, 1.0);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+
+    use super::*;
+
+    fn spawn_mooshroom(game: &mut Game, variant: MooshroomVariant) -> Entity {
+        game.ecs.spawn((
+            Mooshroom,
+            EntityKind::Mooshroom,
+            variant,
+            position!(1.5, 64.0, 0.5),
+        ))
+    }
+
+    #[test]
+    fn bowls_are_filled_with_mushroom_stew() {
+        let mut game = Game::new();
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let mooshroom = spawn_mooshroom(&mut game, MooshroomVariant::Red);
+
+        let mut held = InventorySlot::new(Item::Bowl, 1);
+        assert!(milk_stew(&mut game, player, mooshroom, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::MushroomStew, 1));
+    }
+
+    #[test]
+    fn fed_brown_mooshrooms_give_suspicious_stew() {
+        let mut game = Game::new();
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let mooshroom = spawn_mooshroom(&mut game, MooshroomVariant::Brown);
+
+        let mut held = InventorySlot::new(Item::Cornflower, 1);
+        assert!(feed_flower(&mut game, player, mooshroom, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::Empty);
+
+        let mut held = InventorySlot::new(Item::Bowl, 1);
+        assert!(milk_stew(&mut game, player, mooshroom, &mut held).unwrap());
+        let mut stew = ItemStack::new(Item::SuspiciousStew, 1).unwrap();
+        stew.set_stew_effect(Some((StatusEffectKind::JumpBoost.id(), 120)));
+        assert_eq!(held, InventorySlot::Filled(stew));
+
+        // The flower's effect is used up
+        let mut held = InventorySlot::new(Item::Bowl, 1);
+        assert!(milk_stew(&mut game, player, mooshroom, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::MushroomStew, 1));
+    }
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Mule;
+use quill_common::{components::Health, entities::Mule};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Mule).add(EntityKind::Mule);
+    builder
+        .add(Mule)
+        .add(EntityKind::Mule)
+        .add(Health::new(20.0));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Ocelot;
+use quill_common::{components::Health, entities::Ocelot};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Ocelot).add(EntityKind::Ocelot);
+    builder
+        .add(Ocelot)
+        .add(EntityKind::Ocelot)
+        .add(Health::new(10.0));
 }
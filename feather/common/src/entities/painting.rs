@@ -1,8 +1,286 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Painting;
+use base::{Direction, EntityKind, Inventory, Item, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use libcraft_blocks::BlockView;
+use libcraft_core::BlockFace;
+use quill_common::{entities::Painting, entity_init::EntityInit, events::BlockPlacementEvent};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{
+    entities::player::{held_item, HotbarSlot},
+    Game,
+};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder.add(Painting).add(EntityKind::Painting);
 }
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(hang_paintings);
+}
+
+/// The art shown by a painting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Motive {
+    Kebab,
+    Aztec,
+    Alban,
+    Aztec2,
+    Bomb,
+    Plant,
+    Wasteland,
+    Pool,
+    Courbet,
+    Sea,
+    Sunset,
+    Creebet,
+    Wanderer,
+    Graham,
+    Match,
+    Bust,
+    Stage,
+    Void,
+    SkullAndRoses,
+    Wither,
+    Fighters,
+    Pointer,
+    Pigscene,
+    BurningSkull,
+    Skeleton,
+    DonkeyKong,
+}
+
+impl Motive {
+    pub const ALL: [Motive; 26] = [
+        Motive::Kebab,
+        Motive::Aztec,
+        Motive::Alban,
+        Motive::Aztec2,
+        Motive::Bomb,
+        Motive::Plant,
+        Motive::Wasteland,
+        Motive::Pool,
+        Motive::Courbet,
+        Motive::Sea,
+        Motive::Sunset,
+        Motive::Creebet,
+        Motive::Wanderer,
+        Motive::Graham,
+        Motive::Match,
+        Motive::Bust,
+        Motive::Stage,
+        Motive::Void,
+        Motive::SkullAndRoses,
+        Motive::Wither,
+        Motive::Fighters,
+        Motive::Pointer,
+        Motive::Pigscene,
+        Motive::BurningSkull,
+        Motive::Skeleton,
+        Motive::DonkeyKong,
+    ];
+
+    /// Width and height of the art, in blocks.
+    pub fn size(self) -> (i32, i32) {
+        match self {
+            Motive::Kebab
+            | Motive::Aztec
+            | Motive::Alban
+            | Motive::Aztec2
+            | Motive::Bomb
+            | Motive::Plant
+            | Motive::Wasteland => (1, 1),
+            Motive::Pool | Motive::Courbet | Motive::Sea | Motive::Sunset | Motive::Creebet => {
+                (2, 1)
+            }
+            Motive::Wanderer | Motive::Graham => (1, 2),
+            Motive::Match
+            | Motive::Bust
+            | Motive::Stage
+            | Motive::Void
+            | Motive::SkullAndRoses
+            | Motive::Wither => (2, 2),
+            Motive::Fighters => (4, 2),
+            Motive::Pointer | Motive::Pigscene | Motive::BurningSkull => (4, 4),
+            Motive::Skeleton | Motive::DonkeyKong => (4, 3),
+        }
+    }
+
+    /// Number of blocks covered by the art.
+    pub fn area(self) -> i32 {
+        let (width, height) = self.size();
+        width * height
+    }
+}
+
+/// The art of a placed painting and the direction it faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaintingArt {
+    pub motive: Motive,
+    pub facing: Direction,
+}
+
+/// The direction faced by something hung on the `face` side of a wall,
+/// or `None` for the top and bottom of a block.
+pub fn wall_facing(face: BlockFace) -> Option<Direction> {
+    match face {
+        BlockFace::North => Some(Direction::North),
+        BlockFace::South => Some(Direction::South),
+        BlockFace::East => Some(Direction::East),
+        BlockFace::West => Some(Direction::West),
+        BlockFace::Top | BlockFace::Bottom => None,
+    }
+}
+
+/// Offset of the block in front of a wall, for a painting facing `facing`.
+pub(crate) fn facing_offset(facing: Direction) -> (i32, i32) {
+    match facing {
+        Direction::North => (0, -1),
+        Direction::South => (0, 1),
+        Direction::East => (1, 0),
+        Direction::West => (-1, 0),
+    }
+}
+
+/// The wall blocks covered by `motive` when centered on `wall`.
+fn covered_blocks(
+    wall: (i32, i32, i32),
+    facing: Direction,
+    motive: Motive,
+) -> impl Iterator<Item = (i32, i32, i32)> {
+    let (width, height) = motive.size();
+    let (fx, fz) = facing_offset(facing);
+    // The horizontal axis of the wall
+    let (ax, az) = (fz.abs(), fx.abs());
+
+    (0..width).flat_map(move |w| {
+        (0..height).map(move |h| {
+            let along = w - (width - 1) / 2;
+            let up = h - (height - 1) / 2;
+            (wall.0 + ax * along, wall.1 + up, wall.2 + az * along)
+        })
+    })
+}
+
+/// Returns whether `motive` fits on the wall: every block behind it
+/// must be solid, and every block in front of it free.
+pub fn fits(
+    view: &dyn BlockView,
+    wall: (i32, i32, i32),
+    facing: Direction,
+    motive: Motive,
+) -> bool {
+    let (fx, fz) = facing_offset(facing);
+    covered_blocks(wall, facing, motive).all(|(x, y, z)| {
+        let backing = view.kind_at((x, y, z)).map_or(false, |kind| kind.solid());
+        let space = view
+            .kind_at((x + fx, y, z + fz))
+            .map_or(false, |kind| !kind.solid());
+        backing && space
+    })
+}
+
+/// Picks a random motive among the largest that fit on the wall.
+pub fn choose_motive(
+    rng: &mut impl Rng,
+    view: &dyn BlockView,
+    wall: (i32, i32, i32),
+    facing: Direction,
+) -> Option<Motive> {
+    let fitting: Vec<Motive> = Motive::ALL
+        .iter()
+        .copied()
+        .filter(|motive| fits(view, wall, facing, *motive))
+        .collect();
+    let largest = fitting.iter().map(|motive| motive.area()).max()?;
+    let candidates: Vec<Motive> = fitting
+        .into_iter()
+        .filter(|motive| motive.area() == largest)
+        .collect();
+    candidates.choose(rng).copied()
+}
+
+/// Hangs a painting on the `facing` side of the wall block at `wall`,
+/// choosing the largest art that fits the available space.
+///
+/// Returns `None` if not even the smallest art fits.
+pub fn place_painting(game: &mut Game, wall: (i32, i32, i32), facing: Direction) -> Option<Entity> {
+    let motive = game.with_rng(|game, rng| choose_motive(rng, &*game, wall, facing))?;
+    let (fx, fz) = facing_offset(facing);
+    let position = Position {
+        x: f64::from(wall.0 + fx) + 0.5,
+        y: f64::from(wall.1),
+        z: f64::from(wall.2 + fz) + 0.5,
+        pitch: 0.0,
+        yaw: 0.0,
+    };
+
+    let mut builder = game.create_entity_builder(position, EntityInit::Painting);
+    builder.add(PaintingArt { motive, facing });
+    Some(game.spawn_entity(builder))
+}
+
+/// Hangs the paintings players use on the side of a block.
+fn hang_paintings(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockPlacementEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockPlacementEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let mut held = held_item(&inventory, hotbar_slot, event.hand);
+        if held.item_kind() != Some(Item::Painting) {
+            continue;
+        }
+        let facing = match wall_facing(event.face) {
+            Some(facing) => facing,
+            None => continue,
+        };
+        let wall = (event.location.x, event.location.y, event.location.z);
+        if place_painting(game, wall, facing).is_some() {
+            let _ = held.try_take(1);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::BlockKind;
+    use libcraft_blocks::BlockProperties;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    /// A stone wall spanning x in 0..2 and y in 64..65 at z = 0, with air elsewhere
+    fn wall(pos: (i32, i32, i32)) -> Option<(BlockKind, BlockProperties)> {
+        let (x, y, z) = pos;
+        let kind = if z == 0 && (0..2).contains(&x) && y == 64 {
+            BlockKind::Stone
+        } else {
+            BlockKind::Air
+        };
+        Some((kind, kind.default_properties()))
+    }
+
+    #[test]
+    fn largest_fitting_art_is_chosen() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let motive = choose_motive(&mut rng, &wall, (0, 64, 0), Direction::South).unwrap();
+            assert_eq!(motive.size(), (2, 1));
+        }
+    }
+
+    #[test]
+    fn nothing_fits_on_air() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(choose_motive(&mut rng, &wall, (5, 64, 5), Direction::South).is_none());
+    }
+}
@@ -1,12 +1,13 @@
 use base::{Position, BlockPosition, EntityKind};
-use ecs::{Entity, IntoQuery, SysResult, SystemExecutor};
+use ecs::{Entity, IntoQuery, SysResult, SystemExecutor, SystemStage};
 use quill_common::entities::{Axolotl, Goat, GlowSquid};
 use quill_common::components::{OnGround, Velocity, Target, Path, PathNode, NavigationGoal};
 use std::collections::{BinaryHeap, HashSet};
 use std::cmp::Ordering;
 use std::f32::consts::PI;
+use libcraft_blocks::BlockView;
 
-use crate::Game;
+use crate::{physics::STEP_HEIGHT, Game};
 
 /// A node in the A* pathfinding algorithm
 #[derive(Clone, Eq, PartialEq)]
@@ -32,10 +33,10 @@ impl PartialOrd for AStarNode {
 
 pub fn register(systems: &mut SystemExecutor<Game>) {
     systems
-        .add_system(update_axolotl_pathfinding)
-        .add_system(update_goat_pathfinding)
-        .add_system(update_glow_squid_pathfinding)
-        .add_system(execute_paths);
+        .add_system_to_stage(SystemStage::Ai, update_axolotl_pathfinding)
+        .add_system_to_stage(SystemStage::Ai, update_goat_pathfinding)
+        .add_system_to_stage(SystemStage::Ai, update_glow_squid_pathfinding)
+        .add_system_to_stage(SystemStage::Movement, execute_paths);
 }
 
 /// Updates pathfinding for axolotls
@@ -162,7 +163,7 @@ fn execute_paths(game: &mut Game) -> SysResult {
 }
 
 /// Finds a path using A* algorithm
-fn find_path(game: &Game, start: BlockPosition, target: BlockPosition, max_iterations: usize) -> Option<Vec<PathNode>> {
+fn find_path(view: &dyn BlockView, start: BlockPosition, target: BlockPosition, max_iterations: usize) -> Option<Vec<PathNode>> {
     let mut open_set = BinaryHeap::new();
     let mut closed_set = HashSet::new();
     let mut g_scores = std::collections::HashMap::new();
@@ -196,7 +197,7 @@ fn find_path(game: &Game, start: BlockPosition, target: BlockPosition, max_itera
         closed_set.insert(current.position);
         
         // Generate neighbors
-        for neighbor in get_neighbors(game, current.position) {
+        for neighbor in get_neighbors(view, current.position) {
             if closed_set.contains(&neighbor) {
                 continue; // Skip already evaluated neighbors
             }
@@ -255,7 +256,7 @@ fn find_underwater_path(game: &Game, start: BlockPosition, target: BlockPosition
 }
 
 /// Get valid neighboring positions
-fn get_neighbors(game: &Game, pos: BlockPosition) -> Vec<BlockPosition> {
+fn get_neighbors(view: &dyn BlockView, pos: BlockPosition) -> Vec<BlockPosition> {
     // Basic neighbors (horizontally adjacent blocks)
     let basic_neighbors = vec![
         BlockPosition::new(pos.x + 1, pos.y, pos.z),
@@ -268,7 +269,7 @@ fn get_neighbors(game: &Game, pos: BlockPosition) -> Vec<BlockPosition> {
     
     for neighbor in basic_neighbors {
         // Check if the block is passable
-        if is_passable_block(game, neighbor) {
+        if is_passable_block(view, neighbor) {
             valid_neighbors.push(neighbor);
         }
     }
@@ -278,24 +279,27 @@ fn get_neighbors(game: &Game, pos: BlockPosition) -> Vec<BlockPosition> {
     let down = BlockPosition::new(pos.x, pos.y - 1, pos.z);
     
     // Can jump up one block
-    if is_passable_block(game, up) && is_passable_block(game, BlockPosition::new(pos.x, pos.y + 2, pos.z)) {
+    if is_passable_block(view, up) && is_passable_block(view, BlockPosition::new(pos.x, pos.y + 2, pos.z)) {
         valid_neighbors.push(up);
     }
     
-    // Can move down if the block below is solid or water
-    if is_passable_block(game, down) {
+    // Can move down if the block below has a collision shape low enough to step into
+    if is_passable_block(view, down) {
         valid_neighbors.push(down);
     }
     
     valid_neighbors
 }
 
-/// Check if a block is passable
-fn is_passable_block(game: &Game, pos: BlockPosition) -> bool {
-    // This would check the block at the position to see if entities can pass through it
-    // Implementation depends on how the world data is stored
-    // For now, assume all blocks are passable
-    true
+/// Check if a block is passable, i.e. low enough to walk into or onto.
+///
+/// Bottom slabs can be stepped onto and open fence gates walked through,
+/// while fences, walls and closed gates are too tall to get past.
+fn is_passable_block(view: &dyn BlockView, pos: BlockPosition) -> bool {
+    match view.block_at((pos.x, pos.y, pos.z)) {
+        Some((_, properties)) => properties.collision_shape().height() <= STEP_HEIGHT,
+        None => false,
+    }
 }
 
 /// Manhattan distance heuristic
@@ -329,4 +333,73 @@ fn reconstruct_path(came_from: std::collections::HashMap<BlockPosition, BlockPos
     // Reverse to get path from start to end
     path.reverse();
     path
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use base::BlockKind;
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    /// A walled room at y = 64 spanning x in -4..=4 and z in 0..=10, with a
+    /// floor below and a ceiling above. A wall at z = 5 has a fence gate at
+    /// x = 0 and a gap at x = 4.
+    fn room(gate_open: bool) -> impl Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)> {
+        move |(x, y, z)| {
+            let kind = if y != 64 || x.abs() > 4 || !(0..=10).contains(&z) {
+                BlockKind::Stone
+            } else if z == 5 && x == 0 {
+                let mut gate = BlockKind::OakFenceGate.default_properties();
+                gate.set("open", if gate_open { "true" } else { "false" });
+                return Some((BlockKind::OakFenceGate, gate));
+            } else if z == 5 && x != 4 {
+                BlockKind::Stone
+            } else if (x, z) == (-2, 2) {
+                let mut slab = BlockKind::OakSlab.default_properties();
+                slab.set("type", "bottom");
+                return Some((BlockKind::OakSlab, slab));
+            } else {
+                BlockKind::Air
+            };
+            Some((kind, kind.default_properties()))
+        }
+    }
+
+    fn path(view: &dyn BlockView, from: (i32, i32), to: (i32, i32)) -> Vec<PathNode> {
+        find_path(
+            view,
+            BlockPosition::new(from.0, 64, from.1),
+            BlockPosition::new(to.0, 64, to.1),
+            1000,
+        )
+        .unwrap()
+    }
+
+    fn visits(nodes: &[PathNode], x: i32, z: i32) -> bool {
+        nodes
+            .iter()
+            .any(|node| node.position == BlockPosition::new(x, 64, z))
+    }
+
+    #[test]
+    fn path_steps_onto_bottom_slab_without_jumping() {
+        let view = room(false);
+        let nodes = path(&view, (-2, 0), (-2, 4));
+        assert!(visits(&nodes, -2, 2));
+        assert!(nodes.iter().all(|node| !node.jump));
+    }
+
+    #[test]
+    fn path_uses_open_gate_and_avoids_closed_gate() {
+        let open = room(true);
+        let nodes = path(&open, (0, 0), (0, 10));
+        assert!(visits(&nodes, 0, 5));
+        assert_eq!(nodes.len(), 11);
+
+        let closed = room(false);
+        let nodes = path(&closed, (0, 0), (0, 10));
+        assert!(!visits(&nodes, 0, 5));
+        assert!(visits(&nodes, 4, 5));
+    }
+}
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Phantom;
+use quill_common::{components::Health, entities::Phantom};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Phantom).add(EntityKind::Phantom);
+    builder
+        .add(Phantom)
+        .add(EntityKind::Phantom)
+        .add(Health::new(20.0));
 }
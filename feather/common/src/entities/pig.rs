@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Pig;
+use quill_common::{components::Health, entities::Pig};
+
+use super::breeding::Breedable;
+
+/// Root vegetables pigs are bred with
+pub const FOOD: &[Item] = &[Item::Carrot, Item::Potato, Item::Beetroot];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Pig).add(EntityKind::Pig);
+    builder
+        .add(Pig)
+        .add(EntityKind::Pig)
+        .add(Health::new(10.0))
+        .add(Breedable::new(FOOD));
 }
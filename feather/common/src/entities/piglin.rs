@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Piglin;
+use quill_common::{components::Health, entities::Piglin};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Piglin).add(EntityKind::Piglin);
+    builder
+        .add(Piglin)
+        .add(EntityKind::Piglin)
+        .add(Health::new(16.0));
 }
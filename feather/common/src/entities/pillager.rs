@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Pillager;
+use quill_common::{components::Health, entities::Pillager};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Pillager).add(EntityKind::Pillager);
+    builder
+        .add(Pillager)
+        .add(EntityKind::Pillager)
+        .add(Health::new(24.0));
 }
@@ -1,11 +1,20 @@
 use anyhow::bail;
-use base::EntityKind;
+use base::{Area, EntityKind, Inventory, ValidBlockPosition};
 use ecs::{EntityBuilder, SysResult};
+use libcraft_core::Hand;
+use libcraft_items::InventorySlot;
+use parking_lot::MutexGuard;
 use quill_common::{
     components::{CreativeFlying, Sneaking, Sprinting},
     entities::Player,
 };
 
+use crate::{
+    hunger::{Hunger, LastMovement},
+    sleep::TimeSinceRest,
+    Dimension,
+};
+
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder
@@ -13,6 +22,9 @@ pub fn build_default(builder: &mut EntityBuilder) {
         .add(CreativeFlying(false))
         .add(Sneaking(false))
         .add(Sprinting(false))
+        .add(TimeSinceRest(0))
+        .add(Hunger::default())
+        .add(LastMovement::default())
         .add(EntityKind::Player);
 }
 
@@ -38,3 +50,25 @@ impl HotbarSlot {
         Ok(())
     }
 }
+
+/// Gets the slot holding the item a player has in `hand`.
+///
+/// The returned value is a `MutexGuard` and can be mutated.
+pub fn held_item(
+    inventory: &Inventory,
+    hotbar_slot: HotbarSlot,
+    hand: Hand,
+) -> MutexGuard<InventorySlot> {
+    match hand {
+        Hand::Main => inventory.item(Area::Hotbar, hotbar_slot.get()),
+        Hand::Offhand => inventory.item(Area::Offhand, 0),
+    }
+    .expect("player inventories have a hotbar and an offhand")
+}
+
+/// Where a player respawns after dying, set by sleeping in a bed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpawnPoint {
+    pub position: ValidBlockPosition,
+    pub dimension: Dimension,
+}
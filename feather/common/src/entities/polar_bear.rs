@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::PolarBear;
+use quill_common::{components::Health, entities::PolarBear};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(PolarBear).add(EntityKind::PolarBear);
+    builder
+        .add(PolarBear)
+        .add(EntityKind::PolarBear)
+        .add(Health::new(30.0));
 }
@@ -1,8 +1,160 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Potion;
+use base::{EntityKind, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{components::Health, entities::Potion, entity_init::EntityInit};
+
+use crate::{
+    entities::area_effect_cloud::AreaEffectCloudState,
+    events::ProjectileHitBlockEvent,
+    status_effects::{self, StatusEffect},
+    Game,
+};
+
+/// Entities further than this from a splash are not affected
+const SPLASH_RADIUS: f64 = 4.0;
+/// Entities are only affected within this vertical distance of a splash
+const SPLASH_HEIGHT: f64 = 2.0;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder.add(Potion).add(EntityKind::Potion);
 }
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(shatter_potions);
+}
+
+/// The contents of a thrown potion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrownPotion {
+    pub effects: Vec<StatusEffect>,
+    /// Whether the potion leaves a cloud behind instead of splashing
+    pub lingering: bool,
+}
+
+/// Handles a thrown potion hitting a block or entity.
+///
+/// Splash potions apply their effects to living entities nearby, with
+/// a strength that falls off with the distance to the impact. Lingering
+/// potions spawn an area effect cloud for each of their effects instead.
+pub fn on_impact(game: &mut Game, potion: Entity) -> SysResult {
+    let position = *game.ecs.get::<Position>(potion)?;
+    let contents = game.ecs.get::<ThrownPotion>(potion)?.clone();
+
+    if contents.lingering {
+        for effect in contents.effects {
+            let mut builder = game.create_entity_builder(position, EntityInit::AreaEffectCloud);
+            builder.add(AreaEffectCloudState::lingering(effect));
+            game.spawn_entity(builder);
+        }
+    } else {
+        let targets: Vec<(Entity, f64)> = game
+            .ecs
+            .query::<(&Position, &Health)>()
+            .iter()
+            .filter(|(_, (pos, _))| (pos.y - position.y).abs() <= SPLASH_HEIGHT)
+            .map(|(entity, (pos, _))| {
+                let (dx, dz) = (pos.x - position.x, pos.z - position.z);
+                (entity, (dx * dx + dz * dz).sqrt())
+            })
+            .filter(|(_, distance)| *distance < SPLASH_RADIUS)
+            .collect();
+
+        for (target, distance) in targets {
+            let scale = (1.0 - distance / SPLASH_RADIUS) as f32;
+            for effect in &contents.effects {
+                status_effects::apply_effect_scaled(game, target, *effect, scale)?;
+            }
+        }
+    }
+
+    game.remove_entity(potion)?;
+    Ok(())
+}
+
+/// Shatters the thrown potions which hit a block.
+fn shatter_potions(game: &mut Game) -> SysResult {
+    let shattered: Vec<Entity> = game
+        .ecs
+        .query::<(&ProjectileHitBlockEvent, &ThrownPotion)>()
+        .iter()
+        .map(|(potion, _)| potion)
+        .collect();
+    for potion in shattered {
+        on_impact(game, potion)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockPosition};
+    use libcraft_core::BlockFace;
+
+    use crate::status_effects::StatusEffectKind;
+
+    use super::*;
+
+    fn throw(game: &mut Game, effect: StatusEffect, lingering: bool) -> Entity {
+        game.ecs.spawn((
+            position!(0.0, 64.0, 0.0),
+            ThrownPotion {
+                effects: vec![effect],
+                lingering,
+            },
+        ))
+    }
+
+    #[test]
+    fn splash_harming_falls_off_with_distance() {
+        let mut game = Game::new();
+        let near = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Health::new(20.0)));
+        let mid = game
+            .ecs
+            .spawn((position!(2.0, 64.0, 0.0), Health::new(20.0)));
+        let far = game
+            .ecs
+            .spawn((position!(6.0, 64.0, 0.0), Health::new(20.0)));
+
+        let potion = throw(
+            &mut game,
+            StatusEffect::new(StatusEffectKind::InstantDamage, 0, 1),
+            false,
+        );
+        on_impact(&mut game, potion).unwrap();
+
+        assert_eq!(game.ecs.get::<Health>(near).unwrap().current, 14.0);
+        assert_eq!(game.ecs.get::<Health>(mid).unwrap().current, 17.0);
+        assert_eq!(game.ecs.get::<Health>(far).unwrap().current, 20.0);
+    }
+
+    #[test]
+    fn lingering_potion_spawns_cloud() {
+        let mut game = Game::new();
+        let effect = StatusEffect::new(StatusEffectKind::Poison, 0, 900);
+        let potion = throw(&mut game, effect, true);
+        game.ecs
+            .insert_entity_event(
+                potion,
+                ProjectileHitBlockEvent {
+                    block: BlockPosition::new(0, 63, 0).try_into().unwrap(),
+                    face: BlockFace::Top,
+                    hit: position!(0.0, 64.0, 0.0),
+                },
+            )
+            .unwrap();
+        shatter_potions(&mut game).unwrap();
+        assert!(game.ecs.get::<ThrownPotion>(potion).is_err());
+
+        let clouds: Vec<AreaEffectCloudState> = game
+            .ecs
+            .query::<&AreaEffectCloudState>()
+            .iter()
+            .map(|(_, cloud)| cloud.clone())
+            .collect();
+        assert_eq!(clouds, vec![AreaEffectCloudState::lingering(effect)]);
+    }
+}
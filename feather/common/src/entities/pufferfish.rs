@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Pufferfish;
+use quill_common::{components::Health, entities::Pufferfish};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Pufferfish).add(EntityKind::Pufferfish);
+    builder
+        .add(Pufferfish)
+        .add(EntityKind::Pufferfish)
+        .add(Health::new(3.0));
 }
@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Rabbit;
+use quill_common::{components::Health, entities::Rabbit};
+
+use super::breeding::Breedable;
+
+/// Rabbits are bred with dandelions and carrots
+pub const FOOD: &[Item] = &[Item::Dandelion, Item::Carrot, Item::GoldenCarrot];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Rabbit).add(EntityKind::Rabbit);
+    builder
+        .add(Rabbit)
+        .add(EntityKind::Rabbit)
+        .add(Health::new(3.0))
+        .add(Breedable::new(FOOD));
 }
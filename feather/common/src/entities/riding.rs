@@ -0,0 +1,213 @@
+//! Vehicles and the entities riding them.
+
+use anyhow::bail;
+use base::{Position, Vec3d};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::components::Health;
+
+use crate::Game;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_passengers);
+}
+
+/// An entity carrying passengers: a horse, boat, minecart, ...
+#[derive(Debug, Clone, Default)]
+pub struct Vehicle {
+    pub passengers: Vec<Entity>,
+}
+
+/// An entity riding a vehicle.
+#[derive(Debug, Clone, Copy)]
+pub struct Riding {
+    pub vehicle: Entity,
+    /// Position of the passenger's seat relative to the vehicle.
+    pub offset: Vec3d,
+}
+
+/// Mounts `passenger` onto `vehicle`, leaving any vehicle it is already riding.
+///
+/// Vehicles may themselves ride other vehicles, so passengers can be
+/// stacked (e.g. a chicken jockey).
+pub fn mount(game: &mut Game, passenger: Entity, vehicle: Entity, offset: Vec3d) -> SysResult {
+    if vehicle_chain_contains(game, vehicle, passenger) {
+        bail!("an entity cannot ride itself");
+    }
+
+    dismount(game, passenger)?;
+
+    let has_vehicle = game.ecs.get::<Vehicle>(vehicle).is_ok();
+    if has_vehicle {
+        game.ecs
+            .get_mut::<Vehicle>(vehicle)?
+            .passengers
+            .push(passenger);
+    } else {
+        game.ecs.insert(
+            vehicle,
+            Vehicle {
+                passengers: vec![passenger],
+            },
+        )?;
+    }
+    game.ecs.insert(passenger, Riding { vehicle, offset })?;
+    Ok(())
+}
+
+/// Makes `passenger` leave its vehicle, if it is riding one.
+pub fn dismount(game: &mut Game, passenger: Entity) -> SysResult {
+    let riding = match game.ecs.remove::<Riding>(passenger) {
+        Ok(riding) => riding,
+        Err(_) => return Ok(()),
+    };
+
+    if let Ok(mut vehicle) = game.ecs.get_mut::<Vehicle>(riding.vehicle) {
+        vehicle.passengers.retain(|p| *p != passenger);
+    }
+    Ok(())
+}
+
+/// Whether `target` is `entity` or any of the vehicles
+/// below it, following the chain of vehicles it rides.
+fn vehicle_chain_contains(game: &Game, entity: Entity, target: Entity) -> bool {
+    let mut current = entity;
+    loop {
+        if current == target {
+            return true;
+        }
+        match game.ecs.get::<Riding>(current) {
+            Ok(riding) => current = riding.vehicle,
+            Err(_) => return false,
+        }
+    }
+}
+
+/// Computes where `entity` should be, following its vehicle chain.
+fn seat_position(game: &Game, entity: Entity) -> Option<Position> {
+    match game.ecs.get::<Riding>(entity) {
+        Ok(riding) => {
+            let riding = *riding;
+            Some(seat_position(game, riding.vehicle)? + riding.offset)
+        }
+        Err(_) => game.ecs.get::<Position>(entity).ok().map(|pos| *pos),
+    }
+}
+
+fn is_dead(game: &Game, entity: Entity) -> bool {
+    match game.ecs.get::<Health>(entity) {
+        Ok(health) => health.current <= 0.0,
+        Err(_) => game.ecs.entity(entity).is_err(),
+    }
+}
+
+/// Moves passengers along with their vehicles, dismounting
+/// those whose vehicle has died or despawned.
+fn update_passengers(game: &mut Game) -> SysResult {
+    let riders: Vec<(Entity, Riding)> = game
+        .ecs
+        .query::<&Riding>()
+        .iter()
+        .map(|(entity, riding)| (entity, *riding))
+        .collect();
+
+    for (passenger, riding) in riders {
+        if is_dead(game, riding.vehicle) {
+            dismount(game, passenger)?;
+            continue;
+        }
+
+        if let Some(seat) = seat_position(game, passenger) {
+            if let Ok(mut position) = game.ecs.get_mut::<Position>(passenger) {
+                position.x = seat.x;
+                position.y = seat.y;
+                position.z = seat.z;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, vec3};
+
+    use super::*;
+
+    #[test]
+    fn passenger_follows_moving_vehicle() {
+        let mut game = Game::new();
+        let vehicle = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Health::new(20.0)));
+        let passenger = game.ecs.spawn((position!(5.0, 64.0, 5.0),));
+
+        mount(&mut game, passenger, vehicle, vec3(0.0, 0.8, 0.0)).unwrap();
+        assert_eq!(
+            game.ecs.get::<Vehicle>(vehicle).unwrap().passengers,
+            vec![passenger]
+        );
+
+        for step in 1..=3 {
+            game.ecs.get_mut::<Position>(vehicle).unwrap().x = f64::from(step);
+            update_passengers(&mut game).unwrap();
+
+            let pos = *game.ecs.get::<Position>(passenger).unwrap();
+            assert_eq!((pos.x, pos.y, pos.z), (f64::from(step), 64.8, 0.0));
+        }
+    }
+
+    #[test]
+    fn stacked_passengers_follow_bottom_vehicle() {
+        let mut game = Game::new();
+        let chicken = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let zombie = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+
+        mount(&mut game, zombie, chicken, vec3(0.0, 0.5, 0.0)).unwrap();
+        assert!(mount(&mut game, chicken, zombie, vec3(0.0, 0.5, 0.0)).is_err());
+
+        game.ecs.get_mut::<Position>(chicken).unwrap().z = 3.0;
+        update_passengers(&mut game).unwrap();
+
+        let pos = *game.ecs.get::<Position>(zombie).unwrap();
+        assert_eq!((pos.x, pos.y, pos.z), (0.0, 64.5, 3.0));
+    }
+
+    #[test]
+    fn mounting_rejects_cycles_through_the_whole_chain() {
+        let mut game = Game::new();
+        let mut spawn = || game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let (a, b, c, d) = (spawn(), spawn(), spawn(), spawn());
+        let seat = vec3(0.0, 1.0, 0.0);
+
+        mount(&mut game, a, b, seat).unwrap();
+        mount(&mut game, b, c, seat).unwrap();
+        mount(&mut game, c, d, seat).unwrap();
+
+        // a -> b -> c -> d, so c may not ride a
+        assert!(mount(&mut game, c, a, seat).is_err());
+        assert!(mount(&mut game, a, a, seat).is_err());
+        assert_eq!(game.ecs.get::<Riding>(c).unwrap().vehicle, d);
+    }
+
+    #[test]
+    fn vehicle_death_dismounts_passengers() {
+        let mut game = Game::new();
+        let vehicle = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Health::new(20.0)));
+        let passenger = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        mount(&mut game, passenger, vehicle, vec3(0.0, 1.0, 0.0)).unwrap();
+
+        game.ecs.get_mut::<Health>(vehicle).unwrap().current = 0.0;
+        update_passengers(&mut game).unwrap();
+
+        assert!(game.ecs.get::<Riding>(passenger).is_err());
+        assert!(game
+            .ecs
+            .get::<Vehicle>(vehicle)
+            .unwrap()
+            .passengers
+            .is_empty());
+    }
+}
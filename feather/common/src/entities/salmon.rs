@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Salmon;
+use quill_common::{components::Health, entities::Salmon};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Salmon).add(EntityKind::Salmon);
+    builder
+        .add(Salmon)
+        .add(EntityKind::Salmon)
+        .add(Health::new(3.0));
 }
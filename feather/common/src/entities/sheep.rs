@@ -1,8 +1,165 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Sheep;
+use std::convert::TryInto;
+
+use base::{BlockId, BlockKind, EntityKind, EntityMetadata, Item, Position, ValidBlockPosition};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{components::Health, entities::Sheep};
+use rand::Rng;
+
+use crate::{dye::DyeColor, events::EntityMetadataChangeEvent, Game};
+
+use super::{
+    aging::{self, Baby},
+    breeding::Breedable,
+};
+
+/// Metadata indices of sheep, see <https://wiki.vg/Entity_metadata#Sheep>
+pub mod indices {
+    /// Wool color in the low four bits, `0x10` once sheared
+    pub const WOOL: u8 = 16;
+}
+
+const FLAG_SHEARED: u8 = 0x10;
+
+/// Chance each tick for an adult sheep to eat
+const ADULT_EAT_CHANCE: f32 = 0.001;
+/// Chance each tick for a baby sheep to eat
+const BABY_EAT_CHANCE: f32 = 0.02;
+/// Ticks sooner a baby sheep grows up for each meal
+const MEAL_GROWTH_TICKS: u32 = 1200;
+
+/// Sheep are bred with wheat
+pub const FOOD: &[Item] = &[Item::Wheat];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Sheep).add(EntityKind::Sheep);
+    builder
+        .add(Sheep)
+        .add(EntityKind::Sheep)
+        .add(Health::new(8.0))
+        .add(SheepColor::default())
+        .add(Breedable::new(FOOD));
+}
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(graze);
+}
+
+/// Marks a sheep whose wool has been sheared off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sheared;
+
+/// The color of a sheep's wool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SheepColor(pub DyeColor);
+
+impl SheepColor {
+    /// Builds the entity metadata describing a sheep's wool.
+    pub fn metadata(self, sheared: bool) -> EntityMetadata {
+        let mut wool = self.0.id();
+        if sheared {
+            wool |= FLAG_SHEARED;
+        }
+        EntityMetadata::new().with(indices::WOOL, wool)
+    }
+}
+
+/// Lets clients know the wool of `sheep` changed.
+pub fn update_wool(game: &mut Game, sheep: Entity) -> SysResult {
+    let color = *game.ecs.get::<SheepColor>(sheep)?;
+    let sheared = game.ecs.get::<Sheared>(sheep).is_ok();
+    game.ecs.insert_entity_event(
+        sheep,
+        EntityMetadataChangeEvent {
+            metadata: color.metadata(sheared),
+        },
+    )?;
+    Ok(())
+}
+
+/// Makes `sheep` eat the grass it stands in, or else the grass block
+/// under it. Tall grass is eaten up and grass blocks turn to dirt,
+/// but only while mobs are allowed to grief.
+///
+/// Eating regrows a sheared sheep's wool and helps a baby grow up.
+/// Returns whether the sheep found grass to eat.
+pub fn eat_grass(game: &mut Game, sheep: Entity) -> SysResult<bool> {
+    let feet = game.ecs.get::<Position>(sheep)?.block();
+    let is_block =
+        |pos: &ValidBlockPosition, kind| game.block(*pos).map(|block| block.kind()) == Some(kind);
+    let standing_in: Option<ValidBlockPosition> = feet.try_into().ok();
+    let below: Option<ValidBlockPosition> = feet.down().try_into().ok();
+
+    let tall_grass = standing_in.filter(|pos| is_block(pos, BlockKind::Grass));
+    let grass_block = below.filter(|pos| is_block(pos, BlockKind::GrassBlock));
+    let (pos, eaten) = match (tall_grass, grass_block) {
+        (Some(pos), _) => (pos, BlockId::air()),
+        (None, Some(pos)) => (pos, BlockId::dirt()),
+        (None, None) => return Ok(false),
+    };
+    if game.mob_can_grief() {
+        game.set_block(pos, eaten);
+    }
+
+    if game.ecs.remove::<Sheared>(sheep).is_ok() {
+        update_wool(game, sheep)?;
+    }
+    if game.ecs.get::<Baby>(sheep).is_ok() {
+        aging::age_up(game, sheep, MEAL_GROWTH_TICKS)?;
+    }
+    Ok(true)
+}
+
+/// Lets sheep now and then stop to eat grass.
+fn graze(game: &mut Game) -> SysResult {
+    let grazing: Vec<Entity> = game.with_rng(|game, rng| {
+        game.ecs
+            .query::<&Sheep>()
+            .iter()
+            .map(|(sheep, _)| sheep)
+            .filter(|sheep| {
+                let chance = if game.ecs.get::<Baby>(*sheep).is_ok() {
+                    BABY_EAT_CHANCE
+                } else {
+                    ADULT_EAT_CHANCE
+                };
+                rng.gen::<f32>() < chance
+            })
+            .collect()
+    });
+
+    for sheep in grazing {
+        eat_grass(game, sheep)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, BlockPosition, Chunk, ChunkPosition};
+
+    use super::*;
+
+    #[test]
+    fn eating_grass_regrows_wool() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let ground: ValidBlockPosition = BlockPosition::new(2, 63, 2).try_into().unwrap();
+        game.set_block(ground, BlockId::grass_block());
+        let sheep = game.ecs.spawn((
+            Sheep,
+            EntityKind::Sheep,
+            SheepColor::default(),
+            Sheared,
+            position!(2.5, 64.0, 2.5),
+        ));
+
+        assert!(eat_grass(&mut game, sheep).unwrap());
+        assert!(game.ecs.get::<Sheared>(sheep).is_err());
+        assert_eq!(game.block(ground), Some(BlockId::dirt()));
+
+        // Nothing left to eat
+        assert!(!eat_grass(&mut game, sheep).unwrap());
+    }
 }
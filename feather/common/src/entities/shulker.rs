@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Shulker;
+use quill_common::{components::Health, entities::Shulker};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Shulker).add(EntityKind::Shulker);
+    builder
+        .add(Shulker)
+        .add(EntityKind::Shulker)
+        .add(Health::new(30.0));
 }
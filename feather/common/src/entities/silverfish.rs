@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Silverfish;
+use quill_common::{components::Health, entities::Silverfish};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Silverfish).add(EntityKind::Silverfish);
+    builder
+        .add(Silverfish)
+        .add(EntityKind::Silverfish)
+        .add(Health::new(8.0));
 }
@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item, ItemStack};
 use ecs::EntityBuilder;
-use quill_common::entities::Skeleton;
+use quill_common::{components::Health, entities::Skeleton};
+
+use super::equipment::Equipment;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Skeleton).add(EntityKind::Skeleton);
+    builder
+        .add(Skeleton)
+        .add(EntityKind::Skeleton)
+        .add(Health::new(20.0))
+        .add(Equipment {
+            main_hand: ItemStack::new(Item::Bow, 1).ok(),
+            ..Default::default()
+        });
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Slime;
+use quill_common::{components::Health, entities::Slime};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Slime).add(EntityKind::Slime);
+    builder
+        .add(Slime)
+        .add(EntityKind::Slime)
+        .add(Health::new(4.0));
 }
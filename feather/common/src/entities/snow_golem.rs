@@ -1,8 +1,29 @@
-use base::EntityKind;
+use base::{EntityKind, EntityMetadata};
 use ecs::EntityBuilder;
-use quill_common::entities::SnowGolem;
+use quill_common::{components::Health, entities::SnowGolem};
+
+/// Metadata indices of snow golems, see <https://wiki.vg/Entity_metadata#Snow_Golem>
+pub mod indices {
+    pub const PUMPKIN_HAT: u8 = 15;
+}
+
+const FLAG_PUMPKIN_HAT: u8 = 0x10;
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(SnowGolem).add(EntityKind::SnowGolem);
+    builder
+        .add(SnowGolem)
+        .add(EntityKind::SnowGolem)
+        .add(Health::new(4.0))
+        .add(WearingPumpkin);
+}
+
+/// Marks a snow golem that still wears its pumpkin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WearingPumpkin;
+
+/// Builds the entity metadata showing or hiding a snow golem's pumpkin.
+pub fn pumpkin_metadata(wearing_pumpkin: bool) -> EntityMetadata {
+    let hat = if wearing_pumpkin { FLAG_PUMPKIN_HAT } else { 0 };
+    EntityMetadata::new().with(indices::PUMPKIN_HAT, hat)
 }
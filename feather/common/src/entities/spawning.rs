@@ -1,19 +1,44 @@
-use base::{BlockPosition, Biome, BiomeId, EntityKind};
-use ecs::{Entity, IntoQuery, SysResult, SystemExecutor};
-use rand::{Rng, thread_rng};
-use quill_common::entities::{Axolotl, Goat, GlowSquid};
+use ahash::AHashMap;
+use base::{
+    BlockKind, BlockPosition, Biome, BiomeId, ChunkPosition, EntityKind, Position, CHUNK_HEIGHT,
+    TICK_DURATION,
+};
+use ecs::{Entity, EntityBuilder, IntoQuery, SysResult, SystemExecutor};
+use libcraft_blocks::BlockView;
+use rand::{Rng, RngCore};
+use quill_common::entities::{Axolotl, Goat, GlowSquid, Player};
+use quill_common::entity_init::EntityInit;
+use std::convert::TryInto;
 use std::time::Duration;
 
-use crate::Game;
+use crate::{Difficulty, Game, GameRules};
+
+/// Distance from a spawn attempt within which mobs count towards the mob cap
+pub const MOB_CAP_RANGE: f64 = 128.0;
+/// Most mobs of a kind near a natural spawn, unless configured otherwise
+pub const DEFAULT_MOB_CAP: u32 = 10;
+/// Distance in chunks from a player within which mobs spawn naturally
+pub const SPAWN_CHUNK_RADIUS: i32 = 8;
 
 /// Represents the difficulty of spawning for an entity
-enum SpawnDifficulty {
+pub enum SpawnDifficulty {
     /// Entity can always spawn regardless of difficulty
     Any,
     /// Entity can only spawn in non-peaceful difficulties
     NonPeaceful,
     /// Entity requires specific difficulty settings to spawn
-    Custom(fn(u8) -> bool),
+    Custom(fn(Difficulty) -> bool),
+}
+
+impl SpawnDifficulty {
+    /// Whether the entity can spawn at the given difficulty
+    pub fn allows(&self, difficulty: Difficulty) -> bool {
+        match self {
+            SpawnDifficulty::Any => true,
+            SpawnDifficulty::NonPeaceful => !difficulty.is_peaceful(),
+            SpawnDifficulty::Custom(allows) => allows(difficulty),
+        }
+    }
 }
 
 /// Defines the conditions under which an entity can spawn
@@ -30,8 +55,9 @@ pub struct SpawnRule {
     pub min_group_size: u32,
     /// Maximum group size when spawned
     pub max_group_size: u32,
-    /// Specific block requirements for spawning
-    pub required_blocks: Option<fn(BlockPosition) -> bool>,
+    /// Specific block requirements for spawning, given the spawn
+    /// position and access to the surrounding blocks
+    pub required_blocks: Option<fn(BlockPosition, &dyn BlockView) -> bool>,
     /// Difficulty requirements for spawning
     pub difficulty: SpawnDifficulty,
     /// Spawning weight (higher = more common)
@@ -42,6 +68,11 @@ pub struct SpawnRule {
     pub cave_spawn: bool,
     /// If true, entity requires sky access to spawn
     pub requires_sky_access: bool,
+    /// Most entities of this kind allowed within `nearby_radius`
+    /// of the spawn position, or `None` for no limit
+    pub max_nearby: Option<u32>,
+    /// Radius around the spawn position checked for `max_nearby`
+    pub nearby_radius: f64,
 }
 
 impl Default for SpawnRule {
@@ -59,18 +90,65 @@ impl Default for SpawnRule {
             aquatic: false,
             cave_spawn: false,
             requires_sky_access: false,
+            max_nearby: None,
+            nearby_radius: 16.0,
         }
     }
 }
 
+/// Gives the members of a spawned group a shared variant (coat, color, ...)
+#[derive(Clone, Copy)]
+pub struct GroupVariantHook {
+    /// Rolls a variant for the entity kind
+    pub roll: fn(&mut dyn RngCore) -> u32,
+    /// Applies a variant to a member being built
+    pub apply: fn(&mut EntityBuilder, u32),
+    /// Chance for each member to roll its own variant instead of the group's
+    pub deviation_chance: f32,
+}
+
+/// Where a spawn attempt takes place and the conditions there
+pub struct SpawnAttempt<'a, F> {
+    /// Biome of the chunk
+    pub biome: BiomeId,
+    /// Position of the chunk
+    pub chunk_pos: (i32, i32),
+    /// The blocks around the chunk
+    pub block_view: &'a dyn BlockView,
+    /// Gets the light level at a position
+    pub light_getter: F,
+    /// Difficulty of the game
+    pub difficulty: Difficulty,
+}
+
+/// A group of entities selected to spawn together
+#[derive(Debug, Clone)]
+pub struct SpawnGroup {
+    pub kind: EntityKind,
+    pub position: BlockPosition,
+    /// The variant of each member, or `None` if the kind has no variant hook
+    pub variants: Vec<Option<u32>>,
+}
+
+impl SpawnGroup {
+    /// Number of entities in the group
+    pub fn size(&self) -> usize {
+        self.variants.len()
+    }
+}
+
 /// Manages entity spawning rules
 pub struct EntitySpawnManager {
     /// All registered spawn rules
     rules: Vec<SpawnRule>,
+    /// Per-kind hooks giving spawned groups a shared variant
+    variant_hooks: AHashMap<EntityKind, GroupVariantHook>,
     /// Time until next spawn attempt
     next_spawn_time: Duration,
     /// Spawn rate controls how often entities spawn
     spawn_rate: Duration,
+    /// Most entities of a kind within `MOB_CAP_RANGE` of a spawn attempt
+    mob_cap: Option<u32>,
 }
 
 impl EntitySpawnManager {
@@ -78,35 +156,148 @@ impl EntitySpawnManager {
     pub fn new() -> Self {
         Self {
             rules: Vec::new(),
+            variant_hooks: AHashMap::new(),
             next_spawn_time: Duration::from_secs(0),
             spawn_rate: Duration::from_secs(5),
+            mob_cap: None,
         }
     }
 
+    /// Limits how many entities of a kind may be near a spawn attempt,
+    /// or removes the limit with `None`
+    pub fn set_mob_cap(&mut self, cap: Option<u32>) -> &mut Self {
+        self.mob_cap = cap;
+        self
+    }
+
     /// Registers a new spawn rule
     pub fn register_rule(&mut self, rule: SpawnRule) -> &mut Self {
         self.rules.push(rule);
         self
     }
 
-    /// Attempts to spawn entities in the given chunk
+    /// Removes all rules spawning the given entity kind
+    pub fn remove_rules_for(&mut self, kind: EntityKind) -> &mut Self {
+        self.rules.retain(|rule| rule.entity_kind != kind);
+        self
+    }
+
+    /// Replaces all rules spawning the given entity kind
+    pub fn replace_rules_for(&mut self, kind: EntityKind, rules: Vec<SpawnRule>) -> &mut Self {
+        self.remove_rules_for(kind);
+        self.rules
+            .extend(rules.into_iter().filter(|rule| rule.entity_kind == kind));
+        self
+    }
+
+    /// Removes all registered rules, disabling natural spawning
+    pub fn clear_rules(&mut self) -> &mut Self {
+        self.rules.clear();
+        self
+    }
+
+    /// Returns an iterator over the registered rules
+    pub fn rules(&self) -> impl Iterator<Item = &SpawnRule> {
+        self.rules.iter()
+    }
+
+    /// Registers the hook rolling shared variants for groups of `kind`
+    pub fn register_variant_hook(&mut self, kind: EntityKind, hook: GroupVariantHook) -> &mut Self {
+        self.variant_hooks.insert(kind, hook);
+        self
+    }
+
+    /// Rolls the variants of a group of `group_size` entities of `kind`.
+    ///
+    /// All members share one variant, except that each one has the hook's
+    /// `deviation_chance` to roll its own.
+    pub fn roll_group_variants(
+        &self,
+        kind: EntityKind,
+        group_size: u32,
+        rng: &mut dyn RngCore,
+    ) -> Vec<Option<u32>> {
+        let hook = match self.variant_hooks.get(&kind) {
+            Some(hook) => hook,
+            None => return vec![None; group_size as usize],
+        };
+
+        let shared = (hook.roll)(rng);
+        (0..group_size)
+            .map(|_| {
+                if rng.gen::<f32>() < hook.deviation_chance {
+                    Some((hook.roll)(rng))
+                } else {
+                    Some(shared)
+                }
+            })
+            .collect()
+    }
+
+    /// Applies a variant rolled by [`roll_group_variants`](Self::roll_group_variants)
+    /// to a group member being built
+    pub fn apply_variant(&self, kind: EntityKind, builder: &mut EntityBuilder, variant: Option<u32>) {
+        if let (Some(hook), Some(variant)) = (self.variant_hooks.get(&kind), variant) {
+            (hook.apply)(builder, variant);
+        }
+    }
+
+    /// Advances the spawn timer by a tick. Returns whether
+    /// the next spawn attempt is due.
+    fn tick(&mut self) -> bool {
+        if self.next_spawn_time > TICK_DURATION {
+            self.next_spawn_time -= TICK_DURATION;
+            false
+        } else {
+            self.next_spawn_time = self.spawn_rate;
+            true
+        }
+    }
+
+    /// Attempts to spawn entities in the given chunk, ignoring
+    /// the mob cap and `max_nearby` limits
     pub fn try_spawn_in_chunk<F, G>(
         &self,
-        biome: BiomeId,
-        chunk_pos: (i32, i32),
-        light_getter: F,
+        attempt: SpawnAttempt<F>,
+        rng: &mut dyn RngCore,
         entity_spawner: G,
     ) 
     where
         F: Fn(BlockPosition) -> u8,
-        G: FnMut(EntityKind, BlockPosition, u32),
+        G: FnMut(SpawnGroup),
+    {
+        self.try_spawn_in_chunk_with_counts(attempt, |_, _, _| 0, rng, entity_spawner);
+    }
+
+    /// Attempts to spawn entities in the given chunk.
+    ///
+    /// `nearby_count` counts the entities of a kind within a radius
+    /// of a position, which limits spawns to the mob cap and the
+    /// rule's `max_nearby`. Groups are shrunk to fit under the limits.
+    pub fn try_spawn_in_chunk_with_counts<F, C, G>(
+        &self,
+        attempt: SpawnAttempt<F>,
+        nearby_count: C,
+        rng: &mut dyn RngCore,
+        entity_spawner: G,
+    ) 
+    where
+        F: Fn(BlockPosition) -> u8,
+        C: Fn(EntityKind, BlockPosition, f64) -> u32,
+        G: FnMut(SpawnGroup),
     {
         let mut entity_spawner = entity_spawner;
-        let mut rng = thread_rng();
+        let SpawnAttempt {
+            biome,
+            chunk_pos,
+            block_view,
+            light_getter,
+            difficulty,
+        } = attempt;
         
-        // Get all rules that apply to this biome
+        // Get all rules that apply to this biome and difficulty
         let applicable_rules: Vec<&SpawnRule> = self.rules.iter()
-            .filter(|rule| rule.biomes.contains(&biome))
+            .filter(|rule| rule.biomes.contains(&biome) && rule.difficulty.allows(difficulty))
             .collect();
         
         if applicable_rules.is_empty() {
@@ -122,7 +313,7 @@ impl EntitySpawnManager {
             return;
         }
         
-        let mut selection = rng.gen_range(0, total_weight);
+        let mut selection = rng.gen_range(0..total_weight);
         let selected_rule = applicable_rules.iter()
             .find(|rule| {
                 if selection < rule.weight {
@@ -135,11 +326,11 @@ impl EntitySpawnManager {
             .unwrap();
         
         // Choose random position in chunk
-        let x = chunk_pos.0 * 16 + rng.gen_range(0, 16);
-        let z = chunk_pos.1 * 16 + rng.gen_range(0, 16);
+        let x = chunk_pos.0 * 16 + rng.gen_range(0..16);
+        let z = chunk_pos.1 * 16 + rng.gen_range(0..16);
         
         // Find a valid Y position
-        let y = self.find_spawn_y(x, z, selected_rule.aquatic);
+        let y = self.find_spawn_y(x, z, selected_rule.aquatic, block_view);
         if y.is_none() {
             return;
         }
@@ -155,33 +346,84 @@ impl EntitySpawnManager {
         
         // Check custom block requirements
         if let Some(block_check) = selected_rule.required_blocks {
-            if !block_check(pos) {
+            if !block_check(pos, block_view) {
                 return;
             }
         }
         
         // Determine group size
-        let group_size = rng.gen_range(selected_rule.min_group_size, selected_rule.max_group_size + 1);
+        let mut group_size =
+            rng.gen_range(selected_rule.min_group_size..=selected_rule.max_group_size);
+
+        // Keep the group within the limits on entities already around
+        let kind = selected_rule.entity_kind;
+        let limits = [
+            self.mob_cap.map(|cap| (cap, MOB_CAP_RANGE)),
+            selected_rule.max_nearby.map(|max| (max, selected_rule.nearby_radius)),
+        ];
+        for (max, radius) in limits.iter().flatten() {
+            let room = max.saturating_sub(nearby_count(kind, pos, *radius));
+            group_size = group_size.min(room);
+        }
+        if group_size == 0 {
+            return;
+        }
         
         // Spawn the entities
-        entity_spawner(selected_rule.entity_kind, pos, group_size);
+        let variants = self.roll_group_variants(selected_rule.entity_kind, group_size, rng);
+        entity_spawner(SpawnGroup {
+            kind: selected_rule.entity_kind,
+            position: pos,
+            variants,
+        });
     }
     
-    /// Finds a valid Y coordinate for spawning
-    fn find_spawn_y(&self, x: i32, z: i32, aquatic: bool) -> Option<i32> {
-        // This is a simplified version. In a real implementation, this would:
-        // 1. For aquatic mobs, find water blocks
-        // 2. For cave mobs, search caverns
-        // 3. For surface mobs, find highest solid block
-        // For now, we'll just return a placeholder y value
+    /// Finds a valid Y coordinate for spawning in the column at `x`, `z`:
+    /// on top of the highest solid block with air above it, or for aquatic
+    /// mobs at the bottom of the highest water in the column.
+    ///
+    /// Returns `None` if the column has no such spot, e.g. when it is not loaded.
+    fn find_spawn_y(&self, x: i32, z: i32, aquatic: bool, view: &dyn BlockView) -> Option<i32> {
+        let kind_at = |y| view.kind_at((x, y, z));
+        let top = CHUNK_HEIGHT as i32 - 1;
         if aquatic {
-            Some(60) // Ocean level
+            let is_water = |y: &i32| kind_at(*y) == Some(BlockKind::Water);
+            let surface = (0..=top).rev().find(is_water)?;
+            (0..=surface).rev().take_while(is_water).last()
         } else {
-            Some(70) // Typical ground level
+            (0..top)
+                .rev()
+                .find(|y| {
+                    kind_at(*y).map_or(false, |kind| kind.solid())
+                        && kind_at(y + 1) == Some(BlockKind::Air)
+                })
+                .map(|y| y + 1)
         }
     }
 }
 
+/// Returns the kind of the block the entity at `pos` would stand on
+fn block_below(pos: BlockPosition, view: &dyn BlockView) -> Option<BlockKind> {
+    view.kind_at((pos.x, pos.y - 1, pos.z))
+}
+
+/// Spawn requirement for animals, which need grass to stand on
+fn grass_below(pos: BlockPosition, view: &dyn BlockView) -> bool {
+    block_below(pos, view) == Some(BlockKind::GrassBlock)
+}
+
+/// Register default group variant hooks
+pub fn register_default_variant_hooks(manager: &mut EntitySpawnManager) {
+    manager.register_variant_hook(
+        EntityKind::Horse,
+        GroupVariantHook {
+            roll: super::horse::roll_variant,
+            apply: super::horse::apply_variant,
+            deviation_chance: 0.1,
+        },
+    );
+}
+
 /// Register default spawn rules for all entities
 pub fn register_default_spawn_rules(manager: &mut EntitySpawnManager) {
     // Register 1.17 entity spawn rules
@@ -203,15 +445,13 @@ fn register_axolotl_rules(manager: &mut EntitySpawnManager) {
         max_light: 15,
         min_group_size: 1,
         max_group_size: 4,
-        required_blocks: Some(|pos| {
-            // In real implementation, check if there's clay beneath
-            true
-        }),
+        required_blocks: Some(|pos, view| block_below(pos, view) == Some(BlockKind::Clay)),
         difficulty: SpawnDifficulty::Any,
         weight: 10,
         aquatic: true,
         cave_spawn: true,
         requires_sky_access: false,
+        ..Default::default()
     });
 }
 
@@ -224,15 +464,21 @@ fn register_goat_rules(manager: &mut EntitySpawnManager) {
         max_light: 15,
         min_group_size: 2,
         max_group_size: 3,
-        required_blocks: Some(|pos| {
-            // In real implementation, check for stone, snow, etc.
-            true
+        required_blocks: Some(|pos, view| {
+            matches!(
+                block_below(pos, view),
+                Some(BlockKind::Stone)
+                    | Some(BlockKind::SnowBlock)
+                    | Some(BlockKind::PowderSnow)
+                    | Some(BlockKind::GrassBlock)
+            )
         }),
         difficulty: SpawnDifficulty::Any,
         weight: 10,
         aquatic: false,
         cave_spawn: false,
         requires_sky_access: true,
+        ..Default::default()
     });
 }
 
@@ -251,6 +497,7 @@ fn register_glow_squid_rules(manager: &mut EntitySpawnManager) {
         aquatic: true,
         cave_spawn: true,
         requires_sky_access: false,
+        ..Default::default()
     });
 }
 
@@ -264,12 +511,13 @@ fn register_animal_rules(manager: &mut EntitySpawnManager) {
         max_light: 15,
         min_group_size: 2,
         max_group_size: 4,
-        required_blocks: None,
+        required_blocks: Some(grass_below),
         difficulty: SpawnDifficulty::Any,
         weight: 12,
         aquatic: false,
         cave_spawn: false,
         requires_sky_access: true,
+        ..Default::default()
     });
     
     // Cows
@@ -280,12 +528,13 @@ fn register_animal_rules(manager: &mut EntitySpawnManager) {
         max_light: 15,
         min_group_size: 2,
         max_group_size: 4,
-        required_blocks: None,
+        required_blocks: Some(grass_below),
         difficulty: SpawnDifficulty::Any,
         weight: 8,
         aquatic: false,
         cave_spawn: false,
         requires_sky_access: true,
+        ..Default::default()
     });
     
     // Pigs
@@ -296,12 +545,13 @@ fn register_animal_rules(manager: &mut EntitySpawnManager) {
         max_light: 15,
         min_group_size: 2,
         max_group_size: 4,
-        required_blocks: None,
+        required_blocks: Some(grass_below),
         difficulty: SpawnDifficulty::Any,
         weight: 10,
         aquatic: false,
         cave_spawn: false,
         requires_sky_access: true,
+        ..Default::default()
     });
     
     // Additional animal rules would go here
@@ -323,6 +573,7 @@ fn register_monster_rules(manager: &mut EntitySpawnManager) {
         aquatic: false,
         cave_spawn: true,
         requires_sky_access: false,
+        ..Default::default()
     });
     
     // Skeleton
@@ -339,23 +590,512 @@ fn register_monster_rules(manager: &mut EntitySpawnManager) {
         aquatic: false,
         cave_spawn: true,
         requires_sky_access: false,
+        ..Default::default()
     });
     
     // Additional monster rules would go here
 }
 
-pub fn register(systems: &mut SystemExecutor<Game>) {
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    let mut manager = EntitySpawnManager::new();
+    register_default_spawn_rules(&mut manager);
+    register_default_variant_hooks(&mut manager);
+    manager.set_mob_cap(Some(DEFAULT_MOB_CAP));
+    game.insert_resource(manager);
     systems.add_system(update_entity_spawning);
 }
 
+/// Whether the `doMobSpawning` game rule lets mobs spawn naturally
+pub fn mob_spawning_enabled(game: &Game) -> bool {
+    game.resources
+        .get::<GameRules>()
+        .map_or(true, |rules| rules.do_mob_spawning)
+}
+
+/// Counts the entities of `kind` within `radius` blocks of `pos`,
+/// looking only in the chunks the radius reaches.
+pub fn count_nearby(game: &Game, kind: EntityKind, pos: BlockPosition, radius: f64) -> u32 {
+    let center = Position {
+        x: f64::from(pos.x) + 0.5,
+        y: f64::from(pos.y),
+        z: f64::from(pos.z) + 0.5,
+        ..Default::default()
+    };
+    let chunks = (radius / 16.0).ceil() as i32;
+    let origin = pos.chunk();
+
+    let mut count = 0;
+    for cx in origin.x - chunks..=origin.x + chunks {
+        for cz in origin.z - chunks..=origin.z + chunks {
+            for &entity in game.chunk_entities.entities_in_chunk(ChunkPosition::new(cx, cz)) {
+                let is_kind = game.ecs.get::<EntityKind>(entity).map_or(false, |k| *k == kind);
+                let in_range = game
+                    .ecs
+                    .get::<Position>(entity)
+                    .map_or(false, |position| position.distance_to(center) <= radius);
+                if is_kind && in_range {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Rolls which groups spawn in `chunk`, a `biome` chunk of the game's world,
+/// on one spawn attempt, limited by the entities already nearby.
+/// Nothing spawns while `doMobSpawning` is off.
+pub fn roll_spawns_in_chunk(
+    game: &Game,
+    manager: &EntitySpawnManager,
+    biome: BiomeId,
+    chunk: ChunkPosition,
+    rng: &mut dyn RngCore,
+) -> Vec<SpawnGroup> {
+    let mut groups = Vec::new();
+    if !mob_spawning_enabled(game) {
+        return groups;
+    }
+
+    let difficulty = game
+        .resources
+        .get::<Difficulty>()
+        .map_or(Difficulty::default(), |difficulty| *difficulty);
+    let attempt = SpawnAttempt {
+        biome,
+        chunk_pos: (chunk.x, chunk.z),
+        block_view: game,
+        light_getter: |pos: BlockPosition| {
+            pos.try_into()
+                .ok()
+                .and_then(|pos| game.world.sky_light_at(pos))
+                .unwrap_or(0)
+        },
+        difficulty,
+    };
+    manager.try_spawn_in_chunk_with_counts(
+        attempt,
+        |kind, pos, radius| count_nearby(game, kind, pos, radius),
+        rng,
+        |group| groups.push(group),
+    );
+    groups
+}
+
+/// The loaded chunks close enough to a player for mobs to spawn in.
+pub fn spawning_chunks(game: &Game) -> Vec<ChunkPosition> {
+    let mut chunks = Vec::new();
+    for (_, (_, position)) in game.ecs.query::<(&Player, &Position)>().iter() {
+        let center = position.chunk();
+        for x in center.x - SPAWN_CHUNK_RADIUS..=center.x + SPAWN_CHUNK_RADIUS {
+            for z in center.z - SPAWN_CHUNK_RADIUS..=center.z + SPAWN_CHUNK_RADIUS {
+                let chunk = ChunkPosition::new(x, z);
+                if game.world.is_chunk_loaded(chunk) {
+                    chunks.push(chunk);
+                }
+            }
+        }
+    }
+    chunks.sort_by_key(|chunk| (chunk.x, chunk.z));
+    chunks.dedup();
+    chunks
+}
+
+/// The biome spawn rules use for the chunk, taken at its center.
+fn chunk_biome(game: &Game, chunk: ChunkPosition) -> Option<BiomeId> {
+    let center = BlockPosition::new(chunk.x * 16 + 8, 64, chunk.z * 16 + 8);
+    let biome = game.world.biome_at(center.try_into().ok()?)?;
+    Some(match biome {
+        Biome::Plains => BiomeId::Plains,
+        Biome::Desert => BiomeId::Desert,
+        Biome::Forest => BiomeId::Forest,
+        Biome::Taiga => BiomeId::Taiga,
+        Biome::Ocean => BiomeId::Ocean,
+        Biome::DeepOcean => BiomeId::DeepOcean,
+        _ => return None,
+    })
+}
+
+/// How naturally spawned mobs of `kind` are created
+fn spawn_init(kind: EntityKind) -> Option<EntityInit> {
+    Some(match kind {
+        EntityKind::Chicken => EntityInit::Chicken,
+        EntityKind::Cow => EntityInit::Cow,
+        EntityKind::Creeper => EntityInit::Creeper,
+        EntityKind::Horse => EntityInit::Horse,
+        EntityKind::Pig => EntityInit::Pig,
+        EntityKind::Sheep => EntityInit::Sheep,
+        EntityKind::Skeleton => EntityInit::Skeleton,
+        EntityKind::Spider => EntityInit::Spider,
+        EntityKind::Zombie => EntityInit::Zombie,
+        _ => return None,
+    })
+}
+
+/// Spawns the members of `group`, each with its rolled variant.
+fn spawn_group(game: &mut Game, group: &SpawnGroup) -> SysResult {
+    let position = Position {
+        x: f64::from(group.position.x) + 0.5,
+        y: f64::from(group.position.y),
+        z: f64::from(group.position.z) + 0.5,
+        ..Default::default()
+    };
+    for variant in &group.variants {
+        let init = match spawn_init(group.kind) {
+            Some(init) => init,
+            None => return Ok(()),
+        };
+        let mut builder = game.create_entity_builder(position, init);
+        game.resources
+            .get::<EntitySpawnManager>()?
+            .apply_variant(group.kind, &mut builder, *variant);
+        game.spawn_entity(builder);
+    }
+    Ok(())
+}
+
+/// Makes a spawn attempt in each chunk near a player
+/// once every spawn interval.
 fn update_entity_spawning(game: &mut Game) -> SysResult {
-    // This system would handle the timing of spawning attempts
-    // and manage the spawning of entities in loaded chunks
-    
-    // In a real implementation, this would:
-    // 1. Check if enough time has passed since last spawn attempt
-    // 2. Select chunks for spawning attempts
-    // 3. Call the entity spawn manager to try spawning in those chunks
-    
+    if !mob_spawning_enabled(game) {
+        return Ok(());
+    }
+    let due = game
+        .resources
+        .get_mut::<EntitySpawnManager>()
+        .map_or(false, |mut manager| manager.tick());
+    if !due {
+        return Ok(());
+    }
+
+    let groups = game.with_rng(|game, rng| -> SysResult<Vec<SpawnGroup>> {
+        let manager = game.resources.get::<EntitySpawnManager>()?;
+        let mut groups = Vec::new();
+        for chunk in spawning_chunks(game) {
+            if let Some(biome) = chunk_biome(game, chunk) {
+                groups.extend(roll_spawns_in_chunk(game, &manager, biome, chunk, rng));
+            }
+        }
+        Ok(groups)
+    })?;
+    for group in &groups {
+        spawn_group(game, group)?;
+    }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use base::BlockId;
+    use libcraft_blocks::BlockProperties;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::test_support::TestGame;
+
+    use super::*;
+
+    /// A spawn attempt in the plains chunk at the origin
+    fn attempt<F>(view: &dyn BlockView, light_getter: F, difficulty: Difficulty) -> SpawnAttempt<F>
+    where
+        F: Fn(BlockPosition) -> u8,
+    {
+        SpawnAttempt {
+            biome: BiomeId::Plains,
+            chunk_pos: (0, 0),
+            block_view: view,
+            light_getter,
+            difficulty,
+        }
+    }
+
+    fn sheep_manager() -> EntitySpawnManager {
+        let mut manager = EntitySpawnManager::new();
+        manager.register_rule(SpawnRule {
+            entity_kind: EntityKind::Sheep,
+            biomes: vec![BiomeId::Plains],
+            required_blocks: Some(grass_below),
+            difficulty: SpawnDifficulty::Any,
+            ..Default::default()
+        });
+        manager
+    }
+
+    fn spawns_over(ground: BlockKind) -> usize {
+        let manager = sheep_manager();
+        let view = |(_, y, _): (i32, i32, i32)| -> Option<(BlockKind, BlockProperties)> {
+            let kind = if y < 70 { ground } else { BlockKind::Air };
+            Some((kind, kind.default_properties()))
+        };
+
+        let mut spawned = 0;
+        manager.try_spawn_in_chunk(
+            attempt(&view, |_| 15, Difficulty::Normal),
+            &mut StdRng::seed_from_u64(0),
+            |_| spawned += 1,
+        );
+        spawned
+    }
+
+    #[test]
+    fn grass_requirement_passes_over_grass() {
+        assert_eq!(spawns_over(BlockKind::GrassBlock), 1);
+    }
+
+    #[test]
+    fn grass_requirement_fails_over_stone() {
+        assert_eq!(spawns_over(BlockKind::Stone), 0);
+    }
+
+    #[test]
+    fn spawns_stand_on_the_highest_ground() {
+        let manager = EntitySpawnManager::new();
+        let column = |kinds: &'static [(i32, BlockKind)]| {
+            move |(_, y, _): (i32, i32, i32)| -> Option<(BlockKind, BlockProperties)> {
+                let kind = kinds
+                    .iter()
+                    .find(|(below, _)| y < *below)
+                    .map_or(BlockKind::Air, |(_, kind)| *kind);
+                Some((kind, kind.default_properties()))
+            }
+        };
+
+        // A cave under the surface, which spawns don't fall into
+        let hills = column(&[
+            (40, BlockKind::Stone),
+            (50, BlockKind::Air),
+            (72, BlockKind::GrassBlock),
+        ]);
+        assert_eq!(manager.find_spawn_y(3, 5, false, &hills), Some(72));
+        assert_eq!(manager.find_spawn_y(3, 5, true, &hills), None);
+
+        let ocean = column(&[(45, BlockKind::Clay), (62, BlockKind::Water)]);
+        assert_eq!(manager.find_spawn_y(3, 5, true, &ocean), Some(45));
+        assert_eq!(manager.find_spawn_y(3, 5, false, &ocean), None);
+
+        let unloaded = |_: (i32, i32, i32)| -> Option<(BlockKind, BlockProperties)> { None };
+        assert_eq!(manager.find_spawn_y(3, 5, false, &unloaded), None);
+    }
+
+    #[test]
+    fn removed_rules_never_spawn() {
+        let mut manager = EntitySpawnManager::new();
+        register_default_spawn_rules(&mut manager);
+        manager.remove_rules_for(EntityKind::Zombie);
+        assert!(manager
+            .rules()
+            .all(|rule| rule.entity_kind != EntityKind::Zombie));
+
+        let view = |(_, y, _): (i32, i32, i32)| {
+            let kind = if y < 64 { BlockKind::GrassBlock } else { BlockKind::Air };
+            Some((kind, kind.default_properties()))
+        };
+        let mut spawned = Vec::new();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..200 {
+            let spawn_attempt = attempt(&view, |_| 0, Difficulty::Normal);
+            manager.try_spawn_in_chunk(spawn_attempt, &mut rng, |group| spawned.push(group.kind));
+        }
+
+        assert!(!spawned.is_empty());
+        assert!(!spawned.contains(&EntityKind::Zombie));
+    }
+
+    #[test]
+    fn replace_rules_swaps_in_new_rules() {
+        let mut manager = EntitySpawnManager::new();
+        register_default_spawn_rules(&mut manager);
+        manager.replace_rules_for(
+            EntityKind::Sheep,
+            vec![SpawnRule {
+                entity_kind: EntityKind::Sheep,
+                biomes: vec![BiomeId::Desert],
+                ..Default::default()
+            }],
+        );
+
+        let sheep: Vec<_> = manager
+            .rules()
+            .filter(|rule| rule.entity_kind == EntityKind::Sheep)
+            .collect();
+        assert_eq!(sheep.len(), 1);
+        assert_eq!(sheep[0].biomes, vec![BiomeId::Desert]);
+
+        manager.clear_rules();
+        assert_eq!(manager.rules().count(), 0);
+    }
+
+    #[test]
+    fn horse_herd_shares_coat_variant() {
+        let mut manager = EntitySpawnManager::new();
+        register_default_variant_hooks(&mut manager);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let herd = manager.roll_group_variants(EntityKind::Horse, 200, &mut rng);
+
+        let mut counts = AHashMap::new();
+        for variant in &herd {
+            *counts.entry(variant.unwrap()).or_insert(0) += 1;
+        }
+        let shared = counts.values().copied().max().unwrap();
+        // 10% deviation chance, with some slack for the random rolls
+        assert!(shared >= 160, "only {} of 200 horses share a coat", shared);
+    }
+
+    #[test]
+    fn kinds_without_hook_have_no_variant() {
+        let manager = EntitySpawnManager::new();
+        let mut rng = StdRng::seed_from_u64(0);
+        let group = manager.roll_group_variants(EntityKind::Sheep, 3, &mut rng);
+        assert_eq!(group, vec![None; 3]);
+    }
+
+    #[test]
+    fn nearby_mobs_limit_spawns() {
+        let mut manager = EntitySpawnManager::new();
+        manager.register_rule(SpawnRule {
+            entity_kind: EntityKind::Sheep,
+            biomes: vec![BiomeId::Plains],
+            difficulty: SpawnDifficulty::Any,
+            max_nearby: Some(3),
+            nearby_radius: 16.0,
+            ..Default::default()
+        });
+        let mut test = TestGame::builder()
+            .chunks(1)
+            .register(|_, systems| crate::chunk::entities::register(systems))
+            .build();
+        test.fill(
+            BlockPosition::new(0, 69, 0),
+            BlockPosition::new(15, 69, 15),
+            BlockId::grass_block(),
+        );
+        let chunk = ChunkPosition::new(0, 0);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut roll = |game: &Game, manager: &EntitySpawnManager| {
+            roll_spawns_in_chunk(game, manager, BiomeId::Plains, chunk, &mut rng)
+        };
+
+        let spawn = |test: &mut TestGame, kind: EntityKind, x: f64| {
+            let mut builder = EntityBuilder::new();
+            builder.add(kind).add(base::position!(x, 70.0, 8.0));
+            test.game.spawn_entity(builder);
+            test.step();
+        };
+        // Mobs of other kinds and far away sheep don't count
+        spawn(&mut test, EntityKind::Cow, 8.0);
+        spawn(&mut test, EntityKind::Sheep, 8.0);
+        spawn(&mut test, EntityKind::Sheep, 9.0);
+        spawn(&mut test, EntityKind::Sheep, 40.0);
+        assert_eq!(count_nearby(&test.game, EntityKind::Sheep, BlockPosition::new(8, 70, 8), 16.0), 2);
+
+        for _ in 0..20 {
+            let groups = roll(&test.game, &manager);
+            assert_eq!(groups.len(), 1);
+            assert_eq!(groups[0].size(), 1);
+        }
+
+        spawn(&mut test, EntityKind::Sheep, 10.0);
+        for _ in 0..20 {
+            assert!(roll(&test.game, &manager).is_empty());
+        }
+
+        // The mob cap applies on top of the rule's own limit
+        manager.set_mob_cap(Some(2));
+        manager.remove_rules_for(EntityKind::Sheep);
+        manager.register_rule(SpawnRule {
+            entity_kind: EntityKind::Sheep,
+            biomes: vec![BiomeId::Plains],
+            difficulty: SpawnDifficulty::Any,
+            ..Default::default()
+        });
+        assert!(roll(&test.game, &manager).is_empty());
+        manager.set_mob_cap(Some(10));
+        assert_eq!(roll(&test.game, &manager).len(), 1);
+    }
+
+    #[test]
+    fn mobs_spawn_in_chunks_near_players() {
+        let mut test = TestGame::builder()
+            .chunks(1)
+            .register(|game, systems| {
+                game.add_entity_spawn_callback(crate::entities::add_entity_components);
+                register(game, systems);
+            })
+            .build();
+        test.fill(
+            BlockPosition::new(-16, 69, -16),
+            BlockPosition::new(31, 69, 31),
+            BlockId::grass_block(),
+        );
+        {
+            let mut manager = test.game.resources.get_mut::<EntitySpawnManager>().unwrap();
+            manager.clear_rules().register_rule(SpawnRule {
+                entity_kind: EntityKind::Cow,
+                biomes: vec![BiomeId::Plains],
+                difficulty: SpawnDifficulty::Any,
+                ..Default::default()
+            });
+        }
+        let cows = |test: &TestGame| {
+            test.game
+                .ecs
+                .query::<(&EntityKind, &Position)>()
+                .iter()
+                .filter(|(_, (kind, _))| **kind == EntityKind::Cow)
+                .map(|(_, (_, position))| position.chunk())
+                .collect::<Vec<_>>()
+        };
+
+        // Nobody is around to spawn mobs for
+        test.step();
+        assert!(cows(&test).is_empty());
+
+        test.game
+            .ecs
+            .spawn((Player, EntityKind::Player, base::position!(8.0, 70.0, 8.0)));
+        let interval = (Duration::from_secs(5).as_millis() / TICK_DURATION.as_millis()) as u32;
+        test.step_n(interval);
+        let spawned = cows(&test);
+        assert!(spawned.len() >= 9, "only {} cows spawned", spawned.len());
+        assert!(spawned
+            .iter()
+            .all(|chunk| test.game.world.is_chunk_loaded(*chunk)));
+
+        // The next attempt waits for the spawn interval
+        test.step();
+        assert_eq!(cows(&test).len(), spawned.len());
+    }
+
+    #[test]
+    fn no_spawns_without_mob_spawning_rule() {
+        let mut manager = EntitySpawnManager::new();
+        manager.register_rule(SpawnRule {
+            entity_kind: EntityKind::Cow,
+            biomes: vec![BiomeId::Plains],
+            difficulty: SpawnDifficulty::Any,
+            ..Default::default()
+        });
+        let mut test = TestGame::builder().chunks(0).build();
+        test.fill(
+            BlockPosition::new(0, 69, 0),
+            BlockPosition::new(15, 69, 15),
+            BlockId::grass_block(),
+        );
+        let mut game = test.game;
+        let chunk = ChunkPosition::new(0, 0);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut roll = |game: &Game| {
+            roll_spawns_in_chunk(game, &manager, BiomeId::Plains, chunk, &mut rng)
+        };
+        assert_eq!(roll(&game).len(), 1);
+
+        game.insert_resource(GameRules {
+            do_mob_spawning: false,
+            ..Default::default()
+        });
+        for _ in 0..100 {
+            assert!(roll(&game).is_empty());
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Spider;
+use quill_common::{components::Health, entities::Spider};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Spider).add(EntityKind::Spider);
+    builder
+        .add(Spider)
+        .add(EntityKind::Spider)
+        .add(Health::new(16.0));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Squid;
+use quill_common::{components::Health, entities::Squid};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Squid).add(EntityKind::Squid);
+    builder
+        .add(Squid)
+        .add(EntityKind::Squid)
+        .add(Health::new(10.0));
 }
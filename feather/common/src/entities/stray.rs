@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Stray;
+use quill_common::{components::Health, entities::Stray};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Stray).add(EntityKind::Stray);
+    builder
+        .add(Stray)
+        .add(EntityKind::Stray)
+        .add(Health::new(20.0));
 }
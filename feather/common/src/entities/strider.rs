@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Strider;
+use quill_common::{components::Health, entities::Strider};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Strider).add(EntityKind::Strider);
+    builder
+        .add(Strider)
+        .add(EntityKind::Strider)
+        .add(Health::new(20.0));
 }
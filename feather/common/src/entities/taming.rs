@@ -0,0 +1,93 @@
+//! Taming entities and looking up their owners.
+
+use base::{EntityKind, EntityMetadata};
+use ecs::{Entity, SysResult};
+use quill_common::components::Tameable;
+use uuid::Uuid;
+
+use crate::{dye::DyeColor, Game};
+
+use super::{cat, wolf};
+
+/// The color of the collar a tamed wolf or cat wears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollarColor(pub DyeColor);
+
+impl Default for CollarColor {
+    fn default() -> Self {
+        CollarColor(DyeColor::Red)
+    }
+}
+
+impl CollarColor {
+    /// Builds the entity metadata describing the collar
+    /// of an entity of `kind`, if it wears one.
+    pub fn metadata(self, kind: EntityKind) -> Option<EntityMetadata> {
+        let index = match kind {
+            EntityKind::Wolf => wolf::indices::COLLAR_COLOR,
+            EntityKind::Cat => cat::indices::COLLAR_COLOR,
+            _ => return None,
+        };
+        Some(EntityMetadata::new().with(index, i32::from(self.0.id())))
+    }
+}
+
+/// Tames `entity` for `player`, storing the player's UUID as its owner.
+pub fn tame(game: &mut Game, entity: Entity, player: Entity) -> SysResult {
+    let owner = *game.ecs.get::<Uuid>(player)?;
+    let tamed = game.ecs.get::<Tameable>(entity).is_ok();
+    if tamed {
+        game.ecs.get_mut::<Tameable>(entity)?.tame(owner);
+    } else {
+        let mut tameable = Tameable::default();
+        tameable.tame(owner);
+        game.ecs.insert(entity, tameable)?;
+    }
+    Ok(())
+}
+
+/// Finds the owner of a tamed entity, if the owner is online.
+pub fn online_owner(game: &Game, entity: Entity) -> Option<Entity> {
+    let owner = game.ecs.get::<Tameable>(entity).ok()?.owner?;
+    game.ecs
+        .query::<&Uuid>()
+        .iter()
+        .find(|(_, uuid)| **uuid == owner)
+        .map(|(player, _)| player)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+
+    use super::*;
+
+    #[test]
+    fn owner_is_found_by_uuid() {
+        let mut game = Game::new();
+        let alice = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Uuid::from_u128(1)));
+        let bob = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Uuid::from_u128(2)));
+        let wolf = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+
+        tame(&mut game, wolf, alice).unwrap();
+        assert_eq!(online_owner(&game, wolf), Some(alice));
+
+        game.ecs
+            .get_mut::<Tameable>(wolf)
+            .unwrap()
+            .transfer(Uuid::from_u128(2));
+        assert_eq!(online_owner(&game, wolf), Some(bob));
+
+        game.ecs.despawn(bob).unwrap();
+        assert_eq!(online_owner(&game, wolf), None);
+        assert!(game
+            .ecs
+            .get::<Tameable>(wolf)
+            .unwrap()
+            .is_owned_by(Uuid::from_u128(2)));
+    }
+}
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::TraderLlama;
+use quill_common::{components::Health, entities::TraderLlama};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(TraderLlama).add(EntityKind::TraderLlama);
+    builder
+        .add(TraderLlama)
+        .add(EntityKind::TraderLlama)
+        .add(Health::new(22.0));
 }
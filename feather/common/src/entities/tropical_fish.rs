@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::TropicalFish;
+use quill_common::{components::Health, entities::TropicalFish};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(TropicalFish).add(EntityKind::TropicalFish);
+    builder
+        .add(TropicalFish)
+        .add(EntityKind::TropicalFish)
+        .add(Health::new(3.0));
 }
@@ -1,8 +1,17 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Turtle;
+use quill_common::{components::Health, entities::Turtle};
+
+use super::breeding::Breedable;
+
+/// Turtles are bred with seagrass
+pub const FOOD: &[Item] = &[Item::Seagrass];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Turtle).add(EntityKind::Turtle);
+    builder
+        .add(Turtle)
+        .add(EntityKind::Turtle)
+        .add(Health::new(30.0))
+        .add(Breedable::new(FOOD));
 }
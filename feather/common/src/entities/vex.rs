@@ -1,8 +1,8 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Vex;
+use quill_common::{components::Health, entities::Vex};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Vex).add(EntityKind::Vex);
+    builder.add(Vex).add(EntityKind::Vex).add(Health::new(14.0));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Villager;
+use quill_common::{components::Health, entities::Villager};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Villager).add(EntityKind::Villager);
+    builder
+        .add(Villager)
+        .add(EntityKind::Villager)
+        .add(Health::new(20.0));
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Vindicator;
+use quill_common::{components::Health, entities::Vindicator};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Vindicator).add(EntityKind::Vindicator);
+    builder
+        .add(Vindicator)
+        .add(EntityKind::Vindicator)
+        .add(Health::new(24.0));
 }
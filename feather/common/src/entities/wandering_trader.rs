@@ -1,10 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::WanderingTrader;
+use quill_common::{components::Health, entities::WanderingTrader};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder
         .add(WanderingTrader)
-        .add(EntityKind::WanderingTrader);
+        .add(EntityKind::WanderingTrader)
+        .add(Health::new(20.0));
 }
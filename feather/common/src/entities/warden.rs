@@ -0,0 +1,8 @@
+use base::EntityKind;
+use ecs::EntityBuilder;
+use quill_common::entities::Warden;
+
+pub fn build_default(builder: &mut EntityBuilder) {
+    super::build_default(builder);
+    builder.add(Warden).add(EntityKind::Warden);
+}
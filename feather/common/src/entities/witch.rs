@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Witch;
+use quill_common::{components::Health, entities::Witch};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Witch).add(EntityKind::Witch);
+    builder
+        .add(Witch)
+        .add(EntityKind::Witch)
+        .add(Health::new(26.0));
 }
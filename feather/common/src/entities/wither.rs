@@ -1,8 +1,50 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Wither;
+use base::{EntityKind, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{components::Health, entities::Wither, events::EntityCreateEvent};
+
+use crate::{explosion, Game};
+
+/// Power of the explosion a wither sets off when it comes to life
+pub const SPAWN_EXPLOSION_POWER: f32 = 7.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(explode_new_withers);
+}
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Wither).add(EntityKind::Wither);
+    builder
+        .add(Wither)
+        .add(EntityKind::Wither)
+        .add(Health::new(300.0));
+}
+
+/// Sets off the explosion of `wither` coming to life, which only
+/// destroys blocks while the `mobGriefing` rule is on.
+///
+/// Returns the entities hurt by the explosion.
+pub fn explode_on_spawn(game: &mut Game, wither: Entity) -> SysResult<Vec<Entity>> {
+    let position = *game.ecs.get::<Position>(wither)?;
+    let griefing = game.mob_can_grief();
+    explosion::explode(
+        game,
+        position,
+        SPAWN_EXPLOSION_POWER,
+        Some(wither),
+        griefing,
+    )
+}
+
+/// Sets off the explosion of withers that were just summoned.
+fn explode_new_withers(game: &mut Game) -> SysResult {
+    let created: Vec<Entity> = game
+        .ecs
+        .query::<(&Wither, &EntityCreateEvent)>()
+        .iter()
+        .map(|(wither, _)| wither)
+        .collect();
+    for wither in created {
+        explode_on_spawn(game, wither)?;
+    }
+    Ok(())
 }
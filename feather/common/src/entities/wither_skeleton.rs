@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::WitherSkeleton;
+use quill_common::{components::Health, entities::WitherSkeleton};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(WitherSkeleton).add(EntityKind::WitherSkeleton);
+    builder
+        .add(WitherSkeleton)
+        .add(EntityKind::WitherSkeleton)
+        .add(Health::new(20.0));
 }
@@ -1,8 +1,39 @@
 use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::WitherSkull;
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use quill_common::{entities::WitherSkull, events::EntityRemoveEvent};
+
+use crate::{explosion, Game};
+
+/// Power of the explosion of a wither skull
+pub const EXPLOSION_POWER: f32 = 1.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(explode_on_impact);
+}
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder.add(WitherSkull).add(EntityKind::WitherSkull);
 }
+
+/// Blows up the wither skull where it hit, removing it. The blast
+/// only destroys blocks while the `mobGriefing` rule is on.
+pub fn explode(game: &mut Game, wither_skull: Entity) -> SysResult<Vec<Entity>> {
+    explosion::explode_entity(game, wither_skull, EXPLOSION_POWER)
+}
+
+/// Blows up wither skulls that hit a block or anything but a wither.
+fn explode_on_impact(game: &mut Game) -> SysResult {
+    let hit: Vec<Entity> = game
+        .ecs
+        .query::<&WitherSkull>()
+        .iter()
+        .map(|(wither_skull, _)| wither_skull)
+        .filter(|wither_skull| game.ecs.get::<EntityRemoveEvent>(*wither_skull).is_err())
+        .filter(|wither_skull| explosion::projectile_hit(game, *wither_skull, EntityKind::Wither))
+        .collect();
+    for wither_skull in hit {
+        explode(game, wither_skull)?;
+    }
+    Ok(())
+}
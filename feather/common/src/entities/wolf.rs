@@ -1,8 +1,35 @@
-use base::EntityKind;
+use base::{EntityKind, Item};
 use ecs::EntityBuilder;
-use quill_common::entities::Wolf;
+use quill_common::{components::Health, entities::Wolf};
+
+use super::{breeding::Breedable, taming::CollarColor};
+
+/// Metadata indices of wolves, see <https://wiki.vg/Entity_metadata#Wolf>
+pub mod indices {
+    pub const COLLAR_COLOR: u8 = 19;
+}
+
+/// Meat tamed wolves are bred and healed with
+pub const FOOD: &[Item] = &[
+    Item::Beef,
+    Item::CookedBeef,
+    Item::Porkchop,
+    Item::CookedPorkchop,
+    Item::Chicken,
+    Item::CookedChicken,
+    Item::Mutton,
+    Item::CookedMutton,
+    Item::Rabbit,
+    Item::CookedRabbit,
+    Item::RottenFlesh,
+];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Wolf).add(EntityKind::Wolf);
+    builder
+        .add(Wolf)
+        .add(EntityKind::Wolf)
+        .add(Health::new(8.0))
+        .add(CollarColor::default())
+        .add(Breedable::new(FOOD));
 }
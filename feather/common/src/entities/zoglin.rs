@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::Zoglin;
+use quill_common::{components::Health, entities::Zoglin};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Zoglin).add(EntityKind::Zoglin);
+    builder
+        .add(Zoglin)
+        .add(EntityKind::Zoglin)
+        .add(Health::new(40.0));
 }
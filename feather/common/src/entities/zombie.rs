@@ -1,8 +1,247 @@
-use base::EntityKind;
-use ecs::EntityBuilder;
-use quill_common::entities::Zombie;
+use base::{position, EntityKind, Item, ItemStack, Position};
+use ecs::{Entity, EntityBuilder, SysResult, SystemExecutor};
+use libcraft_core::InteractionType;
+use quill_common::{
+    components::Health, entities::Zombie, entity_init::EntityInit, events::InteractEntityEvent,
+};
+use rand::Rng;
+
+use crate::{
+    difficulty::{self, Difficulty},
+    Game,
+};
+
+use super::equipment::{Equipment, EquipmentSlot};
+
+/// Chance for a zombie to spawn as a baby
+pub const BABY_CHANCE: f32 = 0.05;
+/// Chance for a zombie to spawn wearing armor where the
+/// clamped regional difficulty is at its highest
+const ARMOR_CHANCE: f32 = 0.15;
+/// Highest chance a zombie can have to call reinforcements
+const MAX_REINFORCEMENT_CHANCE: f32 = 0.1;
+/// Chance lost by a zombie and its reinforcement when one is called
+const REINFORCEMENT_COST: f32 = 0.05;
+/// Farthest a reinforcement spawns from the zombie, horizontally
+const REINFORCEMENT_RANGE: f64 = 7.0;
+
+/// Armor sets by tier, from the feet up
+const ARMOR_TIERS: [[Item; 4]; 5] = [
+    [
+        Item::LeatherBoots,
+        Item::LeatherLeggings,
+        Item::LeatherChestplate,
+        Item::LeatherHelmet,
+    ],
+    [
+        Item::GoldenBoots,
+        Item::GoldenLeggings,
+        Item::GoldenChestplate,
+        Item::GoldenHelmet,
+    ],
+    [
+        Item::ChainmailBoots,
+        Item::ChainmailLeggings,
+        Item::ChainmailChestplate,
+        Item::ChainmailHelmet,
+    ],
+    [
+        Item::IronBoots,
+        Item::IronLeggings,
+        Item::IronChestplate,
+        Item::IronHelmet,
+    ],
+    [
+        Item::DiamondBoots,
+        Item::DiamondLeggings,
+        Item::DiamondChestplate,
+        Item::DiamondHelmet,
+    ],
+];
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(Zombie).add(EntityKind::Zombie);
+    builder
+        .add(Zombie)
+        .add(EntityKind::Zombie)
+        .add(Health::new(20.0))
+        .add(Equipment::default());
+}
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(reinforce_attacked_zombies);
+}
+
+pub use super::aging::Baby;
+
+/// The armor and weapon a zombie spawned with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZombieLoadout {
+    pub baby: bool,
+    pub armor: Vec<Item>,
+    pub weapon: Option<Item>,
+}
+
+impl ZombieLoadout {
+    /// Whether the zombie wears armor or holds a weapon.
+    pub fn is_armed(&self) -> bool {
+        !self.armor.is_empty() || self.weapon.is_some()
+    }
+
+    /// The zombie's equipment, wearing the armor and holding the weapon.
+    pub fn equipment(&self) -> Equipment {
+        let mut equipment = Equipment::default();
+        for item in self.armor.iter().chain(self.weapon.iter()).copied() {
+            equipment.set(EquipmentSlot::for_item(item), ItemStack::new(item, 1).ok());
+        }
+        equipment
+    }
+}
+
+/// The chance a zombie calls for reinforcements when attacked on hard.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReinforcementChance(pub f32);
+
+/// Rolls the loadout of a zombie spawning where the clamped regional
+/// difficulty is `clamped_difficulty`. Harder regions make armor more
+/// likely, and harder difficulties make fuller sets and weapons more likely.
+pub fn roll_loadout(
+    difficulty: Difficulty,
+    clamped_difficulty: f32,
+    rng: &mut impl Rng,
+) -> ZombieLoadout {
+    let hard = difficulty == Difficulty::Hard;
+    let mut loadout = ZombieLoadout {
+        baby: rng.gen::<f32>() < BABY_CHANCE,
+        ..Default::default()
+    };
+
+    if rng.gen::<f32>() < ARMOR_CHANCE * clamped_difficulty {
+        let mut tier = rng.gen_range(0..2);
+        for _ in 0..3 {
+            if rng.gen::<f32>() < 0.095 {
+                tier += 1;
+            }
+        }
+        let stop_chance = if hard { 0.1 } else { 0.25 };
+        for (i, piece) in ARMOR_TIERS[tier].iter().enumerate() {
+            if i > 0 && rng.gen::<f32>() < stop_chance {
+                break;
+            }
+            loadout.armor.push(*piece);
+        }
+    }
+
+    let weapon_chance = if hard { 0.05 } else { 0.01 };
+    if rng.gen::<f32>() < weapon_chance {
+        loadout.weapon = Some(if rng.gen_range(0..3) == 0 {
+            Item::IronSword
+        } else {
+            Item::IronShovel
+        });
+    }
+    loadout
+}
+
+/// Spawns a zombie at `position`, rolling its loadout from
+/// the regional difficulty there.
+pub fn spawn_zombie(game: &mut Game, position: Position, rng: &mut impl Rng) -> Entity {
+    let difficulty = game
+        .resources
+        .get::<Difficulty>()
+        .map_or(Difficulty::default(), |difficulty| *difficulty);
+    let regional = difficulty::regional_difficulty_at(game, position.block());
+    let loadout = roll_loadout(
+        difficulty,
+        difficulty::clamped_regional_difficulty(regional),
+        rng,
+    );
+
+    let mut builder = game.create_entity_builder(position, EntityInit::Zombie);
+    if loadout.baby {
+        builder.add(Baby);
+    }
+    builder.add(loadout.equipment()).add(ReinforcementChance(
+        rng.gen::<f32>() * MAX_REINFORCEMENT_CHANCE,
+    ));
+    game.spawn_entity(builder)
+}
+
+/// Lets an attacked zombie call for another zombie to help it.
+/// Only happens on hard, and each call makes the next one less likely.
+///
+/// Returns the reinforcement, if one came.
+pub fn call_reinforcements(
+    game: &mut Game,
+    zombie: Entity,
+    rng: &mut impl Rng,
+) -> SysResult<Option<Entity>> {
+    if *game.resources.get::<Difficulty>()? != Difficulty::Hard {
+        return Ok(None);
+    }
+    let chance = game.ecs.get::<ReinforcementChance>(zombie)?.0;
+    if rng.gen::<f32>() >= chance {
+        return Ok(None);
+    }
+
+    let origin = *game.ecs.get::<Position>(zombie)?;
+    let position = position!(
+        origin.x + rng.gen_range(-REINFORCEMENT_RANGE..=REINFORCEMENT_RANGE),
+        origin.y,
+        origin.z + rng.gen_range(-REINFORCEMENT_RANGE..=REINFORCEMENT_RANGE)
+    );
+    let reinforcement = spawn_zombie(game, position, rng);
+    for entity in [zombie, reinforcement].iter().copied() {
+        let mut chance = game.ecs.get_mut::<ReinforcementChance>(entity)?;
+        chance.0 = (chance.0 - REINFORCEMENT_COST).max(0.0);
+    }
+    Ok(Some(reinforcement))
+}
+
+fn reinforce_attacked_zombies(game: &mut Game) -> SysResult {
+    let attacked: Vec<u64> = game
+        .ecs
+        .query::<&InteractEntityEvent>()
+        .iter()
+        .filter(|(_, event)| matches!(event.ty, InteractionType::Attack))
+        .map(|(_, event)| event.target.0)
+        .collect();
+    let zombies: Vec<Entity> = game
+        .ecs
+        .query::<&ReinforcementChance>()
+        .iter()
+        .map(|(zombie, _)| zombie)
+        .filter(|zombie| attacked.contains(&u64::from(zombie.id())))
+        .collect();
+
+    for zombie in zombies {
+        game.with_rng(|game, rng| call_reinforcements(game, zombie, rng))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    fn armed_spawns(clamped_difficulty: f32) -> usize {
+        let mut rng = StdRng::seed_from_u64(0);
+        (0..10_000)
+            .filter(|_| roll_loadout(Difficulty::Normal, clamped_difficulty, &mut rng).is_armed())
+            .count()
+    }
+
+    #[test]
+    fn harder_regions_arm_more_zombies() {
+        let easy = armed_spawns(0.0);
+        let hard = armed_spawns(1.0);
+        assert!(
+            hard > easy * 3,
+            "{} armed zombies in hard regions, {} in easy ones",
+            hard,
+            easy
+        );
+    }
 }
@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::ZombieHorse;
+use quill_common::{components::Health, entities::ZombieHorse};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(ZombieHorse).add(EntityKind::ZombieHorse);
+    builder
+        .add(ZombieHorse)
+        .add(EntityKind::ZombieHorse)
+        .add(Health::new(15.0));
 }
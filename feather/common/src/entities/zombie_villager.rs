@@ -1,8 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::ZombieVillager;
+use quill_common::{components::Health, entities::ZombieVillager};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
-    builder.add(ZombieVillager).add(EntityKind::ZombieVillager);
+    builder
+        .add(ZombieVillager)
+        .add(EntityKind::ZombieVillager)
+        .add(Health::new(20.0));
 }
@@ -1,10 +1,11 @@
 use base::EntityKind;
 use ecs::EntityBuilder;
-use quill_common::entities::ZombifiedPiglin;
+use quill_common::{components::Health, entities::ZombifiedPiglin};
 
 pub fn build_default(builder: &mut EntityBuilder) {
     super::build_default(builder);
     builder
         .add(ZombifiedPiglin)
-        .add(EntityKind::ZombifiedPiglin);
+        .add(EntityKind::ZombifiedPiglin)
+        .add(Health::new(20.0));
 }
@@ -0,0 +1,133 @@
+//! Players using items on entities, like shearing sheep or milking cows.
+//!
+//! Handlers are registered for an entity kind and the item used on it.
+//! Interacting runs the matching handlers until one of them uses the item.
+
+use ahash::AHashMap;
+use base::{EntityKind, Inventory, Item, ItemStack, Position};
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_core::{Hand, InteractionType};
+use libcraft_items::InventorySlot;
+use quill_common::events::InteractEntityEvent;
+
+use crate::{
+    entities::{
+        item,
+        player::{held_item, HotbarSlot},
+    },
+    Game,
+};
+
+/// Uses the `held` item of `player` on `target`,
+/// returning whether the item was used.
+pub type EntityInteractionHandler = fn(
+    game: &mut Game,
+    player: Entity,
+    target: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool>;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(EntityInteractions::default());
+    systems.add_system(interact_with_entities);
+}
+
+/// The handlers for items used on entities.
+#[derive(Default)]
+pub struct EntityInteractions {
+    handlers: AHashMap<(EntityKind, Option<Item>), Vec<EntityInteractionHandler>>,
+}
+
+impl EntityInteractions {
+    /// Registers a handler for `item` used on entities of `kind`.
+    /// A handler for `None` runs whatever item is held, after
+    /// the handlers for the specific item.
+    pub fn register(
+        &mut self,
+        kind: EntityKind,
+        item: Option<Item>,
+        handler: EntityInteractionHandler,
+    ) -> &mut Self {
+        self.handlers.entry((kind, item)).or_default().push(handler);
+        self
+    }
+
+    /// The handlers to try, in order, when `item` is used on an entity of `kind`.
+    pub fn handlers(&self, kind: EntityKind, item: Option<Item>) -> Vec<EntityInteractionHandler> {
+        let specific = item.and_then(|item| self.handlers.get(&(kind, Some(item))));
+        let any = self.handlers.get(&(kind, None));
+        specific.into_iter().chain(any).flatten().copied().collect()
+    }
+}
+
+/// Uses the `held` item of `player` on `target`.
+///
+/// Returns whether a handler used the item.
+pub fn interact(
+    game: &mut Game,
+    player: Entity,
+    target: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let kind = *game.ecs.get::<EntityKind>(target)?;
+    let handlers = game
+        .resources
+        .get::<EntityInteractions>()?
+        .handlers(kind, held.item_kind());
+    for handler in handlers {
+        if handler(game, player, target, held)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Swaps one item of the `held` stack for `product`, dropping
+/// the product at the `player` if the stack had more items.
+pub fn exchange(
+    game: &mut Game,
+    player: Entity,
+    held: &mut InventorySlot,
+    product: ItemStack,
+) -> SysResult {
+    if held.count() == 1 {
+        *held = InventorySlot::Filled(product);
+    } else {
+        let _ = held.try_take(1);
+        let position = *game.ecs.get::<Position>(player)?;
+        item::spawn_item(game, position, product);
+    }
+    Ok(())
+}
+
+fn interact_with_entities(game: &mut Game) -> SysResult {
+    let uses: Vec<(Entity, u64, Hand, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&InteractEntityEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .filter(|(_, (event, _, _))| matches!(event.ty, InteractionType::Interact))
+        .map(|(player, (event, inventory, hotbar_slot))| {
+            (
+                player,
+                event.target.0,
+                event.hand.unwrap_or(Hand::Main),
+                inventory.new_handle(),
+                *hotbar_slot,
+            )
+        })
+        .collect();
+
+    for (player, target_id, hand, inventory, hotbar_slot) in uses {
+        let target = game
+            .ecs
+            .query::<&EntityKind>()
+            .iter()
+            .map(|(entity, _)| entity)
+            .find(|entity| u64::from(entity.id()) == target_id);
+        if let Some(target) = target {
+            let mut held = held_item(&inventory, hotbar_slot, hand);
+            interact(game, player, target, &mut held)?;
+        }
+    }
+    Ok(())
+}
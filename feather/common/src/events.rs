@@ -1,11 +1,16 @@
-use base::{ChunkHandle, ChunkPosition};
+use base::{
+    ChunkHandle, ChunkPosition, EntityMetadata, Item, ItemStack, Position, ValidBlockPosition,
+};
+use ecs::Entity;
+use libcraft_core::{block::Instrument, BlockFace};
 
-use crate::view::View;
+use crate::{view::View, Dimension};
 
 mod block_change;
 mod plugin_message;
 
 pub use block_change::BlockChangeEvent;
+pub use libcraft_blocks::BlockEntityChanged;
 pub use plugin_message::PluginMessageEvent;
 
 /// Event triggered when a player changes their `View`,
@@ -46,6 +51,68 @@ pub struct ChunkCrossEvent {
     pub new_chunk: ChunkPosition,
 }
 
+/// Triggered when a living entity dies.
+#[derive(Debug, Clone)]
+pub struct EntityDeathEvent {
+    /// Experience dropped by the entity
+    pub experience: u32,
+    /// The entity that dealt the killing blow, if any
+    pub killer: Option<Entity>,
+    /// The item the killer held in its main hand
+    pub weapon: Option<ItemStack>,
+}
+
+/// Triggered when something about an entity that clients display
+/// changes, like the color of a sheep's wool.
+#[derive(Debug, Clone)]
+pub struct EntityMetadataChangeEvent {
+    /// The changed metadata entries
+    pub metadata: EntityMetadata,
+}
+
+/// Triggered when something makes a vibration that sculk can detect,
+/// such as a step, a block being placed or an item landing.
+#[derive(Debug, Clone, Copy)]
+pub struct VibrationEvent {
+    pub position: Position,
+}
+
+/// Triggered on a projectile when it hits a block.
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectileHitBlockEvent {
+    pub block: ValidBlockPosition,
+    /// Face of the block that was hit
+    pub face: BlockFace,
+    /// Exact point where the projectile hit
+    pub hit: Position,
+}
+
+/// Triggered when a note block plays a note.
+#[derive(Debug, Clone, Copy)]
+pub struct NotePlayedEvent {
+    pub position: ValidBlockPosition,
+    pub instrument: Instrument,
+    /// Pitch of the note, from 0 to 24 semitones above F#
+    pub note: u8,
+}
+
+/// Triggered on a player who finished eating or drinking an item.
+#[derive(Debug, Clone, Copy)]
+pub struct FoodEatenEvent {
+    pub food: Item,
+}
+
+/// Triggered when an entity goes through a portal and should
+/// be moved to another dimension.
+#[derive(Debug, Clone, Copy)]
+pub struct DimensionChangeRequestEvent {
+    pub entity: Entity,
+    pub dimension: Dimension,
+    /// Where the entity arrives, before a portal is found
+    /// or built there
+    pub position: Position,
+}
+
 /// Triggered when a chunk is loaded.
 #[derive(Debug)]
 pub struct ChunkLoadEvent {
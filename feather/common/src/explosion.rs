@@ -0,0 +1,243 @@
+//! Explosions, which hurt entities and blow up blocks around them.
+
+use std::convert::TryInto;
+
+use base::{
+    position, BlockKind, BlockPosition, EntityKind, ParticleKind, Position, ValidBlockPosition,
+};
+use ecs::{Entity, SysResult};
+use quill_common::components::Health;
+use rand::Rng;
+
+use crate::{
+    combat::{self, DamageType},
+    entities::item,
+    events::ProjectileHitBlockEvent,
+    Game,
+};
+
+/// How close a mob has to be to an exploding projectile to be hit by it
+const PROJECTILE_HIT_DISTANCE: f64 = 1.0;
+/// How much a blast weakens for each block it travels
+const FALLOFF_PER_BLOCK: f32 = 0.75;
+
+/// How well `kind` withstands explosions. Most blocks resist
+/// as much as they are hard; those listed here resist more.
+pub fn blast_resistance(kind: BlockKind) -> f32 {
+    match kind {
+        BlockKind::Stone
+        | BlockKind::Granite
+        | BlockKind::PolishedGranite
+        | BlockKind::Diorite
+        | BlockKind::PolishedDiorite
+        | BlockKind::Andesite
+        | BlockKind::PolishedAndesite
+        | BlockKind::Cobblestone
+        | BlockKind::MossyCobblestone
+        | BlockKind::StoneBricks
+        | BlockKind::MossyStoneBricks
+        | BlockKind::CrackedStoneBricks
+        | BlockKind::ChiseledStoneBricks
+        | BlockKind::Bricks
+        | BlockKind::NetherBricks
+        | BlockKind::RedNetherBricks
+        | BlockKind::Blackstone
+        | BlockKind::PolishedBlackstone
+        | BlockKind::PolishedBlackstoneBricks
+        | BlockKind::IronBlock
+        | BlockKind::GoldBlock
+        | BlockKind::DiamondBlock
+        | BlockKind::EmeraldBlock => 6.0,
+        BlockKind::Basalt | BlockKind::PolishedBasalt => 4.2,
+        BlockKind::EndStone | BlockKind::EndStoneBricks => 9.0,
+        BlockKind::Water | BlockKind::Lava => 100.0,
+        BlockKind::EnderChest => 600.0,
+        BlockKind::Obsidian
+        | BlockKind::CryingObsidian
+        | BlockKind::AncientDebris
+        | BlockKind::NetheriteBlock
+        | BlockKind::RespawnAnchor
+        | BlockKind::EnchantingTable
+        | BlockKind::Anvil
+        | BlockKind::ChippedAnvil
+        | BlockKind::DamagedAnvil => 1200.0,
+        BlockKind::Bedrock
+        | BlockKind::EndPortalFrame
+        | BlockKind::EndPortal
+        | BlockKind::EndGateway
+        | BlockKind::NetherPortal
+        | BlockKind::Barrier
+        | BlockKind::CommandBlock
+        | BlockKind::ChainCommandBlock
+        | BlockKind::RepeatingCommandBlock
+        | BlockKind::StructureBlock
+        | BlockKind::Jigsaw => 3_600_000.0,
+        kind => kind.hardness().max(0.0),
+    }
+}
+
+/// Damage dealt to an entity `distance` blocks from the center
+/// of an explosion with `power`, falling off to nothing at
+/// twice the power.
+pub fn explosion_damage(power: f32, distance: f64) -> f32 {
+    let reach = 2.0 * power;
+    let impact = 1.0 - distance as f32 / reach;
+    if impact <= 0.0 {
+        return 0.0;
+    }
+    ((impact * impact + impact) / 2.0 * 7.0 * reach + 1.0).floor()
+}
+
+/// Sets off an explosion with `power` at `center`, caused by the `source`
+/// entity if there is one.
+///
+/// Entities in reach take explosion damage. If `destroys_blocks`, blocks
+/// within `power` of the center are destroyed unless they withstand
+/// explosions, some dropping as items.
+///
+/// Returns the entities hurt by the explosion.
+pub fn explode(
+    game: &mut Game,
+    center: Position,
+    power: f32,
+    source: Option<Entity>,
+    destroys_blocks: bool,
+) -> SysResult<Vec<Entity>> {
+    game.emit_particle(ParticleKind::ExplosionEmitter, center, 1, 0.0);
+    game.emit_sound("entity.generic.explode", center, 4.0, 0.7);
+
+    let reach = f64::from(2.0 * power);
+    let in_reach: Vec<(Entity, f64)> = game
+        .ecs
+        .query::<(&Position, &Health)>()
+        .iter()
+        .filter(|(entity, _)| Some(*entity) != source)
+        .map(|(entity, (position, _))| (entity, position.distance_to(center)))
+        .filter(|(_, distance)| *distance < reach)
+        .collect();
+    let mut hurt = Vec::new();
+    for (entity, distance) in in_reach {
+        let damage = explosion_damage(power, distance);
+        if combat::apply_damage(game, entity, source, damage, DamageType::Explosion)? > 0.0 {
+            hurt.push(entity);
+        }
+    }
+
+    if destroys_blocks {
+        game.with_rng(|game, rng| destroy_blocks(game, center, power, rng));
+    }
+    Ok(hurt)
+}
+
+/// Blows up the mob or projectile `entity` where it is with `power`,
+/// removing it. Since mobs set these off, the blast only destroys
+/// blocks if [`Game::mob_can_grief`].
+///
+/// Returns the entities hurt by the explosion.
+pub fn explode_entity(game: &mut Game, entity: Entity, power: f32) -> SysResult<Vec<Entity>> {
+    let position = *game.ecs.get::<Position>(entity)?;
+    let griefing = game.mob_can_grief();
+    let hurt = explode(game, position, power, Some(entity), griefing)?;
+    game.remove_entity(entity)?;
+    Ok(hurt)
+}
+
+/// Destroys the blocks the blast of an explosion with `power` at
+/// `center` is still strong enough to break once it reaches them.
+/// The blast varies in strength and weakens with distance, and
+/// each block takes more to break the higher its [`blast_resistance`].
+/// Whether the exploding `projectile` hit a block, or came close enough to
+/// a mob or player to hit it. Mobs of `shooter_kind`, which fire these
+/// projectiles, are not hit by them.
+pub fn projectile_hit(game: &Game, projectile: Entity, shooter_kind: EntityKind) -> bool {
+    if game.ecs.get::<ProjectileHitBlockEvent>(projectile).is_ok() {
+        return true;
+    }
+    let position = match game.ecs.get::<Position>(projectile) {
+        Ok(position) => *position,
+        Err(_) => return false,
+    };
+    let mut targets = game.ecs.query::<(&Position, &EntityKind, &Health)>();
+    let hit = targets.iter().any(|(entity, (target, kind, _))| {
+        entity != projectile
+            && *kind != shooter_kind
+            && target.distance_to(position) < PROJECTILE_HIT_DISTANCE
+    });
+    hit
+}
+
+fn destroy_blocks(game: &mut Game, center: Position, power: f32, rng: &mut impl Rng) {
+    let origin = center.block();
+    let radius = (power * 1.3 / FALLOFF_PER_BLOCK).ceil() as i32;
+    for dx in -radius..=radius {
+        for dy in -radius..=radius {
+            for dz in -radius..=radius {
+                let pos = BlockPosition::new(origin.x + dx, origin.y + dy, origin.z + dz);
+                let corner = pos.position();
+                let distance =
+                    center.distance_to(position!(corner.x + 0.5, corner.y + 0.5, corner.z + 0.5));
+                let pos: ValidBlockPosition = match pos.try_into() {
+                    Ok(pos) => pos,
+                    Err(_) => continue,
+                };
+                let block = match game.block(pos) {
+                    Some(block) => block,
+                    None => continue,
+                };
+                if block.is_air() {
+                    continue;
+                }
+                let kind = block.kind();
+                let strength = power * rng.gen_range(0.7..1.3)
+                    - distance as f32 * FALLOFF_PER_BLOCK
+                    - (blast_resistance(kind) + 0.3) * 0.3;
+                if strength <= 0.0 {
+                    continue;
+                }
+
+                game.break_block(pos);
+                // Larger explosions leave less of what they destroy behind
+                if rng.gen::<f32>() < 1.0 / power {
+                    if let Some(drop) = item::block_drop(kind) {
+                        let position = position!(
+                            f64::from(pos.x()) + 0.5,
+                            f64::from(pos.y()) + 0.5,
+                            f64::from(pos.z()) + 0.5
+                        );
+                        item::spawn_item(game, position, drop);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explosion_damage_falls_off_with_distance() {
+        assert_eq!(explosion_damage(3.0, 0.0), 43.0);
+        assert!(explosion_damage(3.0, 2.0) > explosion_damage(3.0, 4.0));
+        assert_eq!(explosion_damage(3.0, 6.0), 0.0);
+    }
+
+    #[test]
+    fn explosions_break_blocks_they_overpower() {
+        use base::{BlockId, Chunk, ChunkPosition};
+
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let stone: ValidBlockPosition = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        let obsidian: ValidBlockPosition = BlockPosition::new(9, 64, 8).try_into().unwrap();
+        game.set_block(stone, BlockId::stone());
+        game.set_block(obsidian, BlockId::obsidian());
+
+        explode(&mut game, position!(8.5, 65.0, 8.5), 4.0, None, true).unwrap();
+        assert!(game.block(stone).unwrap().is_air());
+        assert_eq!(game.block(obsidian), Some(BlockId::obsidian()));
+    }
+}
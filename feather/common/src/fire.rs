@@ -0,0 +1,313 @@
+//! Entities burning after being set on fire by fire, lava,
+//! fire aspect or daylight.
+
+use std::convert::TryInto;
+
+use base::{BlockKind, EntityKind, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::components::{Health, MovementSpeed};
+
+use crate::{
+    biomes::Precipitation,
+    cauldron,
+    combat::{self, DamageType},
+    entities::equipment::Equipment,
+    Game, Weather, WorldTime,
+};
+
+/// Ticks an entity burns for after touching lava
+pub const LAVA_BURN_TICKS: u32 = 300;
+/// Ticks an entity burns for after standing in fire or the sun
+pub const FIRE_BURN_TICKS: u32 = 160;
+/// Ticks between each point of damage from burning
+const BURN_INTERVAL: u32 = 20;
+/// Damage dealt each tick spent in lava
+const LAVA_DAMAGE: f32 = 4.0;
+/// Damage dealt each tick spent in fire
+const IN_FIRE_DAMAGE: f32 = 1.0;
+/// Sky light of a block open to the sky
+const OPEN_SKY_LIGHT: u8 = 15;
+
+/// Cause of the speed modifier of shivering striders
+pub const SHIVERING_MODIFIER: &str = "shivering";
+/// Speed of a shivering strider, relative to its speed in lava
+const SHIVERING_SPEED: f64 = 0.5;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_burning);
+}
+
+/// Ticks left until a burning entity goes out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Burning {
+    pub ticks_remaining: u32,
+}
+
+/// Marks a strider out of lava, which shivers and slows down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shivering;
+
+/// Whether the mob cannot be set on fire.
+pub fn is_fire_immune(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Blaze
+            | EntityKind::Ghast
+            | EntityKind::MagmaCube
+            | EntityKind::Strider
+            | EntityKind::Wither
+            | EntityKind::WitherSkeleton
+            | EntityKind::ZombifiedPiglin
+            | EntityKind::Zoglin
+            | EntityKind::EnderDragon
+    )
+}
+
+/// Whether the mob catches fire in daylight unless it wears a helmet.
+pub fn burns_in_daylight(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Zombie
+            | EntityKind::ZombieVillager
+            | EntityKind::Drowned
+            | EntityKind::Skeleton
+            | EntityKind::Stray
+            | EntityKind::Phantom
+    )
+}
+
+/// Sets `entity` on fire for at least `ticks`.
+/// Fire-immune mobs do not catch fire.
+pub fn ignite(game: &mut Game, entity: Entity, ticks: u32) -> SysResult {
+    let immune = game
+        .ecs
+        .get::<EntityKind>(entity)
+        .map_or(false, |kind| is_fire_immune(*kind));
+    if immune || ticks == 0 {
+        return Ok(());
+    }
+    let remaining = game
+        .ecs
+        .get::<Burning>(entity)
+        .map_or(0, |burning| burning.ticks_remaining);
+    game.ecs.insert(
+        entity,
+        Burning {
+            ticks_remaining: remaining.max(ticks),
+        },
+    )?;
+    Ok(())
+}
+
+/// Puts out a burning entity.
+pub fn extinguish(game: &mut Game, entity: Entity) {
+    let _ = game.ecs.remove::<Burning>(entity);
+}
+
+/// What an entity is standing in, as far as fire is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Surroundings {
+    Water,
+    /// A cauldron at the given position with water in it
+    Cauldron(ValidBlockPosition),
+    Lava,
+    Fire,
+    Other,
+}
+
+fn surroundings(game: &Game, pos: ValidBlockPosition) -> SysResult<Surroundings> {
+    let kind = match game.block(pos) {
+        Some(block) => block.kind(),
+        None => return Ok(Surroundings::Other),
+    };
+    Ok(match kind {
+        BlockKind::Water | BlockKind::BubbleColumn => Surroundings::Water,
+        BlockKind::Lava => Surroundings::Lava,
+        BlockKind::Fire | BlockKind::SoulFire => Surroundings::Fire,
+        BlockKind::Cauldron => match cauldron::water_level(game, pos)? {
+            0 => Surroundings::Other,
+            _ => Surroundings::Cauldron(pos),
+        },
+        _ => Surroundings::Other,
+    })
+}
+
+/// Sets entities in fire, lava and sunlight on fire, puts out those in
+/// water or rain, and burns those on fire.
+fn update_burning(game: &mut Game) -> SysResult {
+    let weather = game
+        .resources
+        .get::<Weather>()
+        .map_or(Weather::default(), |weather| *weather);
+    let time = game
+        .resources
+        .get::<WorldTime>()
+        .map_or(WorldTime::default(), |time| *time);
+    let daylight = time.sky_darkening(&weather) == 0;
+
+    let living: Vec<(Entity, Position, EntityKind)> = game
+        .ecs
+        .query::<(&Position, &EntityKind, &Health)>()
+        .iter()
+        .map(|(entity, (position, kind, _))| (entity, *position, *kind))
+        .collect();
+    for (entity, position, kind) in living {
+        let pos: ValidBlockPosition = match position.block().try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let surroundings = surroundings(game, pos)?;
+
+        if kind == EntityKind::Strider {
+            shiver(game, entity, surroundings != Surroundings::Lava)?;
+        }
+        if is_fire_immune(kind) {
+            extinguish(game, entity);
+            continue;
+        }
+
+        let open_sky = game.world.sky_light_at(pos) == Some(OPEN_SKY_LIGHT);
+        // Snow and dry biomes don't put fires out
+        let rained_on = open_sky
+            && game.world.biome_at(pos).map_or(weather.raining, |biome| {
+                weather.precipitation_in(biome) == Precipitation::Rain
+            });
+        match surroundings {
+            Surroundings::Water => extinguish(game, entity),
+            // Putting out a fire uses up some of a cauldron's water
+            Surroundings::Cauldron(cauldron_pos) => {
+                if game.ecs.get::<Burning>(entity).is_ok() {
+                    extinguish(game, entity);
+                    let level = cauldron::water_level(game, cauldron_pos)?;
+                    cauldron::set_water_level(game, cauldron_pos, level.saturating_sub(1))?;
+                }
+            }
+            Surroundings::Lava => {
+                combat::apply_damage(game, entity, None, LAVA_DAMAGE, DamageType::Lava)?;
+                ignite(game, entity, LAVA_BURN_TICKS)?;
+            }
+            Surroundings::Fire => {
+                combat::apply_damage(game, entity, None, IN_FIRE_DAMAGE, DamageType::InFire)?;
+                ignite(game, entity, FIRE_BURN_TICKS)?;
+            }
+            Surroundings::Other if rained_on => extinguish(game, entity),
+            Surroundings::Other => {
+                let bare_headed = game
+                    .ecs
+                    .get::<Equipment>(entity)
+                    .map_or(true, |equipment| equipment.helmet.is_none());
+                if open_sky && daylight && bare_headed && burns_in_daylight(kind) {
+                    ignite(game, entity, FIRE_BURN_TICKS)?;
+                }
+            }
+        }
+    }
+
+    let mut burnt = Vec::new();
+    let mut burnt_out = Vec::new();
+    for (entity, burning) in game.ecs.query::<&mut Burning>().iter() {
+        burning.ticks_remaining = burning.ticks_remaining.saturating_sub(1);
+        if burning.ticks_remaining % BURN_INTERVAL == 0 {
+            burnt.push(entity);
+        }
+        if burning.ticks_remaining == 0 {
+            burnt_out.push(entity);
+        }
+    }
+    for entity in burnt {
+        combat::apply_damage(game, entity, None, 1.0, DamageType::Fire)?;
+    }
+    for entity in burnt_out {
+        extinguish(game, entity);
+    }
+    Ok(())
+}
+
+/// Makes a strider shiver and slow down out of lava.
+fn shiver(game: &mut Game, strider: Entity, shivering: bool) -> SysResult {
+    if !shivering {
+        let _ = game.ecs.remove::<Shivering>(strider);
+        return Ok(());
+    }
+    game.ecs.insert(strider, Shivering)?;
+    if let Ok(mut speed) = game.ecs.get_mut::<MovementSpeed>(strider) {
+        speed.set_modifier(SHIVERING_MODIFIER, SHIVERING_SPEED);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, BlockId, BlockPosition, Chunk, ChunkPosition};
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    fn game_with(block: BlockId) -> Game {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let pos = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        game.set_block(pos, block);
+        game
+    }
+
+    fn spawn(game: &mut Game, kind: EntityKind) -> Entity {
+        game.ecs
+            .spawn((position!(0.5, 64.0, 0.5), Health::new(20.0), kind))
+    }
+
+    #[test]
+    fn lava_sets_mobs_on_fire() {
+        let mut game = game_with(BlockId::lava());
+        let cow = spawn(&mut game, EntityKind::Cow);
+        update_burning(&mut game).unwrap();
+
+        assert!(game.ecs.get::<Burning>(cow).is_ok());
+        assert!(game.ecs.get::<Health>(cow).unwrap().current < 20.0);
+    }
+
+    #[test]
+    fn water_puts_out_fire() {
+        let mut game = game_with(BlockId::water());
+        let cow = spawn(&mut game, EntityKind::Cow);
+        ignite(&mut game, cow, LAVA_BURN_TICKS).unwrap();
+        assert!(game.ecs.get::<Burning>(cow).is_ok());
+
+        update_burning(&mut game).unwrap();
+        assert!(game.ecs.get::<Burning>(cow).is_err());
+        assert_eq!(game.ecs.get::<Health>(cow).unwrap().current, 20.0);
+    }
+
+    #[test]
+    fn fire_immune_mobs_do_not_burn() {
+        let mut game = game_with(BlockId::lava());
+        let blaze = spawn(&mut game, EntityKind::Blaze);
+        for _ in 0..BURN_INTERVAL {
+            update_burning(&mut game).unwrap();
+        }
+
+        assert!(game.ecs.get::<Burning>(blaze).is_err());
+        assert_eq!(game.ecs.get::<Health>(blaze).unwrap().current, 20.0);
+    }
+
+    #[test]
+    fn only_burning_mobs_use_up_cauldron_water() {
+        let mut game = game_with(BlockId::air());
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        let mut properties = BlockProperties::new(BlockKind::Cauldron);
+        properties.set("level", "0");
+        game.set_block_properties(pos, &properties);
+        cauldron::set_water_level(&mut game, pos, 3).unwrap();
+        let cow = spawn(&mut game, EntityKind::Cow);
+
+        update_burning(&mut game).unwrap();
+        assert_eq!(cauldron::water_level(&game, pos).unwrap(), 3);
+
+        ignite(&mut game, cow, LAVA_BURN_TICKS).unwrap();
+        update_burning(&mut game).unwrap();
+        assert!(game.ecs.get::<Burning>(cow).is_err());
+        assert_eq!(cauldron::water_level(&game, pos).unwrap(), 2);
+    }
+}
@@ -0,0 +1,177 @@
+//! Powder snow, which entities sink into and freeze in.
+
+use std::convert::TryInto;
+
+use base::{BlockKind, EntityKind, Item, ItemStack, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::components::{Health, MovementSpeed};
+
+use crate::{
+    combat::{self, DamageType},
+    Game,
+};
+
+/// Ticks in powder snow it takes to freeze completely
+pub const FREEZE_THRESHOLD: u32 = 140;
+/// Ticks between each point of damage dealt to frozen entities
+const FREEZE_DAMAGE_INTERVAL: u64 = 40;
+/// Freeze ticks lost each tick out of powder snow
+const THAW_RATE: u32 = 2;
+/// Distance entities sink into powder snow each tick, in blocks
+const SINK_SPEED: f64 = 0.05;
+
+/// Cause of the speed modifier of entities in powder snow
+pub const POWDER_SNOW_MODIFIER: &str = "powder_snow";
+/// Speed of entities wading through powder snow
+const POWDER_SNOW_SPEED: f64 = 0.9;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_freezing);
+}
+
+/// How long an entity has been freezing in powder snow, in ticks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Freezing {
+    pub ticks: u32,
+}
+
+impl Freezing {
+    /// Whether the entity froze completely and takes damage.
+    pub fn is_frozen(&self) -> bool {
+        self.ticks >= FREEZE_THRESHOLD
+    }
+}
+
+/// Whether the mob never freezes.
+pub fn is_freeze_immune(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Stray
+            | EntityKind::PolarBear
+            | EntityKind::SnowGolem
+            | EntityKind::Strider
+            | EntityKind::Wither
+    )
+}
+
+fn is_leather(item: &ItemStack) -> bool {
+    matches!(
+        item.item(),
+        Item::LeatherHelmet | Item::LeatherChestplate | Item::LeatherLeggings | Item::LeatherBoots
+    )
+}
+
+fn in_powder_snow(game: &Game, pos: ValidBlockPosition) -> bool {
+    game.block(pos)
+        .map_or(false, |block| block.kind() == BlockKind::PowderSnow)
+}
+
+/// Sinks entities into powder snow and freezes them.
+/// Leather armor keeps them warm, and leather boots
+/// let them walk on top of the snow.
+fn update_freezing(game: &mut Game) -> SysResult {
+    let living: Vec<(Entity, Position, EntityKind)> = game
+        .ecs
+        .query::<(&Position, &EntityKind, &Health)>()
+        .iter()
+        .map(|(entity, (position, kind, _))| (entity, *position, *kind))
+        .collect();
+
+    let mut frozen = Vec::new();
+    for (entity, position, kind) in living {
+        let pos: ValidBlockPosition = match position.block().try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let in_snow = in_powder_snow(game, pos);
+        let armor = combat::worn_armor(game, entity);
+
+        if in_snow && !armor.iter().any(|piece| piece.item() == Item::LeatherBoots) {
+            let below = position.block().down().try_into();
+            let floor = match below {
+                Ok(below) if in_powder_snow(game, below) => f64::NEG_INFINITY,
+                _ => f64::from(pos.y()),
+            };
+            if let Ok(mut current) = game.ecs.get_mut::<Position>(entity) {
+                current.y = (current.y - SINK_SPEED).max(floor);
+            }
+            if let Ok(mut speed) = game.ecs.get_mut::<MovementSpeed>(entity) {
+                speed.set_modifier(POWDER_SNOW_MODIFIER, POWDER_SNOW_SPEED);
+            }
+        }
+
+        let can_freeze = !is_freeze_immune(kind) && !armor.iter().any(is_leather);
+        let mut freezing = game
+            .ecs
+            .get::<Freezing>(entity)
+            .map_or(Freezing::default(), |freezing| *freezing);
+        freezing.ticks = if in_snow && can_freeze {
+            (freezing.ticks + 1).min(FREEZE_THRESHOLD)
+        } else {
+            freezing.ticks.saturating_sub(THAW_RATE)
+        };
+
+        if freezing.ticks == 0 {
+            let _ = game.ecs.remove::<Freezing>(entity);
+        } else {
+            game.ecs.insert(entity, freezing)?;
+        }
+        if freezing.is_frozen() {
+            frozen.push(entity);
+        }
+    }
+
+    if game.tick_count % FREEZE_DAMAGE_INTERVAL == 0 {
+        for entity in frozen {
+            combat::apply_damage(game, entity, None, 1.0, DamageType::Freezing)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, BlockId, BlockPosition, Chunk, ChunkPosition};
+
+    use crate::entities::equipment::Equipment;
+
+    use super::*;
+
+    #[test]
+    fn powder_snow_freezes_unless_wearing_leather() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let pos = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        game.set_block(pos, BlockId::powder_snow());
+
+        let spawn = |game: &mut Game, equipment: Equipment| {
+            game.ecs.spawn((
+                position!(0.5, 64.5, 0.5),
+                Health::new(20.0),
+                EntityKind::Zombie,
+                equipment,
+            ))
+        };
+        let bare = spawn(&mut game, Equipment::default());
+        let leather = spawn(
+            &mut game,
+            Equipment {
+                chestplate: ItemStack::new(Item::LeatherChestplate, 1).ok(),
+                ..Default::default()
+            },
+        );
+
+        for tick in 1..=u64::from(FREEZE_THRESHOLD) + FREEZE_DAMAGE_INTERVAL {
+            game.tick_count = tick;
+            update_freezing(&mut game).unwrap();
+        }
+
+        assert!(game.ecs.get::<Freezing>(bare).unwrap().is_frozen());
+        assert!(game.ecs.get::<Health>(bare).unwrap().current < 20.0);
+        assert!(game.ecs.get::<Position>(bare).unwrap().y < 64.5);
+        assert!(game.ecs.get::<Freezing>(leather).is_err());
+        assert_eq!(game.ecs.get::<Health>(leather).unwrap().current, 20.0);
+    }
+}
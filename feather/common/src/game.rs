@@ -1,22 +1,44 @@
-use std::{cell::RefCell, mem, rc::Rc, sync::Arc};
+use std::{
+    cell::RefCell, collections::BTreeMap, convert::TryInto, mem, rc::Rc, sync::Arc, time::Duration,
+};
 
-use base::{BlockId, ChunkPosition, Position, Text, Title, ValidBlockPosition};
+use base::{
+    position, BlockId, BlockKind, BlockPosition, ChunkPosition, ItemStack, ParticleKind,
+    Position, Text, Title, ValidBlockPosition,
+};
 use ecs::{
     Ecs, Entity, EntityBuilder, HasEcs, HasResources, NoSuchEntity, Resources, SysResult,
-    SystemExecutor,
+    SystemExecutor, SystemName,
+};
+use libcraft_blocks::{
+    create_block_entity, initialize_block_world_integration, BlockEntityManager,
+    BlockProperties, BlockView, BlockWorldIntegration,
 };
 use quill_common::events::{EntityCreateEvent, EntityRemoveEvent, PlayerJoinEvent};
 use quill_common::{entities::Player, entity_init::EntityInit};
+use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
     chat::{ChatKind, ChatMessage},
     chunk::entities::ChunkEntities,
+    effects::{OutboundEffects, ParticleEmission, SoundEmission},
+    entities::item,
     events::BlockChangeEvent,
-    ChatBox, World,
+    inspection::{BlockEntitySummary, BlockInspection},
+    ChatBox, GameRules, World,
 };
 
 type EntitySpawnCallback = Box<dyn FnMut(&mut EntityBuilder, &EntityInit)>;
 
+const NEIGHBOR_OFFSETS: [(i32, i32, i32); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
 /// Stores the entire state of a Minecraft game.
 ///
 /// This contains:
@@ -49,9 +71,19 @@ pub struct Game {
     /// A spatial index to efficiently find which entities are in a given chunk.
     pub chunk_entities: ChunkEntities,
 
+    /// Block entities (chests, signs, ...) and their data.
+    pub block_entities: BlockEntityManager,
+
+    /// Scheduled and random block ticks.
+    pub block_integration: BlockWorldIntegration,
+
     /// Total ticks elapsed since the server started.
     pub tick_count: u64,
 
+    /// Particles and sounds emitted this tick, waiting
+    /// to be sent to players.
+    pub outbound_effects: OutboundEffects,
+
     entity_spawn_callbacks: Vec<EntitySpawnCallback>,
 
     entity_builder: EntityBuilder,
@@ -63,16 +95,52 @@ impl Default for Game {
     }
 }
 
+/// The time each system took during the last tick, in execution
+/// order, to help find slow systems.
+///
+/// Kept as a resource so systems can read it while the
+/// executor is running.
+#[derive(Debug, Clone, Default)]
+pub struct TickProfile {
+    enabled: bool,
+    systems: Vec<(SystemName, Duration)>,
+}
+
+/// The random number generator gameplay systems draw from.
+///
+/// Kept as a resource so tests can seed it and get the same
+/// results on every run.
+#[derive(Debug, Clone)]
+pub struct GameRng(StdRng);
+
+impl GameRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
 impl Game {
     /// Creates a new, empty `Game`.
     pub fn new() -> Self {
+        let mut resources = Resources::new();
+        resources.insert(TickProfile::default());
+        resources.insert(GameRng::default());
         Self {
             world: World::new(),
             ecs: Ecs::new(),
             system_executor: Rc::new(RefCell::new(SystemExecutor::new())),
-            resources: Arc::new(Resources::new()),
+            resources: Arc::new(resources),
             chunk_entities: ChunkEntities::default(),
+            block_entities: BlockEntityManager::new(),
+            block_integration: initialize_block_world_integration(),
             tick_count: 0,
+            outbound_effects: OutboundEffects::default(),
             entity_spawn_callbacks: Vec::new(),
             entity_builder: EntityBuilder::new(),
         }
@@ -93,6 +161,63 @@ impl Game {
             .insert(resource);
     }
 
+    /// Runs `f` with the game's [`GameRng`], for systems calling
+    /// functions that need both the `Game` and a random number generator.
+    pub fn with_rng<R>(&mut self, f: impl FnOnce(&mut Game, &mut StdRng) -> R) -> R {
+        // Leave a generator seeded from this one in its place,
+        // in case `f` needs random numbers of its own
+        let mut rng = match self.resources.get_mut::<GameRng>() {
+            Ok(mut stored) => {
+                let standin = StdRng::from_rng(&mut stored.0).expect("StdRng never fails");
+                mem::replace(&mut stored.0, standin)
+            }
+            Err(_) => StdRng::from_entropy(),
+        };
+        let result = f(self, &mut rng);
+        if let Ok(mut stored) = self.resources.get_mut::<GameRng>() {
+            stored.0 = rng;
+        }
+        result
+    }
+
+    /// Enables or disables recording how long each system takes per tick,
+    /// starting with the next tick.
+    pub fn set_tick_profiling(&mut self, enabled: bool) {
+        if let Ok(mut profile) = self.resources.get_mut::<TickProfile>() {
+            profile.enabled = enabled;
+            if !enabled {
+                profile.systems.clear();
+            }
+        }
+    }
+
+    /// Gets the time each system took during the last tick, in execution
+    /// order, to help find slow systems.
+    ///
+    /// Empty unless enabled with [`Game::set_tick_profiling`].
+    pub fn tick_profile(&self) -> Vec<(SystemName, Duration)> {
+        self.resources
+            .get::<TickProfile>()
+            .map(|profile| profile.systems.clone())
+            .unwrap_or_default()
+    }
+
+    /// Runs all systems once, recording the [`TickProfile`]
+    /// if profiling is enabled.
+    pub fn run_systems(&mut self) {
+        let systems = Rc::clone(&self.system_executor);
+        let mut systems = systems.borrow_mut();
+        let enabled = self
+            .resources
+            .get::<TickProfile>()
+            .map_or(false, |profile| profile.enabled);
+        systems.set_profiling(enabled);
+        systems.run(self);
+        if let Ok(mut profile) = self.resources.get_mut::<TickProfile>() {
+            profile.systems = systems.profile().to_vec();
+        }
+    }
+
     /// Adds a new entity spawn callback, invoked
     /// before an entity is created.
     ///
@@ -181,22 +306,262 @@ impl Game {
         Ok(())
     }
 
+    /// Whether mobs may change blocks, as set by the `mobGriefing` rule.
+    /// Every mob behavior that modifies blocks checks this first.
+    pub fn mob_can_grief(&self) -> bool {
+        self.resources
+            .get::<GameRules>()
+            .map_or(true, |rules| rules.mob_griefing)
+    }
+
+    /// Shows `count` particles of `kind` at `pos` to nearby players,
+    /// scattered up to `spread` blocks along each axis.
+    pub fn emit_particle(&mut self, kind: ParticleKind, pos: Position, count: i32, spread: f32) {
+        self.outbound_effects.push_particle(ParticleEmission {
+            kind,
+            position: pos,
+            count,
+            spread,
+        });
+    }
+
+    /// Plays `sound` at `pos` for nearby players.
+    pub fn emit_sound(&mut self, sound: impl Into<String>, pos: Position, volume: f32, pitch: f32) {
+        self.outbound_effects.push_sound(SoundEmission {
+            sound: sound.into(),
+            position: pos,
+            volume,
+            pitch,
+        });
+    }
+
     /// Gets the block at the given position.
     pub fn block(&self, pos: ValidBlockPosition) -> Option<BlockId> {
         self.world.block_at(pos)
     }
 
+    /// Gets the block at the given position along with its properties.
+    pub fn block_properties(&self, pos: ValidBlockPosition) -> Option<BlockProperties> {
+        self.block(pos).map(properties_of)
+    }
+
+    /// Collects everything known about the block at the given position,
+    /// for use by debug commands and tooling.
+    ///
+    /// Returns `None` if the position is not loaded.
+    pub fn inspect_block(&self, pos: ValidBlockPosition) -> Option<BlockInspection> {
+        let properties = self.block_properties(pos)?;
+        let kind = properties.kind();
+        let key = (pos.x(), pos.y(), pos.z());
+
+        let block_entity = self.block_entities.get(key).map(|entity| {
+            let mut keys: Vec<String> = entity.data.keys().cloned().collect();
+            keys.sort();
+            BlockEntitySummary {
+                kind: entity.kind.clone(),
+                keys,
+            }
+        });
+        let scheduled_tick = self
+            .block_integration
+            .tick_executor()
+            .scheduler()
+            .pending_tick(key)
+            .cloned();
+
+        Some(BlockInspection {
+            position: pos,
+            kind,
+            name: kind.name(),
+            properties,
+            block_entity,
+            receives_random_ticks: kind.receives_random_ticks(),
+            scheduled_tick,
+        })
+    }
+
     /// Sets the block at the given position.
     ///
-    /// Triggers necessary `BlockChangeEvent`s.
+    /// Triggers necessary `BlockChangeEvent`s, keeps the block tick
+    /// bookkeeping up to date, creates or removes the block entity if
+    /// the kind of block changed, and breaks neighboring blocks which
+    /// lost their support.
     pub fn set_block(&mut self, pos: ValidBlockPosition, block: BlockId) -> bool {
+        let was_successful = self.set_block_without_neighbor_updates(pos, block);
+        if was_successful {
+            self.update_neighbors(pos);
+        }
+        was_successful
+    }
+
+    /// Sets the block at the given position like [`Game::set_block`], but
+    /// leaves its neighbors alone, even if they lost their support.
+    ///
+    /// Used when placing many blocks at once, such as structures, where
+    /// blocks are briefly unsupported until the rest are placed.
+    pub fn set_block_without_neighbor_updates(
+        &mut self,
+        pos: ValidBlockPosition,
+        block: BlockId,
+    ) -> bool {
         let was_successful = self.world.set_block_at(pos, block);
         if was_successful {
             self.ecs.insert_event(BlockChangeEvent::single(pos));
+            let world = &self.world;
+            self.block_integration.on_block_changed(
+                pos,
+                block.kind(),
+                |pos| world.block_at(pos).map(|block| (block.kind(), properties_of(block))),
+                |_, _| {},
+            );
+            self.update_block_entity(pos, block.kind());
         }
         was_successful
     }
 
+    /// Keeps the block entity at `pos` in line with the block there,
+    /// preserving its data while the kind of block stays the same.
+    fn update_block_entity(&mut self, pos: ValidBlockPosition, kind: BlockKind) {
+        let key = (pos.x(), pos.y(), pos.z());
+        if let Some(entity) = self.block_entities.get(key) {
+            if entity.block_kind == kind {
+                return;
+            }
+            self.block_entities.remove(key);
+        }
+        if let Some(entity) = create_block_entity(kind, key) {
+            self.block_entities.set(key, entity);
+        }
+    }
+
+    /// Lets the blocks next to `pos` react to a change there.
+    pub fn update_neighbors(&mut self, pos: ValidBlockPosition) {
+        for (dx, dy, dz) in NEIGHBOR_OFFSETS.iter() {
+            let neighbor = BlockPosition::new(pos.x() + dx, pos.y() + dy, pos.z() + dz);
+            if let Ok(neighbor) = neighbor.try_into() {
+                self.on_neighbor_changed(neighbor);
+            }
+        }
+    }
+
+    /// Places a block, as a player would, if it has the support it needs
+    /// at the given position.
+    ///
+    /// Returns whether the block was placed.
+    pub fn place_block(&mut self, pos: ValidBlockPosition, properties: &BlockProperties) -> bool {
+        properties.can_be_placed_at((pos.x(), pos.y(), pos.z()), &*self)
+            && self.set_block_properties(pos, properties)
+    }
+
+    /// Called when a block next to `pos` changed. Breaks the block at `pos`
+    /// if it lost its support, dropping it as an item.
+    pub fn on_neighbor_changed(&mut self, pos: ValidBlockPosition) {
+        let properties = match self.block_properties(pos) {
+            Some(properties) => properties,
+            None => return,
+        };
+        if properties.can_be_placed_at((pos.x(), pos.y(), pos.z()), &*self) {
+            return;
+        }
+
+        self.break_block(pos);
+        if let Some(drop) = item::block_drop(properties.kind()) {
+            let position = position!(
+                f64::from(pos.x()) + 0.5,
+                f64::from(pos.y()) + 0.5,
+                f64::from(pos.z()) + 0.5
+            );
+            item::spawn_item(self, position, drop);
+        }
+    }
+
+    /// Sets the block at the given position from its kind and properties.
+    ///
+    /// Returns `false` if the properties do not describe a valid block
+    /// or the position is not loaded.
+    pub fn set_block_properties(
+        &mut self,
+        pos: ValidBlockPosition,
+        properties: &BlockProperties,
+    ) -> bool {
+        let identifier = format!("minecraft:{}", properties.kind().name());
+        let map: BTreeMap<String, String> = properties
+            .all()
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        match BlockId::from_identifier_and_properties(&identifier, &map) {
+            Some(block) => self.set_block(pos, block),
+            None => false,
+        }
+    }
+
+    /// Runs the block ticks due this tick: scheduled ticks, such as
+    /// buttons popping back out, and random ticks in loaded chunks,
+    /// such as grass spreading and saplings growing. Applies the block
+    /// changes they make and spawns the items they drop.
+    pub fn run_block_ticks(&mut self) {
+        let loaded: Vec<ChunkPosition> = self
+            .world
+            .chunk_map()
+            .iter_chunks()
+            .into_iter()
+            .map(|chunk| chunk.read().position())
+            .collect();
+        let world = &self.world;
+        let mut changes = Vec::new();
+        self.block_integration.update(
+            |pos| world.block_at(pos).map(|block| (block.kind(), properties_of(block))),
+            |pos| world.sky_light_at(pos).unwrap_or(0),
+            |pos, properties| changes.push((pos, properties)),
+            &loaded,
+        );
+        self.apply_tick_changes(changes);
+    }
+
+    /// Applies bone meal to the block at `pos`, e.g. to help
+    /// a sapling grow.
+    ///
+    /// Returns whether the bone meal was used up.
+    pub fn apply_bone_meal(&mut self, pos: ValidBlockPosition) -> bool {
+        let world = &self.world;
+        let mut changes = Vec::new();
+        let used = self.block_integration.tick_executor_mut().apply_bone_meal(
+            (pos.x(), pos.y(), pos.z()),
+            |(x, y, z)| {
+                let pos: ValidBlockPosition = BlockPosition::new(x, y, z).try_into().ok()?;
+                let block = world.block_at(pos)?;
+                Some((block.kind(), properties_of(block)))
+            },
+            |(x, y, z), properties| {
+                if let Ok(pos) = BlockPosition::new(x, y, z).try_into() {
+                    changes.push((pos, properties));
+                }
+            },
+        );
+        self.apply_tick_changes(changes);
+        used
+    }
+
+    /// Sets the blocks changed by block ticks, then spawns
+    /// the items the ticked blocks dropped.
+    fn apply_tick_changes(&mut self, changes: Vec<(ValidBlockPosition, BlockProperties)>) {
+        for (pos, properties) in changes {
+            self.set_block_properties(pos, &properties);
+        }
+        let drops = self.block_integration.tick_executor_mut().take_drops();
+        for ((x, y, z), drop) in drops {
+            if let Ok(stack) = ItemStack::new(drop, 1) {
+                let position = position!(
+                    f64::from(x) + 0.5,
+                    f64::from(y) + 0.5,
+                    f64::from(z) + 0.5
+                );
+                item::spawn_item(self, position, stack);
+            }
+        }
+    }
+
     /// Fills the given chunk section (16x16x16 blocks).
     ///
     /// All blocks in the chunk section are overwritten with `block`.
@@ -221,6 +586,7 @@ impl Game {
             chunk_pos,
             section_y as u32,
         ));
+        self.block_integration.mark_chunk_dirty(chunk_pos);
 
         true
     }
@@ -232,6 +598,22 @@ impl Game {
     }
 }
 
+pub(crate) fn properties_of(block: BlockId) -> BlockProperties {
+    let mut properties = BlockProperties::new(block.kind());
+    for (name, value) in block.to_properties_map() {
+        properties.set(name, value);
+    }
+    properties
+}
+
+impl BlockView for Game {
+    fn block_at(&self, (x, y, z): (i32, i32, i32)) -> Option<(BlockKind, BlockProperties)> {
+        let pos: ValidBlockPosition = BlockPosition::new(x, y, z).try_into().ok()?;
+        self.block_properties(pos)
+            .map(|properties| (properties.kind(), properties))
+    }
+}
+
 impl HasResources for Game {
     fn resources(&self) -> Arc<Resources> {
         Arc::clone(&self.resources)
@@ -247,3 +629,128 @@ impl HasEcs for Game {
         &mut self.ecs
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idle(_game: &mut Game) -> SysResult {
+        Ok(())
+    }
+
+    fn busy(game: &mut Game) -> SysResult {
+        for _ in 0..1000 {
+            game.tick_count = game.tick_count.wrapping_add(1);
+        }
+        Ok(())
+    }
+
+    /// Records the length of the tick profile it sees into `tick_count`.
+    fn read_profile(game: &mut Game) -> SysResult {
+        game.tick_count = game.tick_profile().len() as u64;
+        Ok(())
+    }
+
+    #[test]
+    fn tick_profile_has_entry_per_system() {
+        let mut game = Game::new();
+        {
+            let mut systems = game.system_executor.borrow_mut();
+            systems.add_system(idle).add_system(busy);
+            systems.add_system_with_name(idle, "named_idle");
+        }
+
+        game.run_systems();
+        assert!(game.tick_profile().is_empty());
+
+        game.set_tick_profiling(true);
+        game.run_systems();
+        let profile = game.tick_profile();
+        assert_eq!(profile.len(), 3);
+        assert!(profile[0].0.ends_with("idle"));
+        assert!(profile[1].0.ends_with("busy"));
+        assert_eq!(profile[2].0, "named_idle");
+
+        game.set_tick_profiling(false);
+        assert!(game.tick_profile().is_empty());
+        game.run_systems();
+        assert!(game.tick_profile().is_empty());
+    }
+
+    #[test]
+    fn systems_can_read_tick_profile_while_running() {
+        let mut game = Game::new();
+        game.system_executor
+            .borrow_mut()
+            .add_system(idle)
+            .add_system(read_profile);
+        game.set_tick_profiling(true);
+
+        game.run_systems();
+        // Nothing was recorded before the first tick
+        assert_eq!(game.tick_count, 0);
+        game.run_systems();
+        assert_eq!(game.tick_count, 2);
+    }
+
+    fn pos(x: i32, y: i32, z: i32) -> ValidBlockPosition {
+        BlockPosition::new(x, y, z).try_into().unwrap()
+    }
+
+    #[test]
+    fn torch_needs_support() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        game.set_block(pos(0, 63, 0), BlockId::stone());
+        game.set_block_properties(pos(1, 63, 0), &BlockKind::OakFence.default_properties());
+
+        let torch = BlockKind::Torch.default_properties();
+        assert!(!game.place_block(pos(2, 64, 0), &torch));
+        assert_eq!(game.block(pos(2, 64, 0)), Some(BlockId::air()));
+        assert!(game.place_block(pos(0, 64, 0), &torch));
+        assert_eq!(game.block(pos(0, 64, 0)).unwrap().kind(), BlockKind::Torch);
+        assert!(game.place_block(pos(1, 64, 0), &torch));
+        assert_eq!(game.block(pos(1, 64, 0)).unwrap().kind(), BlockKind::Torch);
+
+        // Rails need the full top face the fence lacks
+        let rail = BlockKind::Rail.default_properties();
+        game.break_block(pos(1, 64, 0));
+        assert!(!game.place_block(pos(1, 64, 0), &rail));
+    }
+
+    #[test]
+    fn placing_without_updates_keeps_unsupported_blocks() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        game.set_block(pos(0, 63, 0), BlockId::stone());
+        assert!(game.place_block(pos(0, 64, 0), &BlockKind::Torch.default_properties()));
+
+        assert!(game.set_block_without_neighbor_updates(pos(0, 63, 0), BlockId::air()));
+        assert_eq!(game.block(pos(0, 64, 0)).unwrap().kind(), BlockKind::Torch);
+        assert_eq!(game.ecs.query::<&base::ItemStack>().iter().count(), 0);
+    }
+
+    #[test]
+    fn removing_support_breaks_torch() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        game.set_block(pos(0, 63, 0), BlockId::stone());
+        assert!(game.place_block(pos(0, 64, 0), &BlockKind::Torch.default_properties()));
+
+        game.break_block(pos(0, 63, 0));
+        assert_eq!(game.block(pos(0, 64, 0)), Some(BlockId::air()));
+        let drops: Vec<base::Item> = game
+            .ecs
+            .query::<&base::ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.item())
+            .collect();
+        assert_eq!(drops, vec![base::Item::Torch]);
+    }
+}
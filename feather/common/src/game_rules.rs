@@ -0,0 +1,97 @@
+//! Game rules, the world settings that switch gameplay
+//! mechanics on and off or tune them.
+
+use anyhow::{anyhow, bail};
+
+use crate::Game;
+
+pub fn register(game: &mut Game) {
+    game.insert_resource(GameRules::default());
+}
+
+/// The game rules of the world.
+///
+/// Systems read the typed fields. [`GameRules::get`] and
+/// [`GameRules::set`] address rules by their vanilla names,
+/// as commands and saved worlds do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameRules {
+    /// Whether the time of day advances
+    pub do_daylight_cycle: bool,
+    /// Whether mobs spawn naturally
+    pub do_mob_spawning: bool,
+    /// Whether players keep their inventory when they die
+    pub keep_inventory: bool,
+    /// Whether mobs can change blocks, like creepers blowing up terrain
+    pub mob_griefing: bool,
+    /// Random ticks each chunk section receives per tick
+    pub random_tick_speed: u32,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            do_daylight_cycle: true,
+            do_mob_spawning: true,
+            keep_inventory: false,
+            mob_griefing: true,
+            random_tick_speed: 3,
+        }
+    }
+}
+
+impl GameRules {
+    /// The value of the rule called `name`, or `None` if there is no such rule.
+    pub fn get(&self, name: &str) -> Option<String> {
+        let value = match name {
+            "doDaylightCycle" => self.do_daylight_cycle.to_string(),
+            "doMobSpawning" => self.do_mob_spawning.to_string(),
+            "keepInventory" => self.keep_inventory.to_string(),
+            "mobGriefing" => self.mob_griefing.to_string(),
+            "randomTickSpeed" => self.random_tick_speed.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Sets the rule called `name`, parsing `value` as the type of the rule.
+    pub fn set(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        let flag = || {
+            value
+                .parse::<bool>()
+                .map_err(|_| anyhow!("{} is not true or false", value))
+        };
+        match name {
+            "doDaylightCycle" => self.do_daylight_cycle = flag()?,
+            "doMobSpawning" => self.do_mob_spawning = flag()?,
+            "keepInventory" => self.keep_inventory = flag()?,
+            "mobGriefing" => self.mob_griefing = flag()?,
+            "randomTickSpeed" => {
+                self.random_tick_speed = value
+                    .parse()
+                    .map_err(|_| anyhow!("{} is not a whole number", value))?
+            }
+            _ => bail!("unknown game rule {}", name),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_are_set_by_name() {
+        let mut rules = GameRules::default();
+        rules.set("mobGriefing", "false").unwrap();
+        rules.set("randomTickSpeed", "10").unwrap();
+        assert!(!rules.mob_griefing);
+        assert_eq!(rules.random_tick_speed, 10);
+        assert_eq!(rules.get("randomTickSpeed").as_deref(), Some("10"));
+
+        assert!(rules.set("doMobSpawning", "sometimes").is_err());
+        assert!(rules.set("doTraderSpawning", "false").is_err());
+        assert_eq!(rules.get("doTraderSpawning"), None);
+    }
+}
@@ -0,0 +1,59 @@
+//! Natural healing of mobs. Players heal by being well fed
+//! instead, see the [`hunger`](crate::hunger) module.
+
+use base::EntityKind;
+use ecs::{SysResult, SystemExecutor};
+use quill_common::components::Health;
+
+use crate::{entities, Game};
+
+/// Ticks between each point of health passive mobs regenerate
+pub const PASSIVE_REGEN_INTERVAL: u64 = 100;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(regenerate_passive_mobs);
+}
+
+/// Whether the mob slowly heals over time on its own.
+pub fn regenerates(kind: EntityKind) -> bool {
+    kind != EntityKind::Player && !entities::is_monster(kind)
+}
+
+fn regenerate_passive_mobs(game: &mut Game) -> SysResult {
+    if game.tick_count % PASSIVE_REGEN_INTERVAL != 0 {
+        return Ok(());
+    }
+    for (_, (health, kind)) in game.ecs.query::<(&mut Health, &EntityKind)>().iter() {
+        if regenerates(*kind) {
+            health.heal(1.0);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passive_mobs_regenerate_up_to_max() {
+        let mut game = Game::new();
+        let wounded = Health {
+            current: 7.5,
+            ..Health::new(10.0)
+        };
+        let cow = game.ecs.spawn((EntityKind::Cow, wounded));
+        let zombie = game.ecs.spawn((EntityKind::Zombie, wounded));
+
+        let mut previous = wounded.current;
+        for interval in 1..=5 {
+            game.tick_count = PASSIVE_REGEN_INTERVAL * interval;
+            regenerate_passive_mobs(&mut game).unwrap();
+            let current = game.ecs.get::<Health>(cow).unwrap().current;
+            assert!(current >= previous);
+            previous = current;
+        }
+        assert_eq!(game.ecs.get::<Health>(cow).unwrap().current, 10.0);
+        assert_eq!(game.ecs.get::<Health>(zombie).unwrap().current, 7.5);
+    }
+}
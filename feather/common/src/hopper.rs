@@ -0,0 +1,238 @@
+//! Hoppers, which push items into the container they face
+//! and pull items from the container above them.
+//!
+//! Furnaces are special cased like in vanilla: hoppers above them fill
+//! the ingredient slot, hoppers to their side fill the fuel slot and
+//! hoppers below them empty the output slot.
+
+use std::convert::TryInto;
+
+use base::{Area, BlockKind, BlockPosition, Inventory, Item, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::BlockEntityKind;
+use libcraft_items::InventorySlot;
+
+use crate::{block_entities::block_inventory, Game};
+
+/// Ticks between two item transfers of a hopper
+const TRANSFER_INTERVAL: u64 = 8;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(transfer_hopper_items);
+}
+
+/// Whether the item burns in a furnace's fuel slot
+fn is_fuel(item: Item) -> bool {
+    let name = item.name();
+    let wooden = ["planks", "_log", "_wood", "_sapling"]
+        .iter()
+        .any(|wood| name.ends_with(wood));
+    // Nether wood does not burn
+    let nether = name.starts_with("crimson") || name.starts_with("warped");
+    (wooden && !nether)
+        || matches!(
+            item,
+            Item::Coal
+                | Item::Charcoal
+                | Item::CoalBlock
+                | Item::LavaBucket
+                | Item::BlazeRod
+                | Item::Stick
+                | Item::DriedKelpBlock
+        )
+}
+
+fn is_furnace(game: &Game, pos: ValidBlockPosition) -> bool {
+    game.block_entities
+        .get((pos.x(), pos.y(), pos.z()))
+        .map_or(false, |entity| entity.kind == BlockEntityKind::Furnace)
+}
+
+fn slots(inventory: &Inventory, area: Area) -> impl Iterator<Item = usize> + '_ {
+    (0..).take_while(move |slot| inventory.item(area, *slot).is_some())
+}
+
+/// Moves a single item accepted by `accepts` from an area of
+/// one inventory to an area of another.
+///
+/// Returns whether an item was moved.
+fn move_item(
+    (from, from_area): (&Inventory, Area),
+    (to, to_area): (&Inventory, Area),
+    accepts: impl Fn(&InventorySlot) -> bool,
+) -> bool {
+    for from_slot in slots(from, from_area) {
+        let mut source = from.item(from_area, from_slot).unwrap();
+        if source.is_empty() || !accepts(&source) {
+            continue;
+        }
+        for to_slot in slots(to, to_area) {
+            let mut target = to.item(to_area, to_slot).unwrap();
+            let count = target.count();
+            source.transfer_to(1, &mut target);
+            if target.count() > count {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Offset from a hopper to the block it pushes items into
+fn facing_offset(facing: &str) -> (i32, i32, i32) {
+    match facing {
+        "north" => (0, 0, -1),
+        "south" => (0, 0, 1),
+        "west" => (-1, 0, 0),
+        "east" => (1, 0, 0),
+        _ => (0, -1, 0),
+    }
+}
+
+fn offset(pos: ValidBlockPosition, (dx, dy, dz): (i32, i32, i32)) -> Option<ValidBlockPosition> {
+    BlockPosition::new(pos.x() + dx, pos.y() + dy, pos.z() + dz)
+        .try_into()
+        .ok()
+}
+
+/// Pushes an item from the hopper at `pos` into the container it faces.
+///
+/// Returns whether an item was moved.
+pub fn push_item(game: &Game, pos: ValidBlockPosition) -> bool {
+    let (hopper, facing) = match (block_inventory(game, pos), game.block_properties(pos)) {
+        (Some(hopper), Some(properties)) => (
+            hopper,
+            facing_offset(properties.get("facing").map_or("down", String::as_str)),
+        ),
+        _ => return false,
+    };
+    let target_pos = match offset(pos, facing) {
+        Some(target_pos) => target_pos,
+        None => return false,
+    };
+    let target = match block_inventory(game, target_pos) {
+        Some(target) => target,
+        None => return false,
+    };
+
+    let from = (&hopper, Area::Storage);
+    if !is_furnace(game, target_pos) {
+        move_item(from, (&target, Area::Storage), |_| true)
+    } else if facing == (0, -1, 0) {
+        move_item(from, (&target, Area::FurnaceIngredient), |_| true)
+    } else {
+        move_item(from, (&target, Area::FurnaceFuel), |slot| {
+            slot.item_kind().map_or(false, is_fuel)
+        })
+    }
+}
+
+/// Pulls an item into the hopper at `pos` from the container above it.
+///
+/// Returns whether an item was moved.
+pub fn pull_item(game: &Game, pos: ValidBlockPosition) -> bool {
+    let hopper = match block_inventory(game, pos) {
+        Some(hopper) => hopper,
+        None => return false,
+    };
+    let source_pos = match offset(pos, (0, 1, 0)) {
+        Some(source_pos) => source_pos,
+        None => return false,
+    };
+    let source = match block_inventory(game, source_pos) {
+        Some(source) => source,
+        None => return false,
+    };
+
+    let area = if is_furnace(game, source_pos) {
+        Area::FurnaceOutput
+    } else {
+        Area::Storage
+    };
+    move_item((&source, area), (&hopper, Area::Storage), |_| true)
+}
+
+/// Moves items through enabled hoppers, first pushing
+/// out of each hopper and then pulling into it.
+fn transfer_hopper_items(game: &mut Game) -> SysResult {
+    if game.tick_count % TRANSFER_INTERVAL != 0 {
+        return Ok(());
+    }
+
+    let hoppers: Vec<(i32, i32, i32)> = game
+        .block_entities
+        .iter()
+        .filter(|entity| entity.kind == BlockEntityKind::Hopper)
+        .map(|entity| entity.position)
+        .collect();
+    for (x, y, z) in hoppers {
+        let pos = match BlockPosition::new(x, y, z).try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let enabled = match game.block_properties(pos) {
+            Some(properties) if properties.kind() == BlockKind::Hopper => properties
+                .get("enabled")
+                .map_or(true, |enabled| enabled == "true"),
+            _ => false,
+        };
+        if enabled {
+            push_item(game, pos);
+            pull_item(game, pos);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::ChunkPosition;
+    use libcraft_blocks::BlockProperties;
+
+    use crate::block_entities::BlockInventories;
+
+    use super::*;
+
+    fn pos(x: i32, y: i32, z: i32) -> ValidBlockPosition {
+        BlockPosition::new(x, y, z).try_into().unwrap()
+    }
+
+    fn hopper(facing: &str) -> BlockProperties {
+        let mut hopper = BlockProperties::new(BlockKind::Hopper);
+        hopper.set("enabled", "true").set("facing", facing);
+        hopper
+    }
+
+    #[test]
+    fn hoppers_fill_and_empty_furnaces() {
+        let mut game = Game::new();
+        game.insert_resource(BlockInventories::default());
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let mut furnace = BlockProperties::new(BlockKind::Furnace);
+        furnace.set("facing", "north").set("lit", "false");
+        assert!(game.set_block_properties(pos(0, 65, 0), &hopper("down")));
+        assert!(game.set_block_properties(pos(0, 64, 0), &furnace));
+        assert!(game.set_block_properties(pos(0, 63, 0), &hopper("down")));
+
+        let above = block_inventory(&game, pos(0, 65, 0)).unwrap();
+        let furnace = block_inventory(&game, pos(0, 64, 0)).unwrap();
+        let below = block_inventory(&game, pos(0, 63, 0)).unwrap();
+        *above.item(Area::Storage, 0).unwrap() = InventorySlot::new(Item::IronOre, 2);
+        *furnace.item(Area::FurnaceOutput, 0).unwrap() = InventorySlot::new(Item::IronIngot, 3);
+
+        transfer_hopper_items(&mut game).unwrap();
+        assert_eq!(
+            *furnace.item(Area::FurnaceIngredient, 0).unwrap(),
+            InventorySlot::new(Item::IronOre, 1)
+        );
+        assert!(furnace.item(Area::FurnaceFuel, 0).unwrap().is_empty());
+        assert_eq!(above.item(Area::Storage, 0).unwrap().count(), 1);
+        assert_eq!(
+            *below.item(Area::Storage, 0).unwrap(),
+            InventorySlot::new(Item::IronIngot, 1)
+        );
+        assert_eq!(furnace.item(Area::FurnaceOutput, 0).unwrap().count(), 2);
+    }
+}
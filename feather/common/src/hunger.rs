@@ -0,0 +1,262 @@
+//! Player hunger: food points and saturation restored by eating
+//! and used up by exhaustion from actions. A well fed player regains
+//! health, while a starving one takes damage.
+
+use base::Position;
+use ecs::{SysResult, SystemExecutor};
+use libcraft_core::InteractionType;
+use quill_common::{
+    components::{Health, OnGround, Sprinting},
+    events::InteractEntityEvent,
+};
+
+use crate::{
+    combat::{self, DamageType},
+    difficulty::Difficulty,
+    Game,
+};
+
+/// Food level of a player who is not hungry at all
+pub const MAX_FOOD: u8 = 20;
+/// Exhaustion that uses up a point of saturation or food
+const EXHAUSTION_PER_POINT: f32 = 4.0;
+
+/// Exhaustion per meter sprinted
+pub const SPRINT_EXHAUSTION: f32 = 0.1;
+/// Exhaustion per jump
+pub const JUMP_EXHAUSTION: f32 = 0.05;
+/// Exhaustion per jump while sprinting
+pub const SPRINT_JUMP_EXHAUSTION: f32 = 0.2;
+/// Exhaustion per attack
+pub const ATTACK_EXHAUSTION: f32 = 0.1;
+/// Exhaustion per point of health regained
+pub const REGEN_EXHAUSTION: f32 = 6.0;
+
+/// Food needed to regenerate health
+const REGEN_FOOD: u8 = 18;
+/// Ticks between regaining health or taking starvation damage
+const HUNGER_INTERVAL: u64 = 80;
+/// Ticks between regaining health from saturation when fully fed
+const SATURATED_REGEN_INTERVAL: u64 = 10;
+/// Most saturation spent at once when regenerating while fully fed
+const SATURATED_REGEN_COST: f32 = 6.0;
+/// Health of a player at full health
+pub const MAX_HEALTH: f32 = 20.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems
+        .add_system(exhaust_moving_players)
+        .add_system(exhaust_attacking_players)
+        .add_system(regenerate_and_starve);
+}
+
+/// A player's hunger.
+///
+/// Saturation is used up before food and can never exceed it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hunger {
+    pub food: u8,
+    pub saturation: f32,
+    pub exhaustion: f32,
+}
+
+impl Default for Hunger {
+    fn default() -> Self {
+        Self {
+            food: MAX_FOOD,
+            saturation: 5.0,
+            exhaustion: 0.0,
+        }
+    }
+}
+
+impl Hunger {
+    /// Whether the player has room to eat
+    pub fn is_hungry(&self) -> bool {
+        self.food < MAX_FOOD
+    }
+
+    /// Restores `food` points and saturation proportional to
+    /// `saturation_modifier`, as when eating a food item.
+    pub fn eat(&mut self, food: u8, saturation_modifier: f32) {
+        self.food = (self.food + food).min(MAX_FOOD);
+        self.saturation = (self.saturation + f32::from(food) * saturation_modifier * 2.0)
+            .min(f32::from(self.food));
+    }
+
+    /// Adds exhaustion from an action. Each time enough exhaustion
+    /// builds up, a point of saturation is lost, or a point of food
+    /// once saturation has run out.
+    pub fn exhaust(&mut self, exhaustion: f32) {
+        self.exhaustion += exhaustion;
+        while self.exhaustion >= EXHAUSTION_PER_POINT {
+            self.exhaustion -= EXHAUSTION_PER_POINT;
+            if self.saturation > 0.0 {
+                self.saturation = (self.saturation - 1.0).max(0.0);
+            } else {
+                self.food = self.food.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// Where a player was last tick, telling how far they
+/// moved and whether they just jumped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LastMovement {
+    position: Option<Position>,
+    on_ground: bool,
+}
+
+fn exhaust_moving_players(game: &mut Game) -> SysResult {
+    for (_, (hunger, last, position, on_ground, sprinting)) in game
+        .ecs
+        .query::<(
+            &mut Hunger,
+            &mut LastMovement,
+            &Position,
+            &OnGround,
+            &Sprinting,
+        )>()
+        .iter()
+    {
+        if let Some(last_position) = last.position {
+            let (dx, dz) = (position.x - last_position.x, position.z - last_position.z);
+            if sprinting.0 {
+                hunger.exhaust(SPRINT_EXHAUSTION * (dx * dx + dz * dz).sqrt() as f32);
+            }
+            if last.on_ground && !on_ground.0 && position.y > last_position.y {
+                hunger.exhaust(if sprinting.0 {
+                    SPRINT_JUMP_EXHAUSTION
+                } else {
+                    JUMP_EXHAUSTION
+                });
+            }
+        }
+        last.position = Some(*position);
+        last.on_ground = on_ground.0;
+    }
+    Ok(())
+}
+
+fn exhaust_attacking_players(game: &mut Game) -> SysResult {
+    for (_, (event, hunger)) in game
+        .ecs
+        .query::<(&InteractEntityEvent, &mut Hunger)>()
+        .iter()
+    {
+        if matches!(event.ty, InteractionType::Attack) {
+            hunger.exhaust(ATTACK_EXHAUSTION);
+        }
+    }
+    Ok(())
+}
+
+/// Heals well fed players at the cost of exhaustion
+/// and damages starving ones.
+fn regenerate_and_starve(game: &mut Game) -> SysResult {
+    let tick = game.tick_count;
+    let floor = game
+        .resources
+        .get::<Difficulty>()
+        .map_or(Difficulty::default(), |difficulty| *difficulty)
+        .starvation_floor();
+    let mut starving = Vec::new();
+    for (player, (hunger, health)) in game.ecs.query::<(&mut Hunger, &mut Health)>().iter() {
+        if health.current <= 0.0 {
+            continue;
+        }
+
+        let wounded = !health.is_full();
+        if wounded
+            && hunger.food == MAX_FOOD
+            && hunger.saturation > 0.0
+            && tick % SATURATED_REGEN_INTERVAL == 0
+        {
+            let spent = hunger.saturation.min(SATURATED_REGEN_COST);
+            health.heal(spent / SATURATED_REGEN_COST);
+            hunger.exhaust(spent);
+        } else if tick % HUNGER_INTERVAL != 0 {
+            continue;
+        } else if wounded && hunger.food >= REGEN_FOOD {
+            health.heal(1.0);
+            hunger.exhaust(REGEN_EXHAUSTION);
+        } else if hunger.food == 0 && health.current > floor {
+            starving.push((player, (health.current - floor).min(1.0)));
+        }
+    }
+
+    for (player, damage) in starving {
+        combat::apply_damage(game, player, None, damage, DamageType::Starvation)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hungry(food: u8, saturation: f32) -> Hunger {
+        Hunger {
+            food,
+            saturation,
+            exhaustion: 0.0,
+        }
+    }
+
+    #[test]
+    fn exhaustion_uses_saturation_then_food() {
+        let mut hunger = hungry(20, 1.0);
+        hunger.exhaust(3.0);
+        assert_eq!(
+            hunger,
+            Hunger {
+                exhaustion: 3.0,
+                ..hungry(20, 1.0)
+            }
+        );
+        hunger.exhaust(2.0);
+        assert_eq!(hunger.saturation, 0.0);
+        assert_eq!(hunger.food, 20);
+        hunger.exhaust(EXHAUSTION_PER_POINT * 2.0);
+        assert_eq!(hunger.food, 18);
+        assert_eq!(hunger.exhaustion, 1.0);
+    }
+
+    #[test]
+    fn well_fed_players_regenerate() {
+        let mut game = Game::new();
+        let health = Health {
+            current: 10.0,
+            ..Health::new(MAX_HEALTH)
+        };
+        let player = game.ecs.spawn((hungry(REGEN_FOOD, 0.0), health));
+
+        game.tick_count = HUNGER_INTERVAL;
+        regenerate_and_starve(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<Health>(player).unwrap().current, 11.0);
+        let hunger = *game.ecs.get::<Hunger>(player).unwrap();
+        assert_eq!(hunger.food, REGEN_FOOD - 1);
+
+        // Too hungry to regenerate any more
+        game.tick_count += HUNGER_INTERVAL;
+        regenerate_and_starve(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<Health>(player).unwrap().current, 11.0);
+    }
+
+    #[test]
+    fn starving_stops_at_difficulty_floor() {
+        let mut game = Game::new();
+        game.insert_resource(Difficulty::Easy);
+        let player = game.ecs.spawn((hungry(0, 0.0), Health::new(12.0)));
+
+        for interval in 1..=5 {
+            game.tick_count = HUNGER_INTERVAL * interval;
+            regenerate_and_starve(&mut game).unwrap();
+        }
+        assert_eq!(
+            game.ecs.get::<Health>(player).unwrap().current,
+            Difficulty::Easy.starvation_floor()
+        );
+    }
+}
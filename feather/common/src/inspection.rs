@@ -0,0 +1,74 @@
+//! Read-only block introspection for debug commands and tooling.
+
+use base::{BlockKind, ValidBlockPosition};
+use libcraft_blocks::{BlockEntityKind, BlockProperties, BlockTick};
+
+/// Everything known about a single block, as returned by
+/// [`Game::inspect_block`](crate::Game::inspect_block).
+#[derive(Debug, Clone)]
+pub struct BlockInspection {
+    pub position: ValidBlockPosition,
+    pub kind: BlockKind,
+    /// The block's registered name, e.g. `chest`.
+    pub name: &'static str,
+    pub properties: BlockProperties,
+    /// The attached block entity, if any.
+    pub block_entity: Option<BlockEntitySummary>,
+    pub receives_random_ticks: bool,
+    /// The scheduled tick pending at this position, if any.
+    pub scheduled_tick: Option<BlockTick>,
+}
+
+/// A summary of a block entity's data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockEntitySummary {
+    pub kind: BlockEntityKind,
+    /// Keys of the stored data values, sorted.
+    pub keys: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{BlockId, BlockPosition, Chunk, ChunkPosition};
+    use libcraft_blocks::create_block_entity;
+
+    use crate::Game;
+
+    use super::*;
+
+    #[test]
+    fn inspect_chest_with_data() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+
+        let pos: ValidBlockPosition = BlockPosition::new(1, 64, 2).try_into().unwrap();
+        assert!(game.set_block(pos, BlockId::chest()));
+
+        let mut chest = create_block_entity(BlockKind::Chest, (1, 64, 2)).unwrap();
+        chest.data.set_string("CustomName", "Loot".to_owned());
+        chest.data.set_int("LootTableSeed", 7);
+        game.block_entities.set((1, 64, 2), chest);
+
+        let inspection = game.inspect_block(pos).unwrap();
+        assert_eq!(inspection.kind, BlockKind::Chest);
+        assert_eq!(inspection.name, "chest");
+        assert!(inspection.properties.get("facing").is_some());
+        assert!(!inspection.receives_random_ticks);
+        assert!(inspection.scheduled_tick.is_none());
+
+        let entity = inspection.block_entity.unwrap();
+        assert_eq!(entity.kind, BlockEntityKind::Chest);
+        assert_eq!(entity.keys, vec!["CustomName", "LootTableSeed"]);
+    }
+
+    #[test]
+    fn inspect_unloaded_block() {
+        let game = Game::new();
+        let pos = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        assert!(game.inspect_block(pos).is_none());
+    }
+}
@@ -7,7 +7,7 @@
 
 mod game;
 use ecs::SystemExecutor;
-pub use game::Game;
+pub use game::{Game, GameRng, TickProfile};
 
 mod tick_loop;
 pub use tick_loop::TickLoop;
@@ -19,25 +19,166 @@ pub use window::Window;
 
 pub mod events;
 
+pub mod game_rules;
+pub use game_rules::GameRules;
+
+pub mod effects;
+
 pub mod chunk;
 mod region_worker;
 
 pub mod world;
-pub use world::World;
+pub use world::{Dimension, World};
 
 pub mod chat;
 pub use chat::ChatBox;
 
+pub mod structure;
+pub use structure::StructureTemplate;
+
 pub mod entities;
 
 pub mod interactable;
 
+pub mod placement;
+
+pub mod entity_interaction;
+
+pub mod block_entities;
+
+pub mod inspection;
+pub use inspection::BlockInspection;
+
+pub mod status_effects;
+
+pub mod physics;
+
+pub mod movement;
+
+pub mod sculk;
+
+pub mod time;
+pub use time::WorldTime;
+
+pub mod weather;
+pub use weather::Weather;
+
+pub mod sleep;
+
+pub mod block_ticks;
+
+pub mod redstone;
+
+pub mod note_block;
+
+pub mod hopper;
+
+pub mod composter;
+
+pub mod cauldron;
+
+pub mod difficulty;
+pub use difficulty::Difficulty;
+
+pub mod hunger;
+
+pub mod eating;
+
+pub mod cake;
+
+pub mod respawn;
+pub use respawn::WorldSpawn;
+
+pub mod respawn_anchor;
+
+pub mod mining;
+
+pub mod loot;
+
+pub mod combat;
+
+pub mod healing;
+
+pub mod biomes;
+
+pub mod fire;
+
+pub mod freezing;
+
+pub mod explosion;
+
+pub mod dye;
+
+pub mod shearing;
+
+pub mod dyeing;
+
+pub mod milking;
+
+pub mod conduit;
+
+pub mod portal;
+
+pub mod teleport;
+
+pub mod chorus;
+
+pub mod dragon_egg;
+
+pub mod autosave;
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
 /// Registers gameplay systems with the given `Game` and `SystemExecutor`.
 pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game_rules::register(game);
+    interactable::register(game);
     view::register(game, systems);
     chunk::loading::register(game, systems);
     chunk::entities::register(systems);
-    interactable::register(game);
+    block_entities::register(game, systems);
+    entities::register(systems);
+    status_effects::register(systems);
+    movement::register(systems);
+    physics::register(systems);
+    sculk::register(systems);
+    time::register(game, systems);
+    weather::register(game);
+    biomes::register(game, systems);
+    sleep::register(game, systems);
+    entity_interaction::register(game, systems);
+    placement::register(systems);
+    block_ticks::register(systems);
+    redstone::register(game, systems);
+    note_block::register(game, systems);
+    hopper::register(systems);
+    composter::register(game, systems);
+    cauldron::register(game, systems);
+    cake::register(game, systems);
+    difficulty::register(game, systems);
+    hunger::register(systems);
+    eating::register(systems);
+    respawn::register(game, systems);
+    respawn_anchor::register(game, systems);
+    loot::register(systems);
+    combat::register(systems);
+    healing::register(systems);
+    fire::register(systems);
+    freezing::register(systems);
+    shearing::register(game, systems);
+    dyeing::register(game);
+    entities::mooshroom::register(game);
+    milking::register(game);
+    entities::breeding::register(game, systems);
+    entities::spawning::register(game, systems);
+    entities::painting::register(systems);
+    entities::item_frame::register(game, systems);
+    conduit::register(systems);
+    portal::register(systems);
+    chorus::register(systems);
+    dragon_egg::register(game, systems);
+    autosave::register(game, systems);
 
     game.add_entity_spawn_callback(entities::add_entity_components);
 }
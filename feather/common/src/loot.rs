@@ -0,0 +1,250 @@
+//! Items dropped by broken blocks and killed mobs.
+
+use base::{BlockKind, EntityKind, Item, ItemStack, Position};
+use ecs::{Entity, SysResult, SystemExecutor, SystemStage};
+use libcraft_items::EnchantmentKind;
+use quill_common::{components::Health, events::EntityRemoveEvent};
+use rand::Rng;
+
+use crate::{
+    entities::{equipment::Equipment, item},
+    events::EntityDeathEvent,
+    mining, Game,
+};
+
+/// Chance for a wither skeleton to drop its skull
+const WITHER_SKULL_CHANCE: f32 = 0.025;
+/// Added chance of a wither skeleton skull for each level of looting
+const WITHER_SKULL_LOOTING_CHANCE: f32 = 0.01;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system_to_stage(SystemStage::Cleanup, drop_loot_of_killed_mobs);
+}
+
+/// The items dropped when `tool` breaks the block.
+///
+/// Silk touch drops the block itself. Otherwise, some blocks drop
+/// something else, like ores dropping their resource, which fortune
+/// multiplies.
+pub fn block_drops(
+    kind: BlockKind,
+    tool: Option<&ItemStack>,
+    rng: &mut impl Rng,
+) -> Vec<ItemStack> {
+    if !mining::can_harvest(kind, tool.map(ItemStack::item)) {
+        return Vec::new();
+    }
+    let enchantments = tool.map(ItemStack::enchantments);
+    let level = |enchantment| enchantments.map_or(0, |enchantments| enchantments.get(enchantment));
+    if level(EnchantmentKind::SilkTouch) > 0 {
+        return item::block_drop(kind).into_iter().collect();
+    }
+
+    let (item, min, max) = match kind {
+        BlockKind::Stone => (Item::Cobblestone, 1, 1),
+        BlockKind::GrassBlock | BlockKind::Mycelium | BlockKind::Podzol => (Item::Dirt, 1, 1),
+        BlockKind::CoalOre => (Item::Coal, 1, 1),
+        BlockKind::DiamondOre => (Item::Diamond, 1, 1),
+        BlockKind::EmeraldOre => (Item::Emerald, 1, 1),
+        BlockKind::NetherQuartzOre => (Item::Quartz, 1, 1),
+        BlockKind::LapisOre => (Item::LapisLazuli, 4, 9),
+        BlockKind::RedstoneOre => (Item::Redstone, 4, 5),
+        BlockKind::NetherGoldOre => (Item::GoldNugget, 2, 6),
+        BlockKind::Ice | BlockKind::PackedIce | BlockKind::BlueIce => return Vec::new(),
+        _ if kind.name().ends_with("glass") || kind.name().ends_with("glass_pane") => {
+            return Vec::new()
+        }
+        _ => return item::block_drop(kind).into_iter().collect(),
+    };
+
+    let mut count = rng.gen_range(min..=max);
+    let fortune = level(EnchantmentKind::Fortune);
+    if fortune > 0 && kind.name().ends_with("_ore") {
+        // Each level of fortune adds a chance for another multiple of the drops
+        let bonus = rng.gen_range(0..=u32::from(fortune) + 1).saturating_sub(1);
+        count *= bonus + 1;
+    }
+    ItemStack::new(item, count).into_iter().collect()
+}
+
+/// The items a mob drops when killed with `weapon`.
+/// Looting raises the most of each item that can drop.
+pub fn mob_loot(
+    kind: EntityKind,
+    weapon: Option<&ItemStack>,
+    rng: &mut impl Rng,
+) -> Vec<ItemStack> {
+    let drops: &[(Item, u32, u32)] = match kind {
+        EntityKind::Zombie | EntityKind::Husk | EntityKind::Drowned => &[(Item::RottenFlesh, 0, 2)],
+        EntityKind::Skeleton | EntityKind::Stray => &[(Item::Bone, 0, 2), (Item::Arrow, 0, 2)],
+        EntityKind::Spider | EntityKind::CaveSpider => &[(Item::String, 0, 2)],
+        EntityKind::Creeper => &[(Item::Gunpowder, 0, 2)],
+        EntityKind::Enderman => &[(Item::EnderPearl, 0, 1)],
+        EntityKind::Cow | EntityKind::Mooshroom => &[(Item::Leather, 0, 2), (Item::Beef, 1, 3)],
+        EntityKind::Pig => &[(Item::Porkchop, 1, 3)],
+        EntityKind::Sheep => &[(Item::Mutton, 1, 2)],
+        EntityKind::Chicken => &[(Item::Feather, 0, 2), (Item::Chicken, 1, 1)],
+        EntityKind::WitherSkeleton => &[(Item::Coal, 0, 1), (Item::Bone, 0, 2)],
+        _ => &[],
+    };
+    let looting = weapon.map_or(0, |weapon| {
+        u32::from(weapon.enchantments().get(EnchantmentKind::Looting))
+    });
+
+    let mut loot: Vec<ItemStack> = drops
+        .iter()
+        .filter_map(|(item, min, max)| {
+            let count = rng.gen_range(*min..=*max) + rng.gen_range(0..=looting);
+            ItemStack::new(*item, count).ok()
+        })
+        .collect();
+    if kind == EntityKind::WitherSkeleton {
+        let chance = WITHER_SKULL_CHANCE + WITHER_SKULL_LOOTING_CHANCE * looting as f32;
+        if rng.gen::<f32>() < chance {
+            loot.extend(ItemStack::new(Item::WitherSkeletonSkull, 1).ok());
+        }
+    }
+    loot
+}
+
+/// The head a mob drops when killed by a charged creeper, if it has one.
+pub fn mob_head(kind: EntityKind) -> Option<Item> {
+    match kind {
+        EntityKind::Zombie => Some(Item::ZombieHead),
+        EntityKind::Skeleton => Some(Item::SkeletonSkull),
+        EntityKind::Creeper => Some(Item::CreeperHead),
+        EntityKind::WitherSkeleton => Some(Item::WitherSkeletonSkull),
+        _ => None,
+    }
+}
+
+/// Drops the loot of `mob`, killed with `weapon`, where it died:
+/// its own drops along with each piece of its equipment
+/// that passes its drop chance.
+pub fn drop_death_loot(
+    game: &mut Game,
+    mob: Entity,
+    weapon: Option<&ItemStack>,
+    rng: &mut impl Rng,
+) -> SysResult<Vec<Entity>> {
+    let position = *game.ecs.get::<Position>(mob)?;
+    let kind = *game.ecs.get::<EntityKind>(mob)?;
+    let mut drops = mob_loot(kind, weapon, rng);
+    if let Ok(equipment) = game.ecs.get::<Equipment>(mob) {
+        let looting = weapon.map_or(0, |weapon| {
+            weapon.enchantments().get(EnchantmentKind::Looting)
+        });
+        drops.extend(equipment.roll_drops(looting, rng));
+    }
+    Ok(drops
+        .into_iter()
+        .map(|stack| item::spawn_item(game, position, stack))
+        .collect())
+}
+
+/// Drops the loot of mobs that ran out of health and removes them.
+/// Looting on the weapon that killed a mob adds to its loot.
+fn drop_loot_of_killed_mobs(game: &mut Game) -> SysResult {
+    let killed: Vec<(Entity, Option<ItemStack>)> = game
+        .ecs
+        .query::<(&Health, &EntityKind, Option<&EntityDeathEvent>)>()
+        .iter()
+        .filter(|(_, (health, kind, _))| health.current <= 0.0 && **kind != EntityKind::Player)
+        .map(|(mob, (_, _, death))| (mob, death.and_then(|death| death.weapon.clone())))
+        .filter(|(mob, _)| game.ecs.get::<EntityRemoveEvent>(*mob).is_err())
+        .collect();
+
+    for (mob, weapon) in killed {
+        game.with_rng(|game, rng| drop_death_loot(game, mob, weapon.as_ref(), rng))?;
+        game.remove_entity(mob)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::entities::equipment::EquipmentSlot;
+
+    use super::*;
+
+    fn total(drops: Vec<ItemStack>) -> u32 {
+        drops.iter().map(ItemStack::count).sum()
+    }
+
+    #[test]
+    fn looting_sword_increases_mob_drops() {
+        let sword = ItemStack::new(Item::DiamondSword, 1).unwrap();
+        let mut looting = sword.clone();
+        looting.set_enchantment_level(EnchantmentKind::Looting, 3);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let (mut plain, mut looted) = (0, 0);
+        for _ in 0..100 {
+            plain += total(mob_loot(EntityKind::Zombie, Some(&sword), &mut rng));
+            looted += total(mob_loot(EntityKind::Zombie, Some(&looting), &mut rng));
+        }
+        assert!(
+            looted > plain,
+            "{} drops with looting, {} without",
+            looted,
+            plain
+        );
+    }
+
+    #[test]
+    fn silk_touch_pickaxe_drops_the_block() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let pickaxe = ItemStack::new(Item::IronPickaxe, 1).unwrap();
+        let mut silk = pickaxe.clone();
+        silk.set_enchantment_level(EnchantmentKind::SilkTouch, 1);
+
+        let items = |drops: Vec<ItemStack>| drops.iter().map(ItemStack::item).collect::<Vec<_>>();
+        assert_eq!(
+            items(block_drops(BlockKind::Stone, Some(&pickaxe), &mut rng)),
+            vec![Item::Cobblestone]
+        );
+        assert_eq!(
+            items(block_drops(BlockKind::Stone, Some(&silk), &mut rng)),
+            vec![Item::Stone]
+        );
+        assert_eq!(
+            items(block_drops(BlockKind::DiamondOre, Some(&silk), &mut rng)),
+            vec![Item::DiamondOre]
+        );
+        assert!(block_drops(BlockKind::Glass, Some(&pickaxe), &mut rng).is_empty());
+        assert!(block_drops(BlockKind::Stone, None, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn killed_mob_drops_equipment_by_drop_chance() {
+        let mut game = Game::new();
+        let helmet = ItemStack::new(Item::IronHelmet, 1).unwrap();
+        let mut equipment = Equipment {
+            helmet: Some(helmet.clone()),
+            main_hand: ItemStack::new(Item::IronSword, 1).ok(),
+            ..Default::default()
+        };
+        equipment.set_drop_chance(EquipmentSlot::Head, 1.0);
+        equipment.set_drop_chance(EquipmentSlot::MainHand, 0.0);
+        let husk = game.ecs.spawn((
+            position!(0.0, 64.0, 0.0),
+            EntityKind::Husk,
+            Health::new(0.0),
+            equipment,
+        ));
+
+        drop_loot_of_killed_mobs(&mut game).unwrap();
+        assert!(game.ecs.get::<EntityRemoveEvent>(husk).is_ok());
+        let dropped: Vec<ItemStack> = game
+            .ecs
+            .query::<&ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.clone())
+            .collect();
+        assert!(dropped.contains(&helmet));
+        assert!(dropped.iter().all(|stack| stack.item() != Item::IronSword));
+    }
+}
@@ -0,0 +1,73 @@
+//! Milking cows, goats and mooshrooms with a bucket.
+
+use base::{EntityKind, Item, ItemStack, Position};
+use ecs::{Entity, SysResult};
+use libcraft_items::InventorySlot;
+
+use crate::{
+    entities::aging::Baby,
+    entity_interaction::{self, EntityInteractions},
+    Game,
+};
+
+pub fn register(game: &mut Game) {
+    let mut interactions = game
+        .resources
+        .get_mut::<EntityInteractions>()
+        .expect("Failed to get the entity interactions");
+    for kind in [EntityKind::Cow, EntityKind::Goat, EntityKind::Mooshroom] {
+        interactions.register(kind, Some(Item::Bucket), milk);
+    }
+}
+
+/// Fills the `held` bucket with milk from an adult `target`.
+///
+/// Returns whether the bucket was filled.
+pub fn milk(
+    game: &mut Game,
+    player: Entity,
+    target: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    if game.ecs.get::<Baby>(target).is_ok() {
+        return Ok(false);
+    }
+    let sound = match *game.ecs.get::<EntityKind>(target)? {
+        EntityKind::Goat => "entity.goat.milk",
+        _ => "entity.cow.milk",
+    };
+
+    entity_interaction::exchange(game, player, held, ItemStack::new(Item::MilkBucket, 1)?)?;
+    let position = *game.ecs.get::<Position>(target)?;
+    game.emit_sound(sound, position, 1.0, 1.0);
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use quill_common::entities::{Cow, Pig};
+
+    use super::*;
+
+    #[test]
+    fn buckets_are_filled_with_milk_from_cows() {
+        let mut game = Game::new();
+        game.insert_resource(EntityInteractions::default());
+        register(&mut game);
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let cow = game
+            .ecs
+            .spawn((Cow, EntityKind::Cow, position!(1.5, 64.0, 0.5)));
+        let pig = game
+            .ecs
+            .spawn((Pig, EntityKind::Pig, position!(-1.5, 64.0, 0.5)));
+
+        let mut held = InventorySlot::new(Item::Bucket, 1);
+        assert!(!entity_interaction::interact(&mut game, player, pig, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::Bucket, 1));
+
+        assert!(entity_interaction::interact(&mut game, player, cow, &mut held).unwrap());
+        assert_eq!(held, InventorySlot::new(Item::MilkBucket, 1));
+    }
+}
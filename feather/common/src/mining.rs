@@ -0,0 +1,119 @@
+//! How long blocks take to break, which tools harvest them
+//! and how tools wear down with use.
+
+use base::{BlockKind, Item, ItemStack};
+use libcraft_items::{EnchantmentKind, InventorySlot};
+use rand::Rng;
+
+/// Whether breaking the block with `tool` makes it drop as an item.
+pub fn can_harvest(kind: BlockKind, tool: Option<Item>) -> bool {
+    match kind.harvest_tools() {
+        Some(tools) => tool.map_or(false, |tool| tools.contains(&tool)),
+        None => true,
+    }
+}
+
+/// How many times faster `tool` breaks the block than a bare hand.
+/// Efficiency only speeds up tools suited to the block.
+pub fn mining_speed(kind: BlockKind, tool: Option<&ItemStack>) -> f32 {
+    let item = tool.map(ItemStack::item);
+    let mut speed = kind
+        .dig_multipliers()
+        .iter()
+        .find(|(multiplier_tool, _)| Some(*multiplier_tool) == item)
+        .map_or(1.0, |(_, multiplier)| *multiplier);
+    let efficiency = tool.map_or(0, |tool| {
+        tool.enchantments().get(EnchantmentKind::Efficiency)
+    });
+    if speed > 1.0 && efficiency > 0 {
+        let efficiency = f32::from(efficiency);
+        speed += efficiency * efficiency + 1.0;
+    }
+    speed
+}
+
+/// Ticks it takes to break the block with `tool`, or `None`
+/// if it cannot be broken at all. Blocks that break
+/// instantly take 0 ticks.
+pub fn break_ticks(kind: BlockKind, tool: Option<&ItemStack>) -> Option<u32> {
+    let hardness = kind.hardness();
+    if !kind.diggable() || hardness < 0.0 {
+        return None;
+    }
+    if hardness == 0.0 {
+        return Some(0);
+    }
+    let divisor = if can_harvest(kind, tool.map(ItemStack::item)) {
+        30.0
+    } else {
+        100.0
+    };
+    let progress_per_tick = mining_speed(kind, tool) / hardness / divisor;
+    if progress_per_tick >= 1.0 {
+        return Some(0);
+    }
+    Some((1.0 / progress_per_tick).ceil() as u32)
+}
+
+/// Wears down the tool in `slot` by one use, emptying the slot once
+/// the tool breaks. Each level of unbreaking makes it likelier
+/// that a use leaves the tool undamaged.
+///
+/// Returns whether the tool broke.
+pub fn damage_tool(slot: &mut InventorySlot, rng: &mut impl Rng) -> bool {
+    let tool = match slot.option_mut() {
+        Some(tool) if tool.item().durability().is_some() => tool,
+        _ => return false,
+    };
+    let unbreaking = tool.enchantments().get(EnchantmentKind::Unbreaking);
+    if rng.gen_range(0..=u32::from(unbreaking)) != 0 {
+        return false;
+    }
+    let broken = tool.damage(1);
+    if broken {
+        *slot = InventorySlot::Empty;
+    }
+    broken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn efficiency_pickaxe_mines_faster() {
+        let pickaxe = ItemStack::new(Item::IronPickaxe, 1).unwrap();
+        let mut efficient = pickaxe.clone();
+        efficient.set_enchantment_level(EnchantmentKind::Efficiency, 3);
+
+        let plain = break_ticks(BlockKind::Stone, Some(&pickaxe)).unwrap();
+        let fast = break_ticks(BlockKind::Stone, Some(&efficient)).unwrap();
+        assert!(fast < plain, "{} ticks is not faster than {}", fast, plain);
+
+        // Efficiency does not help tools unsuited to the block
+        let mut shovel = ItemStack::new(Item::IronShovel, 1).unwrap();
+        let bare = break_ticks(BlockKind::Stone, Some(&shovel));
+        shovel.set_enchantment_level(EnchantmentKind::Efficiency, 5);
+        assert_eq!(break_ticks(BlockKind::Stone, Some(&shovel)), bare);
+
+        assert!(!can_harvest(BlockKind::Stone, None));
+        assert!(can_harvest(BlockKind::Stone, Some(Item::WoodenPickaxe)));
+        assert_eq!(break_ticks(BlockKind::Bedrock, Some(&efficient)), None);
+    }
+
+    #[test]
+    fn tools_break_when_worn_out() {
+        let mut rng = rand::thread_rng();
+        let mut shears = InventorySlot::new(Item::Shears, 1);
+        let durability = Item::Shears.durability().unwrap();
+        for _ in 1..durability {
+            assert!(!damage_tool(&mut shears, &mut rng));
+        }
+        assert_eq!(
+            shears.option_ref().and_then(ItemStack::damage_taken),
+            Some(durability as i32 - 1)
+        );
+        assert!(damage_tool(&mut shears, &mut rng));
+        assert_eq!(shears, InventorySlot::Empty);
+    }
+}
@@ -0,0 +1,175 @@
+//! Movement speed of entities, as affected by the terrain they walk on
+//! and their status effects.
+//!
+//! Other systems slow entities down or speed them up by setting a modifier
+//! on their [`MovementSpeed`] each tick. The modifiers are applied and
+//! cleared here, so a modifier no longer set stops applying on the next tick.
+
+use base::inventory::SLOT_ARMOR_FEET;
+use base::{BlockKind, Position};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::BlockView;
+use libcraft_items::EnchantmentKind;
+use quill_common::components::MovementSpeed;
+
+use crate::{
+    status_effects::{StatusEffectKind, StatusEffects},
+    Game, Window,
+};
+
+/// Speed bonus per level of Soul Speed on soul sand and soul soil
+const SOUL_SPEED_BONUS: f64 = 0.1;
+/// Speed bonus per level of the Speed effect
+const SPEED_EFFECT_BONUS: f64 = 0.2;
+/// Speed penalty per level of the Slowness effect
+const SLOWNESS_EFFECT_PENALTY: f64 = 0.15;
+
+/// Cause of the speed modifier set by the block walked on
+pub const TERRAIN_MODIFIER: &str = "terrain";
+/// Cause of the speed modifier set by Speed and Slowness
+pub const EFFECT_MODIFIER: &str = "effect";
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_movement_speed);
+}
+
+fn is_soul_block(kind: BlockKind) -> bool {
+    matches!(kind, BlockKind::SoulSand | BlockKind::SoulSoil)
+}
+
+/// Speed multiplier of walking on a block with the given
+/// level of Soul Speed on the entity's boots.
+pub fn terrain_speed(kind: BlockKind, soul_speed: u32) -> f64 {
+    if soul_speed > 0 && is_soul_block(kind) {
+        return 1.0 + SOUL_SPEED_BONUS * f64::from(soul_speed);
+    }
+
+    match kind {
+        BlockKind::SoulSand | BlockKind::HoneyBlock => 0.4,
+        BlockKind::Ice | BlockKind::PackedIce | BlockKind::FrostedIce => 1.2,
+        BlockKind::BlueIce => 1.3,
+        _ => 1.0,
+    }
+}
+
+/// Whether entities landing on the block bounce back up.
+pub fn is_bouncy(kind: BlockKind) -> bool {
+    kind == BlockKind::SlimeBlock
+}
+
+/// The block an entity at `pos` is walking on.
+///
+/// Looks half a block down so that blocks lower than a full
+/// block, like soul sand, count when standing on them.
+pub fn block_under(view: &dyn BlockView, pos: Position) -> Option<BlockKind> {
+    view.kind_at((
+        pos.x.floor() as i32,
+        (pos.y - 0.5).floor() as i32,
+        pos.z.floor() as i32,
+    ))
+}
+
+/// Level of Soul Speed on the boots worn in `window`.
+fn soul_speed(window: &Window) -> u32 {
+    window
+        .item(SLOT_ARMOR_FEET)
+        .ok()
+        .and_then(|boots| {
+            boots.map_ref(|boots| boots.enchantment_level(EnchantmentKind::SoulSpeed))
+        })
+        .flatten()
+        .unwrap_or(0)
+}
+
+/// Speed multiplier of the Speed and Slowness effects.
+pub fn effect_speed(effects: &StatusEffects) -> f64 {
+    let level = |kind| {
+        effects
+            .get(kind)
+            .map_or(0.0, |effect| f64::from(effect.amplifier) + 1.0)
+    };
+    let multiplier = 1.0 + SPEED_EFFECT_BONUS * level(StatusEffectKind::Speed)
+        - SLOWNESS_EFFECT_PENALTY * level(StatusEffectKind::Slowness);
+    multiplier.max(0.0)
+}
+
+fn update_movement_speed(game: &mut Game) -> SysResult {
+    for (_, (position, speed, window, effects)) in game
+        .ecs
+        .query::<(
+            &Position,
+            &mut MovementSpeed,
+            Option<&Window>,
+            Option<&StatusEffects>,
+        )>()
+        .iter()
+    {
+        let soul_speed = window.map_or(0, soul_speed);
+        let terrain =
+            block_under(&*game, *position).map_or(1.0, |kind| terrain_speed(kind, soul_speed));
+        speed.set_modifier(TERRAIN_MODIFIER, terrain);
+        if let Some(effects) = effects {
+            speed.set_modifier(EFFECT_MODIFIER, effect_speed(effects));
+        }
+        speed.recompute();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockId, BlockPosition, Chunk, ChunkPosition};
+
+    use super::*;
+
+    fn speed_on(block: BlockId) -> f64 {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let pos = BlockPosition::new(0, 63, 0).try_into().unwrap();
+        game.set_block(pos, block);
+
+        let entity = game
+            .ecs
+            .spawn((position!(0.5, 64.0, 0.5), MovementSpeed::new(0.1)));
+        update_movement_speed(&mut game).unwrap();
+        let speed = game.ecs.get::<MovementSpeed>(entity).unwrap().value;
+        speed
+    }
+
+    #[test]
+    fn soul_sand_slows_and_packed_ice_speeds_up() {
+        assert_eq!(speed_on(BlockId::stone()), 0.1);
+        assert!(speed_on(BlockId::soul_sand()) < 0.1);
+        assert!(speed_on(BlockId::packed_ice()) > 0.1);
+    }
+
+    #[test]
+    fn soul_speed_negates_soul_sand_slowdown() {
+        assert!(terrain_speed(BlockKind::SoulSand, 0) < 1.0);
+        assert!(terrain_speed(BlockKind::SoulSand, 1) > 1.0);
+        assert_eq!(terrain_speed(BlockKind::HoneyBlock, 3), 0.4);
+    }
+
+    #[test]
+    fn removed_modifier_restores_base_speed() {
+        let mut game = Game::new();
+        let entity = game
+            .ecs
+            .spawn((position!(0.5, 64.0, 0.5), MovementSpeed::new(0.1)));
+
+        game.ecs
+            .get_mut::<MovementSpeed>(entity)
+            .unwrap()
+            .set_modifier("test", 0.5);
+        update_movement_speed(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<MovementSpeed>(entity).unwrap().value, 0.05);
+
+        // Nothing sets the modifier again, so it no longer applies
+        update_movement_speed(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<MovementSpeed>(entity).unwrap().value, 0.1);
+    }
+}
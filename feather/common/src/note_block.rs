@@ -0,0 +1,200 @@
+//! Note blocks, which play a note when used or powered. The instrument
+//! depends on the block below and the pitch is tuned by using the block.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{BlockKind, BlockPosition, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::{BlockEntityKind, BlockProperties};
+use quill_common::events::BlockInteractEvent;
+
+use crate::{events::NotePlayedEvent, interactable::InteractableRegistry, Game};
+
+/// Number of pitches a note block can be tuned to
+const NOTES: u8 = 25;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry")
+        .register(BlockKind::NoteBlock);
+    systems
+        .add_system(tune_used_note_blocks)
+        .add_system(play_powered_note_blocks);
+}
+
+fn offset(pos: ValidBlockPosition, dy: i32) -> Option<ValidBlockPosition> {
+    BlockPosition::new(pos.x(), pos.y() + dy, pos.z())
+        .try_into()
+        .ok()
+}
+
+fn note_block_at(game: &Game, pos: ValidBlockPosition) -> SysResult<BlockProperties> {
+    match game.block_properties(pos) {
+        Some(properties) if properties.kind() == BlockKind::NoteBlock => Ok(properties),
+        _ => bail!("no note block at {:?}", pos),
+    }
+}
+
+/// The note the note block at `pos` is tuned to
+pub fn note(game: &Game, pos: ValidBlockPosition) -> u8 {
+    game.block_entities
+        .get((pos.x(), pos.y(), pos.z()))
+        .and_then(|entity| entity.data.get_int("note"))
+        .map_or(0, |note| note as u8)
+}
+
+/// Plays the note block at `pos`. Note blocks only sound
+/// with air above them.
+///
+/// Returns whether a note was played.
+pub fn play(game: &mut Game, pos: ValidBlockPosition) -> SysResult<bool> {
+    let mut properties = note_block_at(game, pos)?;
+    let muffled = offset(pos, 1)
+        .and_then(|above| game.block(above))
+        .map_or(false, |above| !above.is_air());
+    if muffled {
+        return Ok(false);
+    }
+
+    let instrument = offset(pos, -1)
+        .and_then(|below| game.block(below))
+        .map_or(BlockKind::Air, |below| below.kind())
+        .note_block_instrument();
+    let note = note(game, pos);
+    if properties.get("instrument").map(String::as_str) != Some(instrument.name()) {
+        properties.set("instrument", instrument.name());
+        game.set_block_properties(pos, &properties);
+    }
+
+    game.ecs.insert_event(NotePlayedEvent {
+        position: pos,
+        instrument,
+        note,
+    });
+    Ok(true)
+}
+
+/// Raises the pitch of the note block at `pos` by a semitone,
+/// wrapping back to the lowest note, then plays it.
+///
+/// Returns the new note.
+pub fn tune(game: &mut Game, pos: ValidBlockPosition) -> SysResult<u8> {
+    let mut properties = note_block_at(game, pos)?;
+    let note = (note(game, pos) + 1) % NOTES;
+    properties.set("note", &note.to_string());
+    game.set_block_properties(pos, &properties);
+    if let Some(mut entity) = game.block_entities.get_mut((pos.x(), pos.y(), pos.z())) {
+        entity.data.set_int("note", i32::from(note));
+    }
+
+    play(game, pos)?;
+    Ok(note)
+}
+
+fn tune_used_note_blocks(game: &mut Game) -> SysResult {
+    let used: Vec<BlockPosition> = game
+        .ecs
+        .query::<&BlockInteractEvent>()
+        .iter()
+        .map(|(_, event)| event.location)
+        .collect();
+
+    for location in used {
+        let pos: ValidBlockPosition = match location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        if game.block(pos).map(|block| block.kind()) == Some(BlockKind::NoteBlock) {
+            tune(game, pos)?;
+        }
+    }
+    Ok(())
+}
+
+/// Plays note blocks as they become powered.
+fn play_powered_note_blocks(game: &mut Game) -> SysResult {
+    let note_blocks: Vec<(i32, i32, i32)> = game
+        .block_entities
+        .iter()
+        .filter(|entity| entity.kind == BlockEntityKind::NoteBlock)
+        .map(|entity| entity.position)
+        .collect();
+
+    for (x, y, z) in note_blocks {
+        let pos: ValidBlockPosition = match BlockPosition::new(x, y, z).try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let mut properties = match note_block_at(game, pos) {
+            Ok(properties) => properties,
+            Err(_) => continue,
+        };
+        let powered = game.block_integration.power_at(pos, |p| {
+            game.block_properties(p).map(|props| (props.kind(), props))
+        }) > 0;
+        let was_powered = properties
+            .get("powered")
+            .map_or(false, |powered| powered == "true");
+        if powered == was_powered {
+            continue;
+        }
+
+        properties.set("powered", &powered.to_string());
+        game.set_block_properties(pos, &properties);
+        if powered {
+            play(game, pos)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, ChunkPosition};
+    use libcraft_core::block::Instrument;
+
+    use super::*;
+
+    #[test]
+    fn tuning_plays_instrument_of_block_below() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        game.set_block(offset(pos, -1).unwrap(), BlockId::oak_planks());
+
+        let mut note_block = BlockProperties::new(BlockKind::NoteBlock);
+        note_block
+            .set("instrument", "harp")
+            .set("note", "0")
+            .set("powered", "false");
+        assert!(game.set_block_properties(pos, &note_block));
+
+        assert_eq!(tune(&mut game, pos).unwrap(), 1);
+        assert_eq!(note(&game, pos), 1);
+        assert_eq!(
+            game.block_properties(pos).unwrap().get("note").unwrap(),
+            "1"
+        );
+
+        let played: Vec<NotePlayedEvent> = game
+            .ecs
+            .query::<&NotePlayedEvent>()
+            .iter()
+            .map(|(_, event)| *event)
+            .collect();
+        assert_eq!(played.len(), 1);
+        assert_eq!(played[0].instrument, Instrument::Bass);
+        assert_eq!(played[0].note, 1);
+        assert_eq!(
+            game.block_properties(pos)
+                .unwrap()
+                .get("instrument")
+                .unwrap(),
+            "bass"
+        );
+    }
+}
@@ -0,0 +1,785 @@
+//! Entity physics: gravity, jumping and falling, and the
+//! physics step moving entities by their velocity.
+//!
+//! Other systems only set an entity's [`Velocity`]. Each tick,
+//! [`apply_physics`] moves the entity by it, stops it at blocks in
+//! the way, and then applies gravity, drag and friction.
+
+use std::convert::TryInto;
+
+use base::{position, BlockKind, BlockPosition, EntityKind, Position, Vec3d};
+use ecs::{Entity, SysResult, SystemExecutor, SystemStage};
+use libcraft_blocks::{BlockView, CollisionShape};
+use libcraft_core::BlockFace;
+use quill_common::{
+    components::{CreativeFlying, OnGround, Velocity},
+    entities::Player,
+};
+
+use crate::{
+    combat::{self, DamageType},
+    entities::{boat::BoatMotion, riding::Riding},
+    events::ProjectileHitBlockEvent,
+    status_effects::{StatusEffectKind, StatusEffects},
+    Game,
+};
+
+/// Downward acceleration of living entities, in blocks/tick²
+pub const GRAVITY: f64 = 0.08;
+/// Downward acceleration while under Slow Falling
+pub const SLOW_FALLING_GRAVITY: f64 = 0.01;
+/// Fraction of vertical velocity kept each tick
+pub const VERTICAL_DRAG: f64 = 0.98;
+/// Vertical velocity of a jump without Jump Boost
+pub const JUMP_VELOCITY: f64 = 0.42;
+/// Distance an entity can fall without taking damage
+pub const SAFE_FALL_DISTANCE: f64 = 3.0;
+/// Fraction of horizontal velocity kept each tick while airborne
+pub const AIR_DRAG: f64 = 0.91;
+/// Fraction of velocity kept each tick in water
+pub const WATER_DRAG: f64 = 0.8;
+/// Downward acceleration in water
+pub const WATER_GRAVITY: f64 = 0.02;
+/// Slipperiness of most blocks, scaling the friction of walking on them
+const DEFAULT_SLIPPERINESS: f64 = 0.6;
+/// Height of the highest ledge an entity walks up without jumping
+pub const STEP_HEIGHT: f64 = 0.6;
+/// Leeway for rounding errors when comparing coordinates
+const EPSILON: f64 = 1e-7;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system_to_stage(SystemStage::Physics, apply_physics);
+}
+
+/// Marks an entity that gravity doesn't pull down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoGravity;
+
+/// Distance an entity has fallen since it last stood on the ground, in blocks.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FallDistance(pub f64);
+
+/// Fraction of horizontal velocity kept each tick when walking on a
+/// block of the given kind, before air drag. Ice keeps entities sliding.
+pub fn slipperiness(kind: BlockKind) -> f64 {
+    match kind {
+        BlockKind::Ice | BlockKind::PackedIce | BlockKind::FrostedIce => 0.98,
+        BlockKind::BlueIce => 0.989,
+        BlockKind::SlimeBlock => 0.8,
+        _ => DEFAULT_SLIPPERINESS,
+    }
+}
+
+/// Whether entities of the given kind fly, and so don't fall.
+pub fn flies(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Bat
+            | EntityKind::Bee
+            | EntityKind::Blaze
+            | EntityKind::EnderDragon
+            | EntityKind::Ghast
+            | EntityKind::Parrot
+            | EntityKind::Phantom
+            | EntityKind::Vex
+            | EntityKind::Wither
+    )
+}
+
+/// Whether entities of the given kind swim, and so
+/// don't sink while in water.
+pub fn swims(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Cod
+            | EntityKind::Dolphin
+            | EntityKind::ElderGuardian
+            | EntityKind::Guardian
+            | EntityKind::Pufferfish
+            | EntityKind::Salmon
+            | EntityKind::Squid
+            | EntityKind::TropicalFish
+            | EntityKind::Turtle
+    )
+}
+
+/// An axis-aligned box in world coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: Vec3d,
+    pub max: Vec3d,
+}
+
+impl Bounds {
+    /// The box taken up by an entity of the given kind standing at `position`.
+    pub fn of_entity(kind: EntityKind, position: Position) -> Self {
+        let size = kind.bounding_box();
+        let (width, height) = (size.max.x - size.min.x, size.max.y - size.min.y);
+        Self {
+            min: Vec3d::new(
+                position.x - width / 2.0,
+                position.y,
+                position.z - width / 2.0,
+            ),
+            max: Vec3d::new(
+                position.x + width / 2.0,
+                position.y + height,
+                position.z + width / 2.0,
+            ),
+        }
+    }
+
+    /// The full cube of the block at the given coordinates.
+    pub fn block(x: i32, y: i32, z: i32) -> Self {
+        let min = Vec3d::new(f64::from(x), f64::from(y), f64::from(z));
+        Self {
+            min,
+            max: min + Vec3d::one(),
+        }
+    }
+
+    /// This box moved by `distance` along `axis` (0, 1 and 2 for x, y and z).
+    pub fn offset(mut self, axis: usize, distance: f64) -> Self {
+        self.min[axis] += distance;
+        self.max[axis] += distance;
+        self
+    }
+
+    /// Whether the boxes overlap along `axis`, not counting touching faces.
+    fn overlaps_along(&self, other: &Bounds, axis: usize) -> bool {
+        self.min[axis] < other.max[axis] - EPSILON && self.max[axis] > other.min[axis] + EPSILON
+    }
+}
+
+/// The box entities collide with in the block at the given coordinates,
+/// following its collision shape: slabs fill half the block, and fences
+/// and walls reach half a block above it.
+///
+/// Blocks in unloaded chunks are solid, so entities don't wander into them.
+fn block_bounds(view: &dyn BlockView, (x, y, z): (i32, i32, i32)) -> Option<Bounds> {
+    let shape = match view.block_at((x, y, z)) {
+        Some((_, properties)) => properties.collision_shape(),
+        None if !(0..256).contains(&y) => return None,
+        None => CollisionShape::Full,
+    };
+    if shape.is_empty() {
+        return None;
+    }
+    // Top slabs fill the upper half of their block
+    let bottom = if shape == CollisionShape::TopSlab {
+        0.5
+    } else {
+        0.0
+    };
+    let mut bounds = Bounds::block(x, y, z);
+    bounds.min.y = f64::from(y) + bottom;
+    bounds.max.y = f64::from(y) + shape.height();
+    Some(bounds)
+}
+
+/// How far `bounds` can move along `axis`, up to `distance`,
+/// before running into a block.
+fn clip_axis(view: &dyn BlockView, bounds: Bounds, axis: usize, distance: f64) -> f64 {
+    if distance == 0.0 {
+        return 0.0;
+    }
+    let swept = if distance > 0.0 {
+        Bounds {
+            max: bounds.offset(axis, distance).max,
+            ..bounds
+        }
+    } else {
+        Bounds {
+            min: bounds.offset(axis, distance).min,
+            ..bounds
+        }
+    };
+
+    let mut distance = distance;
+    for x in swept.min.x.floor() as i32..=swept.max.x.floor() as i32 {
+        // Fences and walls below reach up into the next block
+        for y in swept.min.y.floor() as i32 - 1..=swept.max.y.floor() as i32 {
+            for z in swept.min.z.floor() as i32..=swept.max.z.floor() as i32 {
+                let block = match block_bounds(view, (x, y, z)) {
+                    Some(block) => block,
+                    None => continue,
+                };
+                let in_the_way = (0..3)
+                    .filter(|other| *other != axis)
+                    .all(|other| bounds.overlaps_along(&block, other));
+                if !in_the_way {
+                    continue;
+                }
+                if distance > 0.0 && block.min[axis] >= bounds.max[axis] - EPSILON {
+                    distance = distance.min(block.min[axis] - bounds.max[axis]);
+                } else if distance < 0.0 && block.max[axis] <= bounds.min[axis] + EPSILON {
+                    distance = distance.max(block.max[axis] - bounds.min[axis]);
+                }
+            }
+        }
+    }
+    distance
+}
+
+/// Moves `bounds` along each axis in turn, stopping at the first
+/// block in the way, so that entities slide along walls.
+fn move_along_axes(
+    view: &dyn BlockView,
+    mut bounds: Bounds,
+    motion: Vec3d,
+    axes: &[usize],
+) -> (Bounds, Vec3d) {
+    let mut moved = Vec3d::zero();
+    for axis in axes.iter().copied() {
+        moved[axis] = clip_axis(view, bounds, axis, motion[axis]);
+        bounds = bounds.offset(axis, moved[axis]);
+    }
+    (bounds, moved)
+}
+
+fn horizontal_distance_squared(motion: Vec3d) -> f64 {
+    motion.x * motion.x + motion.z * motion.z
+}
+
+/// Moves `bounds` by `motion`, stopping along each axis at the first
+/// block in the way.
+///
+/// An entity walking on the ground into a ledge no higher than
+/// [`STEP_HEIGHT`] steps up onto it instead of stopping.
+///
+/// Returns the motion actually made.
+pub fn collide(view: &dyn BlockView, bounds: Bounds, motion: Vec3d, on_ground: bool) -> Vec3d {
+    // Vertical first, so entities land before sliding along the ground
+    let (_, moved) = move_along_axes(view, bounds, motion, &[1, 0, 2]);
+
+    let landed = motion.y < 0.0 && moved.y > motion.y + EPSILON;
+    let blocked = (moved.x - motion.x).abs() > EPSILON || (moved.z - motion.z).abs() > EPSILON;
+    if !(on_ground || landed) || !blocked {
+        return moved;
+    }
+
+    // Try again from a step higher, then settle onto what was stepped on
+    let lift = Vec3d::new(0.0, STEP_HEIGHT, 0.0);
+    let (raised, lifted) = move_along_axes(view, bounds, lift, &[1]);
+    let (stepped, mut step) = move_along_axes(view, raised, motion, &[0, 2]);
+    let settle = Vec3d::new(0.0, -lifted.y, 0.0);
+    let (_, settled) = move_along_axes(view, stepped, settle, &[1]);
+    step.y = lifted.y + settled.y;
+
+    if horizontal_distance_squared(step) > horizontal_distance_squared(moved) + EPSILON {
+        step
+    } else {
+        moved
+    }
+}
+
+/// The level of an active effect, starting at 1, or 0 if inactive.
+fn level(effects: Option<&StatusEffects>, kind: StatusEffectKind) -> u8 {
+    effects
+        .and_then(|effects| effects.get(kind))
+        .map_or(0, |effect| effect.amplifier.saturating_add(1))
+}
+
+/// Initial vertical velocity of a jump, raised by Jump Boost.
+pub fn jump_velocity(effects: Option<&StatusEffects>) -> f64 {
+    JUMP_VELOCITY + 0.1 * f64::from(level(effects, StatusEffectKind::JumpBoost))
+}
+
+/// Advances an airborne entity's vertical velocity by one tick.
+///
+/// Levitation pushes the entity towards a rising speed instead of
+/// letting it fall, and Slow Falling weakens gravity while descending.
+pub fn vertical_velocity_step(velocity: f64, effects: Option<&StatusEffects>) -> f64 {
+    let levitation = level(effects, StatusEffectKind::Levitation);
+    if levitation > 0 {
+        return velocity + (0.05 * f64::from(levitation) - velocity) * 0.2;
+    }
+
+    let slow_falling = level(effects, StatusEffectKind::SlowFalling) > 0;
+    let gravity = if slow_falling && velocity <= 0.0 {
+        SLOW_FALLING_GRAVITY
+    } else {
+        GRAVITY
+    };
+    (velocity - gravity) * VERTICAL_DRAG
+}
+
+/// Damage taken when landing after falling `distance` blocks.
+///
+/// Each level of Jump Boost adds a block of safe fall distance,
+/// and Slow Falling prevents fall damage entirely.
+pub fn fall_damage(distance: f64, effects: Option<&StatusEffects>) -> f32 {
+    if level(effects, StatusEffectKind::SlowFalling) > 0 {
+        return 0.0;
+    }
+    let safe = SAFE_FALL_DISTANCE + f64::from(level(effects, StatusEffectKind::JumpBoost));
+    (distance - safe).ceil().max(0.0) as f32
+}
+
+/// Whether entities of this kind are projectiles, which report the
+/// blocks they run into with a [`ProjectileHitBlockEvent`].
+pub fn is_projectile(kind: EntityKind) -> bool {
+    matches!(
+        kind,
+        EntityKind::Arrow
+            | EntityKind::SpectralArrow
+            | EntityKind::Trident
+            | EntityKind::Snowball
+            | EntityKind::Egg
+            | EntityKind::EnderPearl
+            | EntityKind::Potion
+            | EntityKind::ExperienceBottle
+            | EntityKind::Fireball
+            | EntityKind::SmallFireball
+            | EntityKind::DragonFireball
+            | EntityKind::WitherSkull
+            | EntityKind::LlamaSpit
+            | EntityKind::ShulkerBullet
+    )
+}
+
+/// The block an entity at `bounds` ran into when it tried to move by
+/// `motion` but only made it by `moved`, if anything stopped it.
+fn block_hit(
+    view: &dyn BlockView,
+    bounds: Bounds,
+    motion: Vec3d,
+    moved: Vec3d,
+) -> Option<ProjectileHitBlockEvent> {
+    let axis = [1, 0, 2]
+        .iter()
+        .copied()
+        .find(|axis| (moved[*axis] - motion[*axis]).abs() > EPSILON)?;
+    let forward = motion[axis] > 0.0;
+    let face = match (axis, forward) {
+        (0, true) => BlockFace::West,
+        (0, false) => BlockFace::East,
+        (1, true) => BlockFace::Bottom,
+        (1, false) => BlockFace::Top,
+        (2, true) => BlockFace::North,
+        _ => BlockFace::South,
+    };
+
+    // The point of the face the entity's centre line stopped at
+    let stopped = Bounds {
+        min: bounds.min + moved,
+        max: bounds.max + moved,
+    };
+    let mut hit = (stopped.min + stopped.max) / 2.0;
+    hit[axis] = if forward {
+        stopped.max[axis]
+    } else {
+        stopped.min[axis]
+    };
+
+    // Just past the face, inside the block that was hit
+    let mut inside = hit;
+    inside[axis] += if forward { 1e-4 } else { -1e-4 };
+    let cell = (
+        inside.x.floor() as i32,
+        inside.y.floor() as i32,
+        inside.z.floor() as i32,
+    );
+    // Fences and walls reach up from the block below
+    let contains = |cell: (i32, i32, i32)| {
+        block_bounds(view, cell).map_or(false, |block| {
+            (0..3).all(|axis| block.min[axis] <= inside[axis] && inside[axis] <= block.max[axis])
+        })
+    };
+    let (x, y, z) = if contains(cell) {
+        cell
+    } else {
+        (cell.0, cell.1 - 1, cell.2)
+    };
+
+    Some(ProjectileHitBlockEvent {
+        block: BlockPosition::new(x, y, z).try_into().ok()?,
+        face,
+        hit: position!(hit.x, hit.y, hit.z),
+    })
+}
+
+/// Whether the entity can move on its own without being pulled down.
+fn ignores_gravity(game: &Game, entity: Entity, kind: EntityKind, in_water: bool) -> bool {
+    game.ecs.get::<NoGravity>(entity).is_ok()
+        || game
+            .ecs
+            .get::<CreativeFlying>(entity)
+            .map_or(false, |flying| flying.0)
+        || flies(kind)
+        || (in_water && swims(kind))
+}
+
+/// Moves entities by their velocity, stopping them at blocks, then
+/// applies gravity, drag and friction to their velocity. Projectiles
+/// stopped by a block get a [`ProjectileHitBlockEvent`].
+///
+/// Players move themselves, passengers move with their vehicle,
+/// and boats have physics of their own.
+pub fn apply_physics(game: &mut Game) -> SysResult {
+    let entities: Vec<(Entity, EntityKind, Position, Velocity)> = game
+        .ecs
+        .query::<(&EntityKind, &Position, &Velocity)>()
+        .iter()
+        .map(|(entity, (kind, position, velocity))| (entity, *kind, *position, *velocity))
+        .collect();
+
+    for (entity, kind, mut position, mut velocity) in entities {
+        if game.ecs.get::<Player>(entity).is_ok()
+            || game.ecs.get::<Riding>(entity).is_ok()
+            || game.ecs.get::<BoatMotion>(entity).is_ok()
+            || !game.world.is_chunk_loaded(position.chunk())
+        {
+            continue;
+        }
+
+        let motion = Vec3d::new(velocity.x, velocity.y, velocity.z);
+        let was_on_ground = game
+            .ecs
+            .get::<OnGround>(entity)
+            .map_or(false, |on_ground| on_ground.0);
+        let bounds = Bounds::of_entity(kind, position);
+        let moved = collide(&*game, bounds, motion, was_on_ground);
+        if is_projectile(kind) {
+            if let Some(hit) = block_hit(&*game, bounds, motion, moved) {
+                game.ecs.insert_entity_event(entity, hit)?;
+            }
+        }
+        position.x += moved.x;
+        position.y += moved.y;
+        position.z += moved.z;
+
+        let on_ground = motion.y < 0.0 && moved.y > motion.y + EPSILON;
+        if (moved.x - motion.x).abs() > EPSILON {
+            velocity.x = 0.0;
+        }
+        if (moved.y - motion.y).abs() > EPSILON {
+            velocity.y = 0.0;
+        }
+        if (moved.z - motion.z).abs() > EPSILON {
+            velocity.z = 0.0;
+        }
+
+        let feet = (
+            position.x.floor() as i32,
+            position.y.floor() as i32,
+            position.z.floor() as i32,
+        );
+        let in_water = game.kind_at(feet) == Some(BlockKind::Water);
+        let gravity = !ignores_gravity(game, entity, kind, in_water);
+        if in_water {
+            velocity.x *= WATER_DRAG;
+            velocity.y *= WATER_DRAG;
+            velocity.z *= WATER_DRAG;
+            if gravity {
+                velocity.y -= WATER_GRAVITY;
+            }
+        } else {
+            velocity.y = if gravity {
+                let effects = game.ecs.get::<StatusEffects>(entity).ok();
+                vertical_velocity_step(velocity.y, effects.as_deref())
+            } else {
+                velocity.y * VERTICAL_DRAG
+            };
+            let friction = if on_ground {
+                let below = game.kind_at((feet.0, (position.y - 0.5).floor() as i32, feet.2));
+                below.map_or(DEFAULT_SLIPPERINESS, slipperiness) * AIR_DRAG
+            } else {
+                AIR_DRAG
+            };
+            velocity.x *= friction;
+            velocity.z *= friction;
+        }
+
+        *game.ecs.get_mut::<Position>(entity)? = position;
+        *game.ecs.get_mut::<Velocity>(entity)? = velocity;
+        if let Ok(mut grounded) = game.ecs.get_mut::<OnGround>(entity) {
+            grounded.0 = on_ground;
+        }
+
+        let fallen = game
+            .ecs
+            .get::<FallDistance>(entity)
+            .map_or(0.0, |fallen| fallen.0);
+        update_fall_distance(game, entity, fallen - moved.y.min(0.0), on_ground, in_water)?;
+    }
+    Ok(())
+}
+
+/// Stores how far `entity` has fallen, hurting it once it lands.
+/// Water breaks the fall.
+fn update_fall_distance(
+    game: &mut Game,
+    entity: Entity,
+    fallen: f64,
+    on_ground: bool,
+    in_water: bool,
+) -> SysResult {
+    if on_ground && !in_water {
+        let damage = {
+            let effects = game.ecs.get::<StatusEffects>(entity).ok();
+            fall_damage(fallen, effects.as_deref())
+        };
+        if damage > 0.0 {
+            combat::apply_damage(game, entity, None, damage, DamageType::Fall)?;
+        }
+    }
+
+    if on_ground || in_water || fallen <= 0.0 {
+        let _ = game.ecs.remove::<FallDistance>(entity);
+    } else {
+        game.ecs.insert(entity, FallDistance(fallen))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockId, BlockPosition};
+
+    use quill_common::components::Health;
+
+    use crate::{status_effects::StatusEffect, test_support::TestGame};
+
+    use super::*;
+
+    /// A world with a stone floor at y = 63, stepping the physics system
+    fn flat_world() -> TestGame {
+        let mut test = TestGame::builder()
+            .chunks(1)
+            .register(|_, systems| register(systems))
+            .build();
+        test.fill(
+            BlockPosition::new(-16, 63, -16),
+            BlockPosition::new(31, 63, 31),
+            BlockId::stone(),
+        );
+        test
+    }
+
+    fn spawn_zombie(test: &mut TestGame, position: Position) -> Entity {
+        test.game.ecs.spawn((
+            EntityKind::Zombie,
+            position,
+            Velocity::default(),
+            OnGround(false),
+        ))
+    }
+
+    /// Keeps setting the horizontal velocity of `entity`, like a mob walking
+    fn walk(test: &mut TestGame, entity: Entity, x: f64, z: f64) {
+        test.game.system_executor.borrow_mut().add_system_to_stage(
+            SystemStage::Movement,
+            move |game: &mut Game| {
+                let mut velocity = game.ecs.get_mut::<Velocity>(entity)?;
+                velocity.x = x;
+                velocity.z = z;
+                Ok(())
+            },
+        );
+    }
+
+    fn with_effect(kind: StatusEffectKind, amplifier: u8) -> StatusEffects {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::new(kind, amplifier, 600));
+        effects
+    }
+
+    /// Height reached by a jump before the entity starts falling
+    fn jump_height(effects: Option<&StatusEffects>) -> f64 {
+        let mut velocity = jump_velocity(effects);
+        let mut height = 0.0;
+        while velocity > 0.0 {
+            height += velocity;
+            velocity = vertical_velocity_step(velocity, effects);
+        }
+        height
+    }
+
+    #[test]
+    fn jump_boost_raises_jump_and_softens_landing() {
+        let boost = with_effect(StatusEffectKind::JumpBoost, 1);
+        assert!(jump_height(Some(&boost)) > jump_height(None) + 0.5);
+
+        assert_eq!(fall_damage(6.0, None), 3.0);
+        assert_eq!(fall_damage(6.0, Some(&boost)), 1.0);
+    }
+
+    #[test]
+    fn slow_falling_caps_descent_and_prevents_damage() {
+        let slow = with_effect(StatusEffectKind::SlowFalling, 0);
+        let (mut normal, mut slowed) = (0.0, 0.0);
+        for _ in 0..100 {
+            normal = vertical_velocity_step(normal, None);
+            slowed = vertical_velocity_step(slowed, Some(&slow));
+        }
+        assert!(normal < -3.0);
+        assert!(slowed > -0.5);
+        assert_eq!(fall_damage(50.0, Some(&slow)), 0.0);
+    }
+
+    #[test]
+    fn airborne_entities_fall_and_land() {
+        let mut test = flat_world();
+        let zombie = spawn_zombie(&mut test, position!(8.5, 70.0, 8.5));
+
+        test.step_n(3);
+        assert!(test.component::<Position>(zombie).y < 70.0);
+        test.assert_component(zombie, OnGround(false));
+
+        test.step_n(40);
+        let position = test.component::<Position>(zombie);
+        assert!((position.y - 64.0).abs() < 1e-6);
+        assert_eq!((position.x, position.z), (8.5, 8.5));
+        test.assert_component(zombie, OnGround(true));
+
+        // Entities without gravity stay where they are
+        let floating = spawn_zombie(&mut test, position!(4.5, 70.0, 4.5));
+        test.game.ecs.insert(floating, NoGravity).unwrap();
+        test.step_n(10);
+        assert_eq!(test.component::<Position>(floating).y, 70.0);
+    }
+
+    #[test]
+    fn landing_after_a_long_fall_hurts() {
+        let mut test = flat_world();
+        let high = spawn_zombie(&mut test, position!(8.5, 73.5, 8.5));
+        let low = spawn_zombie(&mut test, position!(4.5, 66.0, 4.5));
+        for entity in [high, low].iter().copied() {
+            test.game.ecs.insert(entity, Health::new(20.0)).unwrap();
+        }
+
+        test.step_n(60);
+
+        // 9.5 blocks down, 3 of which are safe
+        assert_eq!(test.component::<Health>(high).current, 13.0);
+        assert_eq!(test.component::<Health>(low).current, 20.0);
+        assert!(test.game.ecs.get::<FallDistance>(high).is_err());
+    }
+
+    #[test]
+    fn walls_stop_horizontal_movement() {
+        let mut test = flat_world();
+        test.fill(
+            BlockPosition::new(12, 64, 0),
+            BlockPosition::new(12, 66, 15),
+            BlockId::stone(),
+        );
+        let zombie = spawn_zombie(&mut test, position!(8.5, 64.0, 8.5));
+        walk(&mut test, zombie, 0.3, 0.0);
+
+        test.step_n(30);
+        let position = test.component::<Position>(zombie);
+        // Half a zombie's width from the wall
+        assert!((position.x - 11.7).abs() < 1e-6);
+        assert_eq!(position.z, 8.5);
+        assert!((position.y - 64.0).abs() < 1e-6);
+        test.assert_component(zombie, OnGround(true));
+    }
+
+    #[test]
+    fn entities_slide_along_walls() {
+        let mut test = flat_world();
+        test.fill(
+            BlockPosition::new(12, 64, -16),
+            BlockPosition::new(12, 66, 31),
+            BlockId::stone(),
+        );
+        let zombie = spawn_zombie(&mut test, position!(8.5, 64.0, 0.5));
+        walk(&mut test, zombie, 0.2, 0.1);
+
+        test.step_n(40);
+        let position = test.component::<Position>(zombie);
+        assert!((position.x - 11.7).abs() < 1e-6);
+        assert!((position.z - 4.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn entities_step_up_slabs_but_not_walls() {
+        let mut test = flat_world();
+        test.fill(
+            BlockPosition::new(12, 64, 0),
+            BlockPosition::new(15, 64, 15),
+            BlockId::stone_slab(),
+        );
+        test.fill(
+            BlockPosition::new(16, 64, 0),
+            BlockPosition::new(16, 66, 15),
+            BlockId::stone(),
+        );
+        let zombie = spawn_zombie(&mut test, position!(8.5, 64.0, 8.5));
+        walk(&mut test, zombie, 0.2, 0.0);
+
+        test.step_n(20);
+        let position = test.component::<Position>(zombie);
+        assert!(position.x > 12.0);
+        assert!((position.y - 64.5).abs() < 1e-6);
+        test.assert_component(zombie, OnGround(true));
+
+        test.step_n(20);
+        let position = test.component::<Position>(zombie);
+        assert!((position.x - 15.7).abs() < 1e-6);
+        assert!((position.y - 64.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn entities_stand_on_collision_shapes() {
+        let mut test = flat_world();
+        let fence = BlockPosition::new(4, 64, 4).try_into().unwrap();
+        test.game.set_block(fence, BlockId::oak_fence());
+        let on_fence = spawn_zombie(&mut test, position!(4.5, 70.0, 4.5));
+        let on_floor = spawn_zombie(&mut test, position!(8.5, 70.0, 8.5));
+
+        test.step_n(40);
+        // Fences are one and a half blocks tall
+        assert!((test.component::<Position>(on_fence).y - 65.5).abs() < 1e-6);
+        assert!((test.component::<Position>(on_floor).y - 64.0).abs() < 1e-6);
+        test.assert_component(on_fence, OnGround(true));
+        test.assert_component(on_floor, OnGround(true));
+    }
+
+    #[test]
+    fn projectiles_report_the_blocks_they_hit() {
+        let mut test = flat_world();
+        let wall = BlockPosition::new(8, 64, 4).try_into().unwrap();
+        test.game.set_block(wall, BlockId::stone());
+        let snowball = test.game.ecs.spawn((
+            EntityKind::Snowball,
+            position!(4.5, 64.5, 4.5),
+            Velocity {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            OnGround(false),
+        ));
+
+        let mut hit = None;
+        for _ in 0..10 {
+            test.step();
+            if let Ok(event) = test.game.ecs.get::<ProjectileHitBlockEvent>(snowball) {
+                hit = Some(*event);
+                break;
+            }
+        }
+        let hit = hit.expect("snowball never hit the wall");
+        assert_eq!(hit.block, wall);
+        assert_eq!(hit.face, BlockFace::West);
+        assert!((hit.hit.x - 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn levitation_lifts_entities() {
+        let levitation = with_effect(StatusEffectKind::Levitation, 0);
+        let mut velocity = -0.2;
+        let mut height = 0.0;
+        for _ in 0..40 {
+            velocity = vertical_velocity_step(velocity, Some(&levitation));
+            height += velocity;
+        }
+        assert!(height > 0.0);
+        assert!(velocity > 0.0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Placing the blocks players hold.
+
+use std::convert::TryInto;
+
+use base::{
+    vec3, BlockKind, BlockPosition, Gamemode, Inventory, Item, Position, ValidBlockPosition,
+};
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_blocks::BlockProperties;
+use libcraft_core::BlockFace;
+use quill_common::events::BlockPlacementEvent;
+
+use crate::{
+    entities::player::{held_item, HotbarSlot},
+    events::VibrationEvent,
+    Game,
+};
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(place_blocks);
+}
+
+/// The position next to `pos` on its `face` side.
+fn adjacent(pos: BlockPosition, face: BlockFace) -> BlockPosition {
+    match face {
+        BlockFace::Bottom => pos.down(),
+        BlockFace::Top => pos.up(),
+        BlockFace::North => pos.north(),
+        BlockFace::South => pos.south(),
+        BlockFace::West => pos.west(),
+        BlockFace::East => pos.east(),
+    }
+}
+
+/// The wall-mounted variant of blocks placed from `item`, if it has one.
+fn wall_variant(item: Item) -> Option<BlockKind> {
+    Some(match item {
+        Item::Torch => BlockKind::WallTorch,
+        Item::SoulTorch => BlockKind::SoulWallTorch,
+        Item::RedstoneTorch => BlockKind::RedstoneWallTorch,
+        _ => return None,
+    })
+}
+
+/// The block placed by using `item` on the `face` side of a block,
+/// or `None` if the item is not a block.
+///
+/// Items with a wall-mounted variant use it when placed against the
+/// side of a block, facing away from that block.
+pub fn block_for_item(item: Item, face: BlockFace) -> Option<BlockProperties> {
+    let facing = match face {
+        BlockFace::North => "north",
+        BlockFace::South => "south",
+        BlockFace::West => "west",
+        BlockFace::East => "east",
+        BlockFace::Top | BlockFace::Bottom => "",
+    };
+    if let (Some(kind), false) = (wall_variant(item), facing.is_empty()) {
+        let mut properties = kind.default_properties();
+        properties.set("facing", facing);
+        return Some(properties);
+    }
+    BlockKind::from_name(item.name()).map(|kind| kind.default_properties())
+}
+
+/// Places the blocks players use on the side of other blocks,
+/// consuming the item unless they are in creative mode.
+fn place_blocks(game: &mut Game) -> SysResult {
+    let uses: Vec<(Entity, BlockPlacementEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockPlacementEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(player, (event, inventory, hotbar_slot))| {
+            (player, event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (player, event, inventory, hotbar_slot) in uses {
+        let mut held = held_item(&inventory, hotbar_slot, event.hand);
+        let properties = match held
+            .item_kind()
+            .and_then(|item| block_for_item(item, event.face))
+        {
+            Some(properties) => properties,
+            None => continue,
+        };
+        let pos: ValidBlockPosition = match adjacent(event.location, event.face).try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        if !game.block(pos).map_or(false, |block| block.is_air()) {
+            continue;
+        }
+
+        if game.place_block(pos, &properties) {
+            game.ecs.insert_event(VibrationEvent {
+                position: Position::from(pos) + vec3(0.5, 0.5, 0.5),
+            });
+            let creative = game
+                .ecs
+                .get::<Gamemode>(player)
+                .map_or(false, |gamemode| *gamemode == Gamemode::Creative);
+            if !creative {
+                let _ = held.try_take(1);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, Chunk, ChunkPosition};
+
+    use super::*;
+
+    fn pos(x: i32, y: i32, z: i32) -> ValidBlockPosition {
+        BlockPosition::new(x, y, z).try_into().unwrap()
+    }
+
+    #[test]
+    fn torches_on_walls_face_away_from_them() {
+        let torch = block_for_item(Item::Torch, BlockFace::East).unwrap();
+        assert_eq!(torch.kind(), BlockKind::WallTorch);
+        assert_eq!(torch.get("facing").map(String::as_str), Some("east"));
+        assert_eq!(
+            block_for_item(Item::Torch, BlockFace::Top).unwrap().kind(),
+            BlockKind::Torch
+        );
+        assert_eq!(
+            block_for_item(Item::Stone, BlockFace::West).unwrap().kind(),
+            BlockKind::Stone
+        );
+        assert!(block_for_item(Item::Diamond, BlockFace::Top).is_none());
+    }
+
+    #[test]
+    fn placement_checks_support() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        game.set_block(pos(1, 64, 1), BlockId::stone());
+
+        let torch = block_for_item(Item::Torch, BlockFace::East).unwrap();
+        assert!(game.place_block(pos(2, 64, 1), &torch));
+        let torch = block_for_item(Item::Torch, BlockFace::West).unwrap();
+        assert!(!game.place_block(pos(3, 64, 1), &torch));
+    }
+}
@@ -0,0 +1,466 @@
+//! Portals, which send entities standing in them to another dimension.
+//!
+//! Nether portals are lit by setting fire inside an obsidian frame and
+//! take players a few seconds of standing in them, while end portals act
+//! right away. Either way, the move itself is left to whoever handles
+//! the [`DimensionChangeRequestEvent`].
+
+use std::convert::TryInto;
+
+use base::{
+    position, BlockFace, BlockId, BlockKind, BlockPosition, Gamemode, Inventory, Item, Position,
+    ValidBlockPosition,
+};
+use blocks::AxisXz;
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::{entities::Player, events::BlockInteractEvent};
+
+use crate::{
+    entities::player::{held_item, HotbarSlot},
+    events::{BlockChangeEvent, DimensionChangeRequestEvent},
+    Dimension, Game, WorldSpawn,
+};
+
+/// Ticks a player in survival stands in a nether portal before going through
+pub const PLAYER_NETHER_PORTAL_TICKS: u32 = 80;
+/// Ticks before an entity that went through a portal can use one again
+pub const PORTAL_COOLDOWN_TICKS: u32 = 300;
+/// Horizontal distance in the Overworld covered by one block in the Nether
+pub const NETHER_SCALE: f64 = 8.0;
+/// Largest width and height of the inside of a nether portal frame
+const MAX_FRAME_SIZE: i32 = 21;
+/// Smallest width of the inside of a nether portal frame
+const MIN_FRAME_WIDTH: i32 = 2;
+/// Smallest height of the inside of a nether portal frame
+const MIN_FRAME_HEIGHT: i32 = 3;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(trigger_portals);
+    systems.add_system(use_flint_and_steel);
+}
+
+/// Ticks an entity has spent in a nether portal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PortalDwell(pub u32);
+
+/// Ticks until an entity can go through a portal again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortalCooldown(pub u32);
+
+/// Where a nether portal at `position` in `dimension` leads, scaling
+/// the horizontal coordinates between the Overworld and the Nether.
+pub fn nether_portal_target(
+    dimension: Dimension,
+    position: Position,
+) -> Option<(Dimension, Position)> {
+    let (target, scale) = match dimension {
+        Dimension::Overworld => (Dimension::Nether, 1.0 / NETHER_SCALE),
+        Dimension::Nether => (Dimension::Overworld, NETHER_SCALE),
+        Dimension::End => return None,
+    };
+    let target_position = position!(position.x * scale, position.y, position.z * scale);
+    Some((target, target_position))
+}
+
+/// Where an end portal in `dimension` leads: the obsidian platform in
+/// the End, or back to the world spawn from the End.
+pub fn end_portal_target(game: &Game, dimension: Dimension) -> SysResult<(Dimension, Position)> {
+    Ok(match dimension {
+        Dimension::End => {
+            let spawn = game.resources.get::<WorldSpawn>()?.0;
+            (
+                Dimension::Overworld,
+                position!(
+                    f64::from(spawn.x()) + 0.5,
+                    f64::from(spawn.y()),
+                    f64::from(spawn.z()) + 0.5
+                ),
+            )
+        }
+        _ => (Dimension::End, position!(100.5, 49.0, 0.5)),
+    })
+}
+
+/// The inside of an obsidian frame that a nether portal can fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortalFrame {
+    /// The lowest inside corner with the smallest coordinates
+    pub corner: BlockPosition,
+    /// The horizontal axis the frame lies along
+    pub axis: AxisXz,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl PortalFrame {
+    /// The positions inside the frame.
+    pub fn interior(&self) -> impl Iterator<Item = BlockPosition> + '_ {
+        (0..self.width).flat_map(move |along| {
+            (0..self.height).map(move |up| offset_along(self.corner, self.axis, along, up))
+        })
+    }
+}
+
+fn offset_along(pos: BlockPosition, axis: AxisXz, along: i32, up: i32) -> BlockPosition {
+    match axis {
+        AxisXz::X => BlockPosition::new(pos.x + along, pos.y + up, pos.z),
+        AxisXz::Z => BlockPosition::new(pos.x, pos.y + up, pos.z + along),
+    }
+}
+
+fn kind_at(game: &Game, pos: BlockPosition) -> Option<BlockKind> {
+    let pos: ValidBlockPosition = pos.try_into().ok()?;
+    game.block(pos).map(|block| block.kind())
+}
+
+fn is_obsidian(game: &Game, pos: BlockPosition) -> bool {
+    kind_at(game, pos) == Some(BlockKind::Obsidian)
+}
+
+fn is_empty(game: &Game, pos: BlockPosition) -> bool {
+    matches!(
+        kind_at(game, pos),
+        Some(BlockKind::Air) | Some(BlockKind::Fire)
+    )
+}
+
+/// Looks for an obsidian frame along `axis` around the empty block at `pos`.
+fn find_frame_along(game: &Game, pos: BlockPosition, axis: AxisXz) -> Option<PortalFrame> {
+    let mut corner = pos;
+    let mut steps = 0;
+    while is_empty(game, corner.down()) {
+        corner = corner.down();
+        steps += 1;
+        if steps >= MAX_FRAME_SIZE {
+            return None;
+        }
+    }
+    let mut steps = 0;
+    while is_empty(game, offset_along(corner, axis, -1, 0)) {
+        corner = offset_along(corner, axis, -1, 0);
+        steps += 1;
+        if steps >= MAX_FRAME_SIZE {
+            return None;
+        }
+    }
+    if !is_obsidian(game, offset_along(corner, axis, -1, 0)) {
+        return None;
+    }
+
+    let mut width = 0;
+    while width <= MAX_FRAME_SIZE && is_empty(game, offset_along(corner, axis, width, 0)) {
+        if !is_obsidian(game, offset_along(corner, axis, width, -1)) {
+            return None;
+        }
+        width += 1;
+    }
+    if width < MIN_FRAME_WIDTH
+        || width > MAX_FRAME_SIZE
+        || !is_obsidian(game, offset_along(corner, axis, width, 0))
+    {
+        return None;
+    }
+
+    for height in 0..=MAX_FRAME_SIZE {
+        let top =
+            (0..width).all(|along| is_obsidian(game, offset_along(corner, axis, along, height)));
+        if top {
+            if height < MIN_FRAME_HEIGHT {
+                return None;
+            }
+            return Some(PortalFrame {
+                corner,
+                axis,
+                width,
+                height,
+            });
+        }
+        let row_open = (0..width)
+            .all(|along| is_empty(game, offset_along(corner, axis, along, height)))
+            && is_obsidian(game, offset_along(corner, axis, -1, height))
+            && is_obsidian(game, offset_along(corner, axis, width, height));
+        if !row_open {
+            return None;
+        }
+    }
+    None
+}
+
+/// Finds the obsidian frame that the empty block at `pos` is inside of.
+pub fn find_frame(game: &Game, pos: BlockPosition) -> Option<PortalFrame> {
+    if !is_empty(game, pos) {
+        return None;
+    }
+    find_frame_along(game, pos, AxisXz::X).or_else(|| find_frame_along(game, pos, AxisXz::Z))
+}
+
+/// Sets fire to the empty block at `pos`, as flint and steel does. Fire
+/// inside an obsidian frame lights a nether portal filling the frame.
+///
+/// Returns the lit portal's frame, if any.
+pub fn ignite(game: &mut Game, pos: ValidBlockPosition) -> Option<PortalFrame> {
+    let frame = match game.world.dimension {
+        Dimension::End => None,
+        _ => find_frame(game, pos.into()),
+    };
+    let frame = match frame {
+        Some(frame) => frame,
+        None => {
+            game.set_block(pos, BlockId::fire());
+            return None;
+        }
+    };
+
+    // Every portal block needs its neighbors in place, so they are all
+    // set before any of them checks for support
+    let portal = BlockId::nether_portal().with_axis_xz(frame.axis);
+    let interior: Vec<ValidBlockPosition> = frame
+        .interior()
+        .filter_map(|pos| pos.try_into().ok())
+        .collect();
+    for pos in &interior {
+        game.world.set_block_at(*pos, portal);
+        game.ecs.insert_event(BlockChangeEvent::single(*pos));
+    }
+    let center = position!(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 0.5,
+        f64::from(pos.z()) + 0.5
+    );
+    game.emit_sound("block.portal.trigger", center, 1.0, 1.0);
+    Some(frame)
+}
+
+/// Lights fires, and with them portals, where players use flint and steel.
+fn use_flint_and_steel(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockInteractEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockInteractEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let held = held_item(&inventory, hotbar_slot, event.hand);
+        if held.item_kind() != Some(Item::FlintAndSteel) {
+            continue;
+        }
+        let location = event.location;
+        let target = match event.face {
+            BlockFace::Bottom => location.down(),
+            BlockFace::Top => location.up(),
+            BlockFace::North => location.north(),
+            BlockFace::South => location.south(),
+            BlockFace::West => location.west(),
+            BlockFace::East => location.east(),
+        };
+        let target: ValidBlockPosition = match target.try_into() {
+            Ok(target) => target,
+            Err(_) => continue,
+        };
+        if game.block(target).map_or(false, |block| block.is_air()) {
+            ignite(game, target);
+        }
+    }
+    Ok(())
+}
+
+/// Ticks `entity` has to stand in a nether portal to go through it.
+fn nether_portal_ticks(game: &Game, entity: Entity) -> u32 {
+    let player = game.ecs.get::<Player>(entity).is_ok();
+    let creative = game
+        .ecs
+        .get::<Gamemode>(entity)
+        .map_or(false, |gamemode| *gamemode == Gamemode::Creative);
+    if player && !creative {
+        PLAYER_NETHER_PORTAL_TICKS
+    } else {
+        1
+    }
+}
+
+fn portal_at(game: &Game, position: Position) -> Option<BlockKind> {
+    let pos: ValidBlockPosition = position.block().try_into().ok()?;
+    match game.block(pos)?.kind() {
+        kind @ BlockKind::NetherPortal | kind @ BlockKind::EndPortal => Some(kind),
+        _ => None,
+    }
+}
+
+/// Requests dimension changes for entities that have stood in
+/// portals long enough, and counts down their cooldowns.
+fn trigger_portals(game: &mut Game) -> SysResult {
+    for (_, cooldown) in game.ecs.query::<&mut PortalCooldown>().iter() {
+        cooldown.0 = cooldown.0.saturating_sub(1);
+    }
+
+    let entities: Vec<(Entity, Position, Option<BlockKind>)> = game
+        .ecs
+        .query::<&Position>()
+        .iter()
+        .map(|(entity, position)| (entity, *position, portal_at(game, *position)))
+        .collect();
+
+    let dimension = game.world.dimension;
+    for (entity, position, portal) in entities {
+        let portal = match portal {
+            Some(portal) => portal,
+            None => {
+                let _ = game.ecs.remove::<PortalDwell>(entity);
+                continue;
+            }
+        };
+        let cooling_down = game
+            .ecs
+            .get::<PortalCooldown>(entity)
+            .map_or(false, |cooldown| cooldown.0 > 0);
+        if cooling_down {
+            continue;
+        }
+
+        let target = if portal == BlockKind::NetherPortal {
+            let dwell = game
+                .ecs
+                .get::<PortalDwell>(entity)
+                .map_or(0, |dwell| dwell.0)
+                + 1;
+            if dwell < nether_portal_ticks(game, entity) {
+                game.ecs.insert(entity, PortalDwell(dwell))?;
+                continue;
+            }
+            match nether_portal_target(dimension, position) {
+                Some(target) => target,
+                None => continue,
+            }
+        } else {
+            end_portal_target(game, dimension)?
+        };
+
+        let _ = game.ecs.remove::<PortalDwell>(entity);
+        game.ecs
+            .insert(entity, PortalCooldown(PORTAL_COOLDOWN_TICKS))?;
+        game.ecs.insert_event(DimensionChangeRequestEvent {
+            entity,
+            dimension: target.0,
+            position: target.1,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, BlockPosition, Chunk, ChunkPosition};
+
+    use super::*;
+
+    fn requests(game: &Game) -> Vec<DimensionChangeRequestEvent> {
+        game.ecs
+            .query::<&DimensionChangeRequestEvent>()
+            .iter()
+            .map(|(_, event)| *event)
+            .collect()
+    }
+
+    #[test]
+    fn standing_in_a_nether_portal_requests_the_nether() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let pos = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        game.set_block(pos, BlockId::nether_portal());
+        let player = game
+            .ecs
+            .spawn((Player, Gamemode::Survival, position!(8.5, 64.0, 8.5)));
+
+        for _ in 1..PLAYER_NETHER_PORTAL_TICKS {
+            trigger_portals(&mut game).unwrap();
+        }
+        assert!(requests(&game).is_empty());
+
+        trigger_portals(&mut game).unwrap();
+        let requests = requests(&game);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].entity, player);
+        assert_eq!(requests[0].dimension, Dimension::Nether);
+        assert_eq!(requests[0].position, position!(1.0625, 64.0, 1.0625));
+        assert_eq!(
+            *game.ecs.get::<PortalCooldown>(player).unwrap(),
+            PortalCooldown(PORTAL_COOLDOWN_TICKS)
+        );
+        assert!(game.ecs.get::<PortalDwell>(player).is_err());
+    }
+
+    /// Builds a 4×5 obsidian frame along the x axis, without its corners
+    /// and with the top left out if `closed` is false.
+    fn build_frame(game: &mut Game, closed: bool) {
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let mut frame = Vec::new();
+        for x in 5..=6 {
+            frame.push((x, 64));
+            if closed {
+                frame.push((x, 68));
+            }
+        }
+        for y in 65..=67 {
+            frame.push((4, y));
+            frame.push((7, y));
+        }
+        for (x, y) in frame {
+            let pos = BlockPosition::new(x, y, 8).try_into().unwrap();
+            game.set_block(pos, BlockId::obsidian());
+        }
+    }
+
+    fn block_kind(game: &Game, x: i32, y: i32, z: i32) -> BlockKind {
+        let pos = BlockPosition::new(x, y, z).try_into().unwrap();
+        game.block(pos).unwrap().kind()
+    }
+
+    #[test]
+    fn lighting_a_frame_fills_it_with_portal() {
+        let mut game = Game::new();
+        build_frame(&mut game, true);
+
+        let lit = BlockPosition::new(6, 66, 8).try_into().unwrap();
+        let frame = ignite(&mut game, lit).unwrap();
+        assert_eq!(frame.axis, AxisXz::X);
+        assert_eq!((frame.width, frame.height), (2, 3));
+        for x in 5..=6 {
+            for y in 65..=67 {
+                let pos = BlockPosition::new(x, y, 8).try_into().unwrap();
+                assert_eq!(
+                    game.block(pos).unwrap(),
+                    BlockId::nether_portal().with_axis_xz(AxisXz::X)
+                );
+            }
+        }
+        assert_eq!(block_kind(&game, 5, 66, 9), BlockKind::Air);
+
+        // Breaking the frame breaks the whole portal
+        let side = BlockPosition::new(4, 66, 8).try_into().unwrap();
+        game.break_block(side);
+        for x in 5..=6 {
+            for y in 65..=67 {
+                assert_eq!(block_kind(&game, x, y, 8), BlockKind::Air);
+            }
+        }
+    }
+
+    #[test]
+    fn lighting_an_open_frame_only_sets_fire() {
+        let mut game = Game::new();
+        build_frame(&mut game, false);
+
+        let lit = BlockPosition::new(5, 65, 8).try_into().unwrap();
+        assert_eq!(ignite(&mut game, lit), None);
+        assert_eq!(block_kind(&game, 5, 65, 8), BlockKind::Fire);
+        assert_eq!(block_kind(&game, 6, 65, 8), BlockKind::Air);
+        assert_eq!(block_kind(&game, 5, 66, 8), BlockKind::Air);
+    }
+}
@@ -0,0 +1,306 @@
+//! Redstone input components: levers, buttons and pressure plates,
+//! along with the detectors in the submodules.
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{BlockKind, BlockPosition, Position, ValidBlockPosition};
+use blocks::SimplifiedBlockKind;
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::{button_press_ticks, BlockProperties, MAX_POWER};
+use quill_common::{components::Health, events::BlockInteractEvent};
+
+use crate::{interactable::InteractableRegistry, Game};
+
+pub mod daylight_detector;
+pub mod target;
+pub mod tripwire;
+
+/// Inputs players use by right clicking them
+const USABLE_INPUTS: [BlockKind; 11] = [
+    BlockKind::Lever,
+    BlockKind::StoneButton,
+    BlockKind::PolishedBlackstoneButton,
+    BlockKind::OakButton,
+    BlockKind::SpruceButton,
+    BlockKind::BirchButton,
+    BlockKind::JungleButton,
+    BlockKind::AcaciaButton,
+    BlockKind::DarkOakButton,
+    BlockKind::CrimsonButton,
+    BlockKind::WarpedButton,
+];
+
+/// Entities needed on a heavy weighted pressure plate per power level
+const HEAVY_PLATE_ENTITIES_PER_LEVEL: u32 = 10;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    {
+        let mut registry = game
+            .resources
+            .get_mut::<InteractableRegistry>()
+            .expect("Failed to get the interactable registry");
+        for kind in USABLE_INPUTS.iter() {
+            registry.register(*kind);
+        }
+    }
+    game.insert_resource(PressedPlates::default());
+    systems
+        .add_system(use_inputs)
+        .add_system(update_pressure_plates);
+    daylight_detector::register(systems);
+    target::register(systems);
+    tripwire::register(game, systems);
+}
+
+/// Pressure plates currently pressed by entities.
+#[derive(Debug, Default)]
+struct PressedPlates(HashSet<ValidBlockPosition>);
+
+fn is_powered(properties: &BlockProperties) -> bool {
+    properties
+        .get("powered")
+        .map_or(false, |powered| powered == "true")
+}
+
+/// Offset from an input to the block it is mounted on
+fn mounted_offset(properties: &BlockProperties) -> (i32, i32, i32) {
+    let face = match properties.kind() {
+        BlockKind::TripwireHook => Some("wall"),
+        _ => properties.get("face").map(String::as_str),
+    };
+    match face {
+        Some("ceiling") => (0, 1, 0),
+        // Wall-mounted inputs face away from their wall
+        Some("wall") => match properties.get("facing").map(String::as_str) {
+            Some("north") => (0, 0, 1),
+            Some("south") => (0, 0, -1),
+            Some("west") => (1, 0, 0),
+            _ => (-1, 0, 0),
+        },
+        // Floor-mounted levers and buttons, and pressure plates
+        _ => (0, -1, 0),
+    }
+}
+
+/// Changes the state of an input, updating its neighbors as well as those
+/// of the block it is mounted on, since that block passes its power on.
+fn set_input(game: &mut Game, pos: ValidBlockPosition, properties: &BlockProperties) {
+    if !game.set_block_properties(pos, properties) {
+        return;
+    }
+    let (dx, dy, dz) = mounted_offset(properties);
+    let mounted_on = BlockPosition::new(pos.x() + dx, pos.y() + dy, pos.z() + dz);
+    if let Ok(mounted_on) = mounted_on.try_into() {
+        game.update_neighbors(mounted_on);
+    }
+}
+
+/// Flips the lever at `pos`, returning whether it is now on.
+pub fn toggle_lever(game: &mut Game, pos: ValidBlockPosition) -> SysResult<bool> {
+    let mut properties = match game.block_properties(pos) {
+        Some(properties) if properties.kind() == BlockKind::Lever => properties,
+        _ => bail!("no lever at {:?}", pos),
+    };
+    let powered = !is_powered(&properties);
+    properties.set("powered", &powered.to_string());
+    set_input(game, pos, &properties);
+    Ok(powered)
+}
+
+/// Presses the button at `pos`. The button stays powered for a while,
+/// then pops back out on a scheduled block tick.
+///
+/// Returns `false` if the button was already pressed.
+pub fn press_button(game: &mut Game, pos: ValidBlockPosition) -> SysResult<bool> {
+    let (mut properties, ticks) = match game.block_properties(pos) {
+        Some(properties) => match button_press_ticks(properties.kind()) {
+            Some(ticks) => (properties, ticks),
+            None => bail!("no button at {:?}", pos),
+        },
+        None => bail!("no button at {:?}", pos),
+    };
+    if is_powered(&properties) {
+        return Ok(false);
+    }
+
+    properties.set("powered", "true");
+    set_input(game, pos, &properties);
+    game.block_integration.tick_executor_mut().schedule_tick(
+        (pos.x(), pos.y(), pos.z()),
+        properties.kind(),
+        ticks,
+        0,
+    );
+    Ok(true)
+}
+
+/// Flips levers and presses buttons players interacted with.
+fn use_inputs(game: &mut Game) -> SysResult {
+    let used: Vec<BlockPosition> = game
+        .ecs
+        .query::<&BlockInteractEvent>()
+        .iter()
+        .map(|(_, event)| event.location)
+        .collect();
+
+    for location in used {
+        let pos: ValidBlockPosition = match location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let kind = match game.block(pos) {
+            Some(block) => block.kind(),
+            None => continue,
+        };
+        if kind == BlockKind::Lever {
+            toggle_lever(game, pos)?;
+        } else if button_press_ticks(kind).is_some() {
+            press_button(game, pos)?;
+        }
+    }
+    Ok(())
+}
+
+/// The state of a pressure plate with the given entities on it.
+/// Stone plates only notice living entities.
+fn pressed_plate(
+    mut properties: BlockProperties,
+    entities: u32,
+    living: u32,
+) -> Option<BlockProperties> {
+    match properties.kind().simplified_kind() {
+        SimplifiedBlockKind::WoodenPressurePlate => {
+            properties.set("powered", &(entities > 0).to_string());
+        }
+        SimplifiedBlockKind::StonePressurePlate
+        | SimplifiedBlockKind::PolishedBlackstonePressurePlate => {
+            properties.set("powered", &(living > 0).to_string());
+        }
+        SimplifiedBlockKind::LightWeightedPressurePlate => {
+            let power = entities.min(u32::from(MAX_POWER));
+            properties.set("power", &power.to_string());
+        }
+        SimplifiedBlockKind::HeavyWeightedPressurePlate => {
+            let power = ((entities + HEAVY_PLATE_ENTITIES_PER_LEVEL - 1)
+                / HEAVY_PLATE_ENTITIES_PER_LEVEL)
+                .min(u32::from(MAX_POWER));
+            properties.set("power", &power.to_string());
+        }
+        _ => return None,
+    }
+    Some(properties)
+}
+
+/// Powers pressure plates entities stand on and unpowers
+/// those all entities have left.
+fn update_pressure_plates(game: &mut Game) -> SysResult {
+    // Entities and living entities in each block
+    let mut occupied: HashMap<ValidBlockPosition, (u32, u32)> = HashMap::new();
+    for (_, (position, health)) in game.ecs.query::<(&Position, Option<&Health>)>().iter() {
+        if let Ok(pos) = position.block().try_into() {
+            let count = occupied.entry(pos).or_default();
+            count.0 += 1;
+            if health.is_some() {
+                count.1 += 1;
+            }
+        }
+    }
+
+    let previously_pressed = std::mem::take(&mut game.resources.get_mut::<PressedPlates>()?.0);
+    let mut pressed = HashSet::new();
+    let candidates: HashSet<ValidBlockPosition> =
+        occupied.keys().copied().chain(previously_pressed).collect();
+    for pos in candidates {
+        let properties = match game.block_properties(pos) {
+            Some(properties) => properties,
+            None => continue,
+        };
+        let (entities, living) = occupied.get(&pos).copied().unwrap_or_default();
+        let updated = match pressed_plate(properties.clone(), entities, living) {
+            Some(updated) => updated,
+            None => continue,
+        };
+        if entities > 0 {
+            pressed.insert(pos);
+        }
+        if updated != properties {
+            set_input(game, pos, &updated);
+        }
+    }
+
+    game.resources.get_mut::<PressedPlates>()?.0 = pressed;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, BlockId, ChunkPosition};
+
+    use super::*;
+
+    fn pos(x: i32, y: i32, z: i32) -> ValidBlockPosition {
+        BlockPosition::new(x, y, z).try_into().unwrap()
+    }
+
+    fn game_with_floor() -> Game {
+        let mut game = Game::new();
+        game.insert_resource(PressedPlates::default());
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        game.set_block(pos(0, 63, 0), BlockId::stone());
+        game
+    }
+
+    fn powered_at(game: &Game, at: ValidBlockPosition) -> bool {
+        is_powered(&game.block_properties(at).unwrap())
+    }
+
+    #[test]
+    fn button_resets_after_delay() {
+        let mut game = game_with_floor();
+        let mut button = BlockProperties::new(BlockKind::StoneButton);
+        button
+            .set("face", "floor")
+            .set("facing", "north")
+            .set("powered", "false");
+        assert!(game.set_block_properties(pos(0, 64, 0), &button));
+
+        assert!(press_button(&mut game, pos(0, 64, 0)).unwrap());
+        assert!(powered_at(&game, pos(0, 64, 0)));
+        let strong_power = game.block_integration.strong_power_at(pos(0, 63, 0), |p| {
+            game.block_properties(p).map(|props| (props.kind(), props))
+        });
+        assert_eq!(strong_power, MAX_POWER);
+
+        game.run_block_ticks();
+        assert!(powered_at(&game, pos(0, 64, 0)));
+        assert!(!press_button(&mut game, pos(0, 64, 0)).unwrap());
+
+        // Stone buttons stay pressed for 20 ticks
+        for _ in 1..19 {
+            game.run_block_ticks();
+        }
+        assert!(powered_at(&game, pos(0, 64, 0)));
+        game.run_block_ticks();
+        assert!(!powered_at(&game, pos(0, 64, 0)));
+    }
+
+    #[test]
+    fn pressure_plate_powered_while_stood_on() {
+        let mut game = game_with_floor();
+        let mut plate = BlockProperties::new(BlockKind::StonePressurePlate);
+        plate.set("powered", "false");
+        assert!(game.set_block_properties(pos(0, 64, 0), &plate));
+
+        let entity = game.ecs.spawn((position!(0.5, 64.0625, 0.5), Health::new(20.0)));
+        update_pressure_plates(&mut game).unwrap();
+        assert!(powered_at(&game, pos(0, 64, 0)));
+
+        *game.ecs.get_mut::<Position>(entity).unwrap() = position!(3.5, 64.0, 0.5);
+        update_pressure_plates(&mut game).unwrap();
+        assert!(!powered_at(&game, pos(0, 64, 0)));
+    }
+}
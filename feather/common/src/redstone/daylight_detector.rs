@@ -0,0 +1,137 @@
+//! Daylight detectors, which output power from the sky light they receive.
+
+use std::convert::TryInto;
+use std::f32::consts::PI;
+
+use anyhow::bail;
+use base::{BlockKind, BlockPosition, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::{BlockEntityKind, MAX_POWER};
+
+use crate::{Game, Weather, WorldTime};
+
+/// Ticks between updates of daylight detector outputs
+const UPDATE_INTERVAL: u64 = 20;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(update_daylight_detectors);
+}
+
+/// The power a daylight detector outputs given the sky light it receives.
+///
+/// The output follows the height of the sun, so it peaks at noon. Inverted
+/// detectors output more the darker the sky is instead.
+pub fn daylight_power(sky_light: u8, time: &WorldTime, weather: &Weather, inverted: bool) -> u8 {
+    let light = i32::from(sky_light) - i32::from(time.sky_darkening(weather));
+    let power = if inverted {
+        i32::from(MAX_POWER) - light
+    } else if light > 0 {
+        // Pull the sun towards noon so the output rises early in the morning
+        let mut angle = time.celestial_angle() * 2.0 * PI;
+        let noon = if angle < PI { 0.0 } else { 2.0 * PI };
+        angle += (noon - angle) * 0.2;
+        (light as f32 * angle.cos()).round() as i32
+    } else {
+        light
+    };
+    power.max(0).min(i32::from(MAX_POWER)) as u8
+}
+
+/// Recomputes the output of the daylight detector at `pos`, returning it.
+pub fn update_daylight_detector(game: &mut Game, pos: ValidBlockPosition) -> SysResult<u8> {
+    let mut properties = match game.block_properties(pos) {
+        Some(properties) if properties.kind() == BlockKind::DaylightDetector => properties,
+        _ => bail!("no daylight detector at {:?}", pos),
+    };
+    let sky_light = game.world.sky_light_at(pos).unwrap_or(0);
+    let inverted = properties
+        .get("inverted")
+        .map_or(false, |inverted| inverted == "true");
+    let power = {
+        let time = game.resources.get::<WorldTime>()?;
+        let weather = game.resources.get::<Weather>()?;
+        daylight_power(sky_light, &time, &weather, inverted)
+    };
+
+    let power_value = power.to_string();
+    if properties.get("power") != Some(&power_value) {
+        properties.set("power", &power_value);
+        game.set_block_properties(pos, &properties);
+    }
+    Ok(power)
+}
+
+fn update_daylight_detectors(game: &mut Game) -> SysResult {
+    if game.tick_count % UPDATE_INTERVAL != 0 {
+        return Ok(());
+    }
+
+    let detectors: Vec<(i32, i32, i32)> = game
+        .block_entities
+        .iter()
+        .filter(|entity| entity.kind == BlockEntityKind::DaylightDetector)
+        .map(|entity| entity.position)
+        .collect();
+    for (x, y, z) in detectors {
+        if let Ok(pos) = BlockPosition::new(x, y, z).try_into() {
+            update_daylight_detector(game, pos)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::ChunkPosition;
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    fn set_time(game: &mut Game, time_of_day: u64) {
+        game.resources.get_mut::<WorldTime>().unwrap().day_time = time_of_day;
+    }
+
+    #[test]
+    fn output_follows_sky_light() {
+        let mut game = Game::new();
+        game.insert_resource(WorldTime::default());
+        game.insert_resource(Weather::default());
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+
+        let mut detector = BlockProperties::new(BlockKind::DaylightDetector);
+        detector.set("inverted", "false").set("power", "0");
+        assert!(game.set_block_properties(pos, &detector));
+
+        set_time(&mut game, 6000);
+        assert_eq!(update_daylight_detector(&mut game, pos).unwrap(), 15);
+        assert_eq!(
+            game.block_properties(pos).unwrap().get("power").unwrap(),
+            "15"
+        );
+        set_time(&mut game, 18000);
+        assert_eq!(update_daylight_detector(&mut game, pos).unwrap(), 0);
+
+        detector.set("inverted", "true");
+        assert!(game.set_block_properties(pos, &detector));
+        assert_eq!(update_daylight_detector(&mut game, pos).unwrap(), 11);
+        set_time(&mut game, 6000);
+        assert_eq!(update_daylight_detector(&mut game, pos).unwrap(), 0);
+
+        // A covered detector sees no daylight at all
+        detector.set("inverted", "false");
+        assert!(game.set_block_properties(pos, &detector));
+        game.world
+            .chunk_map()
+            .chunk_at_mut(ChunkPosition::new(0, 0))
+            .unwrap()
+            .set_sky_light_at(0, 64, 0, 0);
+        assert_eq!(update_daylight_detector(&mut game, pos).unwrap(), 0);
+        assert!(game
+            .block_entities
+            .iter()
+            .any(|entity| entity.kind == BlockEntityKind::DaylightDetector));
+    }
+}
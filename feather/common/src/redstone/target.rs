@@ -0,0 +1,156 @@
+//! Target blocks, which output more power the closer
+//! to their center a projectile hits them.
+
+use base::{BlockKind, EntityKind, Position, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::MAX_POWER;
+use libcraft_core::BlockFace;
+
+use crate::{events::ProjectileHitBlockEvent, Game};
+
+/// Ticks a target stays powered after being hit by an arrow or trident
+const ARROW_POWER_TICKS: u32 = 20;
+/// Ticks a target stays powered after being hit by any other projectile
+const POWER_TICKS: u32 = 8;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(power_hit_targets);
+}
+
+/// The power a target outputs when hit at `hit` on its `face`,
+/// from 15 at the center of the face down to 1 at its edge.
+pub fn hit_power(hit: Position, face: BlockFace) -> u8 {
+    let from_center = |coordinate: f64| (coordinate - coordinate.floor() - 0.5).abs();
+    let (dx, dy, dz) = (from_center(hit.x), from_center(hit.y), from_center(hit.z));
+    // Only the two coordinates along the face that was hit count
+    let distance = match face {
+        BlockFace::Top | BlockFace::Bottom => dx.max(dz),
+        BlockFace::North | BlockFace::South => dx.max(dy),
+        BlockFace::West | BlockFace::East => dy.max(dz),
+    };
+    let accuracy = ((0.5 - distance) / 0.5).max(0.0).min(1.0);
+    ((f64::from(MAX_POWER) * accuracy).ceil() as u8).max(1)
+}
+
+/// Powers the target at `pos` after a projectile hit it, scheduling
+/// the tick that resets it. A target is only powered by one hit at a time.
+///
+/// Returns the power it now outputs.
+pub fn hit_target(
+    game: &mut Game,
+    pos: ValidBlockPosition,
+    hit: Position,
+    face: BlockFace,
+    by_arrow: bool,
+) -> u8 {
+    let mut properties = match game.block_properties(pos) {
+        Some(properties) if properties.kind() == BlockKind::Target => properties,
+        _ => return 0,
+    };
+    let key = (pos.x(), pos.y(), pos.z());
+    let executor = game.block_integration.tick_executor_mut();
+    if executor.scheduler().pending_tick(key).is_some() {
+        return properties
+            .get("power")
+            .and_then(|power| power.parse().ok())
+            .unwrap_or(0);
+    }
+
+    let ticks = if by_arrow {
+        ARROW_POWER_TICKS
+    } else {
+        POWER_TICKS
+    };
+    executor.schedule_tick(key, BlockKind::Target, ticks, 0);
+
+    let power = hit_power(hit, face);
+    properties.set("power", &power.to_string());
+    game.set_block_properties(pos, &properties);
+    power
+}
+
+fn power_hit_targets(game: &mut Game) -> SysResult {
+    let hits: Vec<(ProjectileHitBlockEvent, bool)> = game
+        .ecs
+        .query::<(&ProjectileHitBlockEvent, Option<&EntityKind>)>()
+        .iter()
+        .map(|(_, (event, kind))| {
+            let by_arrow = matches!(
+                kind,
+                Some(EntityKind::Arrow)
+                    | Some(EntityKind::SpectralArrow)
+                    | Some(EntityKind::Trident)
+            );
+            (*event, by_arrow)
+        })
+        .collect();
+
+    for (event, by_arrow) in hits {
+        hit_target(game, event.block, event.hit, event.face, by_arrow);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use base::{position, BlockPosition, ChunkPosition};
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    fn power_at(game: &Game, pos: ValidBlockPosition) -> u8 {
+        game.block_properties(pos)
+            .unwrap()
+            .get("power")
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    fn run_ticks(game: &mut Game, ticks: u32) {
+        for _ in 0..ticks {
+            game.run_block_ticks();
+        }
+    }
+
+    #[test]
+    fn accurate_hits_output_more_power() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(0, 64, 0).try_into().unwrap();
+        let mut target = BlockProperties::new(BlockKind::Target);
+        target.set("power", "0");
+        assert!(game.set_block_properties(pos, &target));
+
+        let center = position!(0.5, 64.5, 0.0);
+        let snowball = game.ecs.spawn((
+            EntityKind::Snowball,
+            ProjectileHitBlockEvent {
+                block: pos,
+                face: BlockFace::North,
+                hit: center,
+            },
+        ));
+        power_hit_targets(&mut game).unwrap();
+        assert_eq!(power_at(&game, pos), 15);
+
+        // Waits for the hit to wear off before hitting near the edge
+        run_ticks(&mut game, POWER_TICKS - 1);
+        assert_eq!(power_at(&game, pos), 15);
+        run_ticks(&mut game, 1);
+        assert_eq!(power_at(&game, pos), 0);
+        game.ecs
+            .remove::<ProjectileHitBlockEvent>(snowball)
+            .unwrap();
+
+        let edge = position!(0.95, 64.5, 0.0);
+        assert_eq!(hit_target(&mut game, pos, edge, BlockFace::North, false), 2);
+        assert_eq!(power_at(&game, pos), 2);
+        run_ticks(&mut game, POWER_TICKS);
+        assert_eq!(power_at(&game, pos), 0);
+    }
+}
@@ -0,0 +1,223 @@
+//! Tripwire circuits: a line of tripwire strung between two hooks,
+//! which power while an entity touches the wire.
+
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use base::{BlockKind, BlockPosition, EntityKind, Position, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+
+use crate::Game;
+
+/// Most blocks of tripwire between two connected hooks
+const MAX_WIRE_LENGTH: i32 = 40;
+/// Height above its block at which entities touch a tripwire
+const WIRE_TOP: f64 = 0.15625;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(TrippedWires::default());
+    systems.add_system(update_tripwires);
+}
+
+/// Tripwire blocks currently touched by entities.
+#[derive(Debug, Default)]
+struct TrippedWires(HashSet<ValidBlockPosition>);
+
+/// A line of tripwire and the two hooks holding it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TripwireCircuit {
+    pub hooks: [ValidBlockPosition; 2],
+    pub wires: Vec<ValidBlockPosition>,
+}
+
+fn offset(pos: ValidBlockPosition, (dx, dy, dz): (i32, i32, i32)) -> Option<ValidBlockPosition> {
+    BlockPosition::new(pos.x() + dx, pos.y() + dy, pos.z() + dz)
+        .try_into()
+        .ok()
+}
+
+fn facing_direction(facing: &str) -> Option<(i32, i32, i32)> {
+    Some(match facing {
+        "north" => (0, 0, -1),
+        "south" => (0, 0, 1),
+        "west" => (-1, 0, 0),
+        "east" => (1, 0, 0),
+        _ => return None,
+    })
+}
+
+/// Follows tripwire from `start` in direction `dir`, returning the hook
+/// facing back along the line and the wires passed on the way.
+fn follow_wire(
+    game: &Game,
+    start: ValidBlockPosition,
+    dir: (i32, i32, i32),
+) -> Option<(ValidBlockPosition, Vec<ValidBlockPosition>)> {
+    let mut wires = Vec::new();
+    let mut pos = start;
+    for _ in 0..=MAX_WIRE_LENGTH {
+        pos = offset(pos, dir)?;
+        let properties = game.block_properties(pos)?;
+        match properties.kind() {
+            BlockKind::Tripwire => wires.push(pos),
+            BlockKind::TripwireHook => {
+                let facing = properties.get("facing").and_then(|f| facing_direction(f))?;
+                return if facing == (-dir.0, -dir.1, -dir.2) {
+                    Some((pos, wires))
+                } else {
+                    None
+                };
+            }
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// Finds the circuit the tripwire at `wire` is part of, if it is
+/// strung between two hooks.
+pub fn circuit_through(game: &Game, wire: ValidBlockPosition) -> Option<TripwireCircuit> {
+    [(1, 0, 0), (0, 0, 1)].iter().find_map(|&dir| {
+        let (first, mut wires) = follow_wire(game, wire, (-dir.0, -dir.1, -dir.2))?;
+        let (second, rest) = follow_wire(game, wire, dir)?;
+        wires.reverse();
+        wires.push(wire);
+        wires.extend(rest);
+        Some(TripwireCircuit {
+            hooks: [first, second],
+            wires,
+        })
+    })
+}
+
+fn set_powered(game: &mut Game, pos: ValidBlockPosition, powered: bool) {
+    if let Some(mut properties) = game.block_properties(pos) {
+        if super::is_powered(&properties) != powered {
+            properties.set("powered", &powered.to_string());
+            super::set_input(game, pos, &properties);
+        }
+    }
+}
+
+/// Tripwire blocks an entity with the given position and size touches
+fn touched_wires(game: &Game, position: Position, kind: EntityKind) -> Vec<ValidBlockPosition> {
+    let size = kind.bounding_box().max;
+    let half_width = size.x / 2.0;
+    let mut wires = Vec::new();
+    for x in (position.x - half_width).floor() as i32..=(position.x + half_width).floor() as i32 {
+        for z in (position.z - half_width).floor() as i32..=(position.z + half_width).floor() as i32
+        {
+            for y in position.y.floor() as i32..=(position.y + size.y).floor() as i32 {
+                let pos = match BlockPosition::new(x, y, z).try_into() {
+                    Ok(pos) => pos,
+                    Err(_) => continue,
+                };
+                let is_wire = game
+                    .block(pos)
+                    .map_or(false, |block| block.kind() == BlockKind::Tripwire);
+                if is_wire && position.y < f64::from(y) + WIRE_TOP {
+                    wires.push(pos);
+                }
+            }
+        }
+    }
+    wires
+}
+
+/// Powers tripwire entities touch, along with the hooks of their
+/// circuits, and unpowers them once no entity touches the line.
+fn update_tripwires(game: &mut Game) -> SysResult {
+    let entities: Vec<(Position, EntityKind)> = game
+        .ecs
+        .query::<(&Position, &EntityKind)>()
+        .iter()
+        .map(|(_, (position, kind))| (*position, *kind))
+        .collect();
+    let mut tripped = HashSet::new();
+    for (position, kind) in entities {
+        tripped.extend(touched_wires(game, position, kind));
+    }
+
+    let previously_tripped = std::mem::take(&mut game.resources.get_mut::<TrippedWires>()?.0);
+    let changed: Vec<ValidBlockPosition> = tripped
+        .symmetric_difference(&previously_tripped)
+        .copied()
+        .collect();
+    for wire in changed {
+        set_powered(game, wire, tripped.contains(&wire));
+        if let Some(circuit) = circuit_through(game, wire) {
+            let powered = circuit.wires.iter().any(|wire| tripped.contains(wire));
+            for hook in circuit.hooks.iter() {
+                set_powered(game, *hook, powered);
+            }
+        }
+    }
+
+    game.resources.get_mut::<TrippedWires>()?.0 = tripped;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, ChunkPosition};
+    use libcraft_blocks::BlockProperties;
+
+    use super::*;
+
+    fn pos(x: i32, y: i32, z: i32) -> ValidBlockPosition {
+        BlockPosition::new(x, y, z).try_into().unwrap()
+    }
+
+    fn hook(facing: &str) -> BlockProperties {
+        let mut hook = BlockProperties::new(BlockKind::TripwireHook);
+        hook.set("attached", "true")
+            .set("facing", facing)
+            .set("powered", "false");
+        hook
+    }
+
+    fn is_powered_at(game: &Game, at: ValidBlockPosition) -> bool {
+        super::super::is_powered(&game.block_properties(at).unwrap())
+    }
+
+    #[test]
+    fn crossing_wire_powers_both_hooks() {
+        let mut game = Game::new();
+        game.insert_resource(TrippedWires::default());
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(base::Chunk::new(ChunkPosition::new(0, 0)));
+
+        let mut wire = BlockProperties::new(BlockKind::Tripwire);
+        wire.set("attached", "true")
+            .set("disarmed", "false")
+            .set("powered", "false")
+            .set("north", "false")
+            .set("south", "false")
+            .set("east", "true")
+            .set("west", "true");
+        assert!(game.set_block_properties(pos(0, 64, 0), &hook("east")));
+        for x in 1..=3 {
+            assert!(game.set_block_properties(pos(x, 64, 0), &wire));
+        }
+        assert!(game.set_block_properties(pos(4, 64, 0), &hook("west")));
+
+        let circuit = circuit_through(&game, pos(2, 64, 0)).unwrap();
+        assert_eq!(circuit.hooks, [pos(0, 64, 0), pos(4, 64, 0)]);
+        assert_eq!(circuit.wires.len(), 3);
+
+        let zombie = game
+            .ecs
+            .spawn((position!(2.5, 64.0, 0.5), EntityKind::Zombie));
+        update_tripwires(&mut game).unwrap();
+        assert!(is_powered_at(&game, pos(0, 64, 0)));
+        assert!(is_powered_at(&game, pos(4, 64, 0)));
+        assert!(is_powered_at(&game, pos(2, 64, 0)));
+
+        *game.ecs.get_mut::<Position>(zombie).unwrap() = position!(2.5, 64.0, 5.5);
+        update_tripwires(&mut game).unwrap();
+        assert!(!is_powered_at(&game, pos(0, 64, 0)));
+        assert!(!is_powered_at(&game, pos(4, 64, 0)));
+        assert!(!is_powered_at(&game, pos(2, 64, 0)));
+    }
+}
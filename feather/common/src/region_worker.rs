@@ -74,6 +74,9 @@ impl RegionWorker {
                 Ok(req) => match req {
                     WorkerRequest::Load(load) => self.load_chunk(load),
                     WorkerRequest::Save(save) => self.save_chunk(save).unwrap(),
+                    WorkerRequest::Flush(done) => {
+                        let _ = done.send(());
+                    }
                 },
                 Err(flume::RecvTimeoutError::Timeout) => (),
                 Err(flume::RecvTimeoutError::Disconnected) => {
@@ -101,6 +104,7 @@ impl RegionWorker {
             &req.chunk.read(),
             &req.entities[..],
             &req.block_entities[..],
+            &req.block_updates[..],
         )?;
         Ok(())
     }
@@ -0,0 +1,271 @@
+//! Player death and respawning.
+//!
+//! A player whose health runs out is marked [`Dead`] until they
+//! ask to respawn. They then return to their bed or respawn anchor
+//! if it is still usable, or to the world spawn otherwise.
+
+use std::convert::TryInto;
+
+use base::{position, Area, BlockKind, BlockPosition, Inventory, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::{components::Health, entities::Player};
+
+use crate::{
+    entities::{item, player::SpawnPoint},
+    hunger::{Hunger, LastMovement, MAX_HEALTH},
+    respawn_anchor,
+    sleep::{self, Sleeping},
+    status_effects::StatusEffects,
+    Game, GameRules,
+};
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(WorldSpawn::default());
+    systems.add_system(kill_players);
+}
+
+/// Where players without a usable spawn point respawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorldSpawn(pub ValidBlockPosition);
+
+impl Default for WorldSpawn {
+    fn default() -> Self {
+        Self(
+            BlockPosition::new(0, 64, 0)
+                .try_into()
+                .expect("world spawn is in bounds"),
+        )
+    }
+}
+
+/// The areas of a player's inventory, all of which they drop on death
+const INVENTORY_AREAS: [Area; 9] = [
+    Area::CraftingInput,
+    Area::CraftingOutput,
+    Area::Helmet,
+    Area::Chestplate,
+    Area::Leggings,
+    Area::Boots,
+    Area::Storage,
+    Area::Hotbar,
+    Area::Offhand,
+];
+
+/// Marks a player who has died and not yet respawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dead;
+
+/// Marks players whose health has run out as dead. They drop
+/// their inventory unless the `keepInventory` rule is on.
+fn kill_players(game: &mut Game) -> SysResult {
+    let died: Vec<Entity> = game
+        .ecs
+        .query::<(&Player, &Health)>()
+        .iter()
+        .filter(|(_, (_, health))| health.current <= 0.0)
+        .map(|(player, _)| player)
+        .filter(|player| game.ecs.get::<Dead>(*player).is_err())
+        .collect();
+
+    for player in died {
+        if game.ecs.get::<Sleeping>(player).is_ok() {
+            sleep::wake_up(game, player)?;
+        }
+        game.ecs.insert(player, Dead)?;
+
+        let keep_inventory = game
+            .resources
+            .get::<GameRules>()
+            .map_or(false, |rules| rules.keep_inventory);
+        if !keep_inventory {
+            drop_inventory(game, player)?;
+        }
+    }
+    Ok(())
+}
+
+/// Empties the inventory of `player` onto the ground where they are.
+fn drop_inventory(game: &mut Game, player: Entity) -> SysResult {
+    let inventory = match game.ecs.get::<Inventory>(player) {
+        Ok(inventory) => inventory.new_handle(),
+        Err(_) => return Ok(()),
+    };
+    let position = *game.ecs.get::<Position>(player)?;
+
+    let mut drops = Vec::new();
+    for area in INVENTORY_AREAS.iter().copied() {
+        let mut slot = 0;
+        while let Some(mut item) = inventory.item(area, slot) {
+            drops.extend(item.take_all().into_option());
+            slot += 1;
+        }
+    }
+    for stack in drops {
+        item::spawn_item(game, position, stack);
+    }
+    Ok(())
+}
+
+/// Checks that the spawn point still works, returning where on it
+/// a player respawns. Respawn anchors use up a charge.
+fn use_spawn_point(game: &mut Game, spawn_point: SpawnPoint) -> Option<Position> {
+    if spawn_point.dimension != game.world.dimension {
+        return None;
+    }
+    let pos = spawn_point.position;
+    let block = game.block(pos)?;
+    let height = if sleep::is_bed(game, pos) {
+        if !game.world.dimension.beds_work() || sleep::is_obstructed(game, pos) {
+            return None;
+        }
+        0.5625
+    } else if block.kind() == BlockKind::RespawnAnchor {
+        let charges = respawn_anchor::charges_at(game, pos).ok()?;
+        if charges == 0 || !game.world.dimension.respawn_anchors_work() {
+            return None;
+        }
+        respawn_anchor::set_charges(game, pos, charges - 1);
+        1.0
+    } else {
+        return None;
+    };
+    Some(position!(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + height,
+        f64::from(pos.z()) + 0.5
+    ))
+}
+
+/// Respawns the dead `player` at their spawn point, or at the world spawn
+/// if it is missing or no longer usable, in which case it is forgotten.
+/// Their health and hunger are restored and their status effects cleared.
+///
+/// Returns where the player respawned.
+pub fn respawn(game: &mut Game, player: Entity) -> SysResult<Position> {
+    game.ecs.remove::<Dead>(player)?;
+
+    let spawn_point = game.ecs.get::<SpawnPoint>(player).ok().map(|point| *point);
+    let position = match spawn_point.and_then(|point| use_spawn_point(game, point)) {
+        Some(position) => position,
+        None => {
+            if spawn_point.is_some() {
+                game.ecs.remove::<SpawnPoint>(player)?;
+            }
+            let spawn = game.resources.get::<WorldSpawn>()?.0;
+            position!(
+                f64::from(spawn.x()) + 0.5,
+                f64::from(spawn.y()),
+                f64::from(spawn.z()) + 0.5
+            )
+        }
+    };
+
+    *game.ecs.get_mut::<Position>(player)? = position;
+    game.ecs.insert(player, Health::new(MAX_HEALTH))?;
+    game.ecs.insert(player, Hunger::default())?;
+    game.ecs.insert(player, LastMovement::default())?;
+    let _ = game.ecs.remove::<StatusEffects>(player);
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, Chunk, ChunkPosition, Item, ItemStack};
+    use libcraft_items::InventorySlot;
+
+    use crate::{
+        status_effects::{StatusEffect, StatusEffectKind},
+        Dimension,
+    };
+
+    use super::*;
+
+    fn world_with_bed() -> (Game, Entity, ValidBlockPosition) {
+        let mut game = Game::new();
+        game.insert_resource(WorldSpawn::default());
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let bed = BlockPosition::new(4, 70, 4).try_into().unwrap();
+        game.set_block(bed, BlockId::red_bed());
+
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::new(StatusEffectKind::Poison, 0, 600));
+        let player = game.ecs.spawn((
+            Player,
+            position!(20.0, 64.0, 20.0),
+            Health::new(20.0),
+            Hunger::default(),
+            effects,
+            SpawnPoint {
+                position: bed,
+                dimension: Dimension::Overworld,
+            },
+        ));
+        (game, player, bed)
+    }
+
+    fn kill(game: &mut Game, player: Entity) {
+        game.ecs.get_mut::<Health>(player).unwrap().current = 0.0;
+        kill_players(game).unwrap();
+        assert!(game.ecs.get::<Dead>(player).is_ok());
+    }
+
+    #[test]
+    fn respawn_at_bed() {
+        let (mut game, player, _) = world_with_bed();
+        kill(&mut game, player);
+
+        let position = respawn(&mut game, player).unwrap();
+        assert_eq!(position, position!(4.5, 70.5625, 4.5));
+        assert_eq!(*game.ecs.get::<Position>(player).unwrap(), position);
+        assert_eq!(game.ecs.get::<Health>(player).unwrap().current, MAX_HEALTH);
+        assert!(game.ecs.get::<StatusEffects>(player).is_err());
+        assert!(game.ecs.get::<Dead>(player).is_err());
+        assert!(game.ecs.get::<SpawnPoint>(player).is_ok());
+    }
+
+    #[test]
+    fn obstructed_bed_falls_back_to_world_spawn() {
+        let (mut game, player, bed) = world_with_bed();
+        let above = BlockPosition::new(bed.x(), bed.y() + 1, bed.z())
+            .try_into()
+            .unwrap();
+        game.set_block(above, BlockId::stone());
+        kill(&mut game, player);
+
+        let position = respawn(&mut game, player).unwrap();
+        assert_eq!(position, position!(0.5, 64.0, 0.5));
+        assert!(game.ecs.get::<SpawnPoint>(player).is_err());
+    }
+
+    fn dropped_items(game: &Game) -> u32 {
+        game.ecs
+            .query::<&ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.count())
+            .sum()
+    }
+
+    #[test]
+    fn dead_players_drop_their_inventory_unless_kept() {
+        for &keep_inventory in &[false, true] {
+            let (mut game, player, _) = world_with_bed();
+            game.insert_resource(GameRules {
+                keep_inventory,
+                ..GameRules::default()
+            });
+            let inventory = Inventory::player();
+            *inventory.item(Area::Hotbar, 0).unwrap() = InventorySlot::new(Item::Diamond, 5);
+            game.ecs.insert(player, inventory.new_handle()).unwrap();
+            kill(&mut game, player);
+
+            let kept = inventory.item(Area::Hotbar, 0).unwrap().count();
+            if keep_inventory {
+                assert_eq!((kept, dropped_items(&game)), (5, 0));
+            } else {
+                assert_eq!((kept, dropped_items(&game)), (0, 5));
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Respawn anchors, which set players' spawn points in the Nether.
+//!
+//! Anchors are charged with glowstone, up to four times, and each
+//! respawn at an anchor uses up a charge. Using a charged anchor
+//! anywhere but the Nether makes it explode.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{position, BlockKind, Inventory, Item, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::events::BlockInteractEvent;
+
+use crate::{
+    entities::player::{held_item, HotbarSlot, SpawnPoint},
+    explosion,
+    interactable::InteractableRegistry,
+    Game,
+};
+
+/// Power of the explosion of a respawn anchor used outside the Nether
+pub const EXPLOSION_POWER: f32 = 5.0;
+/// Number of glowstone charges an anchor holds
+pub const MAX_CHARGES: u8 = 4;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry")
+        .register(BlockKind::RespawnAnchor);
+    systems.add_system(use_anchors);
+}
+
+/// The outcome of a player using a respawn anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorResult {
+    /// The player's spawn point was set to the anchor
+    SpawnSet,
+    /// The anchor has no charges
+    NotCharged,
+    /// The anchor was used outside the Nether and blew up
+    Exploded,
+}
+
+/// The number of charges left in the respawn anchor at `pos`.
+///
+/// They are kept in the anchor's block entity, falling back to
+/// the block state for anchors placed without one.
+pub fn charges_at(game: &Game, pos: ValidBlockPosition) -> SysResult<u8> {
+    let block = match game.block(pos) {
+        Some(block) if block.kind() == BlockKind::RespawnAnchor => block,
+        _ => bail!("no respawn anchor at {:?}", pos),
+    };
+    let charges = game
+        .block_entities
+        .get((pos.x(), pos.y(), pos.z()))
+        .and_then(|entity| entity.data.get_int("Charges"))
+        .or_else(|| block.charges())
+        .unwrap_or(0);
+    Ok(charges as u8)
+}
+
+/// Sets the charges of the respawn anchor at `pos`, mirroring
+/// them to the block state so clients see the anchor glow.
+pub fn set_charges(game: &mut Game, pos: ValidBlockPosition, charges: u8) {
+    if let Some(block) = game.block(pos) {
+        game.set_block(pos, block.with_charges(i32::from(charges)));
+    }
+    if let Some(mut entity) = game.block_entities.get_mut((pos.x(), pos.y(), pos.z())) {
+        entity.data.set_int("Charges", i32::from(charges));
+    }
+}
+
+/// The signal read by a comparator next to the anchor at `pos`,
+/// which matches the light the anchor gives off.
+pub fn comparator_output(game: &Game, pos: ValidBlockPosition) -> u8 {
+    game.block(pos)
+        .filter(|block| block.kind() == BlockKind::RespawnAnchor)
+        .map_or(0, |block| block.light_emission())
+}
+
+/// Adds a glowstone charge to the anchor at `pos`.
+///
+/// Returns whether the anchor had room for it; the caller
+/// consumes the glowstone if so.
+pub fn charge(game: &mut Game, pos: ValidBlockPosition) -> SysResult<bool> {
+    let charges = charges_at(game, pos)?;
+    if charges >= MAX_CHARGES {
+        return Ok(false);
+    }
+    set_charges(game, pos, charges + 1);
+    game.emit_sound("block.respawn_anchor.charge", center(pos), 1.0, 1.0);
+    Ok(true)
+}
+
+fn center(pos: ValidBlockPosition) -> Position {
+    position!(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 0.5,
+        f64::from(pos.z()) + 0.5
+    )
+}
+
+/// Makes `player` use the respawn anchor at `pos`, setting their
+/// spawn point to it if it is charged.
+pub fn use_anchor(
+    game: &mut Game,
+    player: Entity,
+    pos: ValidBlockPosition,
+) -> SysResult<AnchorResult> {
+    if charges_at(game, pos)? == 0 {
+        return Ok(AnchorResult::NotCharged);
+    }
+
+    if !game.world.dimension.respawn_anchors_work() {
+        game.break_block(pos);
+        explosion::explode(game, center(pos), EXPLOSION_POWER, None, true)?;
+        return Ok(AnchorResult::Exploded);
+    }
+
+    let spawn_point = SpawnPoint {
+        position: pos,
+        dimension: game.world.dimension,
+    };
+    game.ecs.insert(player, spawn_point)?;
+    game.emit_sound("block.respawn_anchor.set_spawn", center(pos), 1.0, 1.0);
+    Ok(AnchorResult::SpawnSet)
+}
+
+/// Charges anchors players use with glowstone
+/// and sets their spawn points at charged ones.
+fn use_anchors(game: &mut Game) -> SysResult {
+    let uses: Vec<(Entity, BlockInteractEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockInteractEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(player, (event, inventory, hotbar_slot))| {
+            (player, event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (player, event, inventory, hotbar_slot) in uses {
+        let pos: ValidBlockPosition = match event.location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let charges = match charges_at(game, pos) {
+            Ok(charges) => charges,
+            Err(_) => continue,
+        };
+
+        let mut held = held_item(&inventory, hotbar_slot, event.hand);
+        if held.item_kind() == Some(Item::Glowstone) && charges < MAX_CHARGES {
+            if charge(game, pos)? {
+                let _ = held.try_take(1);
+            }
+            continue;
+        }
+        use_anchor(game, player, pos)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, BlockPosition, Chunk, ChunkPosition};
+    use quill_common::entities::Player;
+
+    use crate::{
+        respawn::{self, Dead},
+        Dimension,
+    };
+
+    use super::*;
+
+    #[test]
+    fn anchors_explode_outside_the_nether() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let pos: ValidBlockPosition = BlockPosition::new(2, 64, 2).try_into().unwrap();
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let anchor = BlockId::respawn_anchor().with_charges(1);
+        game.set_block(pos, anchor);
+
+        game.world.dimension = Dimension::Overworld;
+        assert_eq!(
+            use_anchor(&mut game, player, pos).unwrap(),
+            AnchorResult::Exploded
+        );
+        assert!(game.ecs.get::<SpawnPoint>(player).is_err());
+        assert!(game.block(pos).unwrap().is_air());
+
+        game.set_block(pos, anchor);
+        game.world.dimension = Dimension::Nether;
+        assert_eq!(
+            use_anchor(&mut game, player, pos).unwrap(),
+            AnchorResult::SpawnSet
+        );
+        assert_eq!(
+            *game.ecs.get::<SpawnPoint>(player).unwrap(),
+            SpawnPoint {
+                position: pos,
+                dimension: Dimension::Nether,
+            }
+        );
+    }
+
+    #[test]
+    fn respawning_at_an_anchor_uses_a_charge() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        game.world.dimension = Dimension::Nether;
+        let pos: ValidBlockPosition = BlockPosition::new(2, 64, 2).try_into().unwrap();
+        game.set_block(pos, BlockId::respawn_anchor());
+        let player = game.ecs.spawn((Player, position!(10.0, 64.0, 10.0)));
+
+        assert_eq!(
+            use_anchor(&mut game, player, pos).unwrap(),
+            AnchorResult::NotCharged
+        );
+        for _ in 0..MAX_CHARGES {
+            assert!(charge(&mut game, pos).unwrap());
+        }
+        assert!(!charge(&mut game, pos).unwrap());
+        assert_eq!(charges_at(&game, pos).unwrap(), MAX_CHARGES);
+        assert_eq!(comparator_output(&game, pos), 15);
+
+        assert_eq!(
+            use_anchor(&mut game, player, pos).unwrap(),
+            AnchorResult::SpawnSet
+        );
+        game.ecs.insert(player, Dead).unwrap();
+        assert_eq!(
+            respawn::respawn(&mut game, player).unwrap(),
+            position!(2.5, 65.0, 2.5)
+        );
+        assert_eq!(charges_at(&game, pos).unwrap(), MAX_CHARGES - 1);
+        assert_eq!(game.block(pos).unwrap().charges(), Some(3));
+        assert_eq!(
+            game.block_entities
+                .get((pos.x(), pos.y(), pos.z()))
+                .unwrap()
+                .data
+                .get_int("Charges"),
+            Some(3)
+        );
+        assert_eq!(comparator_output(&game, pos), 11);
+    }
+}
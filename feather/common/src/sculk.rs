@@ -0,0 +1,311 @@
+//! Sculk spreading from catalysts when mobs die nearby, and shriekers
+//! warning players who make vibrations.
+
+use std::convert::TryInto;
+
+use base::{position, BlockKind, BlockPosition, Position, ValidBlockPosition};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_blocks::{create_block_entity, BlockProperties, BlockView, CATALYST_RADIUS};
+use quill_common::{entities::Player, entity_init::EntityInit};
+
+use crate::{
+    events::{EntityDeathEvent, VibrationEvent},
+    status_effects::{self, StatusEffect, StatusEffectKind},
+    Game,
+};
+
+/// Maximum distance at which a shrieker hears vibrations
+const SHRIEKER_RANGE: i32 = 8;
+/// Players within this distance of a shrieker are given Darkness
+const DARKNESS_RANGE: f64 = 40.0;
+/// Duration of the Darkness effect in ticks
+const DARKNESS_DURATION: u32 = 260;
+/// Block entity key holding a shrieker's warning level
+const WARNING_LEVEL_KEY: &str = "warning_level";
+/// Block entity key set to 1 on shriekers that can summon a warden.
+/// Shriekers grown by catalysts never have it.
+pub const CAN_SUMMON_KEY: &str = "can_summon";
+/// Warning level at which a shrieker summons a warden
+pub const WARDEN_WARNING_LEVEL: i32 = 4;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(spread_sculk_on_death);
+    systems.add_system(shriek_on_vibration);
+}
+
+/// Returns the positions of blocks of `kind` within `radius` of `center`.
+fn blocks_near(
+    view: &dyn BlockView,
+    (x, y, z): (i32, i32, i32),
+    radius: i32,
+    kind: BlockKind,
+) -> Vec<(i32, i32, i32)> {
+    let mut found = Vec::new();
+    for dx in -radius..=radius {
+        for dy in -radius..=radius {
+            for dz in -radius..=radius {
+                let pos = (x + dx, y + dy, z + dz);
+                if view.kind_at(pos) == Some(kind) {
+                    found.push(pos);
+                }
+            }
+        }
+    }
+    found
+}
+
+fn block_position((x, y, z): (i32, i32, i32)) -> Option<ValidBlockPosition> {
+    BlockPosition::new(x, y, z).try_into().ok()
+}
+
+fn block_of(position: Position) -> (i32, i32, i32) {
+    (
+        position.x.floor() as i32,
+        position.y.floor() as i32,
+        position.z.floor() as i32,
+    )
+}
+
+/// Spreads sculk around an entity that died at `position`, if a catalyst
+/// is nearby, consuming `experience` as the spread's charge.
+///
+/// Returns the number of blocks converted to sculk.
+pub fn on_entity_death(game: &mut Game, position: Position, experience: u32) -> u32 {
+    let (x, y, z) = block_of(position);
+    let origin = (x, y - 1, z);
+    if experience == 0
+        || blocks_near(&*game, origin, CATALYST_RADIUS, BlockKind::SculkCatalyst).is_empty()
+    {
+        return 0;
+    }
+
+    // Collect the changes first, since reading blocks borrows the game
+    let mut changes: Vec<((i32, i32, i32), BlockProperties)> = Vec::new();
+    let converted = game.with_rng(|game, rng| {
+        libcraft_blocks::spread_sculk(
+            rng,
+            origin,
+            experience,
+            &|pos| game.block_at(pos),
+            &mut |pos, properties| changes.push((pos, properties)),
+        )
+    });
+
+    for (pos, properties) in changes {
+        if let Some(pos) = block_position(pos) {
+            game.set_block_properties(pos, &properties);
+        }
+    }
+    converted
+}
+
+/// Spreads sculk for every entity that died this tick.
+fn spread_sculk_on_death(game: &mut Game) -> SysResult {
+    let deaths: Vec<(Position, u32)> = game
+        .ecs
+        .query::<(&EntityDeathEvent, &Position)>()
+        .iter()
+        .map(|(_, (event, position))| (*position, event.experience))
+        .collect();
+
+    for (position, experience) in deaths {
+        on_entity_death(game, position, experience);
+    }
+    Ok(())
+}
+
+/// Triggers the shrieker at `pos`: nearby players are given Darkness and
+/// the shrieker's warning level goes up. Shriekers that can summon call a
+/// warden once the warning level reaches [`WARDEN_WARNING_LEVEL`], which
+/// resets the level.
+///
+/// Returns the new warning level, or `None` if there is no shrieker at `pos`.
+pub fn shriek(game: &mut Game, pos: (i32, i32, i32)) -> SysResult<Option<i32>> {
+    if game.kind_at(pos) != Some(BlockKind::SculkShrieker) {
+        return Ok(None);
+    }
+    let center = position!(
+        f64::from(pos.0) + 0.5,
+        f64::from(pos.1) + 0.5,
+        f64::from(pos.2) + 0.5
+    );
+
+    let players: Vec<_> = game
+        .ecs
+        .query::<(&Position, &Player)>()
+        .iter()
+        .filter(|(_, (position, _))| position.distance_to(center) <= DARKNESS_RANGE)
+        .map(|(player, _)| player)
+        .collect();
+    for player in players {
+        let darkness = StatusEffect::new(StatusEffectKind::Darkness, 0, DARKNESS_DURATION);
+        status_effects::apply_effect(game, player, darkness)?;
+    }
+
+    if !game.block_entities.exists(pos) {
+        if let Some(entity) = create_block_entity(BlockKind::SculkShrieker, pos) {
+            game.block_entities.set(pos, entity);
+        }
+    }
+    let mut level = 0;
+    let mut summon = false;
+    if let Some(mut entity) = game.block_entities.get_mut(pos) {
+        let can_summon = entity.data.get_int(CAN_SUMMON_KEY) == Some(1);
+        level = entity.data.get_int(WARNING_LEVEL_KEY).unwrap_or(0) + 1;
+        summon = can_summon && level >= WARDEN_WARNING_LEVEL;
+        if summon {
+            level = 0;
+        }
+        entity.data.set_int(WARNING_LEVEL_KEY, level);
+    }
+
+    if summon {
+        let spawn_at = position!(center.x, center.y + 0.5, center.z);
+        let builder = game.create_entity_builder(spawn_at, EntityInit::Warden);
+        game.spawn_entity(builder);
+    }
+    Ok(Some(level))
+}
+
+/// Triggers shriekers within range of vibrations made this tick.
+fn shriek_on_vibration(game: &mut Game) -> SysResult {
+    let vibrations: Vec<Position> = game
+        .ecs
+        .query::<&VibrationEvent>()
+        .iter()
+        .map(|(_, event)| event.position)
+        .collect();
+
+    for position in vibrations {
+        let shriekers = blocks_near(
+            &*game,
+            block_of(position),
+            SHRIEKER_RANGE,
+            BlockKind::SculkShrieker,
+        );
+        for shrieker in shriekers {
+            shriek(game, shrieker)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, Chunk, ChunkPosition, EntityKind};
+
+    use crate::status_effects::StatusEffects;
+
+    use super::*;
+
+    fn deep_dark(catalyst: bool) -> Game {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        for x in 0..16 {
+            for z in 0..16 {
+                let pos = BlockPosition::new(x, 63, z).try_into().unwrap();
+                game.set_block(pos, BlockId::stone());
+            }
+        }
+        if catalyst {
+            let pos = BlockPosition::new(8, 63, 8).try_into().unwrap();
+            game.set_block(pos, BlockId::sculk_catalyst());
+        }
+        game
+    }
+
+    fn count_sculk(game: &Game) -> usize {
+        (0..16)
+            .flat_map(|x| (0..16).map(move |z| (x, 63, z)))
+            .filter(|pos| game.kind_at(*pos) == Some(BlockKind::Sculk))
+            .count()
+    }
+
+    #[test]
+    fn death_near_catalyst_spreads_sculk_by_experience() {
+        let mut game = deep_dark(true);
+        let mob = game.ecs.spawn((position!(4.5, 64.0, 4.5),));
+        let death = EntityDeathEvent {
+            experience: 5,
+            killer: None,
+            weapon: None,
+        };
+        game.ecs.insert_entity_event(mob, death).unwrap();
+        spread_sculk_on_death(&mut game).unwrap();
+        assert_eq!(count_sculk(&game), 5);
+
+        assert_eq!(
+            on_entity_death(&mut game, position!(4.5, 64.0, 4.5), 10),
+            10
+        );
+        assert_eq!(count_sculk(&game), 15);
+    }
+
+    #[test]
+    fn no_spread_without_catalyst() {
+        let mut game = deep_dark(false);
+        assert_eq!(on_entity_death(&mut game, position!(4.5, 64.0, 4.5), 5), 0);
+        assert_eq!(count_sculk(&game), 0);
+    }
+
+    #[test]
+    fn fourth_warning_summons_warden() {
+        let mut game = deep_dark(false);
+        let pos = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        game.set_block(pos, BlockId::sculk_shrieker());
+        let mut shrieker = create_block_entity(BlockKind::SculkShrieker, (8, 64, 8)).unwrap();
+        shrieker.data.set_int(CAN_SUMMON_KEY, 1);
+        game.block_entities.set((8, 64, 8), shrieker);
+        let player = game.ecs.spawn((position!(10.0, 64.0, 10.0), Player));
+
+        let wardens = |game: &Game| {
+            game.ecs
+                .query::<&EntityKind>()
+                .iter()
+                .filter(|(_, kind)| **kind == EntityKind::Warden)
+                .count()
+        };
+        for warning in 1..=WARDEN_WARNING_LEVEL {
+            let step = game.ecs.spawn((position!(9.0, 64.0, 9.0),));
+            game.ecs
+                .insert_entity_event(
+                    step,
+                    VibrationEvent {
+                        position: position!(9.0, 64.0, 9.0),
+                    },
+                )
+                .unwrap();
+            shriek_on_vibration(&mut game).unwrap();
+            game.ecs.despawn(step).unwrap();
+
+            assert!(game
+                .ecs
+                .get::<StatusEffects>(player)
+                .unwrap()
+                .has(StatusEffectKind::Darkness));
+            let expected = if warning < WARDEN_WARNING_LEVEL { 0 } else { 1 };
+            assert_eq!(wardens(&game), expected);
+        }
+
+        let level = game
+            .block_entities
+            .get((8, 64, 8))
+            .unwrap()
+            .data
+            .get_int(WARNING_LEVEL_KEY);
+        assert_eq!(level, Some(0));
+    }
+
+    #[test]
+    fn grown_shriekers_never_summon() {
+        let mut game = deep_dark(false);
+        let pos = BlockPosition::new(8, 64, 8).try_into().unwrap();
+        game.set_block(pos, BlockId::sculk_shrieker());
+        for warning in 1..=WARDEN_WARNING_LEVEL {
+            assert_eq!(shriek(&mut game, (8, 64, 8)).unwrap(), Some(warning));
+        }
+        assert_eq!(shriek(&mut game, (0, 64, 0)).unwrap(), None);
+    }
+}
@@ -0,0 +1,250 @@
+//! Shears, used on sheep, mooshrooms and snow golems, and to
+//! harvest honeycomb from full bee nests.
+
+use std::convert::TryInto;
+
+use base::{
+    position, BlockKind, EntityKind, Inventory, Item, ItemStack, Position, ValidBlockPosition,
+};
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_items::InventorySlot;
+use quill_common::{entity_init::EntityInit, events::BlockInteractEvent};
+use rand::Rng;
+
+use crate::{
+    entities::{
+        self,
+        aging::Baby,
+        item,
+        mooshroom::MooshroomVariant,
+        player::{held_item, HotbarSlot},
+        sheep,
+        sheep::{Sheared, SheepColor},
+        snow_golem::{self, WearingPumpkin},
+    },
+    entity_interaction::EntityInteractions,
+    events::EntityMetadataChangeEvent,
+    interactable::InteractableRegistry,
+    mining, Game,
+};
+
+/// Mushrooms dropped by a sheared mooshroom
+const MOOSHROOM_MUSHROOMS: u32 = 5;
+/// Honeycomb harvested from a full bee nest
+const NEST_HONEYCOMB: u32 = 3;
+/// Honey level of a bee nest ready to harvest
+const MAX_HONEY_LEVEL: u8 = 5;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.resources
+        .get_mut::<EntityInteractions>()
+        .expect("Failed to get the entity interactions")
+        .register(EntityKind::Sheep, Some(Item::Shears), shear)
+        .register(EntityKind::Mooshroom, Some(Item::Shears), shear)
+        .register(EntityKind::SnowGolem, Some(Item::Shears), shear);
+
+    let mut interactables = game
+        .resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry");
+    interactables.register(BlockKind::BeeNest);
+    interactables.register(BlockKind::Beehive);
+    drop(interactables);
+    systems.add_system(shear_bee_nests);
+}
+
+/// Shears `target`, dropping what grows on it. Shearing
+/// something wears down the shears in `held`.
+///
+/// Returns whether there was anything to shear.
+pub fn shear(
+    game: &mut Game,
+    _player: Entity,
+    target: Entity,
+    held: &mut InventorySlot,
+) -> SysResult<bool> {
+    let position = *game.ecs.get::<Position>(target)?;
+    match *game.ecs.get::<EntityKind>(target)? {
+        EntityKind::Sheep => {
+            if game.ecs.get::<Sheared>(target).is_ok() || game.ecs.get::<Baby>(target).is_ok() {
+                return Ok(false);
+            }
+            let color = game.ecs.get::<SheepColor>(target)?.0;
+            let count = game.with_rng(|_, rng| rng.gen_range(1..=3));
+            drop_items(game, position, color.wool(), count);
+            game.ecs.insert(target, Sheared)?;
+            sheep::update_wool(game, target)?;
+            game.emit_sound("entity.sheep.shear", position, 1.0, 1.0);
+        }
+        EntityKind::Mooshroom => {
+            if game.ecs.get::<Baby>(target).is_ok() {
+                return Ok(false);
+            }
+            let variant = game
+                .ecs
+                .get::<MooshroomVariant>(target)
+                .map_or(MooshroomVariant::default(), |variant| *variant);
+            drop_items(game, position, variant.mushroom(), MOOSHROOM_MUSHROOMS);
+            entities::convert(game, target, EntityInit::Cow)?;
+            game.emit_sound("entity.mooshroom.shear", position, 1.0, 1.0);
+        }
+        EntityKind::SnowGolem => {
+            if game.ecs.remove::<WearingPumpkin>(target).is_err() {
+                return Ok(false);
+            }
+            game.ecs.insert_entity_event(
+                target,
+                EntityMetadataChangeEvent {
+                    metadata: snow_golem::pumpkin_metadata(false),
+                },
+            )?;
+            drop_items(game, position, Item::CarvedPumpkin, 1);
+            game.emit_sound("entity.snow_golem.shear", position, 1.0, 1.0);
+        }
+        _ => return Ok(false),
+    }
+    game.with_rng(|_, rng| mining::damage_tool(held, rng));
+    Ok(true)
+}
+
+/// Harvests honeycomb from the bee nest or beehive at `pos`
+/// if it is full of honey.
+///
+/// Returns whether there was honeycomb to harvest.
+pub fn shear_bee_nest(game: &mut Game, pos: ValidBlockPosition) -> SysResult<bool> {
+    let mut properties = match game.block_properties(pos) {
+        Some(properties)
+            if matches!(properties.kind(), BlockKind::BeeNest | BlockKind::Beehive) =>
+        {
+            properties
+        }
+        _ => return Ok(false),
+    };
+    let honey_level = properties
+        .get("honey_level")
+        .and_then(|level| level.parse::<u8>().ok())
+        .unwrap_or(0);
+    if honey_level < MAX_HONEY_LEVEL {
+        return Ok(false);
+    }
+
+    properties.set("honey_level", "0");
+    game.set_block_properties(pos, &properties);
+    let position = position!(
+        f64::from(pos.x()) + 0.5,
+        f64::from(pos.y()) + 1.0,
+        f64::from(pos.z()) + 0.5
+    );
+    drop_items(game, position, Item::Honeycomb, NEST_HONEYCOMB);
+    game.emit_sound("block.beehive.shear", position, 1.0, 1.0);
+    Ok(true)
+}
+
+fn drop_items(game: &mut Game, position: Position, item: Item, count: u32) {
+    if let Ok(stack) = ItemStack::new(item, count) {
+        item::spawn_item(game, position, stack);
+    }
+}
+
+fn shear_bee_nests(game: &mut Game) -> SysResult {
+    let uses: Vec<(BlockInteractEvent, Inventory, HotbarSlot)> = game
+        .ecs
+        .query::<(&BlockInteractEvent, &Inventory, &HotbarSlot)>()
+        .iter()
+        .map(|(_, (event, inventory, hotbar_slot))| {
+            (event.clone(), inventory.new_handle(), *hotbar_slot)
+        })
+        .collect();
+
+    for (event, inventory, hotbar_slot) in uses {
+        let pos: ValidBlockPosition = match event.location.try_into() {
+            Ok(pos) => pos,
+            Err(_) => continue,
+        };
+        let held = held_item(&inventory, hotbar_slot, event.hand).item_kind();
+        if held == Some(Item::Shears) {
+            shear_bee_nest(game, pos)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+    use quill_common::{
+        components::Health,
+        entities::{Mooshroom, Sheep},
+        events::EntityRemoveEvent,
+    };
+
+    use crate::dye::DyeColor;
+
+    use super::*;
+
+    fn dropped(game: &Game) -> Vec<ItemStack> {
+        game.ecs
+            .query::<&ItemStack>()
+            .iter()
+            .map(|(_, stack)| stack.clone())
+            .collect()
+    }
+
+    #[test]
+    fn shearing_a_sheep_drops_wool() {
+        let mut game = Game::new();
+        game.add_entity_spawn_callback(entities::add_entity_components);
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let sheep = game.ecs.spawn((
+            Sheep,
+            EntityKind::Sheep,
+            SheepColor(DyeColor::Lime),
+            position!(1.5, 64.0, 0.5),
+        ));
+        let mut shears = InventorySlot::new(Item::Shears, 1);
+
+        assert!(shear(&mut game, player, sheep, &mut shears).unwrap());
+        assert!(game.ecs.get::<Sheared>(sheep).is_ok());
+        assert_eq!(
+            shears.option_ref().and_then(ItemStack::damage_taken),
+            Some(1)
+        );
+        let wool = dropped(&game);
+        assert_eq!(wool.len(), 1);
+        assert!((1..=3).any(|count| wool[0] == ItemStack::new(Item::LimeWool, count).unwrap()));
+
+        // Nothing grows back until the sheep eats
+        assert!(!shear(&mut game, player, sheep, &mut shears).unwrap());
+    }
+
+    #[test]
+    fn shearing_a_mooshroom_turns_it_into_a_cow() {
+        let mut game = Game::new();
+        game.add_entity_spawn_callback(entities::add_entity_components);
+        let player = game.ecs.spawn((position!(0.0, 64.0, 0.0),));
+        let mooshroom = game.ecs.spawn((
+            Mooshroom,
+            EntityKind::Mooshroom,
+            MooshroomVariant::Brown,
+            position!(1.5, 64.0, 0.5),
+            Health::new(10.0),
+        ));
+        let mut shears = InventorySlot::new(Item::Shears, 1);
+
+        assert!(shear(&mut game, player, mooshroom, &mut shears).unwrap());
+        assert_eq!(
+            dropped(&game),
+            vec![ItemStack::new(Item::BrownMushroom, 5).unwrap()]
+        );
+        assert!(game.ecs.get::<EntityRemoveEvent>(mooshroom).is_ok());
+
+        let cows: Vec<(Position, Health)> = game
+            .ecs
+            .query::<(&EntityKind, &Position, &Health)>()
+            .iter()
+            .filter(|(_, (kind, _, _))| **kind == EntityKind::Cow)
+            .map(|(_, (_, position, health))| (*position, *health))
+            .collect();
+        assert_eq!(cows, vec![(position!(1.5, 64.0, 0.5), Health::new(10.0))]);
+    }
+}
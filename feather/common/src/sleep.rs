@@ -0,0 +1,492 @@
+//! Sleeping in beds and skipping the night.
+
+use std::convert::TryInto;
+
+use anyhow::bail;
+use base::{position, BlockKind, BlockPosition, EntityKind, Position, ValidBlockPosition};
+use blocks::SimplifiedBlockKind;
+use ecs::{Entity, SysResult, SystemExecutor};
+use libcraft_blocks::BlockView;
+use quill_common::{entities::Player, events::BlockInteractEvent};
+
+use crate::{
+    entities, entities::player::SpawnPoint, explosion, interactable::InteractableRegistry, Game,
+    Weather, WorldTime,
+};
+
+/// Ticks a player must have slept before they count towards skipping the night
+const DEEP_SLEEP_TICKS: u32 = 100;
+/// Ticks without sleeping after which phantoms start spawning around a player
+pub const INSOMNIA_TICKS: u32 = 72_000;
+/// Monsters this close to a bed horizontally keep players from sleeping
+const MONSTER_RANGE_HORIZONTAL: f64 = 8.0;
+/// Monsters this close to a bed vertically keep players from sleeping
+const MONSTER_RANGE_VERTICAL: f64 = 5.0;
+/// Power of the explosion of a bed used outside the Overworld
+pub const BED_EXPLOSION_POWER: f32 = 5.0;
+
+/// Every color of bed
+const BEDS: [BlockKind; 16] = [
+    BlockKind::WhiteBed,
+    BlockKind::OrangeBed,
+    BlockKind::MagentaBed,
+    BlockKind::LightBlueBed,
+    BlockKind::YellowBed,
+    BlockKind::LimeBed,
+    BlockKind::PinkBed,
+    BlockKind::GrayBed,
+    BlockKind::LightGrayBed,
+    BlockKind::CyanBed,
+    BlockKind::PurpleBed,
+    BlockKind::BlueBed,
+    BlockKind::BrownBed,
+    BlockKind::GreenBed,
+    BlockKind::RedBed,
+    BlockKind::BlackBed,
+];
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(SleepSettings::default());
+    let mut registry = game
+        .resources
+        .get_mut::<InteractableRegistry>()
+        .expect("Failed to get the interactable registry");
+    for bed in BEDS.iter().copied() {
+        registry.register(bed);
+    }
+    systems
+        .add_system(use_beds)
+        .add_system(tick_sleep)
+        .add_system(skip_night);
+}
+
+/// Configures when the night is skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SleepSettings {
+    /// Percentage of players that must be asleep to skip the night
+    pub players_sleeping_percentage: u32,
+}
+
+impl Default for SleepSettings {
+    fn default() -> Self {
+        Self {
+            players_sleeping_percentage: 100,
+        }
+    }
+}
+
+/// Marks a player lying in a bed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sleeping {
+    pub bed: ValidBlockPosition,
+    /// Ticks spent asleep so far
+    pub ticks: u32,
+}
+
+/// Ticks since a player last slept, used for phantom spawning.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimeSinceRest(pub u32);
+
+impl TimeSinceRest {
+    /// Whether the player has gone long enough without sleep to attract phantoms.
+    pub fn has_insomnia(self) -> bool {
+        self.0 >= INSOMNIA_TICKS
+    }
+}
+
+/// The outcome of a player trying to sleep in a bed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SleepResult {
+    Asleep,
+    /// It is daytime and not thundering
+    NotPossibleNow,
+    /// A block above the bed is in the way
+    Obstructed,
+    /// Monsters are nearby ("you may not rest now")
+    NotSafe,
+    /// Someone is already sleeping in the bed
+    Occupied,
+    /// The bed was used outside the Overworld and blew up
+    Exploded,
+}
+
+pub(crate) fn is_bed(game: &Game, pos: ValidBlockPosition) -> bool {
+    game.block(pos).map_or(false, |block| {
+        block.kind().simplified_kind() == SimplifiedBlockKind::Bed
+    })
+}
+
+/// Returns the positions of both halves of the bed at `bed`.
+fn bed_halves(game: &Game, bed: ValidBlockPosition) -> Vec<ValidBlockPosition> {
+    let mut halves = vec![bed];
+    let properties = match game.block_properties(bed) {
+        Some(properties) => properties,
+        None => return halves,
+    };
+    let (dx, dz) = match properties.get("facing").map(String::as_str) {
+        Some("north") => (0, -1),
+        Some("south") => (0, 1),
+        Some("west") => (-1, 0),
+        Some("east") => (1, 0),
+        _ => return halves,
+    };
+    // Beds face from their foot towards their head
+    let sign = match properties.get("part").map(String::as_str) {
+        Some("head") => -1,
+        _ => 1,
+    };
+    let other = BlockPosition::new(bed.x() + sign * dx, bed.y(), bed.z() + sign * dz);
+    if let Ok(other) = other.try_into() {
+        if is_bed(game, other) {
+            halves.push(other);
+        }
+    }
+    halves
+}
+
+fn set_bed_occupied(game: &mut Game, bed: ValidBlockPosition, occupied: bool) {
+    for half in bed_halves(game, bed) {
+        if let Some(mut properties) = game.block_properties(half) {
+            properties.set("occupied", if occupied { "true" } else { "false" });
+            game.set_block_properties(half, &properties);
+        }
+    }
+}
+
+pub(crate) fn is_obstructed(game: &Game, bed: ValidBlockPosition) -> bool {
+    bed_halves(game, bed).into_iter().any(|half| {
+        game.kind_at((half.x(), half.y() + 1, half.z()))
+            .map_or(false, |kind| kind.solid())
+    })
+}
+
+fn monsters_near(game: &Game, bed: ValidBlockPosition) -> bool {
+    game.ecs
+        .query::<(&Position, &EntityKind)>()
+        .iter()
+        .any(|(_, (position, kind))| {
+            let center = (
+                f64::from(bed.x()) + 0.5,
+                f64::from(bed.y()),
+                f64::from(bed.z()) + 0.5,
+            );
+            entities::is_monster(*kind)
+                && (position.x - center.0).abs() <= MONSTER_RANGE_HORIZONTAL
+                && (position.y - center.1).abs() <= MONSTER_RANGE_VERTICAL
+                && (position.z - center.2).abs() <= MONSTER_RANGE_HORIZONTAL
+        })
+}
+
+/// Makes `player` try to sleep in the bed at `bed`.
+///
+/// A player who gets as far as being allowed to sleep at this time of day
+/// has their spawn point set to the bed, even if monsters then keep them
+/// from sleeping.
+pub fn sleep_in_bed(
+    game: &mut Game,
+    player: Entity,
+    bed: ValidBlockPosition,
+) -> SysResult<SleepResult> {
+    if !is_bed(game, bed) {
+        bail!("no bed at {:?}", bed);
+    }
+
+    if !game.world.dimension.beds_work() {
+        for half in bed_halves(game, bed) {
+            game.break_block(half);
+        }
+        let center = position!(
+            f64::from(bed.x()) + 0.5,
+            f64::from(bed.y()) + 0.5,
+            f64::from(bed.z()) + 0.5
+        );
+        explosion::explode(game, center, BED_EXPLOSION_POWER, None, true)?;
+        return Ok(SleepResult::Exploded);
+    }
+
+    if is_obstructed(game, bed) {
+        return Ok(SleepResult::Obstructed);
+    }
+
+    let time = *game.resources.get::<WorldTime>()?;
+    let thundering = game.resources.get::<Weather>()?.thundering;
+    if !time.is_night() && !thundering {
+        return Ok(SleepResult::NotPossibleNow);
+    }
+
+    let spawn_point = SpawnPoint {
+        position: bed,
+        dimension: game.world.dimension,
+    };
+    game.ecs.insert(player, spawn_point)?;
+
+    if monsters_near(game, bed) {
+        return Ok(SleepResult::NotSafe);
+    }
+    let occupied = game
+        .block_properties(bed)
+        .and_then(|properties| properties.get("occupied").cloned());
+    if occupied.as_deref() == Some("true") {
+        return Ok(SleepResult::Occupied);
+    }
+
+    game.ecs.insert(player, Sleeping { bed, ticks: 0 })?;
+    set_bed_occupied(game, bed, true);
+    Ok(SleepResult::Asleep)
+}
+
+/// Gets `player` out of bed.
+pub fn wake_up(game: &mut Game, player: Entity) -> SysResult {
+    let sleeping = game.ecs.remove::<Sleeping>(player)?;
+    if is_bed(game, sleeping.bed) {
+        set_bed_occupied(game, sleeping.bed, false);
+    }
+    Ok(())
+}
+
+/// Puts players who use a bed to sleep.
+fn use_beds(game: &mut Game) -> SysResult {
+    let uses: Vec<(Entity, ValidBlockPosition)> = game
+        .ecs
+        .query::<(&BlockInteractEvent, &Player)>()
+        .iter()
+        .filter_map(|(player, (event, _))| {
+            let pos = event.location.try_into().ok()?;
+            Some((player, pos))
+        })
+        .collect();
+
+    for (player, bed) in uses {
+        if is_bed(game, bed) && game.ecs.get::<Sleeping>(player).is_err() {
+            sleep_in_bed(game, player, bed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Counts up sleep and insomnia timers.
+fn tick_sleep(game: &mut Game) -> SysResult {
+    for (_, (_, rest, sleeping)) in game
+        .ecs
+        .query::<(&Player, &mut TimeSinceRest, Option<&mut Sleeping>)>()
+        .iter()
+    {
+        match sleeping {
+            Some(sleeping) => sleeping.ticks += 1,
+            None => rest.0 = rest.0.saturating_add(1),
+        }
+    }
+    Ok(())
+}
+
+/// Skips to the next morning once enough players are asleep,
+/// clearing the weather and waking everyone up.
+fn skip_night(game: &mut Game) -> SysResult {
+    let mut players = 0;
+    let mut sleepers = Vec::new();
+    for (player, (_, sleeping)) in game.ecs.query::<(&Player, Option<&Sleeping>)>().iter() {
+        players += 1;
+        if sleeping.map_or(false, |sleeping| sleeping.ticks >= DEEP_SLEEP_TICKS) {
+            sleepers.push(player);
+        }
+    }
+
+    let percentage = game
+        .resources
+        .get::<SleepSettings>()?
+        .players_sleeping_percentage;
+    if sleepers.is_empty() || sleepers.len() * 100 < players * percentage as usize {
+        return Ok(());
+    }
+
+    game.resources.get_mut::<WorldTime>()?.skip_to_morning();
+    game.resources.get_mut::<Weather>()?.clear();
+    for player in sleepers {
+        wake_up(game, player)?;
+        game.ecs.get_mut::<TimeSinceRest>(player)?.0 = 0;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{BlockId, Chunk, ChunkPosition, ParticleKind};
+    use libcraft_core::{BlockFace, Hand, Vec3f};
+
+    use crate::{time::DAY_LENGTH, Dimension};
+
+    use super::*;
+
+    fn bedroom() -> (Game, ValidBlockPosition) {
+        let mut game = Game::new();
+        game.insert_resource(WorldTime {
+            world_age: 13_000,
+            day_time: 13_000,
+        });
+        game.insert_resource(Weather::default());
+        game.insert_resource(SleepSettings::default());
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let bed = BlockPosition::new(2, 64, 2).try_into().unwrap();
+        game.set_block(bed, BlockId::red_bed());
+        (game, bed)
+    }
+
+    fn tick(game: &mut Game) {
+        tick_sleep(game).unwrap();
+        skip_night(game).unwrap();
+    }
+
+    #[test]
+    fn all_players_asleep_skips_to_morning() {
+        let (mut game, bed) = bedroom();
+        let alice = game.ecs.spawn((Player, TimeSinceRest(80_000)));
+        let bob = game.ecs.spawn((Player, TimeSinceRest(80_000)));
+        assert!(game.ecs.get::<TimeSinceRest>(alice).unwrap().has_insomnia());
+
+        assert_eq!(
+            sleep_in_bed(&mut game, alice, bed).unwrap(),
+            SleepResult::Asleep
+        );
+        for _ in 0..DEEP_SLEEP_TICKS {
+            tick(&mut game);
+        }
+        // Bob is still awake, so the night goes on
+        assert_eq!(game.resources.get::<WorldTime>().unwrap().day_time, 13_000);
+
+        assert_eq!(
+            sleep_in_bed(&mut game, bob, bed).unwrap(),
+            SleepResult::Asleep
+        );
+        for _ in 0..DEEP_SLEEP_TICKS {
+            tick(&mut game);
+        }
+
+        assert_eq!(
+            game.resources.get::<WorldTime>().unwrap().day_time,
+            DAY_LENGTH
+        );
+        for player in [alice, bob].iter().copied() {
+            assert_eq!(
+                *game.ecs.get::<TimeSinceRest>(player).unwrap(),
+                TimeSinceRest(0)
+            );
+            assert!(game.ecs.get::<Sleeping>(player).is_err());
+        }
+    }
+
+    #[test]
+    fn using_a_bed_puts_the_player_to_sleep() {
+        let (mut game, bed) = bedroom();
+        let player = game.ecs.spawn((Player, TimeSinceRest(0)));
+        game.ecs
+            .insert_entity_event(
+                player,
+                BlockInteractEvent {
+                    hand: Hand::Main,
+                    location: bed.into(),
+                    face: BlockFace::Top,
+                    cursor_position: Vec3f::new(0.5, 0.5, 0.5),
+                    inside_block: false,
+                },
+            )
+            .unwrap();
+
+        use_beds(&mut game).unwrap();
+        assert_eq!(game.ecs.get::<Sleeping>(player).unwrap().bed, bed);
+    }
+
+    #[test]
+    fn beds_only_work_at_night_in_the_overworld() {
+        let (mut game, bed) = bedroom();
+        let player = game.ecs.spawn((Player, TimeSinceRest(0)));
+        game.resources.get_mut::<WorldTime>().unwrap().day_time = 6_000;
+        assert_eq!(
+            sleep_in_bed(&mut game, player, bed).unwrap(),
+            SleepResult::NotPossibleNow
+        );
+
+        game.world.dimension = Dimension::Nether;
+        assert_eq!(
+            sleep_in_bed(&mut game, player, bed).unwrap(),
+            SleepResult::Exploded
+        );
+        assert_eq!(game.block(bed), Some(BlockId::air()));
+    }
+
+    #[test]
+    fn nether_beds_explode_without_setting_spawn() {
+        let (mut game, bed) = bedroom();
+        game.world.dimension = Dimension::Nether;
+        let player = game.ecs.spawn((Player, TimeSinceRest(0)));
+
+        assert_eq!(
+            sleep_in_bed(&mut game, player, bed).unwrap(),
+            SleepResult::Exploded
+        );
+        assert!(game.ecs.get::<SpawnPoint>(player).is_err());
+        assert!(game.ecs.get::<Sleeping>(player).is_err());
+        let explosions: Vec<Position> = game
+            .outbound_effects
+            .drain_particles()
+            .filter(|particle| particle.kind == ParticleKind::ExplosionEmitter)
+            .map(|particle| particle.position)
+            .collect();
+        assert_eq!(explosions, vec![position!(2.5, 64.5, 2.5)]);
+    }
+
+    #[test]
+    fn bed_sets_spawn_but_monsters_prevent_sleep() {
+        let (mut game, bed) = bedroom();
+        let player = game.ecs.spawn((Player, TimeSinceRest(0)));
+        let zombie = game
+            .ecs
+            .spawn((position!(6.0, 64.0, 2.0), EntityKind::Zombie));
+
+        assert_eq!(
+            sleep_in_bed(&mut game, player, bed).unwrap(),
+            SleepResult::NotSafe
+        );
+        assert_eq!(
+            *game.ecs.get::<SpawnPoint>(player).unwrap(),
+            SpawnPoint {
+                position: bed,
+                dimension: Dimension::Overworld,
+            }
+        );
+        assert!(game.ecs.get::<Sleeping>(player).is_err());
+
+        game.ecs.despawn(zombie).unwrap();
+        assert_eq!(
+            sleep_in_bed(&mut game, player, bed).unwrap(),
+            SleepResult::Asleep
+        );
+        let occupied = game.block_properties(bed).unwrap().get("occupied").cloned();
+        assert_eq!(occupied.as_deref(), Some("true"));
+
+        let other = game.ecs.spawn((Player, TimeSinceRest(0)));
+        assert_eq!(
+            sleep_in_bed(&mut game, other, bed).unwrap(),
+            SleepResult::Occupied
+        );
+        wake_up(&mut game, player).unwrap();
+        let occupied = game.block_properties(bed).unwrap().get("occupied").cloned();
+        assert_eq!(occupied.as_deref(), Some("false"));
+    }
+
+    #[test]
+    fn obstructed_bed_is_refused() {
+        let (mut game, bed) = bedroom();
+        let player = game.ecs.spawn((Player, TimeSinceRest(0)));
+        let above = BlockPosition::new(bed.x(), bed.y() + 1, bed.z())
+            .try_into()
+            .unwrap();
+        game.set_block(above, BlockId::stone());
+        assert_eq!(
+            sleep_in_bed(&mut game, player, bed).unwrap(),
+            SleepResult::Obstructed
+        );
+        assert!(game.ecs.get::<SpawnPoint>(player).is_err());
+    }
+}
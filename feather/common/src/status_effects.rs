@@ -0,0 +1,263 @@
+//! Status effects (potion effects) applied to living entities.
+
+use std::collections::BTreeMap;
+
+use ecs::{Entity, SysResult, SystemExecutor};
+use quill_common::components::Health;
+
+use crate::{
+    combat::{self, DamageType},
+    Game,
+};
+
+/// Health an entity cannot be poisoned below
+const POISON_MIN_HEALTH: f32 = 1.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.add_system(tick_status_effects);
+}
+
+/// A kind of status effect, numbered by its protocol id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StatusEffectKind {
+    Speed = 1,
+    Slowness,
+    Haste,
+    MiningFatigue,
+    Strength,
+    InstantHealth,
+    InstantDamage,
+    JumpBoost,
+    Nausea,
+    Regeneration,
+    Resistance,
+    FireResistance,
+    WaterBreathing,
+    Invisibility,
+    Blindness,
+    NightVision,
+    Hunger,
+    Weakness,
+    Poison,
+    Wither,
+    HealthBoost,
+    Absorption,
+    Saturation,
+    Glowing,
+    Levitation,
+    Luck,
+    Unluck,
+    SlowFalling,
+    ConduitPower,
+    DolphinsGrace,
+    BadOmen,
+    HeroOfTheVillage,
+    Darkness,
+}
+
+impl StatusEffectKind {
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// Whether the effect is applied once instead of lasting for a duration.
+    pub fn is_instant(self) -> bool {
+        matches!(
+            self,
+            StatusEffectKind::InstantHealth | StatusEffectKind::InstantDamage
+        )
+    }
+}
+
+/// A status effect with its strength and remaining duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    /// Zero-based level of the effect: an amplifier of 1 is "level II"
+    pub amplifier: u8,
+    /// Remaining duration in ticks
+    pub duration: u32,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusEffectKind, amplifier: u8, duration: u32) -> Self {
+        Self {
+            kind,
+            amplifier,
+            duration,
+        }
+    }
+
+    /// Returns whether a periodic effect triggers this tick,
+    /// given its interval in ticks at level I.
+    fn triggers(&self, base_interval: u32) -> bool {
+        let interval = base_interval >> self.amplifier.min(5);
+        interval == 0 || self.duration % interval == 0
+    }
+}
+
+/// The status effects active on an entity.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusEffects {
+    effects: BTreeMap<StatusEffectKind, StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Adds an effect. An existing effect of the same kind is only
+    /// replaced by a stronger one, or by an equally strong longer one.
+    pub fn add(&mut self, effect: StatusEffect) {
+        let replace = match self.effects.get(&effect.kind) {
+            Some(current) => {
+                effect.amplifier > current.amplifier
+                    || (effect.amplifier == current.amplifier && effect.duration > current.duration)
+            }
+            None => true,
+        };
+        if replace {
+            self.effects.insert(effect.kind, effect);
+        }
+    }
+
+    pub fn get(&self, kind: StatusEffectKind) -> Option<&StatusEffect> {
+        self.effects.get(&kind)
+    }
+
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.effects.contains_key(&kind)
+    }
+
+    pub fn remove(&mut self, kind: StatusEffectKind) -> Option<StatusEffect> {
+        self.effects.remove(&kind)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+}
+
+/// Applies `effect` to `entity`: instant effects change its health
+/// right away, other effects are added to its [`StatusEffects`].
+pub fn apply_effect(game: &mut Game, entity: Entity, effect: StatusEffect) -> SysResult {
+    apply_effect_scaled(game, entity, effect, 1.0)
+}
+
+/// Like [`apply_effect`], but with the strength of instant effects and the
+/// duration of lasting ones scaled by `scale`, as done by splash potions.
+pub fn apply_effect_scaled(
+    game: &mut Game,
+    entity: Entity,
+    effect: StatusEffect,
+    scale: f32,
+) -> SysResult {
+    match effect.kind {
+        StatusEffectKind::InstantHealth => {
+            if let Ok(mut health) = game.ecs.get_mut::<Health>(entity) {
+                health.heal(f32::from(4u8 << effect.amplifier.min(5)) * scale);
+            }
+        }
+        StatusEffectKind::InstantDamage => {
+            let damage = f32::from(6u8 << effect.amplifier.min(5)) * scale;
+            combat::apply_damage(game, entity, None, damage, DamageType::Magic)?;
+        }
+        _ => {
+            let duration = (effect.duration as f32 * scale).round() as u32;
+            if duration == 0 {
+                return Ok(());
+            }
+            let effect = StatusEffect { duration, ..effect };
+
+            let has_effects = game.ecs.get::<StatusEffects>(entity).is_ok();
+            if has_effects {
+                game.ecs.get_mut::<StatusEffects>(entity)?.add(effect);
+            } else {
+                let mut effects = StatusEffects::default();
+                effects.add(effect);
+                game.ecs.insert(entity, effects)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Applies periodic effects and counts down effect durations.
+fn tick_status_effects(game: &mut Game) -> SysResult {
+    let mut damaged = Vec::new();
+    for (entity, (effects, health)) in game
+        .ecs
+        .query::<(&mut StatusEffects, Option<&mut Health>)>()
+        .iter()
+    {
+        if let Some(health) = health {
+            for effect in effects.iter() {
+                match effect.kind {
+                    StatusEffectKind::Poison if effect.triggers(25) => {
+                        if health.current > POISON_MIN_HEALTH {
+                            let damage = (health.current - POISON_MIN_HEALTH).min(1.0);
+                            damaged.push((entity, damage, DamageType::Poison));
+                        }
+                    }
+                    StatusEffectKind::Wither if effect.triggers(40) => {
+                        damaged.push((entity, 1.0, DamageType::Wither))
+                    }
+                    StatusEffectKind::Regeneration if effect.triggers(50) => {
+                        health.heal(1.0);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        effects.effects.retain(|_, effect| {
+            effect.duration = effect.duration.saturating_sub(1);
+            effect.duration > 0
+        });
+    }
+
+    for (entity, damage, damage_type) in damaged {
+        combat::apply_damage(game, entity, None, damage, damage_type)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use base::position;
+
+    use super::*;
+
+    #[test]
+    fn poison_damages_until_one_health_and_expires() {
+        let mut game = Game::new();
+        let entity = game
+            .ecs
+            .spawn((position!(0.0, 64.0, 0.0), Health::new(3.0)));
+        apply_effect(
+            &mut game,
+            entity,
+            StatusEffect::new(StatusEffectKind::Poison, 0, 100),
+        )
+        .unwrap();
+
+        for _ in 0..100 {
+            tick_status_effects(&mut game).unwrap();
+        }
+
+        assert_eq!(game.ecs.get::<Health>(entity).unwrap().current, 1.0);
+        assert!(game.ecs.get::<StatusEffects>(entity).unwrap().is_empty());
+    }
+
+    #[test]
+    fn weaker_effect_does_not_replace_stronger() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::new(StatusEffectKind::Speed, 1, 100));
+        effects.add(StatusEffect::new(StatusEffectKind::Speed, 0, 1000));
+        assert_eq!(effects.get(StatusEffectKind::Speed).unwrap().amplifier, 1);
+
+        effects.add(StatusEffect::new(StatusEffectKind::Speed, 1, 200));
+        assert_eq!(effects.get(StatusEffectKind::Speed).unwrap().duration, 200);
+    }
+}
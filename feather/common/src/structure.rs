@@ -0,0 +1,333 @@
+//! Multi-block structure templates, such as trees, golem patterns
+//! and village buildings.
+//!
+//! Templates are loaded from the vanilla structure format and
+//! placed into the world rotated and mirrored, along with
+//! the block entities (chest contents, sign text, ...) they contain.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+    io::Read,
+};
+
+use anyhow::{bail, Context};
+use base::{
+    anvil::structure::{StructureData, StructurePaletteEntry},
+    BlockId, BlockKind, BlockPosition, ValidBlockPosition,
+};
+use libcraft_blocks::{create_block_entity, BlockEntityData, BlockProperties, Mirror, Rotation};
+
+use crate::Game;
+
+/// A block of a [`StructureTemplate`].
+#[derive(Debug, Clone)]
+pub struct TemplateBlock {
+    /// Offset from the template's origin, before any transform.
+    pub offset: (i32, i32, i32),
+    pub block: BlockId,
+    /// Data of the block entity placed along with this block.
+    pub block_entity: Option<BlockEntityData>,
+}
+
+/// A fixed arrangement of blocks which can be
+/// placed into the world, e.g. a village house.
+#[derive(Debug, Clone)]
+pub struct StructureTemplate {
+    size: (i32, i32, i32),
+    blocks: Vec<TemplateBlock>,
+}
+
+impl StructureTemplate {
+    /// Reads a template from a gzipped structure (`.nbt`) file.
+    pub fn load(reader: impl Read) -> anyhow::Result<Self> {
+        let data = StructureData::load(reader).context("malformed structure file")?;
+        Self::from_data(&data)
+    }
+
+    /// Creates a template from the contents of a structure file.
+    pub fn from_data(data: &StructureData) -> anyhow::Result<Self> {
+        let size = match data.dimensions() {
+            Some(size) => size,
+            None => bail!("structure size has {} coordinates", data.size.len()),
+        };
+        let palette = data
+            .palette
+            .iter()
+            .map(palette_block)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut blocks = Vec::with_capacity(data.blocks.len());
+        for block in &data.blocks {
+            let offset = match block.position() {
+                Some(offset) => offset,
+                None => bail!("block position has {} coordinates", block.pos.len()),
+            };
+            let state = match palette.get(block.state as usize) {
+                Some(state) if block.state >= 0 => *state,
+                _ => bail!("block state {} is not in the palette", block.state),
+            };
+            blocks.push(TemplateBlock {
+                offset,
+                block: state,
+                block_entity: block.nbt.as_ref().map(block_entity_data),
+            });
+        }
+
+        Ok(Self { size, blocks })
+    }
+
+    /// Size of the template along the x, y and z axes, before any transform.
+    pub fn size(&self) -> (i32, i32, i32) {
+        self.size
+    }
+
+    pub fn blocks(&self) -> &[TemplateBlock] {
+        &self.blocks
+    }
+
+    /// Places the template with its origin at `origin`.
+    ///
+    /// Like vanilla, the template is first mirrored, then rotated
+    /// around its origin, so offsets along the mirrored or rotated
+    /// axes end up on the other side of `origin`. Structure void
+    /// blocks leave the world untouched.
+    ///
+    /// Returns the number of blocks placed. Blocks in
+    /// unloaded chunks are skipped.
+    pub fn place(
+        &self,
+        game: &mut Game,
+        origin: BlockPosition,
+        rotation: Rotation,
+        mirror: Mirror,
+    ) -> usize {
+        let mut placed = 0;
+        for template_block in &self.blocks {
+            if template_block.block.kind() == BlockKind::StructureVoid {
+                continue;
+            }
+
+            let (x, y, z) = transform_offset(template_block.offset, rotation, mirror);
+            let pos: ValidBlockPosition =
+                match BlockPosition::new(origin.x + x, origin.y + y, origin.z + z).try_into() {
+                    Ok(pos) => pos,
+                    Err(_) => continue,
+                };
+            let block = transform_block(template_block.block, rotation, mirror);
+            // Neighbor updates would break blocks whose support
+            // has not been placed yet
+            if !game.set_block_without_neighbor_updates(pos, block) {
+                continue;
+            }
+            placed += 1;
+
+            // Overwritten blocks lose their block entities
+            let key = (pos.x(), pos.y(), pos.z());
+            game.block_entities.remove(key);
+            if let Some(data) = &template_block.block_entity {
+                if let Some(mut entity) = create_block_entity(block.kind(), key) {
+                    entity.data = data.clone();
+                    game.block_entities.set(key, entity);
+                }
+            }
+        }
+        placed
+    }
+}
+
+fn palette_block(entry: &StructurePaletteEntry) -> anyhow::Result<BlockId> {
+    BlockId::from_identifier_and_properties(&entry.name, &entry.properties)
+        .with_context(|| format!("invalid block state {} {:?}", entry.name, entry.properties))
+}
+
+/// Converts the block entity data of a structure file. Only
+/// strings and integers are kept, as those are all block
+/// entities can store for now; the position tags are dropped.
+fn block_entity_data(nbt: &HashMap<String, nbt::Value>) -> BlockEntityData {
+    let mut data = BlockEntityData::new();
+    for (key, value) in nbt {
+        if matches!(key.as_str(), "x" | "y" | "z") {
+            continue;
+        }
+        match value {
+            nbt::Value::String(value) => data.set_string(key, value.clone()),
+            nbt::Value::Byte(value) => data.set_int(key, i32::from(*value)),
+            nbt::Value::Short(value) => data.set_int(key, i32::from(*value)),
+            nbt::Value::Int(value) => data.set_int(key, *value),
+            _ => {}
+        }
+    }
+    data
+}
+
+/// Moves an offset from the template's origin the way
+/// vanilla does: mirrored first, then rotated clockwise
+/// (as seen from above) around the origin.
+fn transform_offset(
+    (x, y, z): (i32, i32, i32),
+    rotation: Rotation,
+    mirror: Mirror,
+) -> (i32, i32, i32) {
+    let (x, z) = match mirror {
+        Mirror::None => (x, z),
+        Mirror::LeftRight => (x, -z),
+        Mirror::FrontBack => (-x, z),
+    };
+    match rotation {
+        Rotation::None => (x, y, z),
+        Rotation::Clockwise90 => (-z, y, x),
+        Rotation::Clockwise180 => (-x, y, -z),
+        Rotation::CounterClockwise90 => (z, y, -x),
+    }
+}
+
+/// Turns a block's state to match its transformed position,
+/// e.g. so stairs keep facing the same wall of a house.
+fn transform_block(block: BlockId, rotation: Rotation, mirror: Mirror) -> BlockId {
+    if rotation == Rotation::None && mirror == Mirror::None {
+        return block;
+    }
+
+    let mut properties = BlockProperties::new(block.kind());
+    for (name, value) in block.to_properties_map() {
+        properties.set(name, value);
+    }
+    let properties = properties.mirror(mirror).rotate(rotation);
+
+    let map: BTreeMap<String, String> = properties
+        .all()
+        .iter()
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    BlockId::from_identifier_and_properties(block.identifier(), &map).unwrap_or(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{anvil::structure::StructureBlock, Chunk, ChunkPosition, FacingCardinal};
+    use libcraft_blocks::BlockEntityKind;
+
+    use super::*;
+
+    fn palette_entry(block: BlockId) -> StructurePaletteEntry {
+        StructurePaletteEntry {
+            name: block.identifier().to_owned(),
+            properties: block
+                .to_properties_map()
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect(),
+        }
+    }
+
+    fn structure_file() -> Vec<u8> {
+        let mut chest_nbt = HashMap::new();
+        chest_nbt.insert(
+            "id".to_owned(),
+            nbt::Value::String("minecraft:chest".into()),
+        );
+        chest_nbt.insert("LootTableSeed".to_owned(), nbt::Value::Int(42));
+        chest_nbt.insert("x".to_owned(), nbt::Value::Int(2));
+
+        let data = StructureData {
+            data_version: 2586,
+            size: vec![3, 2, 1],
+            palette: vec![
+                palette_entry(BlockId::stone()),
+                palette_entry(BlockId::chest().with_facing_cardinal(FacingCardinal::North)),
+                palette_entry(BlockId::structure_void()),
+            ],
+            blocks: vec![
+                StructureBlock {
+                    pos: vec![0, 0, 0],
+                    state: 0,
+                    nbt: None,
+                },
+                StructureBlock {
+                    pos: vec![1, 0, 0],
+                    state: 0,
+                    nbt: None,
+                },
+                StructureBlock {
+                    pos: vec![2, 1, 0],
+                    state: 1,
+                    nbt: Some(chest_nbt),
+                },
+                StructureBlock {
+                    pos: vec![2, 0, 0],
+                    state: 2,
+                    nbt: None,
+                },
+            ],
+        };
+        let mut buf = Vec::new();
+        data.save(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn loads_and_places_rotated_templates() {
+        let template = StructureTemplate::load(structure_file().as_slice()).unwrap();
+        assert_eq!(template.size(), (3, 2, 1));
+        assert_eq!(template.blocks().len(), 4);
+
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let marker: ValidBlockPosition = BlockPosition::new(8, 64, 10).try_into().unwrap();
+        game.set_block(marker, BlockId::glass());
+
+        let origin = BlockPosition::new(8, 64, 8);
+        let placed = template.place(&mut game, origin, Rotation::Clockwise90, Mirror::None);
+        assert_eq!(placed, 3);
+
+        // Offsets along +x end up along +z after a clockwise turn
+        let block_at = |x, y, z| {
+            let pos: ValidBlockPosition = BlockPosition::new(x, y, z).try_into().unwrap();
+            game.block(pos).unwrap()
+        };
+        assert_eq!(block_at(8, 64, 8), BlockId::stone());
+        assert_eq!(block_at(8, 64, 9), BlockId::stone());
+        assert_eq!(block_at(9, 64, 8), BlockId::air());
+        // Structure voids keep what was there
+        assert_eq!(block_at(8, 64, 10), BlockId::glass());
+
+        let chest = block_at(8, 65, 10);
+        assert_eq!(chest.kind(), BlockKind::Chest);
+        assert_eq!(chest.facing_cardinal(), Some(FacingCardinal::East));
+
+        let entity = game.block_entities.get((8, 65, 10)).unwrap();
+        assert_eq!(entity.kind, BlockEntityKind::Chest);
+        assert_eq!(entity.position, (8, 65, 10));
+        assert_eq!(entity.data.get_int("LootTableSeed"), Some(42));
+        assert_eq!(
+            entity.data.get_string("id").map(String::as_str),
+            Some("minecraft:chest")
+        );
+        assert_eq!(entity.data.get_int("x"), None);
+        assert!(!game.block_entities.exists((10, 65, 8)));
+    }
+
+    #[test]
+    fn mirroring_flips_offsets_and_states() {
+        let template = StructureTemplate::load(structure_file().as_slice()).unwrap();
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+
+        let origin = BlockPosition::new(8, 64, 8);
+        template.place(&mut game, origin, Rotation::None, Mirror::FrontBack);
+
+        let pos: ValidBlockPosition = BlockPosition::new(7, 64, 8).try_into().unwrap();
+        assert_eq!(game.block(pos), Some(BlockId::stone()));
+        let chest: ValidBlockPosition = BlockPosition::new(6, 65, 8).try_into().unwrap();
+        assert_eq!(
+            game.block(chest).unwrap().facing_cardinal(),
+            Some(FacingCardinal::North)
+        );
+        assert!(game.block_entities.exists((6, 65, 8)));
+    }
+}
@@ -0,0 +1,108 @@
+//! Random teleports, like those from eating chorus fruit.
+
+use std::convert::TryInto;
+
+use base::{BlockId, BlockPosition, Position, ValidBlockPosition};
+use ecs::{Entity, SysResult};
+use rand::Rng;
+
+use crate::Game;
+
+/// Tries at finding a spot before a random teleport gives up
+const ATTEMPTS: usize = 16;
+
+fn block_at(game: &Game, x: i32, y: i32, z: i32) -> Option<BlockId> {
+    let pos: ValidBlockPosition = BlockPosition::new(x, y, z).try_into().ok()?;
+    game.block(pos)
+}
+
+/// Finds where an entity dropped into the column at `x`, `z` from `y`
+/// would land, as long as it has room to stand there out of any fluid.
+pub fn landing_spot(game: &Game, x: i32, y: i32, z: i32) -> Option<BlockPosition> {
+    let mut y = y;
+    loop {
+        let below = block_at(game, x, y - 1, z)?;
+        if below.is_solid() {
+            break;
+        }
+        y -= 1;
+    }
+
+    let fits = (0..2).all(|dy| {
+        block_at(game, x, y + dy, z).map_or(false, |block| !block.is_solid() && !block.is_fluid())
+    });
+    if fits {
+        Some(BlockPosition::new(x, y, z))
+    } else {
+        None
+    }
+}
+
+/// Teleports `entity` to a random spot it can stand on, up to `range`
+/// blocks away along each axis.
+///
+/// Returns where the entity ended up, or `None` if no spot was found
+/// and it stayed where it was.
+pub fn random_teleport(
+    game: &mut Game,
+    entity: Entity,
+    range: f64,
+    rng: &mut impl Rng,
+) -> SysResult<Option<Position>> {
+    let origin = *game.ecs.get::<Position>(entity)?;
+    let vertical_range = range as i32;
+    for _ in 0..ATTEMPTS {
+        let x = origin.x + (rng.gen::<f64>() - 0.5) * 2.0 * range;
+        let z = origin.z + (rng.gen::<f64>() - 0.5) * 2.0 * range;
+        let y = origin.y.floor() as i32 + rng.gen_range(-vertical_range..vertical_range);
+        let spot = match landing_spot(game, x.floor() as i32, y, z.floor() as i32) {
+            Some(spot) => spot,
+            None => continue,
+        };
+
+        let destination = Position {
+            x,
+            y: f64::from(spot.y),
+            z,
+            ..origin
+        };
+        *game.ecs.get_mut::<Position>(entity)? = destination;
+        return Ok(Some(destination));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, Chunk, ChunkPosition};
+
+    use super::*;
+
+    #[test]
+    fn teleports_land_on_solid_ground() {
+        let mut game = Game::new();
+        game.world
+            .chunk_map_mut()
+            .insert_chunk(Chunk::new(ChunkPosition::new(0, 0)));
+        let entity = game.ecs.spawn((position!(8.0, 64.0, 8.0),));
+
+        // Nothing to stand on in the whole chunk
+        let mut rng = rand::thread_rng();
+        assert_eq!(
+            random_teleport(&mut game, entity, 8.0, &mut rng).unwrap(),
+            None
+        );
+        assert_eq!(
+            *game.ecs.get::<Position>(entity).unwrap(),
+            position!(8.0, 64.0, 8.0)
+        );
+
+        let ground = BlockPosition::new(3, 60, 3).try_into().unwrap();
+        game.set_block(ground, BlockId::stone());
+        assert_eq!(
+            landing_spot(&game, 3, 66, 3),
+            Some(BlockPosition::new(3, 61, 3))
+        );
+        assert_eq!(landing_spot(&game, 3, 59, 3), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! A harness for tests stepping several systems together.
+//!
+//! [`TestGame`] runs a `Game` with an in-memory world of empty chunks
+//! and only the systems a test registers, one tick at a time.
+//! The game's [`GameRng`] is seeded, so systems drawing random
+//! numbers from it do the same thing on every run.
+//!
+//! Available to other crates' tests with the `test-support` feature.
+
+use std::{cell::RefCell, convert::TryInto, fmt::Debug, rc::Rc};
+
+use base::{BlockId, BlockKind, BlockPosition, Chunk, ChunkPosition, ValidBlockPosition};
+use ecs::{Entity, SysResult, SystemExecutor};
+
+use crate::{Game, GameRng};
+
+type Registration = Box<dyn FnOnce(&mut Game, &mut SystemExecutor<Game>)>;
+
+/// Builder for a [`TestGame`]. Created with [`TestGame::builder`].
+pub struct TestGameBuilder {
+    seed: u64,
+    chunks: Vec<ChunkPosition>,
+    registrations: Vec<Registration>,
+}
+
+impl TestGameBuilder {
+    /// Seeds the game's random number generator.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Adds an empty chunk to the world.
+    pub fn chunk(mut self, pos: ChunkPosition) -> Self {
+        self.chunks.push(pos);
+        self
+    }
+
+    /// Adds empty chunks up to `radius` chunks away from
+    /// the chunk at the origin along each axis.
+    pub fn chunks(mut self, radius: i32) -> Self {
+        for x in -radius..=radius {
+            for z in -radius..=radius {
+                self.chunks.push(ChunkPosition::new(x, z));
+            }
+        }
+        self
+    }
+
+    /// Registers systems with a function like the `register`
+    /// functions of gameplay modules.
+    pub fn register(
+        mut self,
+        register: impl FnOnce(&mut Game, &mut SystemExecutor<Game>) + 'static,
+    ) -> Self {
+        self.registrations.push(Box::new(register));
+        self
+    }
+
+    /// Adds a single system, in the default stage.
+    pub fn system(self, system: impl FnMut(&mut Game) -> SysResult + 'static) -> Self {
+        self.register(move |_, systems| {
+            systems.add_system(system);
+        })
+    }
+
+    pub fn build(self) -> TestGame {
+        let mut game = Game::new();
+        game.insert_resource(GameRng::seeded(self.seed));
+        for pos in self.chunks {
+            game.world.chunk_map_mut().insert_chunk(Chunk::new(pos));
+        }
+
+        let mut systems = SystemExecutor::new();
+        for register in self.registrations {
+            register(&mut game, &mut systems);
+        }
+        game.system_executor = Rc::new(RefCell::new(systems));
+
+        TestGame { game }
+    }
+}
+
+/// A `Game` stepped tick by tick in tests.
+pub struct TestGame {
+    pub game: Game,
+}
+
+impl TestGame {
+    pub fn builder() -> TestGameBuilder {
+        TestGameBuilder {
+            seed: 0,
+            chunks: Vec::new(),
+            registrations: Vec::new(),
+        }
+    }
+
+    /// Runs all systems once, like a server tick.
+    pub fn step(&mut self) {
+        self.game.run_systems();
+        self.game.tick_count += 1;
+    }
+
+    /// Runs `ticks` ticks.
+    pub fn step_n(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            self.step();
+        }
+    }
+
+    /// Sets every block in the box between `from` and `to`, inclusive.
+    ///
+    /// # Panics
+    /// Panics if any of the blocks is outside the world's chunks.
+    pub fn fill(&mut self, from: BlockPosition, to: BlockPosition, block: BlockId) {
+        for x in from.x.min(to.x)..=from.x.max(to.x) {
+            for y in from.y.min(to.y)..=from.y.max(to.y) {
+                for z in from.z.min(to.z)..=from.z.max(to.z) {
+                    let pos: ValidBlockPosition = BlockPosition::new(x, y, z)
+                        .try_into()
+                        .expect("block outside the world");
+                    assert!(
+                        self.game.set_block(pos, block),
+                        "block {:?} is in a missing chunk",
+                        pos
+                    );
+                }
+            }
+        }
+    }
+
+    /// Gets a copy of a component of `entity`.
+    ///
+    /// # Panics
+    /// Panics if the entity doesn't have the component.
+    pub fn component<T>(&self, entity: Entity) -> T
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        match self.game.ecs.get::<T>(entity) {
+            Ok(component) => component.clone(),
+            Err(e) => panic!(
+                "failed to get {} of {:?}: {}",
+                std::any::type_name::<T>(),
+                entity,
+                e
+            ),
+        }
+    }
+
+    /// Asserts that the `T` component of `entity` equals `expected`.
+    pub fn assert_component<T>(&self, entity: Entity, expected: T)
+    where
+        T: Clone + Debug + PartialEq + Send + Sync + 'static,
+    {
+        assert_eq!(
+            self.component::<T>(entity),
+            expected,
+            "unexpected {} of {:?}",
+            std::any::type_name::<T>(),
+            entity
+        );
+    }
+
+    /// Asserts that `entity` has no `T` component, or no longer exists.
+    pub fn assert_no_component<T>(&self, entity: Entity)
+    where
+        T: Send + Sync + 'static,
+    {
+        assert!(
+            self.game.ecs.get::<T>(entity).is_err(),
+            "{:?} has a {}",
+            entity,
+            std::any::type_name::<T>()
+        );
+    }
+
+    /// Asserts the kind of block at `pos`.
+    pub fn assert_block(&self, pos: BlockPosition, expected: BlockKind) {
+        let block = pos
+            .try_into()
+            .ok()
+            .and_then(|pos: ValidBlockPosition| self.game.block(pos));
+        assert_eq!(
+            block.map(BlockId::kind),
+            Some(expected),
+            "unexpected block at {:?}",
+            pos
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{position, Position};
+    use rand::Rng;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Speed(f64);
+
+    fn move_entities(game: &mut Game) -> SysResult {
+        for (_, (position, speed)) in game.ecs.query::<(&mut Position, &Speed)>().iter() {
+            position.x += speed.0;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn steps_registered_systems() {
+        let mut test = TestGame::builder().chunks(1).system(move_entities).build();
+        let entity = test.game.ecs.spawn((position!(0.0, 64.0, 0.0), Speed(0.5)));
+
+        test.step_n(10);
+        test.assert_component(entity, position!(5.0, 64.0, 0.0));
+        test.assert_component(entity, Speed(0.5));
+        test.assert_no_component::<u32>(entity);
+        assert_eq!(test.game.tick_count, 10);
+
+        test.fill(
+            BlockPosition::new(-16, 63, -16),
+            BlockPosition::new(31, 63, 31),
+            BlockId::stone(),
+        );
+        test.assert_block(BlockPosition::new(31, 63, -16), BlockKind::Stone);
+        test.assert_block(BlockPosition::new(0, 64, 0), BlockKind::Air);
+    }
+
+    #[test]
+    fn rng_is_seeded() {
+        let roll = |seed| {
+            let mut test = TestGame::builder().seed(seed).build();
+            test.game.with_rng(|_, rng| rng.gen::<u64>())
+        };
+        assert_eq!(roll(1), roll(1));
+        assert_ne!(roll(1), roll(2));
+    }
+}
@@ -0,0 +1,131 @@
+//! The world's day/night cycle.
+
+use std::f64::consts::PI;
+
+use ecs::{SysResult, SystemExecutor};
+
+use crate::{Game, GameRules, Weather};
+
+/// Length of a full day in ticks
+pub const DAY_LENGTH: u64 = 24_000;
+/// Time of day at which beds can first be used in clear weather
+const NIGHT_START: u64 = 12_542;
+/// Time of day after which beds can no longer be used
+const NIGHT_END: u64 = 23_460;
+/// Sky light levels lost at night
+const MAX_SKY_DARKENING: f32 = 11.0;
+/// Fraction of daylight blocked by each of rain and thunder
+const RAIN_DIMMING: f32 = 5.0 / 16.0;
+
+pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
+    game.insert_resource(WorldTime::default());
+    systems.add_system(advance_time);
+}
+
+/// The age of the world and the current time of day, in ticks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorldTime {
+    /// Ticks elapsed since the world was created
+    pub world_age: u64,
+    /// Time of the day-night cycle. Keeps counting across days;
+    /// use [`WorldTime::time_of_day`] for the time within the current day.
+    pub day_time: u64,
+}
+
+impl WorldTime {
+    /// Time within the current day, from 0 (sunrise) to [`DAY_LENGTH`].
+    pub fn time_of_day(&self) -> u64 {
+        self.day_time % DAY_LENGTH
+    }
+
+    /// Whether it is late enough for players to sleep.
+    pub fn is_night(&self) -> bool {
+        (NIGHT_START..NIGHT_END).contains(&self.time_of_day())
+    }
+
+    /// Jumps forward to the start of the next day.
+    pub fn skip_to_morning(&mut self) {
+        self.day_time += DAY_LENGTH - self.time_of_day();
+    }
+
+    /// The phase of the moon, from 0 (full moon) to 7.
+    pub fn moon_phase(&self) -> u8 {
+        (self.day_time / DAY_LENGTH % 8) as u8
+    }
+
+    /// How much of the moon is lit, from 0 at a new moon to 1 at a full moon.
+    pub fn moon_size(&self) -> f32 {
+        [1.0, 0.75, 0.5, 0.25, 0.0, 0.25, 0.5, 0.75][self.moon_phase() as usize]
+    }
+
+    /// Position of the sun as a fraction of a full turn,
+    /// from 0 at noon to 0.5 at midnight.
+    pub fn celestial_angle(&self) -> f32 {
+        let day = (self.time_of_day() as f64 / DAY_LENGTH as f64 - 0.25).rem_euclid(1.0);
+        // The sun moves faster around sunrise and sunset
+        let eased = 0.5 - (day * PI).cos() / 2.0;
+        ((day * 2.0 + eased) / 3.0) as f32
+    }
+
+    /// How much sky light is dimmed by the time of day and the weather,
+    /// from 0 at a clear noon to 11 at night.
+    pub fn sky_darkening(&self, weather: &Weather) -> u8 {
+        let sun = (self.celestial_angle() * 2.0 * std::f32::consts::PI).cos();
+        let mut brightness = (sun * 2.0 + 0.5).min(1.0).max(0.0);
+        if weather.raining {
+            brightness *= 1.0 - RAIN_DIMMING;
+        }
+        if weather.thundering {
+            brightness *= 1.0 - RAIN_DIMMING;
+        }
+        ((1.0 - brightness) * MAX_SKY_DARKENING) as u8
+    }
+}
+
+/// Ages the world and, unless the `doDaylightCycle` rule
+/// is off, moves the time of day on.
+fn advance_time(game: &mut Game) -> SysResult {
+    let daylight_cycle = game
+        .resources
+        .get::<GameRules>()
+        .map_or(true, |rules| rules.do_daylight_cycle);
+    let mut time = game.resources.get_mut::<WorldTime>()?;
+    time.world_age += 1;
+    if daylight_cycle {
+        time.day_time += 1;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipping_night_lands_on_next_morning() {
+        let mut time = WorldTime {
+            world_age: 50_000,
+            day_time: 2 * DAY_LENGTH + 13_000,
+        };
+        assert!(time.is_night());
+        time.skip_to_morning();
+        assert_eq!(time.day_time, 3 * DAY_LENGTH);
+        assert_eq!(time.time_of_day(), 0);
+        assert!(!time.is_night());
+    }
+
+    #[test]
+    fn time_of_day_stops_without_daylight_cycle() {
+        let mut game = Game::new();
+        game.insert_resource(WorldTime::default());
+        game.insert_resource(GameRules {
+            do_daylight_cycle: false,
+            ..Default::default()
+        });
+        advance_time(&mut game).unwrap();
+
+        let time = *game.resources.get::<WorldTime>().unwrap();
+        assert_eq!(time.world_age, 1);
+        assert_eq!(time.day_time, 0);
+    }
+}
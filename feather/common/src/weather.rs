@@ -0,0 +1,42 @@
+//! Global weather state.
+
+use base::Biome;
+
+use crate::{
+    biomes::{precipitation_type, Precipitation},
+    Game,
+};
+
+pub fn register(game: &mut Game) {
+    game.insert_resource(Weather::default());
+}
+
+/// Whether it is raining or thundering in the world.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Weather {
+    pub raining: bool,
+    pub thundering: bool,
+}
+
+impl Weather {
+    /// Whether it is clear.
+    pub fn is_clear(&self) -> bool {
+        !self.raining && !self.thundering
+    }
+
+    /// What is falling in the given biome: nothing when it's
+    /// clear, and otherwise the biome's precipitation.
+    pub fn precipitation_in(&self, biome: Biome) -> Precipitation {
+        if self.raining {
+            precipitation_type(biome)
+        } else {
+            Precipitation::None
+        }
+    }
+
+    /// Stops any rain or thunder.
+    pub fn clear(&mut self) {
+        self.raining = false;
+        self.thundering = false;
+    }
+}
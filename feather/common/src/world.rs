@@ -6,7 +6,8 @@ use uuid::Uuid;
 
 use base::anvil::player::PlayerData;
 use base::{
-    BlockPosition, Chunk, ChunkHandle, ChunkLock, ChunkPosition, ValidBlockPosition, CHUNK_HEIGHT,
+    Biome, BlockPosition, Chunk, ChunkHandle, ChunkLock, ChunkPosition, ValidBlockPosition,
+    CHUNK_HEIGHT,
 };
 use blocks::BlockId;
 use ecs::{Ecs, SysResult};
@@ -14,10 +15,31 @@ use worldgen::{ComposableGenerator, WorldGenerator};
 
 use crate::{
     chunk::cache::ChunkCache,
+    chunk::save::ChunkSaveData,
     chunk::worker::{ChunkWorker, LoadRequest, SaveRequest},
     events::ChunkLoadEvent,
 };
 
+/// The dimension a world belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Overworld,
+    Nether,
+    End,
+}
+
+impl Dimension {
+    /// Whether beds can be slept in, rather than exploding.
+    pub fn beds_work(self) -> bool {
+        self == Dimension::Overworld
+    }
+
+    /// Whether respawn anchors can set spawn points, rather than exploding.
+    pub fn respawn_anchors_work(self) -> bool {
+        self == Dimension::Nether
+    }
+}
+
 /// Stores all blocks and chunks in a world,
 /// along with global world data like weather, time,
 /// and the [`WorldSource`](crate::world_source::WorldSource).
@@ -31,6 +53,7 @@ pub struct World {
     loading_chunks: AHashSet<ChunkPosition>,
     canceled_chunk_loads: AHashSet<ChunkPosition>,
     world_dir: PathBuf,
+    pub dimension: Dimension,
 }
 
 impl Default for World {
@@ -45,6 +68,7 @@ impl Default for World {
             loading_chunks: AHashSet::new(),
             canceled_chunk_loads: AHashSet::new(),
             world_dir: "world".into(),
+            dimension: Dimension::Overworld,
         }
     }
 }
@@ -100,16 +124,39 @@ impl World {
         Ok(())
     }
 
-    /// Unloads the given chunk.
-    pub fn unload_chunk(&mut self, pos: ChunkPosition) -> anyhow::Result<()> {
+    /// Queues the given chunk to be saved to disk along with `data`,
+    /// keeping it loaded.
+    ///
+    /// Returns `false` if the chunk is not loaded.
+    pub fn save_chunk(&mut self, pos: ChunkPosition, data: ChunkSaveData) -> bool {
+        let handle = match self.chunk_map.chunk_handle_at(pos) {
+            Some(handle) => handle,
+            None => return false,
+        };
+        self.queue_save(pos, handle, data);
+        true
+    }
+
+    /// Blocks until the chunks queued to be saved have been written.
+    pub fn flush_chunk_saves(&mut self) {
+        self.chunk_worker.flush();
+    }
+
+    fn queue_save(&mut self, pos: ChunkPosition, chunk: ChunkHandle, data: ChunkSaveData) {
+        self.chunk_worker.queue_chunk_save(SaveRequest {
+            pos,
+            chunk,
+            entities: data.entities,
+            block_entities: data.block_entities,
+            block_updates: data.block_updates,
+        });
+    }
+
+    /// Unloads the given chunk, saving it along with `data`.
+    pub fn unload_chunk(&mut self, pos: ChunkPosition, data: ChunkSaveData) -> anyhow::Result<()> {
         if let Some((pos, handle)) = self.chunk_map.0.remove_entry(&pos) {
             handle.set_unloaded()?;
-            self.chunk_worker.queue_chunk_save(SaveRequest {
-                pos,
-                chunk: handle.clone(),
-                entities: vec![],
-                block_entities: vec![],
-            });
+            self.queue_save(pos, handle.clone(), data);
             self.cache.insert(pos, handle);
         }
         self.chunk_map.remove_chunk(pos);
@@ -149,6 +196,19 @@ impl World {
         self.chunk_map.block_at(pos)
     }
 
+    /// Retrieves the sky light level at the specified
+    /// location, or `None` if its chunk is not loaded
+    /// or the coordinates are out of bounds.
+    pub fn sky_light_at(&self, pos: ValidBlockPosition) -> Option<u8> {
+        self.chunk_map.sky_light_at(pos)
+    }
+
+    /// Retrieves the biome at the specified location,
+    /// or `None` if its chunk is not loaded.
+    pub fn biome_at(&self, pos: ValidBlockPosition) -> Option<Biome> {
+        self.chunk_map.biome_at(pos)
+    }
+
     /// Returns the chunk map.
     pub fn chunk_map(&self) -> &ChunkMap {
         &self.chunk_map
@@ -215,6 +275,22 @@ impl ChunkMap {
             .and_then(|chunk| chunk.block_at(x, y, z))
     }
 
+    pub fn sky_light_at(&self, pos: ValidBlockPosition) -> Option<u8> {
+        check_coords(pos)?;
+
+        let (x, y, z) = chunk_relative_pos(pos.into());
+        self.chunk_at(pos.chunk())
+            .and_then(|chunk| chunk.sky_light_at(x, y, z))
+    }
+
+    pub fn biome_at(&self, pos: ValidBlockPosition) -> Option<Biome> {
+        check_coords(pos)?;
+
+        let (x, y, z) = chunk_relative_pos(pos.into());
+        self.chunk_at(pos.chunk())
+            .map(|chunk| chunk.biomes().get_at_block(x, y, z))
+    }
+
     pub fn set_block_at(&self, pos: ValidBlockPosition, block: BlockId) -> bool {
         if check_coords(pos).is_none() {
             return false;
@@ -21,7 +21,9 @@ pub use hecs::{
 };
 
 mod system;
-pub use system::{GroupBuilder, HasEcs, HasResources, SysResult, SystemExecutor};
+pub use system::{
+    GroupBuilder, HasEcs, HasResources, SysResult, SystemExecutor, SystemName, SystemStage,
+};
 
 mod resources;
 pub use resources::{ResourceError, Resources};
@@ -1,6 +1,11 @@
 //! System execution, using a simple "systems as functions" model.
 
-use std::{any::type_name, marker::PhantomData, sync::Arc};
+use std::{
+    any::type_name,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{Ecs, Resources};
 
@@ -22,9 +27,44 @@ pub type SysResult<T = ()> = anyhow::Result<T>;
 
 type SystemFn<Input> = Box<dyn FnMut(&mut Input) -> SysResult>;
 
+/// The name of a system, as reported by [`SystemExecutor::system_names`]
+/// and in tick profiles.
+pub type SystemName = String;
+
+/// A stage of the tick, used to order systems that depend
+/// on each other's results.
+///
+/// Systems run stage by stage in the order listed here. Within
+/// a stage, they run in the order they were added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SystemStage {
+    /// Handling input from players, like received packets
+    Input,
+    /// Entities deciding what to do, like picking targets and paths
+    Ai,
+    /// Entities moving along their paths
+    Movement,
+    /// Physics acting on entities and blocks
+    Physics,
+    /// Entities attacking and taking damage
+    Combat,
+    /// Everything else, which is where systems go by default
+    Update,
+    /// Handling the aftermath of the tick, like dropping loot
+    /// of entities that died
+    Cleanup,
+}
+
+impl Default for SystemStage {
+    fn default() -> Self {
+        SystemStage::Update
+    }
+}
+
 struct System<Input> {
     function: SystemFn<Input>,
     name: String,
+    stage: SystemStage,
 }
 
 impl<Input> System<Input> {
@@ -32,6 +72,7 @@ impl<Input> System<Input> {
         Self {
             function: Box::new(f),
             name: type_name::<F>().to_owned(),
+            stage: SystemStage::default(),
         }
     }
 }
@@ -68,11 +109,18 @@ impl HasEcs for Ecs {
 /// For example, the `Server` group has state contained in the `Server`
 /// struct, so all its systems get `Server` as an extra parameter.
 ///
-/// Systems run sequentially in the order they are added to the executor.
+/// Systems run sequentially, ordered by their [`SystemStage`]
+/// and then by the order they are added to the executor.
+/// They should all be added before the executor first runs.
 pub struct SystemExecutor<Input> {
     systems: Vec<System<Input>>,
 
     is_first_run: bool,
+
+    /// Whether to time each system when running.
+    profiling: bool,
+    /// Time taken by each system during the last run, if profiling.
+    last_profile: Vec<(SystemName, Duration)>,
 }
 
 impl<Input> Default for SystemExecutor<Input> {
@@ -80,6 +128,8 @@ impl<Input> Default for SystemExecutor<Input> {
         Self {
             systems: Vec::new(),
             is_first_run: true,
+            profiling: false,
+            last_profile: Vec::new(),
         }
     }
 }
@@ -89,13 +139,24 @@ impl<Input> SystemExecutor<Input> {
         Self::default()
     }
 
-    /// Adds a system to the executor.
+    /// Adds a system to the executor, in the default stage.
     pub fn add_system(
         &mut self,
         system: impl FnMut(&mut Input) -> SysResult + 'static,
     ) -> &mut Self {
-        let system = System::from_fn(system);
-        self.systems.push(system);
+        self.add_system_to_stage(SystemStage::default(), system)
+    }
+
+    /// Adds a system to the executor, running after the systems
+    /// of earlier stages and before those of later ones.
+    pub fn add_system_to_stage(
+        &mut self,
+        stage: SystemStage,
+        system: impl FnMut(&mut Input) -> SysResult + 'static,
+    ) -> &mut Self {
+        let mut system = System::from_fn(system);
+        system.stage = stage;
+        self.insert(system);
         self
     }
 
@@ -103,10 +164,31 @@ impl<Input> SystemExecutor<Input> {
         &mut self,
         system: impl FnMut(&mut Input) -> SysResult + 'static,
         name: &str,
+    ) {
+        self.add_system_with_name_to_stage(system, name, SystemStage::default());
+    }
+
+    pub fn add_system_with_name_to_stage(
+        &mut self,
+        system: impl FnMut(&mut Input) -> SysResult + 'static,
+        name: &str,
+        stage: SystemStage,
     ) {
         let mut system = System::from_fn(system);
         system.name = name.to_owned();
-        self.systems.push(system);
+        system.stage = stage;
+        self.insert(system);
+    }
+
+    /// Inserts `system` after the last system of its stage,
+    /// keeping the systems sorted by stage.
+    fn insert(&mut self, system: System<Input>) {
+        let index = self
+            .systems
+            .iter()
+            .position(|other| other.stage > system.stage)
+            .unwrap_or_else(|| self.systems.len());
+        self.systems.insert(index, system);
     }
 
     /// Begins a group with the provided group state type.
@@ -122,6 +204,26 @@ impl<Input> SystemExecutor<Input> {
         }
     }
 
+    /// Enables or disables timing each system when running.
+    ///
+    /// Disabling profiling discards the last recorded profile.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.profiling = enabled;
+        if !enabled {
+            self.last_profile.clear();
+        }
+    }
+
+    pub fn is_profiling(&self) -> bool {
+        self.profiling
+    }
+
+    /// Gets the time taken by each system during the last run,
+    /// in execution order. Empty unless profiling is enabled.
+    pub fn profile(&self) -> &[(SystemName, Duration)] {
+        &self.last_profile
+    }
+
     /// Runs all systems in order.
     ///
     /// Errors are logged using the `log` crate.
@@ -129,6 +231,7 @@ impl<Input> SystemExecutor<Input> {
     where
         Input: HasEcs,
     {
+        self.last_profile.clear();
         for (i, system) in self.systems.iter_mut().enumerate() {
             input.ecs_mut().set_current_system_index(i);
 
@@ -141,7 +244,12 @@ impl<Input> SystemExecutor<Input> {
                 input.ecs_mut().remove_old_events();
             }
 
+            let start = Instant::now();
             let result = (system.function)(input);
+            if self.profiling {
+                self.last_profile
+                    .push((system.name.clone(), start.elapsed()));
+            }
             if let Err(e) = result {
                 log::error!(
                     "System {} returned an error; this is a bug: {:?}",
@@ -154,10 +262,18 @@ impl<Input> SystemExecutor<Input> {
         self.is_first_run = false;
     }
 
-    /// Gets an iterator over system names.
+    /// Gets an iterator over system names, in execution order.
     pub fn system_names(&self) -> impl Iterator<Item = &'_ str> + '_ {
         self.systems.iter().map(|system| system.name.as_str())
     }
+
+    /// Gets an iterator over system names and their stages,
+    /// in execution order.
+    pub fn system_stages(&self) -> impl Iterator<Item = (&'_ str, SystemStage)> + '_ {
+        self.systems
+            .iter()
+            .map(|system| (system.name.as_str(), system.stage))
+    }
 }
 
 /// Builder for a group. Created with [`SystemExecutor::group`].
@@ -171,14 +287,23 @@ where
     Input: HasResources + 'static,
     State: 'static,
 {
-    /// Adds a system to the group.
+    /// Adds a system to the group, in the default stage.
     pub fn add_system<F: FnMut(&mut Input, &mut State) -> SysResult + 'static>(
         &mut self,
         system: F,
+    ) -> &mut Self {
+        self.add_system_to_stage(SystemStage::default(), system)
+    }
+
+    /// Adds a system to the group in the given stage.
+    pub fn add_system_to_stage<F: FnMut(&mut Input, &mut State) -> SysResult + 'static>(
+        &mut self,
+        stage: SystemStage,
+        system: F,
     ) -> &mut Self {
         let function = Self::make_function(system);
         self.systems
-            .add_system_with_name(function, type_name::<F>());
+            .add_system_with_name_to_stage(function, type_name::<F>(), stage);
         self
     }
 
@@ -1,6 +1,6 @@
 #![allow(clippy::unnecessary_wraps)]
 
-use feather_ecs::{Ecs, HasEcs, SysResult, SystemExecutor};
+use feather_ecs::{Ecs, HasEcs, SysResult, SystemExecutor, SystemStage};
 
 struct Input {
     x: i32,
@@ -40,3 +40,40 @@ fn systems_are_executed_in_order() {
     executor.run(&mut input);
     assert_eq!(input.x, 110);
 }
+
+#[test]
+fn systems_are_executed_in_stage_order() {
+    let mut executor = SystemExecutor::new();
+    executor
+        .add_system_to_stage(SystemStage::Cleanup, |input: &mut Input| {
+            input.x -= 1;
+            Ok(())
+        })
+        .add_system(system2)
+        .add_system_to_stage(SystemStage::Input, system1)
+        .add_system_to_stage(SystemStage::Combat, |input: &mut Input| {
+            input.x += 2;
+            Ok(())
+        })
+        .add_system_to_stage(SystemStage::Input, system2);
+
+    let stages: Vec<SystemStage> = executor.system_stages().map(|(_, stage)| stage).collect();
+    assert_eq!(
+        stages,
+        vec![
+            SystemStage::Input,
+            SystemStage::Input,
+            SystemStage::Combat,
+            SystemStage::Update,
+            SystemStage::Cleanup,
+        ]
+    );
+
+    let mut input = Input {
+        x: 1,
+        ecs: Ecs::new(),
+    };
+    executor.run(&mut input);
+    // ((1 + 10) * 10 + 2) * 10 - 1
+    assert_eq!(input.x, 1119);
+}
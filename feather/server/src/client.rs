@@ -11,7 +11,7 @@ use slab::Slab;
 use uuid::Uuid;
 
 use base::{
-    BlockId, ChunkHandle, ChunkPosition, EntityKind, EntityMetadata, Gamemode, Position,
+    BlockId, BlockKind, ChunkHandle, ChunkPosition, EntityKind, EntityMetadata, Gamemode, Position,
     ProfileProperty, Text, ValidBlockPosition,
 };
 use common::{
@@ -19,7 +19,9 @@ use common::{
     Window,
 };
 use libcraft_items::InventorySlot;
-use packets::server::{Particle, SetSlot, SpawnLivingEntity, UpdateLight, WindowConfirmation};
+use packets::server::{
+    Particle, SetSlot, SpawnEntity, SpawnLivingEntity, UpdateLight, WindowConfirmation,
+};
 use protocol::packets::server::{
     ChangeGameState, EntityPosition, EntityPositionAndRotation, EntityTeleport, GameStateChange,
     HeldItemChange, PlayerAbilities,
@@ -28,10 +30,10 @@ use protocol::{
     packets::{
         self,
         server::{
-            AddPlayer, Animation, BlockChange, ChatPosition, ChunkData, ChunkDataKind,
+            AddPlayer, Animation, BlockAction, BlockChange, ChatPosition, ChunkData, ChunkDataKind,
             DestroyEntities, Disconnect, EntityAnimation, EntityHeadLook, JoinGame, KeepAlive,
-            PlayerInfo, PlayerPositionAndLook, PluginMessage, SendEntityMetadata, SpawnPlayer,
-            Title, UnloadChunk, UpdateViewPosition, WindowItems,
+            NamedSoundEffect, PlayerInfo, PlayerPositionAndLook, PluginMessage, SendEntityMetadata,
+            SpawnPlayer, Title, UnloadChunk, UpdateHealth, UpdateViewPosition, WindowItems,
         },
     },
     ClientPlayPacket, Nbt, ProtocolVersion, ServerPlayPacket, Writeable,
@@ -299,6 +301,23 @@ impl Client {
         });
     }
 
+    /// Sends a block action, such as a note block playing or
+    /// a chest opening, for the block of kind `block` at `position`.
+    pub fn send_block_action(
+        &self,
+        position: ValidBlockPosition,
+        block: BlockKind,
+        action_id: u8,
+        action_param: u8,
+    ) {
+        self.send_packet(BlockAction {
+            position,
+            action_id,
+            action_param,
+            block_type: block.id() as i32,
+        });
+    }
+
     pub fn unload_chunk(&self, pos: ChunkPosition) {
         log::trace!("Unloading chunk at {:?} on {}", pos, self.username);
         self.send_packet(UnloadChunk {
@@ -388,6 +407,30 @@ impl Client {
         });
     }
 
+    /// Spawns a falling block showing `block`.
+    pub fn send_falling_block(
+        &self,
+        network_id: NetworkId,
+        uuid: Uuid,
+        pos: Position,
+        block: BlockId,
+    ) {
+        self.send_packet(SpawnEntity {
+            entity_id: network_id.0,
+            uuid,
+            kind: EntityKind::FallingBlock.id() as i32,
+            x: pos.x,
+            y: pos.y,
+            z: pos.z,
+            pitch: pos.pitch,
+            yaw: pos.yaw,
+            data: i32::from(block.vanilla_id()),
+            velocity_x: 0,
+            velocity_y: 0,
+            velocity_z: 0,
+        });
+    }
+
     pub fn update_entity_position(
         &self,
         network_id: NetworkId,
@@ -562,6 +605,20 @@ impl Client {
         })
     }
 
+    /// Plays the sound named `sound` at `position`.
+    pub fn send_sound(&self, sound: &str, position: Position, volume: f32, pitch: f32) {
+        self.send_packet(NamedSoundEffect {
+            name: sound.to_owned(),
+            category: 0, // master
+            // Fixed-point with 3 fraction bits
+            position_x: (position.x * 8.0) as i32,
+            position_y: (position.y * 8.0) as i32,
+            position_z: (position.z * 8.0) as i32,
+            volume,
+            pitch,
+        })
+    }
+
     pub fn set_cursor_slot(&self, item: &InventorySlot) {
         log::trace!("Setting cursor slot of {} to {:?}", self.username, item);
         self.set_slot(-1, item);
@@ -607,6 +664,14 @@ impl Client {
         });
     }
 
+    pub fn send_health(&self, health: f32, food: u8, food_saturation: f32) {
+        self.send_packet(UpdateHealth {
+            health,
+            food: i32::from(food),
+            food_saturation,
+        });
+    }
+
     pub fn set_hotbar_slot(&self, slot: u8) {
         self.send_packet(HeldItemChange { slot });
     }
@@ -1,6 +1,6 @@
 //! Loads an `Options` from a TOML config.
 
-use std::{fs, net::IpAddr, path::Path, str::FromStr};
+use std::{collections::HashMap, fs, net::IpAddr, path::Path, str::FromStr};
 
 use anyhow::Context;
 use base::Gamemode;
@@ -44,6 +44,8 @@ pub struct Config {
     pub log: Log,
     pub world: World,
     pub proxy: Proxy,
+    #[serde(default)]
+    pub random_ticks: RandomTicks,
 }
 
 impl Config {
@@ -103,6 +105,13 @@ pub struct World {
     pub name: String,
     pub generator: String,
     pub seed: String,
+    /// Seconds between saves of changed chunks; zero disables autosaving.
+    #[serde(default = "default_autosave_interval")]
+    pub autosave_interval: u64,
+}
+
+fn default_autosave_interval() -> u64 {
+    300
 }
 
 #[derive(Debug, Deserialize)]
@@ -111,6 +120,25 @@ pub struct Proxy {
     pub velocity_secret: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RandomTicks {
+    /// Number of blocks randomly ticked per chunk section each tick.
+    pub speed: u32,
+    /// Per-block random tick chances, keyed by block name,
+    /// overriding the built-in defaults.
+    #[serde(default)]
+    pub probabilities: HashMap<String, f32>,
+}
+
+impl Default for RandomTicks {
+    fn default() -> Self {
+        Self {
+            speed: 3,
+            probabilities: HashMap::new(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum ProxyMode {
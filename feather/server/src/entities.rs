@@ -1,4 +1,4 @@
-use base::{EntityKind, Position};
+use base::{BlockId, EntityKind, Position};
 use ecs::{EntityBuilder, EntityRef, SysResult};
 use quill_common::{components::OnGround, entity_init::EntityInit};
 use uuid::Uuid;
@@ -25,6 +25,11 @@ pub struct PreviousPosition(pub Position);
 /// what movement packet to send.
 #[derive(Copy, Clone, Debug)]
 pub struct PreviousOnGround(pub OnGround);
+/// Stores the health, food and saturation last sent
+/// to a player. Used to determine when to send
+/// health updates.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PreviousHealth(pub Option<(f32, u8, f32)>);
 
 pub fn add_entity_components(builder: &mut EntityBuilder, init: &EntityInit) {
     if !builder.has::<NetworkId>() {
@@ -48,6 +53,7 @@ fn add_spawn_packet(builder: &mut EntityBuilder, init: &EntityInit) {
     // (minecarts, items, ...)
     let spawn_packet = match init {
         EntityInit::Player => spawn_player,
+        EntityInit::FallingBlock => spawn_falling_block,
         _ => spawn_living_entity,
     };
     builder.add(SpawnPacketSender(spawn_packet));
@@ -62,6 +68,16 @@ fn spawn_player(entity: &EntityRef, client: &Client) -> SysResult {
     Ok(())
 }
 
+fn spawn_falling_block(entity: &EntityRef, client: &Client) -> SysResult {
+    let network_id = *entity.get::<NetworkId>()?;
+    let uuid = *entity.get::<Uuid>()?;
+    let pos = *entity.get::<Position>()?;
+    let block = *entity.get::<BlockId>()?;
+
+    client.send_falling_block(network_id, uuid, pos, block);
+    Ok(())
+}
+
 fn spawn_living_entity(entity: &EntityRef, client: &Client) -> SysResult {
     let network_id = *entity.get::<NetworkId>()?;
     let uuid = *entity.get::<Uuid>()?;
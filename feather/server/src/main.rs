@@ -1,17 +1,33 @@
-use std::{cell::RefCell, rc::Rc, sync::Arc};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 use anyhow::Context;
-use base::anvil::level::SuperflatGeneratorOptions;
-use common::{Game, TickLoop, World};
+use base::{anvil::level::SuperflatGeneratorOptions, Biome, TPS};
+use common::{
+    autosave::{self, AutosaveInterval},
+    biomes::biome_for_climate,
+    Game, GameRules, TickLoop, World,
+};
 use ecs::SystemExecutor;
 use feather_server::{config::Config, Server};
 use plugin_host::PluginManager;
-use worldgen::{ComposableGenerator, SuperflatWorldGenerator, VoidWorldGenerator, WorldGenerator};
+use worldgen::{
+    village_well, ClimateBiomes, ComposableGenerator, HookedGenerator, StructurePlacement,
+    SuperflatWorldGenerator, VoidWorldGenerator, WorldGenerator,
+};
 
 mod logging;
 
 const PLUGINS_DIRECTORY: &str = "plugins";
 const CONFIG_PATH: &str = "config.toml";
+/// One in this many chunks gets a village well
+const VILLAGE_RARITY: u32 = 64;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -40,6 +56,10 @@ fn init_game(server: Server, config: &Config) -> anyhow::Result<Game> {
     let mut game = Game::new();
     init_systems(&mut game, server);
     init_world_source(&mut game, config);
+    init_random_ticks(&mut game, config)?;
+    game.insert_resource(AutosaveInterval(
+        config.world.autosave_interval * u64::from(TPS),
+    ));
     init_plugin_manager(&mut game)?;
     Ok(game)
 }
@@ -49,7 +69,9 @@ fn init_systems(game: &mut Game, server: Server) {
 
     // Register common before server code, so
     // that packet broadcasting happens after
-    // gameplay actions.
+    // gameplay actions. Systems that need to run
+    // earlier in the tick, like packet handling,
+    // are put in an earlier stage.
     common::register(game, &mut systems);
     server.link_with_game(game, &mut systems);
 
@@ -67,15 +89,51 @@ fn init_world_source(game: &mut Game, config: &Config) {
     let seed = 42; // FIXME: load from the level file
 
     let generator: Arc<dyn WorldGenerator> = match &config.world.generator[..] {
-        "flat" => Arc::new(SuperflatWorldGenerator::new(
-            SuperflatGeneratorOptions::default(),
-        )),
+        "flat" => {
+            let options = SuperflatGeneratorOptions::default();
+            let has_villages = options.structures.contains_key("village");
+            let mut generator =
+                HookedGenerator::new(Arc::new(SuperflatWorldGenerator::new(options)), seed);
+            if has_villages {
+                generator = generator
+                    .with_hook(StructurePlacement::new(village_well()).with_rarity(VILLAGE_RARITY));
+            }
+            Arc::new(generator)
+        }
         "void" => Arc::new(VoidWorldGenerator),
-        _ => Arc::new(ComposableGenerator::default_with_seed(seed)),
+        _ => Arc::new(
+            HookedGenerator::new(Arc::new(ComposableGenerator::default_with_seed(seed)), seed)
+                // Biomes come first, since structures are placed by biome
+                .with_hook(ClimateBiomes::with_selector(seed, biome_for_climate))
+                .with_hook(
+                    StructurePlacement::new(village_well())
+                        .in_biomes(&[Biome::Plains, Biome::Desert, Biome::Savanna])
+                        .with_rarity(VILLAGE_RARITY),
+                ),
+        ),
     };
     game.world = World::with_gen_and_path(generator, config.world.name.clone());
 }
 
+fn init_random_ticks(game: &mut Game, config: &Config) -> anyhow::Result<()> {
+    game.resources
+        .get_mut::<GameRules>()
+        .context("game rules are not registered")?
+        .random_tick_speed = config.random_ticks.speed;
+    game.block_integration
+        .tick_executor_mut()
+        .probabilities_mut()
+        .apply_overrides(
+            config
+                .random_ticks
+                .probabilities
+                .iter()
+                .map(|(name, probability)| (name.as_str(), *probability)),
+        )
+        .context("invalid random tick probabilities in the config")?;
+    Ok(())
+}
+
 fn init_plugin_manager(game: &mut Game) -> anyhow::Result<()> {
     let mut plugin_manager = PluginManager::new();
     plugin_manager.load_dir(game, PLUGINS_DIRECTORY)?;
@@ -97,9 +155,22 @@ fn run(game: Game) {
 }
 
 fn create_tick_loop(mut game: Game) -> TickLoop {
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let shutdown_requested = Arc::clone(&shutdown);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            log::info!("Shutting down");
+            shutdown_requested.store(true, Ordering::Relaxed);
+        }
+    });
+
     TickLoop::new(move || {
-        let systems = Rc::clone(&game.system_executor);
-        systems.borrow_mut().run(&mut game);
+        if shutdown.load(Ordering::Relaxed) {
+            autosave::save_on_shutdown(&mut game);
+            return true;
+        }
+
+        game.run_systems();
         game.tick_count += 1;
 
         false
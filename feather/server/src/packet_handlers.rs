@@ -3,7 +3,7 @@ use common::{chat::ChatKind, Game};
 use ecs::{Entity, EntityRef, SysResult};
 use interaction::{
     handle_held_item_change, handle_interact_entity, handle_player_block_placement,
-    handle_player_digging,
+    handle_player_digging, handle_use_item,
 };
 use protocol::{
     packets::{
@@ -20,6 +20,7 @@ mod entity_action;
 mod interaction;
 pub mod inventory;
 mod movement;
+mod vehicle;
 
 /// Handles a packet received from a client.
 pub fn handle_packet(
@@ -62,6 +63,8 @@ pub fn handle_packet(
             handle_player_block_placement(game, server, packet, player_id)
         }
 
+        ClientPlayPacket::UseItem(packet) => handle_use_item(game, packet, player_id),
+
         ClientPlayPacket::HeldItemChange(packet) => handle_held_item_change(player, packet),
         ClientPlayPacket::InteractEntity(packet) => {
             handle_interact_entity(game, server, packet, player_id)
@@ -77,6 +80,15 @@ pub fn handle_packet(
             entity_action::handle_entity_action(game, player_id, packet)
         }
 
+        ClientPlayPacket::SteerBoat(packet) => vehicle::handle_steer_boat(game, player_id, packet),
+        ClientPlayPacket::SteerVehicle(packet) => {
+            vehicle::handle_steer_vehicle(game, player_id, packet)
+        }
+
+        ClientPlayPacket::ClientStatus(client::ClientStatus::PerformRespawn) => {
+            common::respawn::respawn(game, player_id).map(|_| ())
+        }
+
         ClientPlayPacket::TeleportConfirm(_)
         | ClientPlayPacket::QueryBlockNbt(_)
         | ClientPlayPacket::SetDifficulty(_)
@@ -92,10 +104,8 @@ pub fn handle_packet(
         | ClientPlayPacket::KeepAlive(_)
         | ClientPlayPacket::LockDifficulty(_)
         | ClientPlayPacket::VehicleMove(_)
-        | ClientPlayPacket::SteerBoat(_)
         | ClientPlayPacket::PickItem(_)
         | ClientPlayPacket::CraftRecipeRequest(_)
-        | ClientPlayPacket::SteerVehicle(_)
         | ClientPlayPacket::SetDisplayedRecipe(_)
         | ClientPlayPacket::SetRecipeBookState(_)
         | ClientPlayPacket::NameItem(_)
@@ -108,8 +118,7 @@ pub fn handle_packet(
         | ClientPlayPacket::UpdateJigsawBlock(_)
         | ClientPlayPacket::UpdateStructureBlock(_)
         | ClientPlayPacket::UpdateSign(_)
-        | ClientPlayPacket::Spectate(_)
-        | ClientPlayPacket::UseItem(_) => Ok(()),
+        | ClientPlayPacket::Spectate(_) => Ok(()),
     }
 }
 
@@ -1,14 +1,18 @@
 use crate::{ClientId, NetworkId, Server};
 use base::inventory::{SLOT_HOTBAR_OFFSET, SLOT_OFFHAND};
+use base::{vec3, Area, Gamemode, Inventory, Item, ItemStack, Position, ValidBlockPosition};
 use common::entities::player::HotbarSlot;
+use common::entities::{experience_orb, item};
+use common::events::VibrationEvent;
 use common::interactable::InteractableRegistry;
-use common::{Game, Window};
+use common::{combat, eating, loot, mining, Game, Window};
 use ecs::{Entity, EntityRef, SysResult};
 use libcraft_core::{BlockFace as LibcraftBlockFace, Hand};
 use libcraft_core::{InteractionType, Vec3f};
+use libcraft_items::EnchantmentKind;
 use protocol::packets::client::{
     BlockFace, HeldItemChange, InteractEntity, InteractEntityKind, PlayerBlockPlacement,
-    PlayerDigging, PlayerDiggingStatus,
+    PlayerDigging, PlayerDiggingStatus, UseItem,
 };
 use quill_common::{
     events::{BlockInteractEvent, BlockPlacementEvent, InteractEntityEvent},
@@ -104,6 +108,92 @@ pub fn handle_player_block_placement(
     Ok(())
 }
 
+/// The item `player` holds in their main hand.
+fn held_tool(game: &Game, player: Entity) -> SysResult<Option<ItemStack>> {
+    let window = game.ecs.get::<Window>(player)?;
+    let hotbar_slot = game.ecs.get::<HotbarSlot>(player)?.get();
+    let tool = window.item(SLOT_HOTBAR_OFFSET + hotbar_slot)?.clone();
+    Ok(tool.into_option())
+}
+
+/// Wears down the tool `player` is holding by one use.
+fn wear_held_tool(game: &Game, player: Entity, rng: &mut impl rand::Rng) -> SysResult {
+    let window = game.ecs.get::<Window>(player)?;
+    let hotbar_slot = game.ecs.get::<HotbarSlot>(player)?.get();
+    let mut tool = window.item(SLOT_HOTBAR_OFFSET + hotbar_slot)?;
+    mining::damage_tool(&mut tool, rng);
+    Ok(())
+}
+
+/// Takes an arrow out of `player`'s inventory, looking in
+/// the off hand first. Returns whether there was one.
+fn take_arrow(game: &Game, player: Entity) -> SysResult<bool> {
+    let inventory = game.ecs.get::<Inventory>(player)?;
+    for area in [Area::Offhand, Area::Hotbar, Area::Storage].iter() {
+        let mut slot = 0;
+        while let Some(mut item) = inventory.item(*area, slot) {
+            if item.item_kind() == Some(Item::Arrow) {
+                let _ = item.try_take(1);
+                return Ok(true);
+            }
+            slot += 1;
+        }
+    }
+    Ok(false)
+}
+
+/// Shoots an arrow from the bow `player` let go of, using up
+/// an arrow unless they are in creative mode or the bow has infinity.
+fn shoot_bow(game: &mut Game, player: Entity) -> SysResult {
+    let bow = match held_tool(game, player)? {
+        Some(bow) if bow.item() == Item::Bow => bow,
+        _ => return Ok(()),
+    };
+    let free = *game.ecs.get::<Gamemode>(player)? == Gamemode::Creative
+        || bow.enchantments().has(EnchantmentKind::Infinity);
+    if free || take_arrow(game, player)? {
+        combat::shoot_arrow(game, player, Some(&bow))?;
+    }
+    Ok(())
+}
+
+/// Breaks the block `player` dug up. Outside of creative mode, the block
+/// drops its loot and, unless mined with silk touch, its experience.
+fn harvest_block(game: &mut Game, player: Entity, position: ValidBlockPosition) -> SysResult {
+    let broken = game.block(position);
+    if !game.break_block(position) {
+        return Ok(());
+    }
+    let center = Position::from(position) + vec3(0.5, 0.5, 0.5);
+    game.ecs.insert_event(VibrationEvent { position: center });
+    let block = match broken {
+        Some(block) => block,
+        None => return Ok(()),
+    };
+    if *game.ecs.get::<Gamemode>(player)? == Gamemode::Creative {
+        return Ok(());
+    }
+
+    let tool = held_tool(game, player)?;
+    game.with_rng(|game, rng| {
+        if block.kind().hardness() > 0.0 {
+            wear_held_tool(game, player, rng)?;
+        }
+        for drop in loot::block_drops(block.kind(), tool.as_ref(), rng) {
+            item::spawn_item(game, center, drop);
+        }
+
+        let silk_touch = tool.map_or(false, |tool| {
+            tool.enchantments().has(EnchantmentKind::SilkTouch)
+        });
+        if !silk_touch {
+            let amount = experience_orb::block_experience(block.kind(), rng);
+            experience_orb::spawn_experience(game, center, amount);
+        }
+        Ok(())
+    })
+}
+
 /// Handles the Player Digging packet sent for the following
 /// actions:
 /// * Breaking blocks.
@@ -119,10 +209,27 @@ pub fn handle_player_digging(
 ) -> SysResult {
     log::trace!("Got player digging with status {:?}", packet.status);
     match packet.status {
-        PlayerDiggingStatus::StartDigging | PlayerDiggingStatus::CancelDigging => {
-            game.break_block(packet.position);
+        PlayerDiggingStatus::StartDigging => {
+            // Creative players and blocks which break instantly
+            // don't wait for the client to finish digging
+            let instant = match game.block(packet.position) {
+                Some(block) => {
+                    let tool = held_tool(game, player)?;
+                    mining::break_ticks(block.kind(), tool.as_ref()) == Some(0)
+                }
+                None => false,
+            };
+            if instant || *game.ecs.get::<Gamemode>(player)? == Gamemode::Creative {
+                harvest_block(game, player, packet.position)?;
+            }
             Ok(())
         }
+        PlayerDiggingStatus::FinishDigging => harvest_block(game, player, packet.position),
+        PlayerDiggingStatus::ShootArrow => {
+            // Letting go of the use button also stops eating
+            eating::stop_eating(game, player);
+            shoot_bow(game, player)
+        }
         PlayerDiggingStatus::SwapItemInHand => {
             let window = game.ecs.get::<Window>(player)?;
 
@@ -149,6 +256,18 @@ pub fn handle_player_digging(
     }
 }
 
+/// Handles the Use Item packet, sent when a player starts using
+/// the item in one of their hands, e.g. to eat it.
+pub fn handle_use_item(game: &mut Game, packet: UseItem, player: Entity) -> SysResult {
+    let hand = match packet.hand {
+        0 => Hand::Main,
+        1 => Hand::Offhand,
+        _ => anyhow::bail!("Player sent a malformed `UseItem` packet. {:?}", packet),
+    };
+    eating::start_eating(game, player, hand)?;
+    Ok(())
+}
+
 pub fn handle_interact_entity(
     game: &mut Game,
     _server: &mut Server,
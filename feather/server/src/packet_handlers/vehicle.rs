@@ -0,0 +1,33 @@
+use common::{
+    entities::{boat::BoatControls, riding::Riding},
+    Game,
+};
+use ecs::{Entity, SysResult};
+use protocol::packets::client::{SteerBoat, SteerVehicle};
+
+/// Returns the boat `player` is driving, if any.
+fn driven_boat(game: &Game, player: Entity) -> Option<Entity> {
+    let vehicle = game.ecs.get::<Riding>(player).ok()?.vehicle;
+    game.ecs.get::<BoatControls>(vehicle).ok()?;
+    Some(vehicle)
+}
+
+/// Handles the paddles of the boat a player is driving.
+pub fn handle_steer_boat(game: &mut Game, player: Entity, packet: SteerBoat) -> SysResult {
+    if let Some(boat) = driven_boat(game, player) {
+        let mut controls = game.ecs.get_mut::<BoatControls>(boat)?;
+        controls.left = packet.left_paddle_turning;
+        controls.right = packet.right_paddle_turning;
+    }
+    Ok(())
+}
+
+/// Handles the forward and backward input of a player driving a boat.
+pub fn handle_steer_vehicle(game: &mut Game, player: Entity, packet: SteerVehicle) -> SysResult {
+    if let Some(boat) = driven_boat(game, player) {
+        let mut controls = game.ecs.get_mut::<BoatControls>(boat)?;
+        controls.forward = packet.forward > 0.0;
+        controls.back = packet.forward < 0.0;
+    }
+    Ok(())
+}
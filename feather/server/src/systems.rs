@@ -4,6 +4,8 @@ mod block;
 mod chat;
 mod entity;
 mod gamemode;
+mod health;
+mod note_block;
 mod particle;
 mod player_join;
 mod player_leave;
@@ -14,7 +16,7 @@ pub mod view;
 use std::time::{Duration, Instant};
 
 use common::Game;
-use ecs::{SysResult, SystemExecutor};
+use ecs::{SysResult, SystemExecutor, SystemStage};
 use quill_common::components::Name;
 
 use crate::{client::ClientId, Server};
@@ -26,7 +28,7 @@ pub fn register(server: Server, game: &mut Game, systems: &mut SystemExecutor<Ga
     player_join::register(systems);
     systems
         .group::<Server>()
-        .add_system(handle_packets)
+        .add_system_to_stage(SystemStage::Input, handle_packets)
         .add_system(send_keepalives);
     view::register(game, systems);
     crate::chunk_subscriptions::register(systems);
@@ -38,6 +40,8 @@ pub fn register(server: Server, game: &mut Game, systems: &mut SystemExecutor<Ga
     particle::register(systems);
     plugin_message::register(systems);
     gamemode::register(systems);
+    health::register(systems);
+    note_block::register(systems);
 
     systems.group::<Server>().add_system(tick_clients);
 }
@@ -5,7 +5,7 @@ use base::{
     metadata::{EntityBitMask, Pose, META_INDEX_ENTITY_BITMASK, META_INDEX_POSE},
     EntityMetadata, Position,
 };
-use common::Game;
+use common::{events::EntityMetadataChangeEvent, Game};
 use ecs::{SysResult, SystemExecutor};
 use quill_common::{
     components::{OnGround, Sprinting},
@@ -25,7 +25,8 @@ pub fn register(game: &mut Game, systems: &mut SystemExecutor<Game>) {
         .group::<Server>()
         .add_system(send_entity_movement)
         .add_system(send_entity_sneak_metadata)
-        .add_system(send_entity_sprint_metadata);
+        .add_system(send_entity_sprint_metadata)
+        .add_system(send_entity_metadata_changes);
 }
 
 /// Sends entity movement packets.
@@ -107,3 +108,17 @@ fn send_entity_sprint_metadata(game: &mut Game, server: &mut Server) -> SysResul
     }
     Ok(())
 }
+
+/// Sends [SendEntityMetadata](protocol::packets::server::play::SendEntityMetadata) packet for when an entity's displayed state changes.
+fn send_entity_metadata_changes(game: &mut Game, server: &mut Server) -> SysResult {
+    for (_, (&position, event, &network_id)) in game
+        .ecs
+        .query::<(&Position, &EntityMetadataChangeEvent, &NetworkId)>()
+        .iter()
+    {
+        server.broadcast_nearby_with(position, |client| {
+            client.send_entity_metadata(network_id, event.metadata.clone());
+        });
+    }
+    Ok(())
+}
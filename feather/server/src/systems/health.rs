@@ -0,0 +1,32 @@
+//! Sends players their health and hunger.
+
+use common::{hunger::Hunger, Game};
+use ecs::{SysResult, SystemExecutor};
+use quill_common::components::Health;
+
+use crate::{entities::PreviousHealth, ClientId, Server};
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.group::<Server>().add_system(send_health_updates);
+}
+
+/// Sends an Update Health packet to players whose health,
+/// food or saturation changed since the last one.
+fn send_health_updates(game: &mut Game, server: &mut Server) -> SysResult {
+    for (_, (&client_id, health, hunger, previous)) in game
+        .ecs
+        .query::<(&ClientId, &Health, &Hunger, &mut PreviousHealth)>()
+        .iter()
+    {
+        let current = (health.current.max(0.0), hunger.food, hunger.saturation);
+        if previous.0 == Some(current) {
+            continue;
+        }
+        previous.0 = Some(current);
+
+        if let Some(client) = server.clients.get(client_id) {
+            client.send_health(current.0, current.1, current.2);
+        }
+    }
+    Ok(())
+}
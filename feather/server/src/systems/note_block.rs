@@ -0,0 +1,64 @@
+//! Plays note blocks for nearby players.
+
+use base::{position, BlockKind};
+use common::{events::NotePlayedEvent, Game};
+use ecs::{SysResult, SystemExecutor};
+use libcraft_core::block::Instrument;
+
+use crate::Server;
+
+/// Volume note blocks are played at, audible from 48 blocks away
+const VOLUME: f32 = 3.0;
+
+pub fn register(systems: &mut SystemExecutor<Game>) {
+    systems.group::<Server>().add_system(send_played_notes);
+}
+
+/// The action ID the client uses for a note block instrument.
+fn action_id(instrument: Instrument) -> u8 {
+    match instrument {
+        Instrument::Harp => 0,
+        Instrument::Basedrum => 1,
+        Instrument::Snare => 2,
+        Instrument::Hat => 3,
+        Instrument::Bass => 4,
+        Instrument::Flute => 5,
+        Instrument::Bell => 6,
+        Instrument::Guitar => 7,
+        Instrument::Chime => 8,
+        Instrument::Xylophone => 9,
+        Instrument::IronXylophone => 10,
+        Instrument::CowBell => 11,
+        Instrument::Didgeridoo => 12,
+        Instrument::Bit => 13,
+        Instrument::Banjo => 14,
+        Instrument::Pling => 15,
+    }
+}
+
+/// The pitch a note is played at, from 0.5 to 2 over two octaves.
+fn pitch(note: u8) -> f32 {
+    2f32.powf((f32::from(note) - 12.0) / 12.0)
+}
+
+/// Sends the note block animation and sound for each note played.
+fn send_played_notes(game: &mut Game, server: &mut Server) -> SysResult {
+    for (_, event) in game.ecs.query::<&NotePlayedEvent>().iter() {
+        let position = position!(
+            f64::from(event.position.x()) + 0.5,
+            f64::from(event.position.y()) + 0.5,
+            f64::from(event.position.z()) + 0.5,
+        );
+        let sound = format!("minecraft:block.note_block.{}", event.instrument.name());
+        server.broadcast_nearby_with(position, |client| {
+            client.send_block_action(
+                event.position,
+                BlockKind::NoteBlock,
+                action_id(event.instrument),
+                event.note,
+            );
+            client.send_sound(&sound, position, VOLUME, pitch(event.note));
+        });
+    }
+    Ok(())
+}
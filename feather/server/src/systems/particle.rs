@@ -4,7 +4,10 @@ use common::Game;
 use ecs::{SysResult, SystemExecutor};
 
 pub fn register(systems: &mut SystemExecutor<Game>) {
-    systems.group::<Server>().add_system(send_particle_packets);
+    systems
+        .group::<Server>()
+        .add_system(send_particle_packets)
+        .add_system(send_emitted_effects);
 }
 
 fn send_particle_packets(game: &mut Game, server: &mut Server) -> SysResult {
@@ -24,3 +27,32 @@ fn send_particle_packets(game: &mut Game, server: &mut Server) -> SysResult {
 
     Ok(())
 }
+
+/// Sends the particles and sounds emitted through `Game` to nearby players.
+fn send_emitted_effects(game: &mut Game, server: &mut Server) -> SysResult {
+    for emission in game.outbound_effects.drain_particles() {
+        let particle = Particle {
+            kind: emission.kind,
+            offset_x: emission.spread,
+            offset_y: emission.spread,
+            offset_z: emission.spread,
+            count: emission.count,
+        };
+        server.broadcast_nearby_with(emission.position, |client| {
+            client.send_particle(&particle, &emission.position);
+        });
+    }
+
+    for emission in game.outbound_effects.drain_sounds() {
+        server.broadcast_nearby_with(emission.position, |client| {
+            client.send_sound(
+                &emission.sound,
+                emission.position,
+                emission.volume,
+                emission.pitch,
+            );
+        });
+    }
+
+    Ok(())
+}
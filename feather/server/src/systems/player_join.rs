@@ -6,6 +6,7 @@ use base::{Gamemode, Inventory, ItemStack, Position, Text};
 use common::{
     chat::{ChatKind, ChatPreference},
     entities::player::HotbarSlot,
+    hunger::MAX_HEALTH,
     view::View,
     window::BackingWindow,
     ChatBox, Game, Window,
@@ -18,7 +19,7 @@ use quill_common::components::{
 use quill_common::events::GamemodeEvent;
 use quill_common::{components::Name, entity_init::EntityInit};
 
-use crate::{ClientId, NetworkId, Server};
+use crate::{entities::PreviousHealth, ClientId, NetworkId, Server};
 
 pub fn register(systems: &mut SystemExecutor<Game>) {
     systems.group::<Server>().add_system(poll_new_players);
@@ -118,12 +119,14 @@ fn accept_new_player(game: &mut Game, server: &mut Server, client_id: ClientId)
         .add(inventory)
         .add(window)
         .add(hotbar_slot)
-        .add(Health(
-            player_data
+        .add(Health {
+            current: player_data
                 .as_ref()
                 .map(|data| data.animal.health)
-                .unwrap_or(20.0),
-        ))
+                .unwrap_or(MAX_HEALTH),
+            ..Health::new(MAX_HEALTH)
+        })
+        .add(PreviousHealth::default())
         .add(abilities.walk_speed)
         .add(abilities.fly_speed)
         .add(abilities.is_flying)
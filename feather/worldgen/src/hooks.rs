@@ -0,0 +1,259 @@
+//! Hooks run on every newly generated chunk, after the
+//! world generator has produced its terrain.
+//!
+//! Hooks let other parts of the server decorate chunks
+//! without writing a whole generator: [`ClimateBiomes`]
+//! assigns biomes from climate noise, and
+//! [`StructurePlacement`](crate::StructurePlacement)s place
+//! structure templates where their conditions hold.
+
+use std::sync::Arc;
+
+use base::{Biome, Chunk, ChunkPosition};
+
+use crate::util::shuffle_seed_for_chunk;
+use crate::WorldGenerator;
+
+/// Blocks across the temperature and humidity noise features
+const CLIMATE_SCALE: f64 = 512.0;
+/// Blocks across the continentalness noise features
+const CONTINENTALNESS_SCALE: f64 = 1024.0;
+
+/// A step run on each chunk after it has been generated.
+pub trait GenerationHook: Send + Sync {
+    /// Decorates the newly generated chunk.
+    /// This function should be deterministic.
+    fn on_chunk_generated(&self, chunk: &mut Chunk, seed: u64);
+}
+
+/// A world generator wrapping another generator and running
+/// its hooks, in order, on each chunk it generates.
+pub struct HookedGenerator {
+    generator: Arc<dyn WorldGenerator>,
+    hooks: Vec<Box<dyn GenerationHook>>,
+    seed: u64,
+}
+
+impl HookedGenerator {
+    pub fn new(generator: Arc<dyn WorldGenerator>, seed: u64) -> Self {
+        Self {
+            generator,
+            hooks: Vec::new(),
+            seed,
+        }
+    }
+
+    /// Adds a hook run after the ones already added.
+    pub fn with_hook(mut self, hook: impl GenerationHook + 'static) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+}
+
+impl WorldGenerator for HookedGenerator {
+    fn generate_chunk(&self, position: ChunkPosition) -> Chunk {
+        let mut chunk = self.generator.generate_chunk(position);
+        for (i, hook) in self.hooks.iter().enumerate() {
+            // Give each hook its own seed so they don't make the same rolls
+            let seed = shuffle_seed_for_chunk(self.seed.wrapping_add(i as u64), position);
+            hook.on_chunk_generated(&mut chunk, seed);
+        }
+        chunk
+    }
+}
+
+type ClimateSelector = dyn Fn(f32, f32, f32) -> Biome + Send + Sync;
+
+/// Assigns the biomes of a chunk from smooth temperature,
+/// humidity and continentalness noise.
+///
+/// Each value is in `-1.0..=1.0`. The selector picks the biome
+/// for a climate; by default, [`default_biome_for_climate`].
+pub struct ClimateBiomes {
+    world_seed: u64,
+    selector: Box<ClimateSelector>,
+}
+
+impl ClimateBiomes {
+    pub fn new(world_seed: u64) -> Self {
+        Self::with_selector(world_seed, default_biome_for_climate)
+    }
+
+    /// Uses `selector(temperature, humidity, continentalness)`
+    /// to pick biomes.
+    pub fn with_selector(
+        world_seed: u64,
+        selector: impl Fn(f32, f32, f32) -> Biome + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            world_seed,
+            selector: Box::new(selector),
+        }
+    }
+
+    /// Returns the temperature, humidity and continentalness
+    /// at the given block column.
+    pub fn climate_at(&self, x: i32, z: i32) -> (f32, f32, f32) {
+        let (x, z) = (f64::from(x), f64::from(z));
+        let sample = |salt: u64, scale: f64| {
+            value_noise(self.world_seed.wrapping_add(salt), x / scale, z / scale) as f32
+        };
+        (
+            sample(0, CLIMATE_SCALE),
+            sample(1, CLIMATE_SCALE),
+            sample(2, CONTINENTALNESS_SCALE),
+        )
+    }
+}
+
+impl GenerationHook for ClimateBiomes {
+    fn on_chunk_generated(&self, chunk: &mut Chunk, _seed: u64) {
+        // Climate is continuous across chunks, so it is sampled from
+        // the world seed rather than the chunk's.
+        let position = chunk.position();
+        for x in 0..4 {
+            for z in 0..4 {
+                // Sample at the center of each 4x4 biome cell
+                let (temperature, humidity, continentalness) = self.climate_at(
+                    position.x * 16 + x as i32 * 4 + 2,
+                    position.z * 16 + z as i32 * 4 + 2,
+                );
+                let biome = (self.selector)(temperature, humidity, continentalness);
+                for y in 0..64 {
+                    chunk.biomes_mut().set(x, y, z, biome);
+                }
+            }
+        }
+    }
+}
+
+/// A simple climate-to-biome mapping used when
+/// [`ClimateBiomes`] is given no other selector.
+pub fn default_biome_for_climate(temperature: f32, humidity: f32, continentalness: f32) -> Biome {
+    if continentalness < -0.4 {
+        return if temperature < -0.4 {
+            Biome::FrozenOcean
+        } else {
+            Biome::Ocean
+        };
+    }
+    match (temperature, humidity) {
+        (t, _) if t < -0.4 => Biome::SnowyTundra,
+        (t, h) if t > 0.4 && h < 0.0 => Biome::Desert,
+        (t, _) if t > 0.4 => Biome::Savanna,
+        (_, h) if h > 0.3 => Biome::Forest,
+        _ => Biome::Plains,
+    }
+}
+
+/// Smooth two-dimensional value noise in `-1.0..=1.0`,
+/// with features one unit across.
+fn value_noise(seed: u64, x: f64, z: f64) -> f64 {
+    let (x0, z0) = (x.floor(), z.floor());
+    let (tx, tz) = (smoothstep(x - x0), smoothstep(z - z0));
+    let (x0, z0) = (x0 as i64, z0 as i64);
+    let corner = |dx: i64, dz: i64| lattice_value(seed, x0 + dx, z0 + dz);
+
+    let north = lerp(corner(0, 0), corner(1, 0), tx);
+    let south = lerp(corner(0, 1), corner(1, 1), tx);
+    lerp(north, south, tz)
+}
+
+/// A random value in `-1.0..=1.0` for a point of the noise lattice.
+fn lattice_value(seed: u64, x: i64, z: i64) -> f64 {
+    let mut hash = seed
+        ^ (x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (z as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    // splitmix64 finalizer
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+    (hash >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+}
+
+fn smoothstep(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use base::anvil::level::SuperflatGeneratorOptions;
+    use base::BlockId;
+
+    use super::*;
+    use crate::{village_well, StructurePlacement, StructureTemplate, SuperflatWorldGenerator};
+
+    fn flat_generator() -> Arc<dyn WorldGenerator> {
+        Arc::new(SuperflatWorldGenerator::new(
+            SuperflatGeneratorOptions::default(),
+        ))
+    }
+
+    #[test]
+    fn hooks_assign_biomes_and_place_structures() {
+        let generator = HookedGenerator::new(flat_generator(), 0)
+            .with_hook(ClimateBiomes::with_selector(0, |_, _, _| Biome::Desert))
+            .with_hook(StructurePlacement::new(village_well()).in_biomes(&[Biome::Desert]))
+            .with_hook(
+                StructurePlacement::new(
+                    StructureTemplate::new("ocean_pillar")
+                        .with_block((0, 0, 0), BlockId::prismarine()),
+                )
+                .in_biomes(&[Biome::Ocean]),
+            );
+        let chunk = generator.generate_chunk(ChunkPosition::new(3, -2));
+
+        for x in 0..4 {
+            for z in 0..4 {
+                for y in 0..64 {
+                    assert_eq!(chunk.biomes().get(x, y, z), Biome::Desert);
+                }
+            }
+        }
+
+        // The flat world's grass is at y = 3, so structures start at y = 4
+        let blocks_at = |y| {
+            (0..16)
+                .flat_map(|x| (0..16).map(move |z| (x, z)))
+                .map(|(x, z)| chunk.block_at(x, y, z).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            blocks_at(4)
+                .iter()
+                .filter(|block| **block == BlockId::water())
+                .count(),
+            4
+        );
+        assert_eq!(
+            blocks_at(6)
+                .iter()
+                .filter(|block| **block == BlockId::oak_planks())
+                .count(),
+            16
+        );
+        // Only allowed in oceans
+        assert!(!blocks_at(4).contains(&BlockId::prismarine()));
+    }
+
+    #[test]
+    fn climate_is_smooth_and_deterministic() {
+        let climate = ClimateBiomes::new(42);
+        let (temperature, humidity, continentalness) = climate.climate_at(100, -300);
+        for value in [temperature, humidity, continentalness].iter() {
+            assert!((-1.0..=1.0).contains(value));
+        }
+        assert_eq!(
+            climate.climate_at(100, -300),
+            ClimateBiomes::new(42).climate_at(100, -300)
+        );
+
+        // Neighboring columns have nearly the same climate
+        let (next_temperature, _, _) = climate.climate_at(101, -300);
+        assert!((temperature - next_temperature).abs() < 0.05);
+    }
+}
@@ -9,7 +9,9 @@ mod biomes;
 mod composition;
 mod density_map;
 mod finishers;
+mod hooks;
 pub mod noise;
+mod structure;
 mod superflat;
 mod util;
 pub mod voronoi;
@@ -22,11 +24,13 @@ use bitvec::{order::LocalBits, slice::BitSlice};
 pub use composition::BasicCompositionGenerator;
 pub use density_map::{DensityMapGeneratorImpl, HeightMapGenerator};
 use finishers::{ClumpedFoliageFinisher, SingleFoliageFinisher, SnowFinisher};
+pub use hooks::{default_biome_for_climate, ClimateBiomes, GenerationHook, HookedGenerator};
 pub use noise::NoiseLerper;
 use num_traits::ToPrimitive;
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use smallvec::SmallVec;
+pub use structure::{village_well, StructurePlacement, StructureTemplate};
 pub use superflat::SuperflatWorldGenerator;
 
 /// Sea-level height.
@@ -0,0 +1,169 @@
+//! Structure templates and the rules for placing
+//! them in newly generated chunks.
+
+use base::{Biome, BlockId, Chunk};
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+use crate::hooks::GenerationHook;
+use crate::SKY_LIMIT;
+
+/// A fixed arrangement of blocks, such as a well or a
+/// village house, which can be copied into the world.
+///
+/// Block offsets are relative to the template's origin,
+/// which is placed just above the ground.
+#[derive(Debug, Clone)]
+pub struct StructureTemplate {
+    name: String,
+    blocks: Vec<((i32, i32, i32), BlockId)>,
+}
+
+impl StructureTemplate {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            blocks: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The blocks of this template and their offsets from its origin.
+    pub fn blocks(&self) -> &[((i32, i32, i32), BlockId)] {
+        &self.blocks
+    }
+
+    /// Adds a block at the given offset, replacing
+    /// any block added there before.
+    pub fn with_block(mut self, offset: (i32, i32, i32), block: BlockId) -> Self {
+        self.blocks.retain(|(other, _)| *other != offset);
+        self.blocks.push((offset, block));
+        self
+    }
+
+    /// Adds blocks filling the box between `from` and `to`, inclusive.
+    pub fn with_fill(mut self, from: (i32, i32, i32), to: (i32, i32, i32), block: BlockId) -> Self {
+        for x in from.0.min(to.0)..=from.0.max(to.0) {
+            for y in from.1.min(to.1)..=from.1.max(to.1) {
+                for z in from.2.min(to.2)..=from.2.max(to.2) {
+                    self = self.with_block((x, y, z), block);
+                }
+            }
+        }
+        self
+    }
+
+    /// How far the template extends past its origin along
+    /// the x and z axes.
+    fn extent(&self) -> (i32, i32) {
+        self.blocks
+            .iter()
+            .fold((0, 0), |(x, z), ((dx, _, dz), _)| (x.max(*dx), z.max(*dz)))
+    }
+
+    /// Copies the template into the chunk with its origin at the
+    /// given chunk-local coordinates. Blocks falling outside
+    /// the chunk are left out.
+    pub fn place(&self, chunk: &mut Chunk, origin: (usize, usize, usize)) {
+        for ((dx, dy, dz), block) in &self.blocks {
+            let x = origin.0 as i32 + dx;
+            let y = origin.1 as i32 + dy;
+            let z = origin.2 as i32 + dz;
+            if (0..16).contains(&x) && (0..=SKY_LIMIT as i32).contains(&y) && (0..16).contains(&z) {
+                chunk.set_block_at(x as usize, y as usize, z as usize, *block);
+            }
+        }
+    }
+}
+
+/// Places a [`StructureTemplate`] on the ground of the
+/// chunks where its placement condition holds: the chunk is
+/// picked by the rarity roll, and the ground at the chosen spot
+/// is solid and in one of the allowed biomes.
+pub struct StructurePlacement {
+    template: StructureTemplate,
+    biomes: Vec<Biome>,
+    rarity: u32,
+}
+
+impl StructurePlacement {
+    /// Places the template in every chunk, in any biome.
+    pub fn new(template: StructureTemplate) -> Self {
+        Self {
+            template,
+            biomes: Vec::new(),
+            rarity: 1,
+        }
+    }
+
+    /// Only places the template in the given biomes.
+    pub fn in_biomes(mut self, biomes: &[Biome]) -> Self {
+        self.biomes = biomes.to_vec();
+        self
+    }
+
+    /// Places the template in about one in `rarity` chunks.
+    pub fn with_rarity(mut self, rarity: u32) -> Self {
+        self.rarity = rarity.max(1);
+        self
+    }
+
+    pub fn template(&self) -> &StructureTemplate {
+        &self.template
+    }
+
+    /// Picks where in the chunk the template's origin goes,
+    /// or returns `None` if it isn't placed in this chunk.
+    pub fn origin_in(&self, chunk: &Chunk, seed: u64) -> Option<(usize, usize, usize)> {
+        let mut rng = XorShiftRng::seed_from_u64(seed);
+        if rng.gen_range(0, self.rarity) != 0 {
+            return None;
+        }
+
+        // Keep as much of the template inside the chunk as possible
+        let (extent_x, extent_z) = self.template.extent();
+        let x = rng.gen_range(0, (16 - extent_x).max(1)) as usize;
+        let z = rng.gen_range(0, (16 - extent_z).max(1)) as usize;
+
+        let ground = (0..SKY_LIMIT)
+            .rev()
+            .find(|y| !chunk.block_at(x, *y, z).unwrap().is_air())?;
+        let block = chunk.block_at(x, ground, z).unwrap();
+        if !block.is_solid() || block.is_fluid() {
+            return None;
+        }
+        if !self.biomes.is_empty()
+            && !self
+                .biomes
+                .contains(&chunk.biomes().get_at_block(x, ground, z))
+        {
+            return None;
+        }
+        Some((x, ground + 1, z))
+    }
+}
+
+impl GenerationHook for StructurePlacement {
+    fn on_chunk_generated(&self, chunk: &mut Chunk, seed: u64) {
+        if let Some(origin) = self.origin_in(chunk, seed) {
+            self.template.place(chunk, origin);
+            chunk.recalculate_heightmaps();
+        }
+    }
+}
+
+/// A village well: a ring of cobblestone around
+/// a pool of water, under a roof of planks.
+pub fn village_well() -> StructureTemplate {
+    StructureTemplate::new("village_well")
+        .with_fill((0, 0, 0), (3, 0, 3), BlockId::cobblestone())
+        .with_fill((1, 0, 1), (2, 0, 2), BlockId::water())
+        .with_block((0, 1, 0), BlockId::oak_fence())
+        .with_block((3, 1, 0), BlockId::oak_fence())
+        .with_block((0, 1, 3), BlockId::oak_fence())
+        .with_block((3, 1, 3), BlockId::oak_fence())
+        .with_fill((0, 2, 0), (3, 2, 3), BlockId::oak_planks())
+}
@@ -777,6 +777,12 @@ pub enum BlockKind {
     ChiseledNetherBricks,
     CrackedNetherBricks,
     QuartzBricks,
+    PowderSnow,
+    SculkSensor,
+    Sculk,
+    SculkVein,
+    SculkCatalyst,
+    SculkShrieker,
 }
 
 #[allow(warnings)]
@@ -1548,6 +1554,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => 760,
             BlockKind::CrackedNetherBricks => 761,
             BlockKind::QuartzBricks => 762,
+            BlockKind::PowderSnow => 763,
+            BlockKind::SculkSensor => 764,
+            BlockKind::Sculk => 765,
+            BlockKind::SculkVein => 766,
+            BlockKind::SculkCatalyst => 767,
+            BlockKind::SculkShrieker => 768,
         }
     }
 
@@ -2317,6 +2329,12 @@ impl BlockKind {
             760 => Some(BlockKind::ChiseledNetherBricks),
             761 => Some(BlockKind::CrackedNetherBricks),
             762 => Some(BlockKind::QuartzBricks),
+            763 => Some(BlockKind::PowderSnow),
+            764 => Some(BlockKind::SculkSensor),
+            765 => Some(BlockKind::Sculk),
+            766 => Some(BlockKind::SculkVein),
+            767 => Some(BlockKind::SculkCatalyst),
+            768 => Some(BlockKind::SculkShrieker),
             _ => None,
         }
     }
@@ -3090,6 +3108,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => "chiseled_nether_bricks",
             BlockKind::CrackedNetherBricks => "cracked_nether_bricks",
             BlockKind::QuartzBricks => "quartz_bricks",
+            BlockKind::PowderSnow => "powder_snow",
+            BlockKind::SculkSensor => "sculk_sensor",
+            BlockKind::Sculk => "sculk",
+            BlockKind::SculkVein => "sculk_vein",
+            BlockKind::SculkCatalyst => "sculk_catalyst",
+            BlockKind::SculkShrieker => "sculk_shrieker",
         }
     }
 
@@ -3863,6 +3887,12 @@ impl BlockKind {
             "chiseled_nether_bricks" => Some(BlockKind::ChiseledNetherBricks),
             "cracked_nether_bricks" => Some(BlockKind::CrackedNetherBricks),
             "quartz_bricks" => Some(BlockKind::QuartzBricks),
+            "powder_snow" => Some(BlockKind::PowderSnow),
+            "sculk_sensor" => Some(BlockKind::SculkSensor),
+            "sculk" => Some(BlockKind::Sculk),
+            "sculk_vein" => Some(BlockKind::SculkVein),
+            "sculk_catalyst" => Some(BlockKind::SculkCatalyst),
+            "sculk_shrieker" => Some(BlockKind::SculkShrieker),
             _ => None,
         }
     }
@@ -4636,6 +4666,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => "Chiseled Nether Bricks",
             BlockKind::CrackedNetherBricks => "Cracked Nether Bricks",
             BlockKind::QuartzBricks => "Quartz Bricks",
+            BlockKind::PowderSnow => "Powder Snow",
+            BlockKind::SculkSensor => "Sculk Sensor",
+            BlockKind::Sculk => "Sculk",
+            BlockKind::SculkVein => "Sculk Vein",
+            BlockKind::SculkCatalyst => "Sculk Catalyst",
+            BlockKind::SculkShrieker => "Sculk Shrieker",
         }
     }
 
@@ -5409,6 +5445,12 @@ impl BlockKind {
             "Chiseled Nether Bricks" => Some(BlockKind::ChiseledNetherBricks),
             "Cracked Nether Bricks" => Some(BlockKind::CrackedNetherBricks),
             "Quartz Bricks" => Some(BlockKind::QuartzBricks),
+            "Powder Snow" => Some(BlockKind::PowderSnow),
+            "Sculk Sensor" => Some(BlockKind::SculkSensor),
+            "Sculk" => Some(BlockKind::Sculk),
+            "Sculk Vein" => Some(BlockKind::SculkVein),
+            "Sculk Catalyst" => Some(BlockKind::SculkCatalyst),
+            "Sculk Shrieker" => Some(BlockKind::SculkShrieker),
             _ => None,
         }
     }
@@ -6182,6 +6224,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => 2 as f32,
             BlockKind::CrackedNetherBricks => 2 as f32,
             BlockKind::QuartzBricks => 0 as f32,
+            BlockKind::PowderSnow => 0.25 as f32,
+            BlockKind::SculkSensor => 1.5 as f32,
+            BlockKind::Sculk => 0.2 as f32,
+            BlockKind::SculkVein => 0.2 as f32,
+            BlockKind::SculkCatalyst => 3 as f32,
+            BlockKind::SculkShrieker => 3 as f32,
         }
     }
 }
@@ -6954,6 +7002,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => true,
             BlockKind::CrackedNetherBricks => true,
             BlockKind::QuartzBricks => true,
+            BlockKind::PowderSnow => true,
+            BlockKind::SculkSensor => true,
+            BlockKind::Sculk => true,
+            BlockKind::SculkVein => true,
+            BlockKind::SculkCatalyst => true,
+            BlockKind::SculkShrieker => true,
         }
     }
 }
@@ -7726,6 +7780,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => false,
             BlockKind::CrackedNetherBricks => false,
             BlockKind::QuartzBricks => false,
+            BlockKind::PowderSnow => false,
+            BlockKind::SculkSensor => true,
+            BlockKind::Sculk => false,
+            BlockKind::SculkVein => true,
+            BlockKind::SculkCatalyst => false,
+            BlockKind::SculkShrieker => true,
         }
     }
 }
@@ -8498,6 +8558,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => 0,
             BlockKind::CrackedNetherBricks => 0,
             BlockKind::QuartzBricks => 0,
+            BlockKind::PowderSnow => 0,
+            BlockKind::SculkSensor => 1,
+            BlockKind::Sculk => 0,
+            BlockKind::SculkVein => 0,
+            BlockKind::SculkCatalyst => 6,
+            BlockKind::SculkShrieker => 0,
         }
     }
 }
@@ -9270,6 +9336,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => 15,
             BlockKind::CrackedNetherBricks => 15,
             BlockKind::QuartzBricks => 15,
+            BlockKind::PowderSnow => 15,
+            BlockKind::SculkSensor => 0,
+            BlockKind::Sculk => 15,
+            BlockKind::SculkVein => 0,
+            BlockKind::SculkCatalyst => 15,
+            BlockKind::SculkShrieker => 0,
         }
     }
 }
@@ -10042,6 +10114,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => true,
             BlockKind::CrackedNetherBricks => true,
             BlockKind::QuartzBricks => true,
+            BlockKind::PowderSnow => true,
+            BlockKind::SculkSensor => true,
+            BlockKind::Sculk => true,
+            BlockKind::SculkVein => false,
+            BlockKind::SculkCatalyst => true,
+            BlockKind::SculkShrieker => true,
         }
     }
 }
@@ -10888,6 +10966,12 @@ impl BlockKind {
             BlockKind::ChiseledNetherBricks => DIG_MULTIPLIERS_rock,
             BlockKind::CrackedNetherBricks => DIG_MULTIPLIERS_rock,
             BlockKind::QuartzBricks => DIG_MULTIPLIERS_rock,
+            BlockKind::PowderSnow => &[],
+            BlockKind::SculkSensor => &[],
+            BlockKind::Sculk => &[],
+            BlockKind::SculkVein => &[],
+            BlockKind::SculkCatalyst => &[],
+            BlockKind::SculkShrieker => &[],
         }
     }
 }
@@ -13717,6 +13801,12 @@ impl BlockKind {
                 ];
                 Some(TOOLS)
             }
+            BlockKind::PowderSnow => None,
+            BlockKind::SculkSensor => None,
+            BlockKind::Sculk => None,
+            BlockKind::SculkVein => None,
+            BlockKind::SculkCatalyst => None,
+            BlockKind::SculkShrieker => None,
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::any::Any;
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 use std::sync::{Arc, RwLock};
 use serde::{Deserialize, Serialize};
 use crate::{BlockKind, BlockProperties};
@@ -42,9 +43,18 @@ pub enum BlockEntityKind {
     SkullBlock,
     Beehive,
     StructureBlock,
+    DaylightDetector,
+    NoteBlock,
+    Composter,
+    Cauldron,
+    RespawnAnchor,
+    Conduit,
     
     // 1.17 Block Entities
     SculkSensor,
+
+    // 1.19 Block Entities
+    SculkShrieker,
 }
 
 /// Container for custom block entity data
@@ -102,13 +112,30 @@ impl BlockEntityData {
         self.data.insert(key.to_string(), BlockEntityValue::Int(value));
     }
     
+    /// Returns the keys of all stored values
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.data.keys()
+    }
+    
     // Similar methods for other types...
 }
 
+/// Event emitted when a block entity is added, removed or modified.
+///
+/// Used to recompute comparator outputs and to send
+/// block entity updates to clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockEntityChanged {
+    /// The position of the changed block entity
+    pub pos: (i32, i32, i32),
+}
+
 /// Manager for block entities
 pub struct BlockEntityManager {
     /// Map of position to block entity
     entities: HashMap<(i32, i32, i32), BlockEntity>,
+    /// Changes made since the last call to `drain_changes`
+    changes: Vec<BlockEntityChanged>,
 }
 
 impl BlockEntityManager {
@@ -116,6 +143,7 @@ impl BlockEntityManager {
     pub fn new() -> Self {
         Self {
             entities: HashMap::new(),
+            changes: Vec::new(),
         }
     }
 
@@ -124,19 +152,41 @@ impl BlockEntityManager {
         self.entities.get(&position)
     }
 
-    /// Gets a mutable reference to a block entity at the given position
-    pub fn get_mut(&mut self, position: (i32, i32, i32)) -> Option<&mut BlockEntity> {
-        self.entities.get_mut(&position)
+    /// Gets a mutable handle to a block entity at the given position.
+    ///
+    /// A change event is emitted once the handle is dropped,
+    /// but only if the block entity was mutably accessed through it.
+    pub fn get_mut(&mut self, position: (i32, i32, i32)) -> Option<BlockEntityMut> {
+        let entity = self.entities.get_mut(&position)?;
+        Some(BlockEntityMut {
+            position,
+            entity,
+            changes: &mut self.changes,
+            modified: false,
+        })
     }
 
     /// Sets a block entity at the given position
     pub fn set(&mut self, position: (i32, i32, i32), entity: BlockEntity) {
         self.entities.insert(position, entity);
+        self.changes.push(BlockEntityChanged { pos: position });
     }
 
     /// Removes a block entity at the given position
     pub fn remove(&mut self, position: (i32, i32, i32)) -> Option<BlockEntity> {
-        self.entities.remove(&position)
+        let entity = self.entities.remove(&position)?;
+        self.changes.push(BlockEntityChanged { pos: position });
+        Some(entity)
+    }
+
+    /// Drains the change events emitted since the last call
+    pub fn drain_changes(&mut self) -> impl Iterator<Item = BlockEntityChanged> + '_ {
+        self.changes.drain(..)
+    }
+
+    /// Iterates over all block entities
+    pub fn iter(&self) -> impl Iterator<Item = &BlockEntity> {
+        self.entities.values()
     }
 
     /// Checks if a block entity exists at the given position
@@ -145,6 +195,40 @@ impl BlockEntityManager {
     }
 }
 
+/// Mutable handle to a block entity, returned by [`BlockEntityManager::get_mut`].
+///
+/// Emits a [`BlockEntityChanged`] event on drop if the
+/// block entity was mutably dereferenced.
+pub struct BlockEntityMut<'a> {
+    position: (i32, i32, i32),
+    entity: &'a mut BlockEntity,
+    changes: &'a mut Vec<BlockEntityChanged>,
+    modified: bool,
+}
+
+impl Deref for BlockEntityMut<'_> {
+    type Target = BlockEntity;
+
+    fn deref(&self) -> &BlockEntity {
+        self.entity
+    }
+}
+
+impl DerefMut for BlockEntityMut<'_> {
+    fn deref_mut(&mut self) -> &mut BlockEntity {
+        self.modified = true;
+        self.entity
+    }
+}
+
+impl Drop for BlockEntityMut<'_> {
+    fn drop(&mut self) {
+        if self.modified {
+            self.changes.push(BlockEntityChanged { pos: self.position });
+        }
+    }
+}
+
 /// Serializes a block entity to NBT format
 pub fn serialize_block_entity(entity: &BlockEntity) -> Vec<u8> {
     // Implementation would convert the BlockEntity to NBT format
@@ -173,8 +257,32 @@ pub fn create_block_entity(block_kind: BlockKind, position: (i32, i32, i32)) ->
         BlockKind::Dropper => Some(BlockEntityKind::Dropper),
         BlockKind::Dispenser => Some(BlockEntityKind::Dispenser),
         BlockKind::Beehive => Some(BlockEntityKind::Beehive),
+        BlockKind::DaylightDetector => Some(BlockEntityKind::DaylightDetector),
+        BlockKind::NoteBlock => Some(BlockEntityKind::NoteBlock),
+        BlockKind::Composter => Some(BlockEntityKind::Composter),
+        BlockKind::Cauldron => Some(BlockEntityKind::Cauldron),
+        BlockKind::RespawnAnchor => Some(BlockEntityKind::RespawnAnchor),
+        BlockKind::Conduit => Some(BlockEntityKind::Conduit),
+        BlockKind::WhiteBed
+        | BlockKind::OrangeBed
+        | BlockKind::MagentaBed
+        | BlockKind::LightBlueBed
+        | BlockKind::YellowBed
+        | BlockKind::LimeBed
+        | BlockKind::PinkBed
+        | BlockKind::GrayBed
+        | BlockKind::LightGrayBed
+        | BlockKind::CyanBed
+        | BlockKind::PurpleBed
+        | BlockKind::BlueBed
+        | BlockKind::BrownBed
+        | BlockKind::GreenBed
+        | BlockKind::RedBed
+        | BlockKind::BlackBed => Some(BlockEntityKind::Bed),
         // 1.17 blocks
         BlockKind::SculkSensor => Some(BlockEntityKind::SculkSensor),
+        // 1.19 blocks
+        BlockKind::SculkShrieker => Some(BlockEntityKind::SculkShrieker),
         _ => None,
     };
     
@@ -190,4 +298,42 @@ pub fn create_block_entity(block_kind: BlockKind, position: (i32, i32, i32)) ->
 /// Checks if the given block kind requires a block entity
 pub fn requires_block_entity(block_kind: BlockKind) -> bool {
     create_block_entity(block_kind, (0, 0, 0)).is_some()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutating_chest_emits_change() {
+        let mut manager = BlockEntityManager::new();
+        let pos = (3, 64, -2);
+        manager.set(pos, create_block_entity(BlockKind::Chest, pos).unwrap());
+        manager.drain_changes().for_each(drop);
+
+        manager.get_mut(pos).unwrap().data.set_int("Slot0", 1);
+
+        let changes: Vec<_> = manager.drain_changes().collect();
+        assert_eq!(changes, vec![BlockEntityChanged { pos }]);
+        assert_eq!(manager.drain_changes().count(), 0);
+    }
+
+    #[test]
+    fn reading_chest_emits_nothing() {
+        let mut manager = BlockEntityManager::new();
+        let pos = (3, 64, -2);
+        manager.set(pos, create_block_entity(BlockKind::Chest, pos).unwrap());
+        manager.drain_changes().for_each(drop);
+
+        assert_eq!(manager.get_mut(pos).unwrap().data.get_int("Slot0"), None);
+        assert_eq!(manager.drain_changes().count(), 0);
+    }
+
+    #[test]
+    fn missing_block_entity_emits_nothing() {
+        let mut manager = BlockEntityManager::new();
+        assert!(manager.get_mut((0, 0, 0)).is_none());
+        assert!(manager.remove((0, 0, 0)).is_none());
+        assert_eq!(manager.drain_changes().count(), 0);
+    }
+}
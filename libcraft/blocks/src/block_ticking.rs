@@ -1,7 +1,6 @@
 use std::collections::{BinaryHeap, HashMap};
 use std::cmp::{Ordering, Reverse};
-use std::time::{Duration, Instant};
-use rand::{Rng, thread_rng};
+use rand::Rng;
 
 use crate::{BlockKind, BlockProperties};
 
@@ -21,8 +20,8 @@ pub struct BlockTick {
     pub position: (i32, i32, i32),
     /// The kind of block
     pub kind: BlockKind,
-    /// When this tick should execute
-    pub scheduled_time: Instant,
+    /// The game tick on which this tick should execute
+    pub scheduled_tick: u64,
     /// The type of tick
     pub tick_type: TickType,
     /// Priority of the tick (lower values = higher priority)
@@ -31,7 +30,7 @@ pub struct BlockTick {
 
 impl PartialEq for BlockTick {
     fn eq(&self, other: &Self) -> bool {
-        self.scheduled_time.eq(&other.scheduled_time) && self.priority.eq(&other.priority)
+        self.scheduled_tick.eq(&other.scheduled_tick) && self.priority.eq(&other.priority)
     }
 }
 
@@ -45,8 +44,8 @@ impl PartialOrd for BlockTick {
 
 impl Ord for BlockTick {
     fn cmp(&self, other: &Self) -> Ordering {
-        Reverse(self.scheduled_time)
-            .cmp(&Reverse(other.scheduled_time))
+        Reverse(self.scheduled_tick)
+            .cmp(&Reverse(other.scheduled_tick))
             .then_with(|| self.priority.cmp(&other.priority))
     }
 }
@@ -59,6 +58,8 @@ pub struct BlockTickScheduler {
     position_to_tick: HashMap<(i32, i32, i32), BlockTick>,
     /// Random tick speed (how many random ticks per chunk section per game tick)
     random_tick_speed: u32,
+    /// Number of times ticks have been processed, i.e. the current game tick
+    current_tick: u64,
 }
 
 impl BlockTickScheduler {
@@ -68,52 +69,74 @@ impl BlockTickScheduler {
             pending_ticks: BinaryHeap::new(),
             position_to_tick: HashMap::new(),
             random_tick_speed,
+            current_tick: 0,
         }
     }
 
-    /// Schedules a block tick
+    /// Schedules a block tick `delay` game ticks from now. A delay of
+    /// zero runs the tick the next time ticks are processed.
     pub fn schedule_tick(
         &mut self, 
         position: (i32, i32, i32), 
         kind: BlockKind, 
-        delay: Duration,
+        delay: u32,
         tick_type: TickType,
         priority: i32,
     ) {
-        let scheduled_time = Instant::now() + delay;
         let tick = BlockTick {
             position,
             kind,
-            scheduled_time,
+            scheduled_tick: self.current_tick + u64::from(delay.max(1)),
             tick_type,
             priority,
         };
 
-        // If a tick at this position already exists, replace it
-        if let Some(existing_tick) = self.position_to_tick.remove(&position) {
-            self.pending_ticks.iter()
-                .position(|t| t.position == existing_tick.position)
-                .map(|i| self.pending_ticks.into_iter().nth(i));
-        }
-        
+        // A tick already pending at this position is replaced; its entry
+        // stays in the queue but is skipped when it comes up
         self.position_to_tick.insert(position, tick.clone());
         self.pending_ticks.push(tick);
     }
 
-    /// Processes all ticks that are due
+    /// The current game tick, as counted by [`process_ticks`](Self::process_ticks)
+    pub fn current_tick(&self) -> u64 {
+        self.current_tick
+    }
+
+    /// Gets the tick pending at the given position, if any
+    pub fn pending_tick(&self, position: (i32, i32, i32)) -> Option<&BlockTick> {
+        self.position_to_tick.get(&position)
+    }
+
+    /// Iterates over the scheduled ticks still waiting to run
+    pub fn scheduled_ticks(&self) -> impl Iterator<Item = &BlockTick> {
+        self.position_to_tick
+            .values()
+            .filter(|tick| tick.tick_type == TickType::Scheduled)
+    }
+
+    /// Advances to the next game tick and processes all ticks that are due.
+    ///
+    /// Meant to be called once per game tick.
     pub fn process_ticks<F>(&mut self, mut tick_handler: F)
     where
         F: FnMut((i32, i32, i32), BlockKind, TickType),
     {
-        let now = Instant::now();
+        self.current_tick += 1;
         
         // Process all ticks that are due
         while let Some(tick) = self.pending_ticks.peek() {
-            if tick.scheduled_time > now {
+            if tick.scheduled_tick > self.current_tick {
                 break;
             }
             
             let tick = self.pending_ticks.pop().unwrap();
+            let current = self.position_to_tick.get(&tick.position).map_or(false, |pending| {
+                pending.scheduled_tick == tick.scheduled_tick && pending.kind == tick.kind
+            });
+            if !current {
+                // Replaced by a later call to `schedule_tick`
+                continue;
+            }
             self.position_to_tick.remove(&tick.position);
             
             tick_handler(tick.position, tick.kind, tick.tick_type);
@@ -121,17 +144,17 @@ impl BlockTickScheduler {
     }
 
     /// Performs random ticks in a chunk section
-    pub fn process_random_ticks<F>(
+    pub fn process_random_ticks<R, F>(
         &self,
         chunk_position: (i32, i32),
         blocks: &[(BlockKind, (i32, i32, i32), BlockProperties)],
+        rng: &mut R,
         mut tick_handler: F,
     )
     where
-        F: FnMut((i32, i32, i32), BlockKind),
+        R: Rng,
+        F: FnMut(&mut R, (i32, i32, i32), BlockKind),
     {
-        let mut rng = thread_rng();
-        
         // Perform random_tick_speed random ticks
         for _ in 0..self.random_tick_speed {
             if blocks.is_empty() {
@@ -141,7 +164,7 @@ impl BlockTickScheduler {
             let index = rng.gen_range(0..blocks.len());
             let (kind, pos, _) = &blocks[index];
             
-            tick_handler(*pos, *kind);
+            tick_handler(rng, *pos, *kind);
         }
     }
 
@@ -160,4 +183,37 @@ impl BlockTickScheduler {
     pub fn random_tick_speed(&self) -> u32 {
         self.random_tick_speed
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(scheduler: &mut BlockTickScheduler) -> Vec<(i32, i32, i32)> {
+        let mut ticked = Vec::new();
+        scheduler.process_ticks(|pos, _, _| ticked.push(pos));
+        ticked
+    }
+
+    #[test]
+    fn ticks_run_after_their_delay() {
+        let mut scheduler = BlockTickScheduler::new(3);
+        scheduler.schedule_tick((0, 0, 0), BlockKind::Stone, 3, TickType::Scheduled, 0);
+        scheduler.schedule_tick((1, 0, 0), BlockKind::Stone, 0, TickType::Scheduled, 0);
+
+        assert_eq!(run(&mut scheduler), vec![(1, 0, 0)]);
+        assert!(run(&mut scheduler).is_empty());
+        assert_eq!(run(&mut scheduler), vec![(0, 0, 0)]);
+        assert!(scheduler.pending_tick((0, 0, 0)).is_none());
+    }
+
+    #[test]
+    fn rescheduling_replaces_pending_tick() {
+        let mut scheduler = BlockTickScheduler::new(3);
+        scheduler.schedule_tick((0, 0, 0), BlockKind::Stone, 1, TickType::Scheduled, 0);
+        scheduler.schedule_tick((0, 0, 0), BlockKind::Stone, 2, TickType::Scheduled, 0);
+
+        assert!(run(&mut scheduler).is_empty());
+        assert_eq!(run(&mut scheduler), vec![(0, 0, 0)]);
+        assert!(run(&mut scheduler).is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 use std::time::Duration;
-use crate::{BlockKind, BlockProperties, BlockBehavior};
+use rand::Rng;
+use crate::{BlockKind, BlockProperties, BlockBehavior, RandomTickProbabilities};
+use crate::block_ticking::TickType;
 
 /// Represents a possible transition from one block state to another
 pub struct BlockStateTransition {
@@ -24,8 +26,9 @@ pub enum TransitionCondition {
     RedstonePowered,
     /// Block must be in a specific biome
     InBiome(String),
-    /// Random tick (with probability)
-    RandomTick(f32),
+    /// Random tick, succeeding with the block's probability
+    /// from the [`RandomTickProbabilities`] table
+    RandomTick,
     /// Custom condition with closure
     Custom(fn(&BlockProperties) -> bool),
 }
@@ -50,14 +53,21 @@ impl BlockTransitionManager {
     }
 
     /// Checks if a block can transition and returns the target state
-    pub fn check_transition(&self, kind: BlockKind, properties: &BlockProperties) -> Option<BlockKind> {
+    pub fn check_transition<R: Rng>(
+        &self,
+        kind: BlockKind,
+        properties: &BlockProperties,
+        tick_type: &TickType,
+        probabilities: &RandomTickProbabilities,
+        rng: &mut R,
+    ) -> Option<BlockKind> {
         for transition in &self.transitions {
             if transition.source_kind != kind {
                 continue;
             }
 
             let conditions_met = transition.conditions.iter()
-                .all(|condition| self.check_condition(condition, properties));
+                .all(|condition| self.check_condition(condition, kind, properties, tick_type, probabilities, rng));
             
             if conditions_met {
                 return Some(transition.target_kind);
@@ -67,7 +77,15 @@ impl BlockTransitionManager {
     }
 
     /// Checks if a specific condition is met
-    fn check_condition(&self, condition: &TransitionCondition, properties: &BlockProperties) -> bool {
+    fn check_condition<R: Rng>(
+        &self,
+        condition: &TransitionCondition,
+        kind: BlockKind,
+        properties: &BlockProperties,
+        tick_type: &TickType,
+        probabilities: &RandomTickProbabilities,
+        rng: &mut R,
+    ) -> bool {
         match condition {
             TransitionCondition::ExposedToSky => {
                 // Would need world context, simplified for demo
@@ -84,9 +102,8 @@ impl BlockTransitionManager {
                 // Would need world context, simplified for demo
                 false
             }
-            TransitionCondition::RandomTick(_) => {
-                // Would be implemented with server's random tick system
-                false
+            TransitionCondition::RandomTick => {
+                *tick_type == TickType::Random && rng.gen::<f32>() < probabilities.random_tick(kind)
             }
             TransitionCondition::Custom(func) => {
                 func(properties)
@@ -101,7 +118,7 @@ pub fn register_copper_transitions(manager: &mut BlockTransitionManager) {
     manager.register_transition(BlockStateTransition {
         source_kind: BlockKind::Copper,
         target_kind: BlockKind::ExposedCopper,
-        conditions: vec![TransitionCondition::RandomTick(0.05)],
+        conditions: vec![TransitionCondition::RandomTick],
         transition_time: Some(Duration::from_secs(12000)), // Example time
     });
 
@@ -109,7 +126,7 @@ pub fn register_copper_transitions(manager: &mut BlockTransitionManager) {
     manager.register_transition(BlockStateTransition {
         source_kind: BlockKind::ExposedCopper,
         target_kind: BlockKind::WeatheredCopper,
-        conditions: vec![TransitionCondition::RandomTick(0.05)],
+        conditions: vec![TransitionCondition::RandomTick],
         transition_time: Some(Duration::from_secs(12000)),
     });
 
@@ -117,7 +134,7 @@ pub fn register_copper_transitions(manager: &mut BlockTransitionManager) {
     manager.register_transition(BlockStateTransition {
         source_kind: BlockKind::WeatheredCopper,
         target_kind: BlockKind::OxidizedCopper,
-        conditions: vec![TransitionCondition::RandomTick(0.05)],
+        conditions: vec![TransitionCondition::RandomTick],
         transition_time: Some(Duration::from_secs(12000)),
     });
     
@@ -125,7 +142,7 @@ pub fn register_copper_transitions(manager: &mut BlockTransitionManager) {
     manager.register_transition(BlockStateTransition {
         source_kind: BlockKind::CutCopper,
         target_kind: BlockKind::ExposedCutCopper,
-        conditions: vec![TransitionCondition::RandomTick(0.05)],
+        conditions: vec![TransitionCondition::RandomTick],
         transition_time: Some(Duration::from_secs(12000)),
     });
     
@@ -0,0 +1,25 @@
+use crate::{BlockKind, BlockProperties};
+
+/// Read-only access to the blocks of a world.
+///
+/// Implemented for any `Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>`,
+/// so the block getter closures used throughout this crate can be passed
+/// wherever a `&dyn BlockView` is expected.
+pub trait BlockView {
+    /// Gets the block at the given position, or `None` if it is not loaded
+    fn block_at(&self, pos: (i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>;
+
+    /// Gets the kind of the block at the given position
+    fn kind_at(&self, pos: (i32, i32, i32)) -> Option<BlockKind> {
+        self.block_at(pos).map(|(kind, _)| kind)
+    }
+}
+
+impl<F> BlockView for F
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    fn block_at(&self, pos: (i32, i32, i32)) -> Option<(BlockKind, BlockProperties)> {
+        self(pos)
+    }
+}
@@ -0,0 +1,235 @@
+use rand::Rng;
+
+use crate::{BlockKind, BlockProperties};
+
+/// Age at which a chorus flower stops growing
+const MAX_AGE: u8 = 5;
+/// Age past which a chorus flower no longer branches out
+const MAX_BRANCHING_AGE: u8 = 4;
+/// Height of the chorus plant below a flower past which it may stop growing upward
+const MIN_STEM_HEIGHT: i32 = 2;
+/// Longest stem of chorus plant measured below a flower
+const MAX_STEM_HEIGHT: i32 = 4;
+
+const HORIZONTAL: [((i32, i32), &str); 4] = [
+    ((0, -1), "north"),
+    ((1, 0), "east"),
+    ((0, 1), "south"),
+    ((-1, 0), "west"),
+];
+
+fn offset(pos: (i32, i32, i32), dx: i32, dy: i32, dz: i32) -> (i32, i32, i32) {
+    (pos.0 + dx, pos.1 + dy, pos.2 + dz)
+}
+
+fn kind_at<F>(block_getter: &F, pos: (i32, i32, i32)) -> BlockKind
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    block_getter(pos).map_or(BlockKind::Air, |(kind, _)| kind)
+}
+
+/// Whether chorus plants connect to blocks of the given kind
+fn is_chorus(kind: BlockKind) -> bool {
+    matches!(kind, BlockKind::ChorusPlant | BlockKind::ChorusFlower)
+}
+
+/// Returns a chorus plant connected to the chorus around `pos`,
+/// and to end stone below it.
+pub fn connected_plant<F>(pos: (i32, i32, i32), block_getter: &F) -> BlockProperties
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    let connects = |dx, dy, dz| is_chorus(kind_at(block_getter, offset(pos, dx, dy, dz)));
+    let below = kind_at(block_getter, offset(pos, 0, -1, 0));
+    let mut plant = BlockKind::ChorusPlant.default_properties();
+    for (name, connected) in &[
+        ("north", connects(0, 0, -1)),
+        ("east", connects(1, 0, 0)),
+        ("south", connects(0, 0, 1)),
+        ("west", connects(-1, 0, 0)),
+        ("up", connects(0, 1, 0)),
+        ("down", is_chorus(below) || below == BlockKind::EndStone),
+    ] {
+        plant.set(name, if *connected { "true" } else { "false" });
+    }
+    plant
+}
+
+fn flower(age: u8) -> BlockProperties {
+    let mut flower = BlockKind::ChorusFlower.default_properties();
+    flower.set("age", &age.to_string());
+    flower
+}
+
+/// Whether the block at `pos` is air with only air around it,
+/// ignoring the neighbor at `except`.
+fn surrounded_by_air<F>(block_getter: &F, pos: (i32, i32, i32), except: (i32, i32)) -> bool
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    HORIZONTAL
+        .iter()
+        .filter(|(direction, _)| *direction != except)
+        .all(|((dx, dz), _)| kind_at(block_getter, offset(pos, *dx, 0, *dz)) == BlockKind::Air)
+}
+
+/// Handles a random tick for a chorus flower, which grows upward
+/// or branches out sideways, leaving chorus plant behind it.
+///
+/// Flowers growing from end stone or a short stem keep going up,
+/// while taller stems are more likely to branch. A flower that can
+/// do neither, or has grown old, dies and stops growing.
+pub fn random_tick_chorus_flower<R, F, G>(
+    rng: &mut R,
+    pos: (i32, i32, i32),
+    properties: &BlockProperties,
+    block_getter: &F,
+    block_setter: &mut G,
+) where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    let age = properties
+        .get("age")
+        .and_then(|age| age.parse::<u8>().ok())
+        .unwrap_or(0);
+    let above = offset(pos, 0, 1, 0);
+    if age >= MAX_AGE || kind_at(block_getter, above) != BlockKind::Air {
+        return;
+    }
+
+    let mut grows_up = false;
+    let mut on_end_stone = false;
+    match kind_at(block_getter, offset(pos, 0, -1, 0)) {
+        BlockKind::EndStone => grows_up = true,
+        BlockKind::Air => grows_up = true,
+        BlockKind::ChorusPlant => {
+            let mut stem = 1;
+            while stem < MAX_STEM_HEIGHT {
+                match kind_at(block_getter, offset(pos, 0, -stem - 1, 0)) {
+                    BlockKind::ChorusPlant => stem += 1,
+                    BlockKind::EndStone => {
+                        on_end_stone = true;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+            let limit = if on_end_stone { 5 } else { 4 };
+            if stem < MIN_STEM_HEIGHT || stem <= rng.gen_range(0..limit) {
+                grows_up = true;
+            }
+        }
+        _ => {}
+    }
+
+    if grows_up
+        && surrounded_by_air(block_getter, above, (0, 0))
+        && kind_at(block_getter, offset(pos, 0, 2, 0)) == BlockKind::Air
+    {
+        let mut plant = connected_plant(pos, block_getter);
+        plant.set("up", "true");
+        block_setter(above, flower(age));
+        block_setter(pos, plant);
+        return;
+    }
+
+    if age < MAX_BRANCHING_AGE {
+        let mut branches = rng.gen_range(0..4);
+        if on_end_stone {
+            branches += 1;
+        }
+        let mut plant = connected_plant(pos, block_getter);
+        let mut branched = false;
+        for _ in 0..branches {
+            let ((dx, dz), direction) = HORIZONTAL[rng.gen_range(0..HORIZONTAL.len())];
+            let target = offset(pos, dx, 0, dz);
+            if kind_at(block_getter, target) == BlockKind::Air
+                && kind_at(block_getter, offset(target, 0, -1, 0)) == BlockKind::Air
+                && surrounded_by_air(block_getter, target, (-dx, -dz))
+            {
+                block_setter(target, flower(age + 1));
+                plant.set(direction, "true");
+                branched = true;
+            }
+        }
+        if branched {
+            block_setter(pos, plant);
+            return;
+        }
+    }
+    block_setter(pos, flower(MAX_AGE));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    type TestWorld = RefCell<HashMap<(i32, i32, i32), BlockProperties>>;
+
+    fn kind_at(world: &TestWorld, pos: (i32, i32, i32)) -> Option<BlockKind> {
+        world.borrow().get(&pos).map(BlockProperties::kind)
+    }
+
+    /// Ticks every chorus flower in the world once.
+    fn tick(world: &TestWorld, rng: &mut StdRng) {
+        let flowers: Vec<((i32, i32, i32), BlockProperties)> = world
+            .borrow()
+            .iter()
+            .filter(|(_, properties)| properties.kind() == BlockKind::ChorusFlower)
+            .map(|(pos, properties)| (*pos, properties.clone()))
+            .collect();
+        for (pos, properties) in flowers {
+            let getter = |p| {
+                world
+                    .borrow()
+                    .get(&p)
+                    .map(|props: &BlockProperties| (props.kind(), props.clone()))
+            };
+            let mut setter = |p, properties| {
+                world.borrow_mut().insert(p, properties);
+            };
+            random_tick_chorus_flower(rng, pos, &properties, &getter, &mut setter);
+        }
+    }
+
+    #[test]
+    fn chorus_flower_grows_up_from_end_stone() {
+        let world = RefCell::new(HashMap::new());
+        world
+            .borrow_mut()
+            .insert((0, 63, 0), BlockKind::EndStone.default_properties());
+        world.borrow_mut().insert((0, 64, 0), flower(0));
+        let mut rng = StdRng::seed_from_u64(0);
+
+        tick(&world, &mut rng);
+        assert_eq!(kind_at(&world, (0, 64, 0)), Some(BlockKind::ChorusPlant));
+        assert_eq!(kind_at(&world, (0, 65, 0)), Some(BlockKind::ChorusFlower));
+        let plant = world.borrow()[&(0, 64, 0)].clone();
+        assert_eq!(plant.get("down").unwrap(), "true");
+        assert_eq!(plant.get("up").unwrap(), "true");
+        assert_eq!(plant.get("north").unwrap(), "false");
+
+        for _ in 0..50 {
+            tick(&world, &mut rng);
+        }
+        // The plant keeps growing until all of its flowers have died
+        assert!(world
+            .borrow()
+            .values()
+            .filter(|properties| properties.kind() == BlockKind::ChorusFlower)
+            .all(|flower| flower.get("age").unwrap() == "5"));
+        let height = (64..)
+            .take_while(|y| kind_at(&world, (0, *y, 0)) == Some(BlockKind::ChorusPlant))
+            .count();
+        assert!(height >= 2);
+    }
+}
@@ -1,8 +1,7 @@
-use std::time::Duration;
-use crate::{BlockKind, BlockProperties, BlockTickExecutor};
-use base::{Chunk, ChunkPosition, ValidBlockPosition};
+use crate::{power, BlockKind, BlockProperties, BlockTickExecutor};
+use base::{ChunkPosition, ValidBlockPosition, CHUNK_HEIGHT};
 use blocks::BlockId;
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 
 /// Manages the integration of block systems with the world and chunk systems
 pub struct BlockWorldIntegration {
@@ -10,19 +9,35 @@ pub struct BlockWorldIntegration {
     tick_executor: BlockTickExecutor,
     /// Tracks which chunks have been registered for ticking
     registered_chunks: AHashMap<ChunkPosition, bool>,
+    /// Positions of blocks that receive random ticks, per registered chunk.
+    /// A chunk is indexed with a full scan the first time it is random ticked.
+    ticking_blocks: AHashMap<ChunkPosition, AHashSet<ValidBlockPosition>>,
     /// Stores pending block updates to be processed
-    pending_updates: Vec<BlockUpdate>,
+    pending_updates: Vec<PendingUpdate>,
     /// How often to process random ticks (in ticks)
     random_tick_interval: u32,
     /// Current tick count since server start
     current_tick: u64,
+    /// Chunks with changes that have not been saved yet
+    dirty_chunks: AHashSet<ChunkPosition>,
 }
 
 /// Represents a pending block update
-struct BlockUpdate {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockUpdate {
+    pub position: ValidBlockPosition,
+    pub kind: BlockKind,
+    pub delay: u32,
+    pub priority: i32,
+}
+
+/// A block update waiting for the tick it is due at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingUpdate {
     position: ValidBlockPosition,
     kind: BlockKind,
-    delay: u32,
+    /// Tick, counted like `current_tick`, at which the update runs
+    due_tick: u64,
     priority: i32,
 }
 
@@ -32,9 +47,11 @@ impl BlockWorldIntegration {
         Self {
             tick_executor,
             registered_chunks: AHashMap::new(),
+            ticking_blocks: AHashMap::new(),
             pending_updates: Vec::new(),
             random_tick_interval: 1, // Process random ticks every tick
             current_tick: 0,
+            dirty_chunks: AHashSet::new(),
         }
     }
     
@@ -46,85 +63,175 @@ impl BlockWorldIntegration {
     /// Unregisters a chunk from processing
     pub fn unregister_chunk(&mut self, pos: ChunkPosition) {
         self.registered_chunks.remove(&pos);
+        self.ticking_blocks.remove(&pos);
+    }
+    
+    /// Records that a chunk changed and needs to be saved
+    pub fn mark_chunk_dirty(&mut self, pos: ChunkPosition) {
+        self.dirty_chunks.insert(pos);
+    }
+
+    /// Records that a chunk was saved, e.g. when it was unloaded
+    pub fn mark_chunk_clean(&mut self, pos: ChunkPosition) {
+        self.dirty_chunks.remove(&pos);
+    }
+
+    /// Whether a chunk changed since it was last saved
+    pub fn is_chunk_dirty(&self, pos: ChunkPosition) -> bool {
+        self.dirty_chunks.contains(&pos)
+    }
+
+    /// Takes the chunks that changed since they were last saved,
+    /// leaving them all clean
+    pub fn take_dirty_chunks(&mut self) -> AHashSet<ChunkPosition> {
+        std::mem::take(&mut self.dirty_chunks)
     }
     
+    /// Gets the block updates and ticks still waiting to run in a chunk,
+    /// with their delay counted from the current tick, e.g. to save them
+    pub fn scheduled_updates_in(&self, chunk: ChunkPosition) -> Vec<BlockUpdate> {
+        let current_tick = self.tick_executor.scheduler().current_tick();
+        let ticks = self.tick_executor.scheduler().scheduled_ticks().filter_map(|tick| {
+            let position = from_tuple(tick.position)?;
+            Some(BlockUpdate {
+                position,
+                kind: tick.kind,
+                delay: tick.scheduled_tick.saturating_sub(current_tick) as u32,
+                priority: tick.priority,
+            })
+        });
+        let pending = self.pending_updates.iter().map(|update| BlockUpdate {
+            position: update.position,
+            kind: update.kind,
+            delay: update.due_tick.saturating_sub(self.current_tick) as u32,
+            priority: update.priority,
+        });
+        ticks
+            .chain(pending)
+            .filter(|update| update.position.chunk() == chunk)
+            .collect()
+    }
+
     /// Schedules a block update at the given position
     pub fn schedule_block_update(&mut self, pos: ValidBlockPosition, kind: BlockKind, delay: u32, priority: i32) {
-        self.pending_updates.push(BlockUpdate {
+        // Pending updates are saved with the chunk
+        self.mark_chunk_dirty(pos.chunk());
+        self.pending_updates.push(PendingUpdate {
             position: pos,
             kind,
-            delay,
+            due_tick: self.current_tick + u64::from(delay),
             priority,
         });
     }
     
-    /// Main update method, to be called each game tick
-    pub fn update<F, G>(&mut self, 
+    /// Main update method, to be called each game tick.
+    ///
+    /// Runs the scheduled ticks that are due, random ticks in the
+    /// given loaded chunks, and pending neighbor updates.
+    pub fn update<F, L, G>(&mut self, 
         block_getter: F, 
-        block_setter: G,
-        chunks: &AHashMap<ChunkPosition, Chunk>
+        light_getter: L,
+        mut block_setter: G,
+        loaded_chunks: &[ChunkPosition]
     ) 
     where
         F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
-        G: FnMut(ValidBlockPosition, BlockKind),
+        L: Fn(ValidBlockPosition) -> u8,
+        G: FnMut(ValidBlockPosition, BlockProperties),
     {
         self.current_tick += 1;
         
         // Process scheduled ticks
-        self.tick_executor.process_ticks(block_getter, block_setter);
+        self.tick_executor.process_ticks(
+            |p| from_tuple(p).and_then(&block_getter),
+            |p, properties| {
+                if let Some(p) = from_tuple(p) {
+                    block_setter(p, properties);
+                }
+            },
+        );
         
-        // Process random ticks for registered chunks
+        // Process random ticks for loaded chunks
         if self.current_tick % u64::from(self.random_tick_interval) == 0 {
-            self.process_random_ticks(block_getter, block_setter, chunks);
+            self.process_random_ticks(&block_getter, &light_getter, &mut block_setter, loaded_chunks);
         }
         
         // Process pending block updates
-        self.process_pending_updates(block_getter);
+        self.process_pending_updates(&block_getter);
     }
     
-    /// Process random ticks for all registered chunks
-    fn process_random_ticks<F, G>(
-        &self,
+    /// Process random ticks for the given loaded chunks
+    pub fn process_random_ticks<F, L, G>(
+        &mut self,
         block_getter: F,
+        light_getter: L,
         mut block_setter: G,
-        chunks: &AHashMap<ChunkPosition, Chunk>
+        loaded_chunks: &[ChunkPosition]
     )
     where
         F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
-        G: FnMut(ValidBlockPosition, BlockKind),
+        L: Fn(ValidBlockPosition) -> u8,
+        G: FnMut(ValidBlockPosition, BlockProperties),
     {
-        for (pos, _) in &self.registered_chunks {
-            if let Some(chunk) = chunks.get(pos) {
-                // Collect ticking blocks in the chunk
-                let mut ticking_blocks = Vec::new();
-                for y in 0..chunk.height() {
-                    for z in 0..16 {
-                        for x in 0..16 {
-                            let block_pos = ValidBlockPosition::new(
-                                pos.x * 16 + x as i32,
-                                y as i32,
-                                pos.z * 16 + z as i32,
-                            ).unwrap();
-                            
-                            if let Some((kind, properties)) = block_getter(block_pos) {
-                                if kind.receives_random_ticks() {
-                                    ticking_blocks.push((kind, block_pos, properties));
-                                }
-                            }
-                        }
-                    }
+        // Forget the index of chunks which were unloaded
+        let loaded: AHashSet<ChunkPosition> = loaded_chunks.iter().copied().collect();
+        self.ticking_blocks.retain(|pos, _| loaded.contains(pos));
+
+        for pos in loaded_chunks {
+            let indexed = self
+                .ticking_blocks
+                .entry(*pos)
+                .or_insert_with(|| scan_ticking_blocks(*pos, &block_getter));
+
+            // Drop positions whose block no longer receives random ticks
+            let mut ticking_blocks = Vec::with_capacity(indexed.len());
+            indexed.retain(|block_pos| match block_getter(*block_pos) {
+                Some((kind, properties)) if kind.receives_random_ticks() => {
+                    ticking_blocks.push((kind, to_tuple(*block_pos), properties));
+                    true
                 }
-                
-                // Process random ticks for the chunk
-                self.tick_executor.scheduler().process_random_ticks(
-                    *pos,
-                    &ticking_blocks,
-                    block_getter,
-                    block_setter,
-                );
+                _ => false,
+            });
+
+            // Process random ticks for the chunk
+            let mut changed = Vec::new();
+            self.tick_executor.process_random_ticks(
+                (pos.x, pos.z),
+                &ticking_blocks,
+                |p| from_tuple(p).and_then(&block_getter),
+                |p| from_tuple(p).map_or(0, &light_getter),
+                |p, properties| {
+                    if let Some(p) = from_tuple(p) {
+                        changed.push((p, properties.kind()));
+                        block_setter(p, properties);
+                    }
+                },
+            );
+
+            // Random ticks may create ticking blocks, e.g. grass spreading
+            for (block_pos, kind) in changed {
+                self.update_ticking_index(block_pos, kind);
+                self.mark_chunk_dirty(block_pos.chunk());
+            }
+        }
+    }
+
+    /// Keeps the ticking block index of an already indexed chunk up to date
+    fn update_ticking_index(&mut self, pos: ValidBlockPosition, kind: BlockKind) {
+        if let Some(indexed) = self.ticking_blocks.get_mut(&pos.chunk()) {
+            if kind.receives_random_ticks() {
+                indexed.insert(pos);
+            } else {
+                indexed.remove(&pos);
             }
         }
     }
+
+    /// Gets the indexed positions of blocks receiving random ticks in a chunk,
+    /// or `None` if the chunk has not been indexed yet.
+    pub fn ticking_blocks(&self, chunk: ChunkPosition) -> Option<&AHashSet<ValidBlockPosition>> {
+        self.ticking_blocks.get(&chunk)
+    }
     
     /// Process pending block updates
     fn process_pending_updates<F>(&mut self, block_getter: F) 
@@ -136,7 +243,7 @@ impl BlockWorldIntegration {
         
         while i < self.pending_updates.len() {
             let update = &self.pending_updates[i];
-            if u64::from(update.delay) <= current_tick {
+            if update.due_tick <= current_tick {
                 let update = self.pending_updates.remove(i);
                 
                 // Check if the block still exists and is the same kind
@@ -145,7 +252,7 @@ impl BlockWorldIntegration {
                         self.tick_executor.schedule_tick(
                             (update.position.x() as i32, update.position.y() as i32, update.position.z() as i32),
                             update.kind,
-                            Duration::from_millis(0),
+                            0,
                             update.priority,
                         );
                     }
@@ -165,19 +272,9 @@ impl BlockWorldIntegration {
     ) 
     where
         F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
-        G: FnMut(ValidBlockPosition, BlockKind),
+        G: FnMut(ValidBlockPosition, BlockProperties),
     {
-        // Get the neighboring positions
-        let neighbors = [
-            ValidBlockPosition::new(pos.x() + 1, pos.y(), pos.z()),
-            ValidBlockPosition::new(pos.x() - 1, pos.y(), pos.z()),
-            ValidBlockPosition::new(pos.x(), pos.y() + 1, pos.z()),
-            ValidBlockPosition::new(pos.x(), pos.y() - 1, pos.z()),
-            ValidBlockPosition::new(pos.x(), pos.y(), pos.z() + 1),
-            ValidBlockPosition::new(pos.x(), pos.y(), pos.z() - 1),
-        ];
-        
-        for neighbor_pos in neighbors.iter().flatten() {
+        for neighbor_pos in neighbors(pos).iter().flatten() {
             if let Some((kind, _)) = block_getter(*neighbor_pos) {
                 // Schedule an update for the neighboring block
                 self.schedule_block_update(*neighbor_pos, kind, 1, 0);
@@ -195,8 +292,11 @@ impl BlockWorldIntegration {
     ) 
     where
         F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
-        G: FnMut(ValidBlockPosition, BlockKind),
+        G: FnMut(ValidBlockPosition, BlockProperties),
     {
+        self.update_ticking_index(pos, new_block);
+        self.mark_chunk_dirty(pos.chunk());
+
         // Propagate changes to neighbors
         self.propagate_block_update(pos, &block_getter, &block_setter);
         
@@ -205,12 +305,80 @@ impl BlockWorldIntegration {
             self.tick_executor.schedule_tick(
                 (pos.x() as i32, pos.y() as i32, pos.z() as i32),
                 new_block,
-                Duration::from_millis(0),
+                0,
                 0,
             );
         }
     }
     
+    /// Sets many blocks at once, e.g. for world edits.
+    ///
+    /// Unlike calling `on_block_changed` for each block, neighbor updates
+    /// are only scheduled once for each block bordering the edited region,
+    /// since blocks inside it have all just been replaced anyway.
+    pub fn apply_block_batch<F, G>(
+        &mut self,
+        changes: &[(ValidBlockPosition, BlockKind)],
+        block_getter: F,
+        mut block_setter: G,
+    )
+    where
+        F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
+        G: FnMut(ValidBlockPosition, BlockProperties),
+    {
+        let changed: AHashSet<ValidBlockPosition> = changes.iter().map(|(pos, _)| *pos).collect();
+
+        for (pos, kind) in changes {
+            block_setter(*pos, kind.default_properties());
+            self.update_ticking_index(*pos, *kind);
+            self.mark_chunk_dirty(pos.chunk());
+            if kind.receives_random_ticks() {
+                self.tick_executor.schedule_tick(
+                    to_tuple(*pos),
+                    *kind,
+                    0,
+                    0,
+                );
+            }
+        }
+
+        // A single update pass over the blocks bordering the region
+        let mut border = AHashSet::new();
+        for (pos, _) in changes {
+            for neighbor_pos in neighbors(*pos).iter().flatten() {
+                if changed.contains(neighbor_pos) || !border.insert(*neighbor_pos) {
+                    continue;
+                }
+                if let Some((kind, _)) = block_getter(*neighbor_pos) {
+                    self.schedule_block_update(*neighbor_pos, kind, 1, 0);
+                }
+            }
+        }
+    }
+    
+    /// Gets the redstone power available to a component at `pos`: the
+    /// highest level it receives directly from adjacent wire, repeaters,
+    /// torches, redstone blocks, levers and buttons, or indirectly through
+    /// an adjacent block those strongly power.
+    pub fn power_at<F>(&self, pos: ValidBlockPosition, block_getter: F) -> u8
+    where
+        F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
+    {
+        let view = |p| from_tuple(p).and_then(&block_getter);
+        power::power_at(&view, to_tuple(pos))
+    }
+
+    /// Gets the power the block at `pos` is strongly powered with, which
+    /// it passes on to adjacent components. Only full, opaque blocks
+    /// can be strongly powered.
+    pub fn strong_power_at<F>(&self, pos: ValidBlockPosition, block_getter: F) -> u8
+    where
+        F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
+    {
+        let view = |p| from_tuple(p).and_then(&block_getter);
+        power::strong_power_at(&view, to_tuple(pos))
+    }
+    
     /// Get a reference to the tick executor
     pub fn tick_executor(&self) -> &BlockTickExecutor {
         &self.tick_executor
@@ -220,4 +388,279 @@ impl BlockWorldIntegration {
     pub fn tick_executor_mut(&mut self) -> &mut BlockTickExecutor {
         &mut self.tick_executor
     }
-}
\ No newline at end of file
+}
+
+/// Scans a whole chunk for blocks receiving random ticks
+fn scan_ticking_blocks<F>(chunk: ChunkPosition, block_getter: &F) -> AHashSet<ValidBlockPosition>
+where
+    F: Fn(ValidBlockPosition) -> Option<(BlockKind, BlockProperties)>,
+{
+    let mut ticking_blocks = AHashSet::new();
+    for y in 0..CHUNK_HEIGHT {
+        for z in 0..16 {
+            for x in 0..16 {
+                let block_pos = ValidBlockPosition::new(
+                    chunk.x * 16 + x as i32,
+                    y as i32,
+                    chunk.z * 16 + z as i32,
+                ).unwrap();
+
+                if let Some((kind, _)) = block_getter(block_pos) {
+                    if kind.receives_random_ticks() {
+                        ticking_blocks.insert(block_pos);
+                    }
+                }
+            }
+        }
+    }
+    ticking_blocks
+}
+
+/// The positions directly adjacent to `pos`, or `None` outside the world
+fn neighbors(pos: ValidBlockPosition) -> [Option<ValidBlockPosition>; 6] {
+    [
+        ValidBlockPosition::new(pos.x() + 1, pos.y(), pos.z()),
+        ValidBlockPosition::new(pos.x() - 1, pos.y(), pos.z()),
+        ValidBlockPosition::new(pos.x(), pos.y() + 1, pos.z()),
+        ValidBlockPosition::new(pos.x(), pos.y() - 1, pos.z()),
+        ValidBlockPosition::new(pos.x(), pos.y(), pos.z() + 1),
+        ValidBlockPosition::new(pos.x(), pos.y(), pos.z() - 1),
+    ]
+}
+
+fn to_tuple(pos: ValidBlockPosition) -> (i32, i32, i32) {
+    (pos.x(), pos.y(), pos.z())
+}
+
+fn from_tuple(pos: (i32, i32, i32)) -> Option<ValidBlockPosition> {
+    ValidBlockPosition::new(pos.0, pos.1, pos.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::initialize_block_world_integration;
+
+    type TestWorld = RefCell<HashMap<ValidBlockPosition, BlockKind>>;
+
+    /// Runs one random tick pass, returning every position the pass looked up
+    fn random_tick_pass(
+        integration: &mut BlockWorldIntegration,
+        world: &TestWorld,
+        chunks: &[ChunkPosition],
+    ) -> AHashSet<ValidBlockPosition> {
+        let queried = RefCell::new(AHashSet::new());
+        integration.process_random_ticks(
+            |p| {
+                queried.borrow_mut().insert(p);
+                world.borrow().get(&p).map(|kind| (*kind, kind.default_properties()))
+            },
+            |_| 15,
+            |p, properties| {
+                world.borrow_mut().insert(p, properties.kind());
+            },
+            chunks,
+        );
+        queried.into_inner()
+    }
+
+    #[test]
+    fn random_ticks_only_visit_indexed_blocks() {
+        let chunk = ChunkPosition::new(0, 0);
+        let chunks = [chunk];
+
+        let mut integration = initialize_block_world_integration();
+        let world: TestWorld = RefCell::new(HashMap::new());
+
+        // The first pass indexes the empty chunk with a full scan
+        random_tick_pass(&mut integration, &world, &chunks);
+        assert!(integration.ticking_blocks(chunk).unwrap().is_empty());
+
+        // Keep the sapling from growing, which would look up its surroundings
+        integration
+            .tick_executor_mut()
+            .probabilities_mut()
+            .set_random_tick(BlockKind::OakSapling, 0.0);
+        let sapling = ValidBlockPosition::new(3, 64, 5).unwrap();
+        world.borrow_mut().insert(sapling, BlockKind::OakSapling);
+        integration.on_block_changed(
+            sapling,
+            BlockKind::OakSapling,
+            |p| world.borrow().get(&p).map(|kind| (*kind, kind.default_properties())),
+            |_, _| {},
+        );
+
+        let queried = random_tick_pass(&mut integration, &world, &chunks);
+        assert_eq!(queried.into_iter().collect::<Vec<_>>(), vec![sapling]);
+        assert!(integration.ticking_blocks(chunk).unwrap().contains(&sapling));
+
+        world.borrow_mut().insert(sapling, BlockKind::Air);
+        integration.on_block_changed(sapling, BlockKind::Air, |_| None, |_, _| {});
+        assert!(integration.ticking_blocks(chunk).unwrap().is_empty());
+        assert!(random_tick_pass(&mut integration, &world, &chunks).is_empty());
+    }
+
+    #[test]
+    fn batch_updates_each_border_block_once() {
+        let mut integration = initialize_block_world_integration();
+        let world: TestWorld = RefCell::new(HashMap::new());
+        for x in -2..=2 {
+            for y in 62..=66 {
+                for z in -2..=2 {
+                    let pos = ValidBlockPosition::new(x, y, z).unwrap();
+                    world.borrow_mut().insert(pos, BlockKind::Stone);
+                }
+            }
+        }
+
+        let mut changes = Vec::new();
+        for x in -1..=1 {
+            for y in 63..=65 {
+                for z in -1..=1 {
+                    changes.push((ValidBlockPosition::new(x, y, z).unwrap(), BlockKind::Glass));
+                }
+            }
+        }
+        integration.apply_block_batch(
+            &changes,
+            |p| world.borrow().get(&p).map(|kind| (*kind, kind.default_properties())),
+            |p, properties| {
+                world.borrow_mut().insert(p, properties.kind());
+            },
+        );
+
+        assert!(changes
+            .iter()
+            .all(|(pos, _)| world.borrow()[pos] == BlockKind::Glass));
+
+        // One update per block touching a face of the cube: 6 faces of 3x3
+        let updated: AHashSet<ValidBlockPosition> = integration
+            .pending_updates
+            .iter()
+            .map(|update| update.position)
+            .collect();
+        assert_eq!(integration.pending_updates.len(), 54);
+        assert_eq!(updated.len(), 54);
+        assert!(updated.iter().all(|pos| world.borrow()[pos] == BlockKind::Stone));
+    }
+
+    #[test]
+    fn block_changes_mark_their_chunks_dirty() {
+        let mut integration = initialize_block_world_integration();
+        let world: TestWorld = RefCell::new(HashMap::new());
+        let getter = |p| world.borrow().get(&p).map(|kind: &BlockKind| (*kind, kind.default_properties()));
+
+        // Blocks in two chunks, away from chunk borders
+        let first = ValidBlockPosition::new(4, 64, 4).unwrap();
+        let second = ValidBlockPosition::new(-12, 70, 20).unwrap();
+        world.borrow_mut().insert(first, BlockKind::Stone);
+        integration.on_block_changed(first, BlockKind::Stone, &getter, |_, _| {});
+        integration.schedule_block_update(second, BlockKind::Stone, 5, 0);
+
+        let dirty = integration.take_dirty_chunks();
+        let expected: AHashSet<ChunkPosition> = vec![ChunkPosition::new(0, 0), ChunkPosition::new(-1, 1)]
+            .into_iter()
+            .collect();
+        assert_eq!(dirty, expected);
+        assert!(!integration.is_chunk_dirty(ChunkPosition::new(0, 0)));
+        assert!(integration.take_dirty_chunks().is_empty());
+    }
+
+    #[test]
+    fn scheduled_updates_are_listed_per_chunk() {
+        let mut integration = initialize_block_world_integration();
+        let inside = ValidBlockPosition::new(4, 64, 4).unwrap();
+        let outside = ValidBlockPosition::new(20, 64, 4).unwrap();
+        integration.schedule_block_update(inside, BlockKind::Stone, 5, 0);
+        integration.schedule_block_update(outside, BlockKind::Stone, 5, 0);
+        integration
+            .tick_executor_mut()
+            .schedule_tick((4, 65, 4), BlockKind::Water, 3, 1);
+
+        let mut updates = integration.scheduled_updates_in(ChunkPosition::new(0, 0));
+        updates.sort_by_key(|update| update.delay);
+        assert_eq!(
+            updates,
+            vec![
+                BlockUpdate {
+                    position: ValidBlockPosition::new(4, 65, 4).unwrap(),
+                    kind: BlockKind::Water,
+                    delay: 3,
+                    priority: 1,
+                },
+                BlockUpdate {
+                    position: inside,
+                    kind: BlockKind::Stone,
+                    delay: 5,
+                    priority: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn saved_updates_count_down_from_when_they_were_scheduled() {
+        let mut integration = initialize_block_world_integration();
+        let run_ticks = |integration: &mut BlockWorldIntegration, ticks| {
+            for _ in 0..ticks {
+                integration.update(|_| None, |_| 0, |_, _| {}, &[]);
+            }
+        };
+        let early = ValidBlockPosition::new(4, 64, 4).unwrap();
+        let late = ValidBlockPosition::new(5, 64, 4).unwrap();
+
+        run_ticks(&mut integration, 10);
+        integration.schedule_block_update(early, BlockKind::Stone, 5, 0);
+        run_ticks(&mut integration, 3);
+        integration.schedule_block_update(late, BlockKind::Stone, 5, 0);
+
+        let mut updates = integration.scheduled_updates_in(ChunkPosition::new(0, 0));
+        updates.sort_by_key(|update| update.delay);
+        let delays: Vec<(ValidBlockPosition, u32)> = updates
+            .iter()
+            .map(|update| (update.position, update.delay))
+            .collect();
+        assert_eq!(delays, vec![(early, 2), (late, 5)]);
+
+        // Each update leaves the queue once it is due
+        run_ticks(&mut integration, 2);
+        assert_eq!(integration.pending_updates.len(), 1);
+        run_ticks(&mut integration, 3);
+        assert!(integration.pending_updates.is_empty());
+    }
+
+    #[test]
+    fn lever_strongly_powers_its_wall() {
+        let integration = initialize_block_world_integration();
+        let at = |x, y, z| ValidBlockPosition::new(x, y, z).unwrap();
+        let mut world = HashMap::new();
+        for x in -2..=2 {
+            for z in -2..=2 {
+                world.insert(at(x, 64, z), BlockKind::Stone.default_properties());
+            }
+        }
+        // A lever on the east face of the stone at the origin
+        let mut lever = BlockProperties::new(BlockKind::Lever);
+        lever.set("face", "wall").set("facing", "east").set("powered", "true");
+        world.insert(at(1, 64, 0), lever.clone());
+        let getter = |p: ValidBlockPosition| world.get(&p).map(|props: &BlockProperties| (props.kind(), props.clone()));
+
+        // The wall the lever is on is strongly powered and powers its neighbors
+        assert_eq!(integration.strong_power_at(at(0, 64, 0), &getter), 15);
+        assert_eq!(integration.power_at(at(-1, 64, 0), &getter), 15);
+        assert_eq!(integration.power_at(at(0, 65, 0), &getter), 15);
+
+        // Other blocks touching the lever are only weakly powered by it
+        assert_eq!(integration.strong_power_at(at(1, 64, 1), &getter), 0);
+        assert_eq!(integration.power_at(at(1, 65, 0), &getter), 15);
+        assert_eq!(integration.power_at(at(2, 64, 1), &getter), 0);
+
+        lever.set("powered", "false");
+        world.insert(at(1, 64, 0), lever);
+        let getter = |p: ValidBlockPosition| world.get(&p).map(|props: &BlockProperties| (props.kind(), props.clone()));
+        assert_eq!(integration.power_at(at(-1, 64, 0), &getter), 0);
+    }
+}
@@ -0,0 +1,191 @@
+use libcraft_core::BlockFace;
+
+use crate::{BlockKind, BlockProperties, SimplifiedBlockKind};
+
+/// The rough shape of a block's collision box, as far as
+/// movement and pathfinding are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollisionShape {
+    /// Nothing to collide with, e.g. air, flowers or torches
+    Empty,
+    /// A full cube
+    Full,
+    /// A slab in the lower half of the block
+    BottomSlab,
+    /// A slab in the upper half of the block
+    TopSlab,
+    /// A fence or wall, which is 1.5 blocks tall
+    Fence,
+    /// A closed fence gate, which is 1.5 blocks tall like a fence
+    ClosedGate,
+    /// An open fence gate, which can be walked through
+    OpenGate,
+}
+
+impl CollisionShape {
+    /// Height of the top of the shape above the bottom of its block.
+    pub fn height(self) -> f64 {
+        match self {
+            CollisionShape::Empty | CollisionShape::OpenGate => 0.0,
+            CollisionShape::BottomSlab => 0.5,
+            CollisionShape::Full | CollisionShape::TopSlab => 1.0,
+            CollisionShape::Fence | CollisionShape::ClosedGate => 1.5,
+        }
+    }
+
+    /// Whether entities can move through the block without colliding.
+    pub fn is_empty(self) -> bool {
+        self.height() == 0.0
+    }
+
+    /// Whether the given face is a full square that other blocks,
+    /// such as torches and doors, can be attached to.
+    pub fn is_face_sturdy(self, face: BlockFace) -> bool {
+        match self {
+            CollisionShape::Full => true,
+            CollisionShape::BottomSlab => face == BlockFace::Bottom,
+            CollisionShape::TopSlab => face == BlockFace::Top,
+            CollisionShape::Empty
+            | CollisionShape::Fence
+            | CollisionShape::ClosedGate
+            | CollisionShape::OpenGate => false,
+        }
+    }
+
+    /// Whether the centre of the given face can hold up a small
+    /// block, such as a torch standing on it.
+    ///
+    /// Sturdy faces always can, and so can the tops of fences and walls.
+    pub fn supports_center(self, face: BlockFace) -> bool {
+        self.is_face_sturdy(face) || (self == CollisionShape::Fence && face == BlockFace::Top)
+    }
+}
+
+fn is_wall(kind: BlockKind) -> bool {
+    kind.name().ends_with("_wall")
+}
+
+impl BlockKind {
+    /// The collision shape of this block in its default state.
+    ///
+    /// Use [`BlockProperties::collision_shape`] when the block's state is
+    /// known, since slabs and fence gates depend on it.
+    pub fn collision_shape(&self) -> CollisionShape {
+        self.default_properties().collision_shape()
+    }
+
+    /// Whether the given face is sturdy in the block's default state.
+    pub fn is_face_sturdy(&self, face: BlockFace) -> bool {
+        self.collision_shape().is_face_sturdy(face)
+    }
+
+    /// Whether the centre of the given face can hold up a small block
+    /// in the block's default state.
+    pub fn supports_center(&self, face: BlockFace) -> bool {
+        self.collision_shape().supports_center(face)
+    }
+}
+
+impl BlockProperties {
+    /// The collision shape of the block in this state.
+    pub fn collision_shape(&self) -> CollisionShape {
+        let kind = self.kind();
+        match kind.simplified_kind() {
+            SimplifiedBlockKind::Slab => match self.get("type").map(String::as_str) {
+                Some("top") => CollisionShape::TopSlab,
+                Some("double") => CollisionShape::Full,
+                _ => CollisionShape::BottomSlab,
+            },
+            SimplifiedBlockKind::FenceGate => {
+                if self.get("open").map(String::as_str) == Some("true") {
+                    CollisionShape::OpenGate
+                } else {
+                    CollisionShape::ClosedGate
+                }
+            }
+            SimplifiedBlockKind::Fence => CollisionShape::Fence,
+            _ if is_wall(kind) => CollisionShape::Fence,
+            // Entities sink into powder snow rather than standing on it
+            _ if kind == BlockKind::PowderSnow => CollisionShape::Empty,
+            _ if kind.solid() => CollisionShape::Full,
+            _ => CollisionShape::Empty,
+        }
+    }
+
+    /// Whether the given face of the block is sturdy in this state.
+    pub fn is_face_sturdy(&self, face: BlockFace) -> bool {
+        self.collision_shape().is_face_sturdy(face)
+    }
+
+    /// Whether the centre of the given face can hold up a small block
+    /// in this state.
+    pub fn supports_center(&self, face: BlockFace) -> bool {
+        self.collision_shape().supports_center(face)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FACES: [BlockFace; 6] = [
+        BlockFace::Bottom,
+        BlockFace::Top,
+        BlockFace::North,
+        BlockFace::South,
+        BlockFace::West,
+        BlockFace::East,
+    ];
+
+    #[test]
+    fn full_block_is_sturdy_on_all_faces() {
+        assert_eq!(BlockKind::Stone.collision_shape(), CollisionShape::Full);
+        assert!(FACES
+            .iter()
+            .all(|face| BlockKind::Stone.is_face_sturdy(*face)));
+        assert!(!FACES
+            .iter()
+            .any(|face| BlockKind::Air.is_face_sturdy(*face)));
+    }
+
+    #[test]
+    fn slab_is_sturdy_only_on_occupied_face() {
+        let mut slab = BlockProperties::new(BlockKind::StoneSlab);
+        slab.set("type", "bottom");
+        let sturdy: Vec<BlockFace> = FACES
+            .iter()
+            .copied()
+            .filter(|face| slab.is_face_sturdy(*face))
+            .collect();
+        assert_eq!(sturdy, vec![BlockFace::Bottom]);
+
+        slab.set("type", "top");
+        assert!(slab.is_face_sturdy(BlockFace::Top));
+        assert!(!slab.is_face_sturdy(BlockFace::Bottom));
+
+        slab.set("type", "double");
+        assert!(FACES.iter().all(|face| slab.is_face_sturdy(*face)));
+    }
+
+    #[test]
+    fn fence_blocks_pathing_and_supports_only_its_center() {
+        let shape = BlockKind::OakFence.collision_shape();
+        assert_eq!(shape, CollisionShape::Fence);
+        assert!(shape.height() > 1.0);
+        assert!(!FACES.iter().any(|face| shape.is_face_sturdy(*face)));
+        let center: Vec<BlockFace> = FACES
+            .iter()
+            .copied()
+            .filter(|face| shape.supports_center(*face))
+            .collect();
+        assert_eq!(center, vec![BlockFace::Top]);
+
+        assert_eq!(
+            BlockKind::CobblestoneWall.collision_shape(),
+            CollisionShape::Fence
+        );
+        assert!(BlockKind::CobblestoneWall.supports_center(BlockFace::Top));
+        assert!(!BlockKind::OakFenceGate.supports_center(BlockFace::Top));
+        assert!(BlockKind::Stone.supports_center(BlockFace::Top));
+    }
+}
@@ -0,0 +1,100 @@
+use libcraft_core::block::Instrument;
+
+use crate::{BlockKind, SimplifiedBlockKind};
+
+/// Name fragments of blocks made of wood
+const WOODEN: [&str; 9] = [
+    "planks",
+    "_log",
+    "_wood",
+    "_stem",
+    "_hyphae",
+    "bookshelf",
+    "crafting_table",
+    "chest",
+    "note_block",
+];
+
+/// Name fragments of blocks made of stone
+const STONY: [&str; 15] = [
+    "stone",
+    "_ore",
+    "brick",
+    "obsidian",
+    "netherrack",
+    "terracotta",
+    "quartz",
+    "prismarine",
+    "andesite",
+    "diorite",
+    "granite",
+    "bedrock",
+    "basalt",
+    "deepslate",
+    "nylium",
+];
+
+impl BlockKind {
+    /// The instrument a note block plays when standing on this block
+    pub fn note_block_instrument(&self) -> Instrument {
+        match self {
+            BlockKind::GoldBlock => return Instrument::Bell,
+            BlockKind::Clay => return Instrument::Flute,
+            BlockKind::PackedIce => return Instrument::Chime,
+            BlockKind::BoneBlock => return Instrument::Xylophone,
+            BlockKind::IronBlock => return Instrument::IronXylophone,
+            BlockKind::SoulSand => return Instrument::CowBell,
+            BlockKind::Pumpkin => return Instrument::Didgeridoo,
+            BlockKind::EmeraldBlock => return Instrument::Bit,
+            BlockKind::HayBlock => return Instrument::Banjo,
+            BlockKind::Glowstone => return Instrument::Pling,
+            BlockKind::SeaLantern | BlockKind::Beacon => return Instrument::Hat,
+            _ => {}
+        }
+
+        match self.simplified_kind() {
+            SimplifiedBlockKind::Wool => return Instrument::Guitar,
+            SimplifiedBlockKind::Sand
+            | SimplifiedBlockKind::RedSand
+            | SimplifiedBlockKind::Gravel
+            | SimplifiedBlockKind::ConcretePowder => return Instrument::Snare,
+            SimplifiedBlockKind::Glass
+            | SimplifiedBlockKind::StainedGlass
+            | SimplifiedBlockKind::StainedGlassPane => return Instrument::Hat,
+            SimplifiedBlockKind::Concrete => return Instrument::Basedrum,
+            _ => {}
+        }
+
+        let name = self.name();
+        if WOODEN.iter().any(|wood| name.contains(wood)) {
+            Instrument::Bass
+        } else if STONY.iter().any(|stone| name.contains(stone)) {
+            Instrument::Basedrum
+        } else {
+            Instrument::Harp
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instrument_depends_on_material() {
+        assert_eq!(
+            BlockKind::OakPlanks.note_block_instrument(),
+            Instrument::Bass
+        );
+        assert_eq!(
+            BlockKind::Stone.note_block_instrument(),
+            Instrument::Basedrum
+        );
+        assert_eq!(BlockKind::Sand.note_block_instrument(), Instrument::Snare);
+        assert_eq!(
+            BlockKind::GoldBlock.note_block_instrument(),
+            Instrument::Bell
+        );
+        assert_eq!(BlockKind::Dirt.note_block_instrument(), Instrument::Harp);
+    }
+}
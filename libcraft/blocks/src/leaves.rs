@@ -0,0 +1,198 @@
+use std::collections::{HashSet, VecDeque};
+
+use libcraft_items::Item;
+use rand::Rng;
+
+use crate::{BlockKind, BlockProperties, SimplifiedBlockKind};
+
+/// Maximum distance (in blocks, following connected leaves) a log may be
+/// from a leaf block for the leaf to be kept alive
+pub const MAX_LOG_DISTANCE: u32 = 6;
+/// Chance that a decaying leaf block drops its sapling
+const SAPLING_DROP_CHANCE: f32 = 0.05;
+
+const NEIGHBOR_OFFSETS: [(i32, i32, i32); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// Returns whether the given block is a kind of leaves
+pub fn is_leaves(kind: BlockKind) -> bool {
+    kind.simplified_kind() == SimplifiedBlockKind::Leaves
+}
+
+/// Returns whether the given block keeps leaves alive
+pub fn is_log(kind: BlockKind) -> bool {
+    kind.simplified_kind() == SimplifiedBlockKind::Log
+}
+
+/// Returns whether the leaves were placed by a player and therefore never decay
+pub fn is_persistent(properties: &BlockProperties) -> bool {
+    properties.get("persistent").map_or(false, |v| v == "true")
+}
+
+/// Returns the sapling a leaf block may drop when it decays
+pub fn sapling_for(kind: BlockKind) -> Option<Item> {
+    match kind {
+        BlockKind::OakLeaves => Some(Item::OakSapling),
+        BlockKind::SpruceLeaves => Some(Item::SpruceSapling),
+        BlockKind::BirchLeaves => Some(Item::BirchSapling),
+        BlockKind::JungleLeaves => Some(Item::JungleSapling),
+        BlockKind::AcaciaLeaves => Some(Item::AcaciaSapling),
+        BlockKind::DarkOakLeaves => Some(Item::DarkOakSapling),
+        _ => None,
+    }
+}
+
+/// Performs a bounded breadth-first search through connected leaves,
+/// returning whether a log is reachable within `max_distance` steps of `pos`.
+pub fn has_log_within<F>(pos: (i32, i32, i32), block_getter: &F, max_distance: u32) -> bool
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(pos);
+    queue.push_back((pos, 0));
+
+    while let Some((current, distance)) = queue.pop_front() {
+        if distance >= max_distance {
+            continue;
+        }
+
+        for (dx, dy, dz) in NEIGHBOR_OFFSETS.iter() {
+            let neighbor = (current.0 + dx, current.1 + dy, current.2 + dz);
+            if !visited.insert(neighbor) {
+                continue;
+            }
+
+            match block_getter(neighbor) {
+                Some((kind, _)) if is_log(kind) => return true,
+                Some((kind, _)) if is_leaves(kind) => queue.push_back((neighbor, distance + 1)),
+                _ => {}
+            }
+        }
+    }
+
+    false
+}
+
+/// Checks whether the leaves at `pos` are still supported by a log,
+/// removing them if not.
+///
+/// Returns the sapling to drop, if the leaves decayed and the drop chance succeeded.
+pub fn tick_leaves<R, F, G>(
+    rng: &mut R,
+    pos: (i32, i32, i32),
+    kind: BlockKind,
+    properties: &BlockProperties,
+    block_getter: &F,
+    block_setter: &mut G,
+) -> Option<Item>
+where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    if is_persistent(properties) || has_log_within(pos, block_getter, MAX_LOG_DISTANCE) {
+        return None;
+    }
+
+    block_setter(pos, BlockKind::Air.default_properties());
+
+    if rng.gen::<f32>() < SAPLING_DROP_CHANCE {
+        sapling_for(kind)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    type TestWorld = RefCell<HashMap<(i32, i32, i32), BlockProperties>>;
+
+    fn tick_all_leaves(world: &TestWorld, rng: &mut StdRng) {
+        let leaves: Vec<_> = world
+            .borrow()
+            .iter()
+            .filter(|(_, props)| is_leaves(props.kind()))
+            .map(|(pos, props)| (*pos, props.clone()))
+            .collect();
+
+        let getter = |p| {
+            world
+                .borrow()
+                .get(&p)
+                .map(|props: &BlockProperties| (props.kind(), props.clone()))
+        };
+        let mut setter = |p, properties| {
+            world.borrow_mut().insert(p, properties);
+        };
+
+        for (pos, props) in leaves {
+            tick_leaves(rng, pos, props.kind(), &props, &getter, &mut setter);
+        }
+    }
+
+    fn kind_at(world: &TestWorld, pos: (i32, i32, i32)) -> BlockKind {
+        world.borrow()[&pos].kind()
+    }
+
+    #[test]
+    fn leaves_far_from_remaining_log_decay() {
+        let world = RefCell::new(HashMap::new());
+        world
+            .borrow_mut()
+            .insert((0, 64, 0), BlockKind::OakLog.default_properties());
+        world
+            .borrow_mut()
+            .insert((10, 64, 0), BlockKind::OakLog.default_properties());
+        for x in 1..10 {
+            world
+                .borrow_mut()
+                .insert((x, 64, 0), BlockKind::OakLeaves.default_properties());
+        }
+
+        // Remove the second log; only leaves within reach of the first survive
+        world
+            .borrow_mut()
+            .insert((10, 64, 0), BlockKind::Air.default_properties());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        tick_all_leaves(&world, &mut rng);
+
+        for x in 1..=6 {
+            assert_eq!(kind_at(&world, (x, 64, 0)), BlockKind::OakLeaves);
+        }
+        for x in 7..10 {
+            assert_eq!(kind_at(&world, (x, 64, 0)), BlockKind::Air);
+        }
+    }
+
+    #[test]
+    fn persistent_leaves_never_decay() {
+        let world = RefCell::new(HashMap::new());
+        let mut persistent = BlockKind::OakLeaves.default_properties();
+        persistent.set("persistent", "true");
+        world.borrow_mut().insert((0, 64, 0), persistent);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..10 {
+            tick_all_leaves(&world, &mut rng);
+        }
+
+        assert_eq!(kind_at(&world, (0, 64, 0)), BlockKind::OakLeaves);
+    }
+}
@@ -11,6 +11,18 @@ mod block_ticking;
 mod tick_executor;
 mod chunk_integration;
 mod block_entity;
+mod block_view;
+mod spreading;
+mod leaves;
+mod sapling;
+mod chorus;
+mod tick_probabilities;
+mod transform;
+mod sculk;
+mod collision;
+mod placement;
+mod power;
+mod instrument;
 
 pub use block::BlockKind;
 pub use block_data::*;
@@ -22,8 +34,14 @@ pub use registration::BlockRegistry;
 pub use block_transitions::{BlockTransitionManager, BlockStateTransition, TransitionCondition};
 pub use block_ticking::{BlockTickScheduler, BlockTick, TickType};
 pub use tick_executor::BlockTickExecutor;
-pub use chunk_integration::BlockWorldIntegration;
-pub use block_entity::{BlockEntity, BlockEntityKind, BlockEntityData, BlockEntityManager, BlockEntityValue, 
+pub use tick_probabilities::{RandomTickProbabilities, UnknownBlockError};
+pub use transform::{Mirror, Rotation};
+pub use sculk::{is_sculk, spread_sculk, CATALYST_RADIUS};
+pub use collision::CollisionShape;
+pub use power::{button_press_ticks, MAX_POWER};
+pub use chunk_integration::{BlockUpdate, BlockWorldIntegration};
+pub use block_view::BlockView;
+pub use block_entity::{BlockEntity, BlockEntityChanged, BlockEntityKind, BlockEntityData, BlockEntityManager, BlockEntityMut, BlockEntityValue, 
                       create_block_entity, requires_block_entity, serialize_block_entity, deserialize_block_entity};
 
 // Add a convenience method to BlockKind
@@ -53,6 +71,17 @@ impl BlockKind {
                     .set("type", "single");
             },
             
+            BlockKind::OakLeaves | BlockKind::SpruceLeaves | BlockKind::BirchLeaves |
+            BlockKind::JungleLeaves | BlockKind::AcaciaLeaves | BlockKind::DarkOakLeaves => {
+                props.set("distance", "7")
+                    .set("persistent", "false");
+            },
+            
+            BlockKind::OakSapling | BlockKind::SpruceSapling | BlockKind::BirchSapling |
+            BlockKind::JungleSapling | BlockKind::AcaciaSapling | BlockKind::DarkOakSapling => {
+                props.set("stage", "0");
+            },
+            
             BlockKind::Lantern | BlockKind::SoulLantern => {
                 props.set("hanging", "false")
                     .set("waterlogged", "false");
@@ -128,6 +157,12 @@ impl BlockKind {
             BlockKind::CutCopperSlab | BlockKind::ExposedCutCopperSlab | BlockKind::WeatheredCutCopperSlab => true,
             BlockKind::BuddingAmethyst => true,
             BlockKind::PointedDripstone => true,
+            BlockKind::GrassBlock | BlockKind::Mycelium => true,
+            BlockKind::OakLeaves | BlockKind::SpruceLeaves | BlockKind::BirchLeaves |
+            BlockKind::JungleLeaves | BlockKind::AcaciaLeaves | BlockKind::DarkOakLeaves => true,
+            BlockKind::OakSapling | BlockKind::SpruceSapling | BlockKind::BirchSapling |
+            BlockKind::JungleSapling | BlockKind::AcaciaSapling | BlockKind::DarkOakSapling => true,
+            BlockKind::ChorusFlower => true,
             _ => false,
         }
     }
@@ -0,0 +1,203 @@
+use libcraft_core::BlockFace;
+
+use crate::{BlockKind, BlockProperties, BlockView, SimplifiedBlockKind};
+
+/// What a block needs from its surroundings to stay in place
+enum Support {
+    /// A sturdy top face on the block below
+    SturdyBelow,
+    /// A block below that can hold something up at the centre of
+    /// its top face, which includes fences and walls
+    CenterBelow,
+    /// A sturdy face on the block it is attached to, opposite its facing
+    SturdyBehind,
+    /// Anything but air below
+    AnyBelow,
+    /// Farmland below
+    Farmland,
+    /// Soil that plants grow in below
+    Soil,
+    /// The lower half of the same door below
+    DoorBelow,
+    /// Obsidian or more portal above, below and on both sides along its axis
+    PortalFrame,
+    /// End stone or chorus plant below, or a chorus plant beside it that is
+    /// itself supported
+    ChorusPlant,
+    /// End stone or chorus plant below, or a single chorus plant beside it
+    ChorusFlower,
+}
+
+fn support(properties: &BlockProperties) -> Option<Support> {
+    use SimplifiedBlockKind::*;
+    Some(match properties.kind().simplified_kind() {
+        RedstoneWire | Repeater | Comparator | Rail | PoweredRail | DetectorRail
+        | ActivatorRail => Support::SturdyBelow,
+        Torch
+        | RedstoneTorch
+        | SoulTorch
+        | WoodenPressurePlate
+        | StonePressurePlate
+        | LightWeightedPressurePlate
+        | HeavyWeightedPressurePlate
+        | PolishedBlackstonePressurePlate => Support::CenterBelow,
+        WallTorch | RedstoneWallTorch | SoulWallTorch => Support::SturdyBehind,
+        Carpet => Support::AnyBelow,
+        Wheat | Carrots | Potatoes | Beetroots => Support::Farmland,
+        Sapling | Flower | Grass | Fern => Support::Soil,
+        NetherPortal => Support::PortalFrame,
+        ChorusPlant => Support::ChorusPlant,
+        ChorusFlower => Support::ChorusFlower,
+        WoodenDoor | IronDoor => match properties.get("half").map(String::as_str) {
+            Some("upper") => Support::DoorBelow,
+            _ => Support::SturdyBelow,
+        },
+        _ => return None,
+    })
+}
+
+/// Offset towards the given horizontal facing, along with the face
+/// pointing that way
+pub(crate) fn facing_offset(facing: &str) -> Option<((i32, i32, i32), BlockFace)> {
+    Some(match facing {
+        "north" => ((0, 0, -1), BlockFace::North),
+        "south" => ((0, 0, 1), BlockFace::South),
+        "west" => ((-1, 0, 0), BlockFace::West),
+        "east" => ((1, 0, 0), BlockFace::East),
+        _ => return None,
+    })
+}
+
+impl BlockProperties {
+    /// Checks whether the block in this state has the support it needs
+    /// at `pos`, e.g. a torch needs a sturdy face to stand on.
+    ///
+    /// Blocks without support requirements can be placed anywhere.
+    pub fn can_be_placed_at(&self, pos: (i32, i32, i32), view: &dyn BlockView) -> bool {
+        let (x, y, z) = pos;
+        let below = view.block_at((x, y - 1, z));
+        match support(self) {
+            None => true,
+            Some(Support::SturdyBelow) => {
+                below.map_or(false, |(_, below)| below.is_face_sturdy(BlockFace::Top))
+            }
+            Some(Support::CenterBelow) => {
+                below.map_or(false, |(_, below)| below.supports_center(BlockFace::Top))
+            }
+            Some(Support::SturdyBehind) => {
+                // Wall-mounted blocks face away from the block they hang on,
+                // so they need the face of that block pointing the same way
+                match self.get("facing").and_then(|facing| facing_offset(facing)) {
+                    Some(((dx, dy, dz), face)) => view
+                        .block_at((x - dx, y - dy, z - dz))
+                        .map_or(false, |(_, behind)| behind.is_face_sturdy(face)),
+                    None => false,
+                }
+            }
+            Some(Support::AnyBelow) => below.map_or(false, |(kind, _)| kind != BlockKind::Air),
+            Some(Support::Farmland) => below.map_or(false, |(kind, _)| kind == BlockKind::Farmland),
+            Some(Support::Soil) => below.map_or(false, |(kind, _)| {
+                matches!(
+                    kind,
+                    BlockKind::GrassBlock
+                        | BlockKind::Dirt
+                        | BlockKind::CoarseDirt
+                        | BlockKind::Podzol
+                        | BlockKind::Farmland
+                )
+            }),
+            Some(Support::DoorBelow) => below.map_or(false, |(kind, _)| kind == self.kind()),
+            Some(Support::ChorusPlant) => {
+                let kind_at = |pos: (i32, i32, i32)| {
+                    view.block_at(pos).map_or(BlockKind::Air, |(kind, _)| kind)
+                };
+                let holds_plant =
+                    |kind: BlockKind| matches!(kind, BlockKind::ChorusPlant | BlockKind::EndStone);
+                // A plant with blocks above and below cannot branch off sideways
+                let enclosed = kind_at((x, y + 1, z)) != BlockKind::Air
+                    && kind_at((x, y - 1, z)) != BlockKind::Air;
+                for (dx, dz) in &[(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                    if kind_at((x + dx, y, z + dz)) == BlockKind::ChorusPlant {
+                        if enclosed {
+                            return false;
+                        }
+                        if holds_plant(kind_at((x + dx, y - 1, z + dz))) {
+                            return true;
+                        }
+                    }
+                }
+                holds_plant(kind_at((x, y - 1, z)))
+            }
+            Some(Support::ChorusFlower) => {
+                let kind_at = |pos: (i32, i32, i32)| {
+                    view.block_at(pos).map_or(BlockKind::Air, |(kind, _)| kind)
+                };
+                match kind_at((x, y - 1, z)) {
+                    BlockKind::ChorusPlant | BlockKind::EndStone => true,
+                    BlockKind::Air => {
+                        let beside: Vec<BlockKind> = [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                            .iter()
+                            .map(|(dx, dz)| kind_at((x + dx, y, z + dz)))
+                            .filter(|kind| *kind != BlockKind::Air)
+                            .collect();
+                        beside == [BlockKind::ChorusPlant]
+                    }
+                    _ => false,
+                }
+            }
+            Some(Support::PortalFrame) => {
+                let (dx, dz) = match self.get("axis").map(String::as_str) {
+                    Some("z") => (0, 1),
+                    _ => (1, 0),
+                };
+                [(dx, 0, dz), (-dx, 0, -dz), (0, 1, 0), (0, -1, 0)]
+                    .iter()
+                    .all(|(ox, oy, oz)| {
+                        view.block_at((x + ox, y + oy, z + oz))
+                            .map_or(false, |(kind, _)| {
+                                matches!(kind, BlockKind::Obsidian | BlockKind::NetherPortal)
+                            })
+                    })
+            }
+        }
+    }
+}
+
+impl BlockKind {
+    /// Checks whether the block in its default state has the support
+    /// it needs at `pos`. See [`BlockProperties::can_be_placed_at`].
+    pub fn can_be_placed_at(&self, pos: (i32, i32, i32), view: &dyn BlockView) -> bool {
+        self.default_properties().can_be_placed_at(pos, view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall_north_of_origin(pos: (i32, i32, i32)) -> Option<(BlockKind, BlockProperties)> {
+        let kind = match pos {
+            (0, 63, 0) => BlockKind::OakFence,
+            (0, 64, 1) => BlockKind::Stone,
+            _ => BlockKind::Air,
+        };
+        Some((kind, kind.default_properties()))
+    }
+
+    #[test]
+    fn torches_need_support() {
+        let view = &wall_north_of_origin as &dyn BlockView;
+        // Torches stand on fences, but rails need a full face
+        assert!(BlockKind::Torch.can_be_placed_at((0, 64, 0), view));
+        assert!(!BlockKind::Rail.can_be_placed_at((0, 64, 0), view));
+        assert!(BlockKind::Torch.can_be_placed_at((0, 65, 1), view));
+        assert!(!BlockKind::Torch.can_be_placed_at((3, 64, 3), view));
+        assert!(BlockKind::Stone.can_be_placed_at((5, 80, 5), view));
+
+        let mut wall_torch = BlockProperties::new(BlockKind::WallTorch);
+        wall_torch.set("facing", "north");
+        assert!(wall_torch.can_be_placed_at((0, 64, 0), view));
+        wall_torch.set("facing", "south");
+        assert!(!wall_torch.can_be_placed_at((0, 64, 0), view));
+    }
+}
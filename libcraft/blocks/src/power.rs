@@ -0,0 +1,212 @@
+use crate::placement::facing_offset;
+use crate::{BlockKind, BlockProperties, BlockView, CollisionShape, SimplifiedBlockKind};
+
+/// The highest redstone power level
+pub const MAX_POWER: u8 = 15;
+
+/// Returns how many ticks a button stays pressed, or `None`
+/// if the block is not a button
+pub fn button_press_ticks(kind: BlockKind) -> Option<u32> {
+    match kind.simplified_kind() {
+        SimplifiedBlockKind::StoneButton | SimplifiedBlockKind::PolishedBlackstoneButton => {
+            Some(20)
+        }
+        SimplifiedBlockKind::WoodenButton => Some(30),
+        _ => None,
+    }
+}
+
+const DIRECTIONS: [(i32, i32, i32); 6] = [
+    (0, 1, 0),
+    (0, -1, 0),
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+fn offset(pos: (i32, i32, i32), by: (i32, i32, i32)) -> (i32, i32, i32) {
+    (pos.0 + by.0, pos.1 + by.1, pos.2 + by.2)
+}
+
+fn negate(dir: (i32, i32, i32)) -> (i32, i32, i32) {
+    (-dir.0, -dir.1, -dir.2)
+}
+
+fn is_set(properties: &BlockProperties, name: &str) -> bool {
+    properties.get(name).map_or(false, |value| value == "true")
+}
+
+fn level(properties: &BlockProperties) -> u8 {
+    properties
+        .get("power")
+        .and_then(|power| power.parse().ok())
+        .unwrap_or(0)
+        .min(MAX_POWER)
+}
+
+/// Direction from a block to the block it is mounted on
+fn attached_direction(properties: &BlockProperties) -> Option<(i32, i32, i32)> {
+    if properties.kind().simplified_kind() == SimplifiedBlockKind::RedstoneTorch {
+        return Some((0, -1, 0));
+    }
+    match properties.get("face").map(String::as_str) {
+        Some("floor") => Some((0, -1, 0)),
+        Some("ceiling") => Some((0, 1, 0)),
+        // Wall-mounted blocks face away from the block they hang on
+        _ => properties
+            .get("facing")
+            .and_then(|facing| facing_offset(facing))
+            .map(|(dir, _)| negate(dir)),
+    }
+}
+
+/// Direction a repeater sends its signal, opposite the side it faces
+fn output_direction(properties: &BlockProperties) -> Option<(i32, i32, i32)> {
+    properties
+        .get("facing")
+        .and_then(|facing| facing_offset(facing))
+        .map(|(dir, _)| negate(dir))
+}
+
+/// Whether redstone wire sends its power towards `dir`. Wire always powers
+/// the block below it; a wire connected to any side only powers those sides,
+/// while an unconnected dot powers all four.
+fn wire_points_to(properties: &BlockProperties, dir: (i32, i32, i32)) -> bool {
+    let connected = |side: &str| properties.get(side).map_or(false, |value| value != "none");
+    let side = match dir {
+        (0, -1, 0) => return true,
+        (0, 1, 0) => return false,
+        (0, 0, -1) => "north",
+        (0, 0, 1) => "south",
+        (-1, 0, 0) => "west",
+        _ => "east",
+    };
+    connected(side)
+        || !["north", "south", "west", "east"]
+            .iter()
+            .any(|side| connected(*side))
+}
+
+/// Power a component emits into the block in direction `dir` from it,
+/// which that block can use but not pass on
+fn direct_power(properties: &BlockProperties, dir: (i32, i32, i32)) -> u8 {
+    use SimplifiedBlockKind::*;
+    match properties.kind().simplified_kind() {
+        RedstoneBlock => MAX_POWER,
+        Lever
+        | StoneButton
+        | WoodenButton
+        | PolishedBlackstoneButton
+        | TripwireHook
+        | WoodenPressurePlate
+        | StonePressurePlate
+        | PolishedBlackstonePressurePlate
+            if is_set(properties, "powered") =>
+        {
+            MAX_POWER
+        }
+        LightWeightedPressurePlate | HeavyWeightedPressurePlate | DaylightDetector | Target => {
+            level(properties)
+        }
+        RedstoneTorch | RedstoneWallTorch
+            if is_set(properties, "lit") && attached_direction(properties) != Some(dir) =>
+        {
+            MAX_POWER
+        }
+        Repeater if is_set(properties, "powered") && output_direction(properties) == Some(dir) => {
+            MAX_POWER
+        }
+        RedstoneWire if wire_points_to(properties, dir) => level(properties),
+        _ => 0,
+    }
+}
+
+/// Power a component emits into the block in direction `dir` from it
+/// strongly enough for that block to pass it on to its own neighbors
+fn strong_power(properties: &BlockProperties, dir: (i32, i32, i32)) -> u8 {
+    use SimplifiedBlockKind::*;
+    match properties.kind().simplified_kind() {
+        Lever | StoneButton | WoodenButton | PolishedBlackstoneButton | TripwireHook
+            if attached_direction(properties) == Some(dir) =>
+        {
+            direct_power(properties, dir)
+        }
+        WoodenPressurePlate
+        | StonePressurePlate
+        | PolishedBlackstonePressurePlate
+        | LightWeightedPressurePlate
+        | HeavyWeightedPressurePlate
+            if dir == (0, -1, 0) =>
+        {
+            direct_power(properties, dir)
+        }
+        RedstoneTorch | RedstoneWallTorch if dir == (0, 1, 0) => direct_power(properties, dir),
+        Repeater => direct_power(properties, dir),
+        _ => 0,
+    }
+}
+
+/// Whether a block passes the power it receives on to its neighbors
+fn conducts(properties: &BlockProperties) -> bool {
+    properties.collision_shape() == CollisionShape::Full
+        && !properties.kind().transparent()
+        // Redstone blocks are sources, not conductors
+        && properties.kind().simplified_kind() != SimplifiedBlockKind::RedstoneBlock
+}
+
+/// Power a block at `pos` receives from the components around it, not
+/// counting the one at `exclude`. Wire only counts when `include_wire` is set,
+/// since a block powered by wire can activate components but not other wire.
+fn received_power(
+    view: &dyn BlockView,
+    pos: (i32, i32, i32),
+    exclude: Option<(i32, i32, i32)>,
+    include_wire: bool,
+) -> u8 {
+    DIRECTIONS
+        .iter()
+        .map(|dir| offset(pos, *dir))
+        .filter(|source| Some(*source) != exclude)
+        .filter_map(|source| view.block_at(source).map(|(_, props)| (source, props)))
+        .map(|(source, props)| {
+            let towards = (pos.0 - source.0, pos.1 - source.1, pos.2 - source.2);
+            let strong = strong_power(&props, towards);
+            if include_wire && props.kind().simplified_kind() == SimplifiedBlockKind::RedstoneWire {
+                strong.max(direct_power(&props, towards))
+            } else {
+                strong
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Power the block at `pos` is strongly powered with, i.e. the power a
+/// conducting block passes on to every adjacent component
+pub(crate) fn strong_power_at(view: &dyn BlockView, pos: (i32, i32, i32)) -> u8 {
+    match view.block_at(pos) {
+        Some((_, props)) if conducts(&props) => received_power(view, pos, None, false),
+        _ => 0,
+    }
+}
+
+/// Power available to a component at `pos`, either directly from adjacent
+/// components or through adjacent blocks powered by them
+pub(crate) fn power_at(view: &dyn BlockView, pos: (i32, i32, i32)) -> u8 {
+    DIRECTIONS
+        .iter()
+        .map(|dir| offset(pos, *dir))
+        .filter_map(|neighbor| view.block_at(neighbor).map(|(_, props)| (neighbor, props)))
+        .map(|(neighbor, props)| {
+            let towards = (pos.0 - neighbor.0, pos.1 - neighbor.1, pos.2 - neighbor.2);
+            let indirect = if conducts(&props) {
+                received_power(view, neighbor, Some(pos), true)
+            } else {
+                0
+            };
+            direct_power(&props, towards).max(indirect)
+        })
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,287 @@
+use rand::Rng;
+
+use crate::{BlockKind, BlockProperties, SimplifiedBlockKind};
+
+/// Minimum light level above a sapling for it to grow
+const MIN_GROWTH_LIGHT: u8 = 9;
+
+/// Describes the tree a sapling grows into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeShape {
+    /// The block used for the trunk
+    pub log: BlockKind,
+    /// The block used for the canopy
+    pub leaves: BlockKind,
+    /// Minimum trunk height
+    pub min_height: i32,
+    /// Maximum additional random trunk height
+    pub extra_height: i32,
+}
+
+/// Returns whether the given block is a sapling
+pub fn is_sapling(kind: BlockKind) -> bool {
+    kind.simplified_kind() == SimplifiedBlockKind::Sapling
+}
+
+/// Returns the tree shape grown by the given sapling
+pub fn tree_for(sapling: BlockKind) -> Option<TreeShape> {
+    let (log, leaves, min_height, extra_height) = match sapling {
+        BlockKind::OakSapling => (BlockKind::OakLog, BlockKind::OakLeaves, 4, 2),
+        BlockKind::SpruceSapling => (BlockKind::SpruceLog, BlockKind::SpruceLeaves, 6, 3),
+        BlockKind::BirchSapling => (BlockKind::BirchLog, BlockKind::BirchLeaves, 5, 2),
+        BlockKind::JungleSapling => (BlockKind::JungleLog, BlockKind::JungleLeaves, 4, 7),
+        BlockKind::AcaciaSapling => (BlockKind::AcaciaLog, BlockKind::AcaciaLeaves, 5, 2),
+        BlockKind::DarkOakSapling => (BlockKind::DarkOakLog, BlockKind::DarkOakLeaves, 6, 2),
+        _ => return None,
+    };
+
+    Some(TreeShape {
+        log,
+        leaves,
+        min_height,
+        extra_height,
+    })
+}
+
+impl TreeShape {
+    /// Computes the blocks of a tree with the given trunk height,
+    /// as offsets relative to the sapling position.
+    ///
+    /// Trunk blocks come first, followed by the canopy.
+    pub fn blocks(&self, height: i32) -> Vec<((i32, i32, i32), BlockKind)> {
+        let mut blocks: Vec<_> = (0..height).map(|y| ((0, y, 0), self.log)).collect();
+
+        for y in (height - 3)..=height {
+            let radius = if y >= height - 1 { 1 } else { 2 };
+            for x in -radius..=radius {
+                for z in -radius..=radius {
+                    // Trim the corners of the wide layers to round the canopy off
+                    if radius == 2 && x.abs() == 2 && z.abs() == 2 {
+                        continue;
+                    }
+                    if x == 0 && z == 0 && y < height {
+                        continue;
+                    }
+                    blocks.push(((x, y, z), self.leaves));
+                }
+            }
+        }
+
+        blocks
+    }
+}
+
+/// Returns whether a tree may overwrite the given block
+fn is_replaceable(kind: BlockKind) -> bool {
+    kind == BlockKind::Air || crate::leaves::is_leaves(kind) || is_sapling(kind)
+}
+
+/// Attempts to grow the sapling at `pos` into a tree.
+///
+/// The trunk must be unobstructed for the tree to grow. Canopy blocks
+/// only replace air, leaves and saplings, so solid terrain is preserved.
+///
+/// Returns whether the tree was placed.
+pub fn grow_tree<R, F, G>(
+    rng: &mut R,
+    pos: (i32, i32, i32),
+    kind: BlockKind,
+    block_getter: &F,
+    block_setter: &mut G,
+) -> bool
+where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    let shape = match tree_for(kind) {
+        Some(shape) => shape,
+        None => return false,
+    };
+    let height = shape.min_height + rng.gen_range(0..=shape.extra_height);
+    let blocks = shape.blocks(height);
+
+    let is_free = |offset: (i32, i32, i32)| {
+        let target = (pos.0 + offset.0, pos.1 + offset.1, pos.2 + offset.2);
+        block_getter(target).map_or(true, |(kind, _)| is_replaceable(kind))
+    };
+
+    let obstructed = blocks
+        .iter()
+        .filter(|(_, block)| *block == shape.log)
+        .any(|(offset, _)| *offset != (0, 0, 0) && !is_free(*offset));
+    if obstructed {
+        return false;
+    }
+
+    for (offset, block) in blocks {
+        if block == shape.leaves && !is_free(offset) {
+            continue;
+        }
+        let target = (pos.0 + offset.0, pos.1 + offset.1, pos.2 + offset.2);
+        block_setter(target, block.default_properties());
+    }
+
+    true
+}
+
+/// Advances a sapling by one growth stage, growing it into a tree
+/// once it is already at its final stage.
+fn advance<R, F, G>(
+    rng: &mut R,
+    pos: (i32, i32, i32),
+    kind: BlockKind,
+    properties: &BlockProperties,
+    block_getter: &F,
+    block_setter: &mut G,
+) where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    if properties.get("stage").map_or(true, |stage| stage == "0") {
+        let mut grown = properties.clone();
+        grown.set("stage", "1");
+        block_setter(pos, grown);
+    } else {
+        grow_tree(rng, pos, kind, block_getter, block_setter);
+    }
+}
+
+/// Handles a random tick for a sapling, advancing it if it has enough light.
+///
+/// The tick executor rolls the sapling's growth chance before calling this.
+pub fn random_tick_sapling<R, F, L, G>(
+    rng: &mut R,
+    pos: (i32, i32, i32),
+    kind: BlockKind,
+    properties: &BlockProperties,
+    block_getter: &F,
+    light_getter: &L,
+    block_setter: &mut G,
+) where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    L: Fn((i32, i32, i32)) -> u8,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    if light_getter((pos.0, pos.1 + 1, pos.2)) < MIN_GROWTH_LIGHT {
+        return;
+    }
+
+    advance(rng, pos, kind, properties, block_getter, block_setter);
+}
+
+/// Applies bone meal to a sapling, advancing its growth with the given chance.
+///
+/// Returns whether the bone meal was consumed.
+pub fn apply_bone_meal<R, F, G>(
+    rng: &mut R,
+    chance: f32,
+    pos: (i32, i32, i32),
+    kind: BlockKind,
+    properties: &BlockProperties,
+    block_getter: &F,
+    block_setter: &mut G,
+) -> bool
+where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    if !is_sapling(kind) {
+        return false;
+    }
+
+    if rng.gen::<f32>() < chance {
+        advance(rng, pos, kind, properties, block_getter, block_setter);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    type TestWorld = RefCell<HashMap<(i32, i32, i32), BlockProperties>>;
+
+    fn tick(world: &TestWorld, pos: (i32, i32, i32), rng: &mut StdRng) {
+        let properties = world.borrow()[&pos].clone();
+        let getter = |p| {
+            world
+                .borrow()
+                .get(&p)
+                .map(|props: &BlockProperties| (props.kind(), props.clone()))
+        };
+        let light_getter = |_| 15;
+        let mut setter = |p, properties| {
+            world.borrow_mut().insert(p, properties);
+        };
+        random_tick_sapling(
+            rng,
+            pos,
+            properties.kind(),
+            &properties,
+            &getter,
+            &light_getter,
+            &mut setter,
+        );
+    }
+
+    fn kind_at(world: &TestWorld, pos: (i32, i32, i32)) -> Option<BlockKind> {
+        world.borrow().get(&pos).map(BlockProperties::kind)
+    }
+
+    fn planted_world() -> TestWorld {
+        let world = RefCell::new(HashMap::new());
+        world
+            .borrow_mut()
+            .insert((0, 63, 0), BlockKind::GrassBlock.default_properties());
+        let mut sapling = BlockKind::OakSapling.default_properties();
+        sapling.set("stage", "0");
+        world.borrow_mut().insert((0, 64, 0), sapling);
+        world
+    }
+
+    #[test]
+    fn oak_sapling_grows_into_tree() {
+        let world = planted_world();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..500 {
+            if kind_at(&world, (0, 64, 0)) != Some(BlockKind::OakSapling) {
+                break;
+            }
+            tick(&world, (0, 64, 0), &mut rng);
+        }
+
+        for y in 64..68 {
+            assert_eq!(kind_at(&world, (0, y, 0)), Some(BlockKind::OakLog));
+        }
+        assert!(world
+            .borrow()
+            .values()
+            .any(|props| props.kind() == BlockKind::OakLeaves));
+    }
+
+    #[test]
+    fn obstructed_sapling_does_not_grow() {
+        let world = planted_world();
+        world
+            .borrow_mut()
+            .insert((0, 66, 0), BlockKind::Stone.default_properties());
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..500 {
+            tick(&world, (0, 64, 0), &mut rng);
+        }
+
+        assert_eq!(kind_at(&world, (0, 64, 0)), Some(BlockKind::OakSapling));
+        assert_eq!(kind_at(&world, (0, 65, 0)), None);
+    }
+}
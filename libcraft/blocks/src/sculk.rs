@@ -0,0 +1,231 @@
+use std::collections::{HashSet, VecDeque};
+
+use rand::Rng;
+
+use crate::{requires_block_entity, BlockKind, BlockProperties};
+
+/// Maximum distance from a catalyst at which deaths cause sculk to spread
+pub const CATALYST_RADIUS: i32 = 8;
+/// Maximum distance sculk spreads from where a mob died
+const SPREAD_RADIUS: i32 = 6;
+/// Chance for a newly converted block to grow a sensor or shrieker on top
+const GROWTH_CHANCE: f32 = 0.1;
+/// Chance for a growth to be a shrieker rather than a sensor
+const SHRIEKER_CHANCE: f32 = 0.2;
+/// Number of surfaces beyond the spread covered with veins
+const VEIN_FRINGE: usize = 4;
+
+const NEIGHBORS: [(i32, i32, i32); 6] = [
+    (0, 1, 0),
+    (0, -1, 0),
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// Returns whether the block is one of the sculk family
+pub fn is_sculk(kind: BlockKind) -> bool {
+    matches!(
+        kind,
+        BlockKind::Sculk
+            | BlockKind::SculkVein
+            | BlockKind::SculkCatalyst
+            | BlockKind::SculkSensor
+            | BlockKind::SculkShrieker
+    )
+}
+
+/// Returns whether sculk may replace the given block
+fn can_become_sculk(kind: BlockKind) -> bool {
+    kind.solid()
+        && !is_sculk(kind)
+        && !requires_block_entity(kind)
+        && !matches!(
+            kind,
+            BlockKind::Bedrock | BlockKind::Obsidian | BlockKind::CryingObsidian
+        )
+}
+
+fn offset(pos: (i32, i32, i32), by: (i32, i32, i32)) -> (i32, i32, i32) {
+    (pos.0 + by.0, pos.1 + by.1, pos.2 + by.2)
+}
+
+fn is_air<F>(pos: (i32, i32, i32), block_getter: &F) -> bool
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    block_getter(pos).map_or(false, |(kind, _)| kind == BlockKind::Air)
+}
+
+/// Returns whether a block has at least one face not covered by a solid block
+fn is_surface<F>(pos: (i32, i32, i32), block_getter: &F) -> bool
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+{
+    NEIGHBORS
+        .iter()
+        .any(|dir| block_getter(offset(pos, *dir)).map_or(false, |(kind, _)| !kind.solid()))
+}
+
+fn vein_on_floor() -> BlockProperties {
+    let mut vein = BlockProperties::new(BlockKind::SculkVein);
+    vein.set("down", "true")
+        .set("up", "false")
+        .set("north", "false")
+        .set("east", "false")
+        .set("south", "false")
+        .set("west", "false")
+        .set("waterlogged", "false");
+    vein
+}
+
+fn growth<R: Rng>(rng: &mut R) -> BlockProperties {
+    if rng.gen::<f32>() < SHRIEKER_CHANCE {
+        let mut shrieker = BlockProperties::new(BlockKind::SculkShrieker);
+        // Shriekers grown by catalysts never summon wardens
+        shrieker
+            .set("can_summon", "false")
+            .set("shrieking", "false")
+            .set("waterlogged", "false");
+        shrieker
+    } else {
+        BlockKind::SculkSensor.default_properties()
+    }
+}
+
+/// Spreads sculk over the surfaces around `origin` after a mob died
+/// near a catalyst, spending one point of `charge` (the mob's
+/// experience) per converted block.
+///
+/// Surfaces are converted closest first, walking through existing sculk
+/// so repeated deaths push the spread outwards. Converted blocks
+/// occasionally grow a sensor or shrieker, and once the charge is spent
+/// the next few surfaces are covered with sculk veins.
+///
+/// Returns the number of blocks converted to sculk.
+pub fn spread_sculk<R, F, G>(
+    rng: &mut R,
+    origin: (i32, i32, i32),
+    mut charge: u32,
+    block_getter: &F,
+    block_setter: &mut G,
+) -> u32
+where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    let mut converted = 0;
+    let mut veins = 0;
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    visited.insert(origin);
+    queue.push_back(origin);
+
+    while let Some(pos) = queue.pop_front() {
+        if veins >= VEIN_FRINGE {
+            break;
+        }
+
+        let kind = match block_getter(pos) {
+            Some((kind, _)) => kind,
+            None => continue,
+        };
+        if !(kind.solid() || is_sculk(kind)) || !is_surface(pos, block_getter) {
+            continue;
+        }
+
+        if can_become_sculk(kind) {
+            let above = offset(pos, (0, 1, 0));
+            if charge > 0 {
+                block_setter(pos, BlockKind::Sculk.default_properties());
+                charge -= 1;
+                converted += 1;
+                if is_air(above, block_getter) && rng.gen::<f32>() < GROWTH_CHANCE {
+                    block_setter(above, growth(rng));
+                }
+            } else if is_air(above, block_getter) {
+                block_setter(above, vein_on_floor());
+                veins += 1;
+            }
+        }
+
+        for dir in NEIGHBORS.iter() {
+            let next = offset(pos, *dir);
+            let within = (next.0 - origin.0).abs() <= SPREAD_RADIUS
+                && (next.1 - origin.1).abs() <= SPREAD_RADIUS
+                && (next.2 - origin.2).abs() <= SPREAD_RADIUS;
+            if within && visited.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    type TestWorld = RefCell<HashMap<(i32, i32, i32), BlockProperties>>;
+
+    /// A stone floor at y = 63 with air above
+    fn floor() -> TestWorld {
+        let world = RefCell::new(HashMap::new());
+        for x in -10..=10 {
+            for z in -10..=10 {
+                world
+                    .borrow_mut()
+                    .insert((x, 63, z), BlockKind::Stone.default_properties());
+                for y in 64..68 {
+                    world
+                        .borrow_mut()
+                        .insert((x, y, z), BlockKind::Air.default_properties());
+                }
+            }
+        }
+        world
+    }
+
+    fn count(world: &TestWorld, kind: BlockKind) -> usize {
+        world
+            .borrow()
+            .values()
+            .filter(|props| props.kind() == kind)
+            .count()
+    }
+
+    fn spread(world: &TestWorld, charge: u32) -> u32 {
+        let mut rng = StdRng::seed_from_u64(0);
+        let getter = |p| {
+            world
+                .borrow()
+                .get(&p)
+                .map(|props: &BlockProperties| (props.kind(), props.clone()))
+        };
+        let mut setter = |p, props| {
+            world.borrow_mut().insert(p, props);
+        };
+        spread_sculk(&mut rng, (0, 63, 0), charge, &getter, &mut setter)
+    }
+
+    #[test]
+    fn sculk_spread_matches_experience() {
+        let world = floor();
+        assert_eq!(spread(&world, 5), 5);
+        assert_eq!(count(&world, BlockKind::Sculk), 5);
+        assert_eq!(count(&world, BlockKind::SculkVein), VEIN_FRINGE);
+
+        // A second death spreads further out from the existing patch
+        assert_eq!(spread(&world, 20), 20);
+        assert_eq!(count(&world, BlockKind::Sculk), 25);
+    }
+}
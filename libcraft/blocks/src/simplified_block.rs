@@ -462,6 +462,12 @@ pub enum SimplifiedBlockKind {
     PowderSnowCauldron,
     PowderSnow,
 
+    SculkSensor,
+    Sculk,
+    SculkVein,
+    SculkCatalyst,
+    SculkShrieker,
+
     SmoothBasalt,
     Calcite,
     TuffBlock,
@@ -1372,6 +1378,12 @@ impl BlockKind {
             BlockKind::PowderSnowCauldron => SimplifiedBlockKind::PowderSnowCauldron,
             BlockKind::PowderSnow => SimplifiedBlockKind::PowderSnow,
 
+            BlockKind::SculkSensor => SimplifiedBlockKind::SculkSensor,
+            BlockKind::Sculk => SimplifiedBlockKind::Sculk,
+            BlockKind::SculkVein => SimplifiedBlockKind::SculkVein,
+            BlockKind::SculkCatalyst => SimplifiedBlockKind::SculkCatalyst,
+            BlockKind::SculkShrieker => SimplifiedBlockKind::SculkShrieker,
+
             BlockKind::SmoothBasalt => SimplifiedBlockKind::SmoothBasalt,
             BlockKind::Calcite => SimplifiedBlockKind::Calcite,
             BlockKind::TuffBlock => SimplifiedBlockKind::TuffBlock,
@@ -0,0 +1,145 @@
+use rand::Rng;
+
+use crate::{BlockKind, BlockProperties};
+
+/// Minimum light level above a spreadable block for it to survive
+const MIN_SURVIVAL_LIGHT: u8 = 4;
+/// Minimum light level above a spreadable block for it to spread
+const MIN_SPREAD_LIGHT: u8 = 9;
+/// Number of spread attempts made per random tick
+const SPREAD_ATTEMPTS: usize = 4;
+
+/// Returns whether this block spreads onto nearby dirt (grass and mycelium)
+pub fn is_spreadable(kind: BlockKind) -> bool {
+    matches!(kind, BlockKind::GrassBlock | BlockKind::Mycelium)
+}
+
+/// Checks if a spreadable block could exist at `pos` given the block and light above it
+fn can_survive<F, L>(pos: (i32, i32, i32), block_getter: &F, light_getter: &L) -> bool
+where
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    L: Fn((i32, i32, i32)) -> u8,
+{
+    let above = (pos.0, pos.1 + 1, pos.2);
+
+    // Opaque blocks smother the block regardless of light
+    if let Some((kind, _)) = block_getter(above) {
+        if !kind.transparent() {
+            return false;
+        }
+    }
+
+    light_getter(above) >= MIN_SURVIVAL_LIGHT
+}
+
+/// Handles a random tick for grass or mycelium.
+///
+/// A block starved of light (or covered by an opaque block) decays back to dirt.
+/// Otherwise, if enough light reaches it, it tries to spread to nearby dirt.
+pub fn random_tick_spreadable<R, F, L, G>(
+    rng: &mut R,
+    pos: (i32, i32, i32),
+    kind: BlockKind,
+    block_getter: &F,
+    light_getter: &L,
+    block_setter: &mut G,
+) where
+    R: Rng,
+    F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+    L: Fn((i32, i32, i32)) -> u8,
+    G: FnMut((i32, i32, i32), BlockProperties),
+{
+    if !can_survive(pos, block_getter, light_getter) {
+        block_setter(pos, BlockKind::Dirt.default_properties());
+        return;
+    }
+
+    if light_getter((pos.0, pos.1 + 1, pos.2)) < MIN_SPREAD_LIGHT {
+        return;
+    }
+
+    for _ in 0..SPREAD_ATTEMPTS {
+        let target = (
+            pos.0 + rng.gen_range(-1..=1),
+            pos.1 + rng.gen_range(-3..=1),
+            pos.2 + rng.gen_range(-1..=1),
+        );
+
+        let is_dirt = matches!(block_getter(target), Some((BlockKind::Dirt, _)));
+        if is_dirt && can_survive(target, block_getter, light_getter) {
+            block_setter(target, kind.default_properties());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn tick(
+        world: &RefCell<HashMap<(i32, i32, i32), BlockKind>>,
+        pos: (i32, i32, i32),
+        light: u8,
+        rng: &mut StdRng,
+    ) {
+        let kind = world.borrow()[&pos];
+        let getter = |p| {
+            world
+                .borrow()
+                .get(&p)
+                .map(|kind: &BlockKind| (*kind, kind.default_properties()))
+        };
+        let light_getter = |_| light;
+        let mut setter = |p, properties: BlockProperties| {
+            world.borrow_mut().insert(p, properties.kind());
+        };
+        random_tick_spreadable(rng, pos, kind, &getter, &light_getter, &mut setter);
+    }
+
+    #[test]
+    fn grass_spreads_to_adjacent_lit_dirt() {
+        let world = RefCell::new(HashMap::new());
+        world.borrow_mut().insert((0, 64, 0), BlockKind::GrassBlock);
+        world.borrow_mut().insert((1, 64, 0), BlockKind::Dirt);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            tick(&world, (0, 64, 0), 15, &mut rng);
+        }
+
+        assert_eq!(world.borrow()[&(1, 64, 0)], BlockKind::GrassBlock);
+        assert_eq!(world.borrow()[&(0, 64, 0)], BlockKind::GrassBlock);
+    }
+
+    #[test]
+    fn grass_does_not_spread_in_darkness() {
+        let world = RefCell::new(HashMap::new());
+        world.borrow_mut().insert((0, 64, 0), BlockKind::GrassBlock);
+        world.borrow_mut().insert((1, 64, 0), BlockKind::Dirt);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            tick(&world, (0, 64, 0), 5, &mut rng);
+        }
+
+        assert_eq!(world.borrow()[&(1, 64, 0)], BlockKind::Dirt);
+    }
+
+    #[test]
+    fn covered_grass_decays_to_dirt() {
+        let world = RefCell::new(HashMap::new());
+        world.borrow_mut().insert((0, 64, 0), BlockKind::GrassBlock);
+        world.borrow_mut().insert((0, 65, 0), BlockKind::Stone);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        tick(&world, (0, 64, 0), 15, &mut rng);
+
+        assert_eq!(world.borrow()[&(0, 64, 0)], BlockKind::Dirt);
+    }
+}
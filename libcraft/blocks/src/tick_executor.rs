@@ -1,11 +1,20 @@
-use std::time::Duration;
-use crate::{BlockKind, BlockProperties, BlockTransitionManager};
+use libcraft_items::Item;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::{BlockKind, BlockProperties, BlockTransitionManager, RandomTickProbabilities};
 use crate::block_ticking::{BlockTickScheduler, TickType};
+use crate::{chorus, leaves, power, sapling, spreading};
 
 /// Executes ticks for blocks
 pub struct BlockTickExecutor {
     scheduler: BlockTickScheduler,
     transition_manager: BlockTransitionManager,
+    /// Per-block random tick odds consulted by the tick arms
+    probabilities: RandomTickProbabilities,
+    /// Source of randomness for all tick arms
+    rng: StdRng,
+    /// Items dropped by ticked blocks, waiting to be spawned into the world
+    pending_drops: Vec<((i32, i32, i32), Item)>,
 }
 
 impl BlockTickExecutor {
@@ -14,15 +23,50 @@ impl BlockTickExecutor {
         Self {
             scheduler: BlockTickScheduler::new(random_tick_speed),
             transition_manager,
+            probabilities: RandomTickProbabilities::default(),
+            rng: StdRng::from_entropy(),
+            pending_drops: Vec::new(),
         }
     }
     
-    /// Schedules a tick for a block
+    /// Replaces the random tick probability table
+    pub fn with_probabilities(mut self, probabilities: RandomTickProbabilities) -> Self {
+        self.probabilities = probabilities;
+        self
+    }
+    
+    /// Seeds the random number generator, making ticks reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+    
+    /// Gets the random tick probability table
+    pub fn probabilities(&self) -> &RandomTickProbabilities {
+        &self.probabilities
+    }
+    
+    /// Mutably gets the random tick probability table
+    pub fn probabilities_mut(&mut self) -> &mut RandomTickProbabilities {
+        &mut self.probabilities
+    }
+    
+    /// Gets the number of random ticks each chunk section receives per tick
+    pub fn random_tick_speed(&self) -> u32 {
+        self.scheduler.random_tick_speed()
+    }
+    
+    /// Sets the number of random ticks each chunk section receives per tick
+    pub fn set_random_tick_speed(&mut self, speed: u32) {
+        self.scheduler.set_random_tick_speed(speed);
+    }
+    
+    /// Schedules a tick for a block, `delay` game ticks from now
     pub fn schedule_tick(
         &mut self,
         position: (i32, i32, i32),
         kind: BlockKind,
-        delay: Duration,
+        delay: u32,
         priority: i32,
     ) {
         self.scheduler.schedule_tick(
@@ -42,10 +86,13 @@ impl BlockTickExecutor {
     )
     where
         F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
-        G: FnMut((i32, i32, i32), BlockKind),
+        G: FnMut((i32, i32, i32), BlockProperties),
     {
         let mut block_setter = block_setter;
         let transition_manager = &self.transition_manager;
+        let probabilities = &self.probabilities;
+        let pending_drops = &mut self.pending_drops;
+        let rng = &mut self.rng;
         
         self.scheduler.process_ticks(move |pos, kind, tick_type| {
             if let Some((current_kind, properties)) = block_getter(pos) {
@@ -55,8 +102,14 @@ impl BlockTickExecutor {
                 }
                 
                 // Check for transitions
-                if let Some(target_kind) = transition_manager.check_transition(current_kind, &properties) {
-                    block_setter(pos, target_kind);
+                if let Some(target_kind) = transition_manager.check_transition(
+                    current_kind,
+                    &properties,
+                    &tick_type,
+                    probabilities,
+                    rng,
+                ) {
+                    block_setter(pos, target_kind.default_properties());
                 }
                 
                 // Execute the tick behavior based on the block type
@@ -75,6 +128,39 @@ impl BlockTickExecutor {
                     BlockKind::PointedDripstone => {
                         // Handle dripstone growth
                     },
+                    BlockKind::Target => {
+                        // Scheduled when the target was hit
+                        let mut properties = properties.clone();
+                        properties.set("power", "0");
+                        block_setter(pos, properties);
+                    },
+                    BlockKind::Composter => {
+                        // Scheduled when the composter was filled, readies its bone meal
+                        if properties.get("level").map(String::as_str) == Some("7") {
+                            let mut properties = properties.clone();
+                            properties.set("level", "8");
+                            block_setter(pos, properties);
+                        }
+                    },
+                    kind if power::button_press_ticks(kind).is_some() => {
+                        // Scheduled when the button was pressed, pops it back out
+                        let mut properties = properties.clone();
+                        properties.set("powered", "false");
+                        block_setter(pos, properties);
+                    },
+                    kind if leaves::is_leaves(kind) => {
+                        // Scheduled by a neighbor change; the leaves may have lost their log
+                        if let Some(sapling) = leaves::tick_leaves(
+                            rng,
+                            pos,
+                            kind,
+                            &properties,
+                            &block_getter,
+                            &mut block_setter,
+                        ) {
+                            pending_drops.push((pos, sapling));
+                        }
+                    },
                     // Handle other blocks with tick behavior
                     _ => {}
                 }
@@ -83,29 +169,121 @@ impl BlockTickExecutor {
     }
     
     /// Process random ticks for a chunk section
-    pub fn process_random_ticks<F, G>(
-        &self,
+    pub fn process_random_ticks<F, L, G>(
+        &mut self,
         chunk_position: (i32, i32),
         blocks: &[(BlockKind, (i32, i32, i32), BlockProperties)],
         block_getter: F,
+        light_getter: L,
         mut block_setter: G,
     )
     where
         F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
-        G: FnMut((i32, i32, i32), BlockKind),
+        L: Fn((i32, i32, i32)) -> u8,
+        G: FnMut((i32, i32, i32), BlockProperties),
     {
         let transition_manager = &self.transition_manager;
+        let probabilities = &self.probabilities;
+        let pending_drops = &mut self.pending_drops;
         
-        self.scheduler.process_random_ticks(chunk_position, blocks, move |pos, kind| {
+        self.scheduler.process_random_ticks(chunk_position, blocks, &mut self.rng, move |rng, pos, kind| {
             if let Some((current_kind, properties)) = block_getter(pos) {
                 // Execute random tick behavior
-                if let Some(target_kind) = transition_manager.check_transition(current_kind, &properties) {
-                    block_setter(pos, target_kind);
+                if let Some(target_kind) = transition_manager.check_transition(
+                    current_kind,
+                    &properties,
+                    &TickType::Random,
+                    probabilities,
+                    rng,
+                ) {
+                    block_setter(pos, target_kind.default_properties());
+                    return;
+                }
+                
+                match current_kind {
+                    kind if spreading::is_spreadable(kind) => {
+                        spreading::random_tick_spreadable(
+                            rng,
+                            pos,
+                            current_kind,
+                            &block_getter,
+                            &light_getter,
+                            &mut block_setter,
+                        );
+                    },
+                    kind if leaves::is_leaves(kind) => {
+                        if let Some(sapling) = leaves::tick_leaves(
+                            rng,
+                            pos,
+                            kind,
+                            &properties,
+                            &block_getter,
+                            &mut block_setter,
+                        ) {
+                            pending_drops.push((pos, sapling));
+                        }
+                    },
+                    kind if sapling::is_sapling(kind) => {
+                        if rng.gen::<f32>() >= probabilities.random_tick(kind) {
+                            return;
+                        }
+                        sapling::random_tick_sapling(
+                            rng,
+                            pos,
+                            kind,
+                            &properties,
+                            &block_getter,
+                            &light_getter,
+                            &mut block_setter,
+                        );
+                    },
+                    BlockKind::ChorusFlower => {
+                        chorus::random_tick_chorus_flower(
+                            rng,
+                            pos,
+                            &properties,
+                            &block_getter,
+                            &mut block_setter,
+                        );
+                    },
+                    _ => {}
                 }
             }
         });
     }
     
+    /// Applies bone meal to the block at `pos`.
+    ///
+    /// Returns whether the bone meal was consumed.
+    pub fn apply_bone_meal<F, G>(
+        &mut self,
+        pos: (i32, i32, i32),
+        block_getter: F,
+        mut block_setter: G,
+    ) -> bool
+    where
+        F: Fn((i32, i32, i32)) -> Option<(BlockKind, BlockProperties)>,
+        G: FnMut((i32, i32, i32), BlockProperties),
+    {
+        match block_getter(pos) {
+            Some((kind, properties)) if sapling::is_sapling(kind) => sapling::apply_bone_meal(
+                &mut self.rng,
+                self.probabilities.bone_meal(kind),
+                pos,
+                kind,
+                &properties,
+                &block_getter,
+                &mut block_setter,
+            ),
+            _ => false,
+        }
+    }
+    
+    /// Drains the items dropped by ticked blocks since the last call
+    pub fn take_drops(&mut self) -> Vec<((i32, i32, i32), Item)> {
+        std::mem::take(&mut self.pending_drops)
+    }
+    
     /// Get a reference to the scheduler
     pub fn scheduler(&self) -> &BlockTickScheduler {
         &self.scheduler
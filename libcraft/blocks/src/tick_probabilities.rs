@@ -0,0 +1,157 @@
+use ahash::AHashMap;
+use thiserror::Error;
+
+use crate::BlockKind;
+
+/// Chance that a random tick advances a sufficiently lit sapling
+const SAPLING_GROWTH_CHANCE: f32 = 1.0 / 7.0;
+/// Chance that a single use of bone meal advances a sapling
+const SAPLING_BONE_MEAL_CHANCE: f32 = 0.45;
+
+#[derive(Debug, Error)]
+#[error("unknown block `{0}` in random tick overrides")]
+pub struct UnknownBlockError(pub String);
+
+/// Central table of per-block random tick odds.
+///
+/// Tick executor arms consult this table instead of hardcoding
+/// their probabilities, so server operators can tune e.g. sapling
+/// growth through the config.
+#[derive(Debug, Clone)]
+pub struct RandomTickProbabilities {
+    /// Chance that a random tick on a block has any effect
+    random_tick: AHashMap<BlockKind, f32>,
+    /// Chance that bone meal advances a block's growth
+    bone_meal: AHashMap<BlockKind, f32>,
+}
+
+impl Default for RandomTickProbabilities {
+    fn default() -> Self {
+        let mut table = Self {
+            random_tick: AHashMap::new(),
+            bone_meal: AHashMap::new(),
+        };
+
+        for kind in &[
+            BlockKind::OakSapling,
+            BlockKind::SpruceSapling,
+            BlockKind::BirchSapling,
+            BlockKind::JungleSapling,
+            BlockKind::AcaciaSapling,
+            BlockKind::DarkOakSapling,
+        ] {
+            table.random_tick.insert(*kind, SAPLING_GROWTH_CHANCE);
+            table.bone_meal.insert(*kind, SAPLING_BONE_MEAL_CHANCE);
+        }
+
+        table
+    }
+}
+
+impl RandomTickProbabilities {
+    /// Creates a table with the default vanilla odds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the chance that a random tick on this block has an effect.
+    ///
+    /// Blocks without an entry always act on their random ticks.
+    pub fn random_tick(&self, kind: BlockKind) -> f32 {
+        self.random_tick.get(&kind).copied().unwrap_or(1.0)
+    }
+
+    /// Gets the chance that bone meal advances this block's growth.
+    ///
+    /// Blocks without an entry never react to bone meal.
+    pub fn bone_meal(&self, kind: BlockKind) -> f32 {
+        self.bone_meal.get(&kind).copied().unwrap_or(0.0)
+    }
+
+    /// Overrides the random tick chance for a block
+    pub fn set_random_tick(&mut self, kind: BlockKind, probability: f32) -> &mut Self {
+        self.random_tick.insert(kind, probability.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Overrides the bone meal chance for a block
+    pub fn set_bone_meal(&mut self, kind: BlockKind, probability: f32) -> &mut Self {
+        self.bone_meal.insert(kind, probability.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Applies random tick overrides keyed by block name (e.g. `"oak_sapling"`),
+    /// as read from the server config.
+    pub fn apply_overrides<'a>(
+        &mut self,
+        overrides: impl IntoIterator<Item = (&'a str, f32)>,
+    ) -> Result<(), UnknownBlockError> {
+        for (name, probability) in overrides {
+            let kind =
+                BlockKind::from_name(name).ok_or_else(|| UnknownBlockError(name.to_owned()))?;
+            self.set_random_tick(kind, probability);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::{initialize_block_transitions, BlockProperties, BlockTickExecutor};
+
+    #[test]
+    fn overrides_are_keyed_by_block_name() {
+        let mut probabilities = RandomTickProbabilities::default();
+        probabilities
+            .apply_overrides(vec![("oak_sapling", 0.5), ("grass_block", 2.0)])
+            .unwrap();
+        assert_eq!(probabilities.random_tick(BlockKind::OakSapling), 0.5);
+        assert_eq!(probabilities.random_tick(BlockKind::GrassBlock), 1.0);
+        assert_eq!(
+            probabilities.random_tick(BlockKind::BirchSapling),
+            SAPLING_GROWTH_CHANCE
+        );
+
+        assert!(probabilities
+            .apply_overrides(vec![("not_a_block", 1.0)])
+            .is_err());
+    }
+
+    #[test]
+    fn zero_chance_sapling_never_grows() {
+        let mut probabilities = RandomTickProbabilities::default();
+        probabilities.set_random_tick(BlockKind::OakSapling, 0.0);
+        let mut executor = BlockTickExecutor::new(1, initialize_block_transitions())
+            .with_probabilities(probabilities)
+            .with_seed(0);
+        let sapling = RefCell::new(BlockKind::OakSapling.default_properties());
+
+        for _ in 0..1_000 {
+            let kind = sapling.borrow().kind();
+            executor.process_random_ticks(
+                (0, 0),
+                &[(kind, (0, 64, 0), sapling.borrow().clone())],
+                |pos| match pos {
+                    (0, 64, 0) => Some((sapling.borrow().kind(), sapling.borrow().clone())),
+                    (_, y, _) if y < 64 => {
+                        Some((BlockKind::Dirt, BlockKind::Dirt.default_properties()))
+                    }
+                    _ => Some((BlockKind::Air, BlockKind::Air.default_properties())),
+                },
+                |_| 15,
+                |pos, properties: BlockProperties| {
+                    if pos == (0, 64, 0) {
+                        *sapling.borrow_mut() = properties;
+                    }
+                },
+            );
+        }
+        assert_eq!(
+            *sapling.borrow(),
+            BlockKind::OakSapling.default_properties()
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{BlockProperties, SimplifiedBlockKind};
+
+/// Horizontal directions, in clockwise order starting from north
+const HORIZONTAL: [&str; 4] = ["north", "east", "south", "west"];
+
+/// Number of steps in the `rotation` property of signs, banners and skulls
+const ROTATION_STEPS: i32 = 16;
+
+/// A clockwise rotation around the Y axis, in 90 degree steps
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    None,
+    Clockwise90,
+    Clockwise180,
+    CounterClockwise90,
+}
+
+impl Rotation {
+    /// Number of clockwise quarter turns this rotation performs
+    pub fn quarter_turns(self) -> usize {
+        match self {
+            Rotation::None => 0,
+            Rotation::Clockwise90 => 1,
+            Rotation::Clockwise180 => 2,
+            Rotation::CounterClockwise90 => 3,
+        }
+    }
+
+    /// Rotates a horizontal direction name, leaving other directions untouched
+    pub fn rotate_direction(self, direction: &str) -> &str {
+        match HORIZONTAL.iter().position(|d| *d == direction) {
+            Some(index) => HORIZONTAL[(index + self.quarter_turns()) % 4],
+            None => direction,
+        }
+    }
+}
+
+/// A reflection in the horizontal plane
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Mirror {
+    None,
+    /// Swaps north and south (reflects along the Z axis)
+    LeftRight,
+    /// Swaps east and west (reflects along the X axis)
+    FrontBack,
+}
+
+impl Mirror {
+    /// Mirrors a direction name, leaving other directions untouched
+    pub fn mirror_direction(self, direction: &str) -> &str {
+        match (self, direction) {
+            (Mirror::LeftRight, "north") => "south",
+            (Mirror::LeftRight, "south") => "north",
+            (Mirror::FrontBack, "east") => "west",
+            (Mirror::FrontBack, "west") => "east",
+            _ => direction,
+        }
+    }
+}
+
+/// Swaps the `left`/`right` part of a value such as `inner_left` or `right`
+fn swap_handedness(value: &str) -> String {
+    if value.contains("left") {
+        value.replace("left", "right")
+    } else {
+        value.replace("right", "left")
+    }
+}
+
+fn is_door(properties: &BlockProperties) -> bool {
+    matches!(
+        properties.kind().simplified_kind(),
+        SimplifiedBlockKind::WoodenDoor | SimplifiedBlockKind::IronDoor
+    )
+}
+
+fn is_stairs(properties: &BlockProperties) -> bool {
+    properties.kind().simplified_kind() == SimplifiedBlockKind::Stairs
+}
+
+impl BlockProperties {
+    /// Returns these properties rotated clockwise around the Y axis.
+    ///
+    /// Updates `facing`, `axis`, the sign/banner `rotation`, and the
+    /// per-side connection properties of fences, walls and panes. Stair
+    /// shapes and door hinges are relative to `facing` and are kept as-is.
+    pub fn rotate(&self, degrees: Rotation) -> BlockProperties {
+        let mut rotated = BlockProperties::new(self.kind());
+        let turns = degrees.quarter_turns();
+
+        for (name, value) in self.all() {
+            match name.as_str() {
+                "facing" => {
+                    rotated.set(name, degrees.rotate_direction(value));
+                }
+                "axis" if turns % 2 == 1 => {
+                    let axis = match value.as_str() {
+                        "x" => "z",
+                        "z" => "x",
+                        other => other,
+                    };
+                    rotated.set(name, axis);
+                }
+                "rotation" => match value.parse::<i32>() {
+                    Ok(rotation) => {
+                        let rotation = (rotation + 4 * turns as i32) % ROTATION_STEPS;
+                        rotated.set(name, &rotation.to_string());
+                    }
+                    Err(_) => {
+                        rotated.set(name, value);
+                    }
+                },
+                side if HORIZONTAL.iter().any(|d| *d == side) => {
+                    rotated.set(degrees.rotate_direction(side), value);
+                }
+                _ => {
+                    rotated.set(name, value);
+                }
+            }
+        }
+
+        rotated
+    }
+
+    /// Returns these properties reflected by the given mirror.
+    ///
+    /// Besides remapping directional properties, this flips door hinges,
+    /// double chest halves, and the handedness of stairs whose facing is reversed.
+    pub fn mirror(&self, axis: Mirror) -> BlockProperties {
+        if axis == Mirror::None {
+            return self.clone();
+        }
+
+        let mut mirrored = BlockProperties::new(self.kind());
+        let facing_reversed = self
+            .get("facing")
+            .map_or(false, |facing| axis.mirror_direction(facing) != facing);
+
+        for (name, value) in self.all() {
+            match name.as_str() {
+                "facing" => {
+                    mirrored.set(name, axis.mirror_direction(value));
+                }
+                "rotation" => match value.parse::<i32>() {
+                    Ok(rotation) => {
+                        let rotation = match axis {
+                            Mirror::LeftRight => {
+                                (ROTATION_STEPS / 2 - rotation).rem_euclid(ROTATION_STEPS)
+                            }
+                            _ => (ROTATION_STEPS - rotation) % ROTATION_STEPS,
+                        };
+                        mirrored.set(name, &rotation.to_string());
+                    }
+                    Err(_) => {
+                        mirrored.set(name, value);
+                    }
+                },
+                "hinge" if is_door(self) => {
+                    mirrored.set(name, &swap_handedness(value));
+                }
+                "type" if value == "left" || value == "right" => {
+                    mirrored.set(name, &swap_handedness(value));
+                }
+                "shape" if is_stairs(self) && facing_reversed => {
+                    mirrored.set(name, &swap_handedness(value));
+                }
+                side if HORIZONTAL.iter().any(|d| *d == side) => {
+                    mirrored.set(axis.mirror_direction(side), value);
+                }
+                _ => {
+                    mirrored.set(name, value);
+                }
+            }
+        }
+
+        mirrored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockKind;
+
+    #[test]
+    fn rotating_stairs_turns_facing_and_keeps_relative_shape() {
+        let mut stairs = BlockProperties::new(BlockKind::OakStairs);
+        stairs
+            .set("facing", "north")
+            .set("half", "bottom")
+            .set("shape", "outer_left");
+
+        let rotated = stairs.rotate(Rotation::Clockwise90);
+        assert_eq!(rotated.get("facing").unwrap(), "east");
+        assert_eq!(rotated.get("shape").unwrap(), "outer_left");
+        assert_eq!(rotated.get("half").unwrap(), "bottom");
+
+        let mirrored = stairs.mirror(Mirror::LeftRight);
+        assert_eq!(mirrored.get("facing").unwrap(), "south");
+        assert_eq!(mirrored.get("shape").unwrap(), "outer_right");
+
+        let back = rotated.rotate(Rotation::CounterClockwise90);
+        assert_eq!(back, stairs);
+    }
+
+    #[test]
+    fn mirroring_door_flips_hinge() {
+        let door = BlockKind::OakDoor.default_properties();
+
+        let mirrored = door.mirror(Mirror::FrontBack);
+        assert_eq!(mirrored.get("hinge").unwrap(), "right");
+        assert_eq!(mirrored.get("facing").unwrap(), "north");
+
+        let mirrored = door.mirror(Mirror::LeftRight);
+        assert_eq!(mirrored.get("hinge").unwrap(), "right");
+        assert_eq!(mirrored.get("facing").unwrap(), "south");
+
+        assert_eq!(door.mirror(Mirror::None), door);
+    }
+
+    #[test]
+    fn rotating_fence_moves_connections() {
+        let mut fence = BlockProperties::new(BlockKind::OakFence);
+        fence
+            .set("north", "true")
+            .set("east", "false")
+            .set("south", "false")
+            .set("west", "false");
+
+        let rotated = fence.rotate(Rotation::Clockwise180);
+        assert_eq!(rotated.get("south").unwrap(), "true");
+        assert_eq!(rotated.get("north").unwrap(), "false");
+    }
+}
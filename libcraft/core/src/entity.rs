@@ -116,6 +116,8 @@ pub enum EntityKind {
     Goat,
     GlowSquid,
     Marker,
+    // New entities in 1.19
+    Warden,
 }
 #[allow(warnings)]
 #[allow(clippy::all)]
@@ -235,6 +237,7 @@ impl EntityKind {
             EntityKind::Goat => 109,
             EntityKind::GlowSquid => 110,
             EntityKind::Marker => 111,
+            EntityKind::Warden => 112,
         }
     }
 
@@ -353,6 +356,7 @@ impl EntityKind {
             109 => Some(EntityKind::Goat),
             110 => Some(EntityKind::GlowSquid),
             111 => Some(EntityKind::Marker),
+            112 => Some(EntityKind::Warden),
             _ => None,
         }
     }
@@ -475,6 +479,7 @@ impl EntityKind {
             EntityKind::Goat => 109,
             EntityKind::GlowSquid => 110,
             EntityKind::Marker => 111,
+            EntityKind::Warden => 112,
         }
     }
 
@@ -593,6 +598,7 @@ impl EntityKind {
             109 => Some(EntityKind::Goat),
             110 => Some(EntityKind::GlowSquid),
             111 => Some(EntityKind::Marker),
+            112 => Some(EntityKind::Warden),
             _ => None,
         }
     }
@@ -715,6 +721,7 @@ impl EntityKind {
             EntityKind::Goat => "goat",
             EntityKind::GlowSquid => "glow_squid",
             EntityKind::Marker => "marker",
+            EntityKind::Warden => "warden",
         }
     }
 
@@ -833,6 +840,7 @@ impl EntityKind {
             "goat" => Some(EntityKind::Goat),
             "glow_squid" => Some(EntityKind::GlowSquid),
             "marker" => Some(EntityKind::Marker),
+            "warden" => Some(EntityKind::Warden),
             _ => None,
         }
     }
@@ -955,6 +963,7 @@ impl EntityKind {
             EntityKind::Goat => "Goat",
             EntityKind::GlowSquid => "Glow Squid",
             EntityKind::Marker => "Marker",
+            EntityKind::Warden => "Warden",
         }
     }
 
@@ -1073,6 +1082,7 @@ impl EntityKind {
             "Goat" => Some(EntityKind::Goat),
             "Glow Squid" => Some(EntityKind::GlowSquid),
             "Marker" => Some(EntityKind::Marker),
+            "Warden" => Some(EntityKind::Warden),
             _ => None,
         }
     }
@@ -1531,6 +1541,10 @@ impl EntityKind {
                 min: vek::Vec3::zero(),
                 max: vek::Vec3::new(0 as f64, 0 as f64, 0 as f64),
             },
+            EntityKind::Warden => vek::Aabb {
+                min: vek::Vec3::zero(),
+                max: vek::Vec3::new(0.9 as f64, 2.9 as f64, 0.9 as f64),
+            },
         }
     }
 }
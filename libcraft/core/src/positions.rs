@@ -433,7 +433,7 @@ impl From<BlockPosition> for ChunkPosition {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BlockFace {
     Bottom,
     Top,
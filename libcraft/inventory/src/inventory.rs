@@ -506,17 +506,17 @@ impl Window {
                 }
             }
             Window::Hopper { hopper, player } => {
-                if (0..4).contains(&index) {
+                if (0..5).contains(&index) {
                     let area = Area::Storage;
                     let slot = index;
                     Some((hopper, area, slot))
-                } else if (4..31).contains(&index) {
+                } else if (5..32).contains(&index) {
                     let area = Area::Storage;
-                    let slot = index - 4;
+                    let slot = index - 5;
                     Some((player, area, slot))
-                } else if (31..40).contains(&index) {
+                } else if (32..41).contains(&index) {
                     let area = Area::Hotbar;
-                    let slot = index - 31;
+                    let slot = index - 32;
                     Some((player, area, slot))
                 } else {
                     None
@@ -908,9 +908,9 @@ impl Window {
                 if area == Area::Storage && hopper.ptr_eq(inventory) {
                     Some(slot)
                 } else if area == Area::Storage && player.ptr_eq(inventory) {
-                    Some(slot + 4)
+                    Some(slot + 5)
                 } else if area == Area::Hotbar && player.ptr_eq(inventory) {
-                    Some(slot + 31)
+                    Some(slot + 32)
                 } else {
                     None
                 }
@@ -1066,6 +1066,9 @@ pub enum InventoryBacking<T> {
         furnace_fuel: [T; 1],
         furnace_output: [T; 1],
     },
+    Hopper {
+        storage: [T; 5],
+    },
 }
 impl<T> InventoryBacking<T> {
     pub fn area_slice(&self, area: Area) -> Option<&[T]> {
@@ -1114,6 +1117,10 @@ impl<T> InventoryBacking<T> {
                 Area::FurnaceOutput => Some(furnace_output.as_ref()),
                 _ => None,
             },
+            InventoryBacking::Hopper { storage } => match area {
+                Area::Storage => Some(storage.as_ref()),
+                _ => None,
+            },
         }
     }
     pub fn areas(&self) -> &'static [Area] {
@@ -1148,6 +1155,10 @@ impl<T> InventoryBacking<T> {
                 ];
                 &AREAS
             }
+            InventoryBacking::Hopper { .. } => {
+                static AREAS: [Area; 1] = [Area::Storage];
+                &AREAS
+            }
         }
     }
     pub fn player() -> Self
@@ -1193,6 +1204,14 @@ impl<T> InventoryBacking<T> {
             furnace_output: Default::default(),
         }
     }
+    pub fn hopper() -> Self
+    where
+        T: Default,
+    {
+        InventoryBacking::Hopper {
+            storage: Default::default(),
+        }
+    }
 }
 impl crate::Inventory {
     pub fn player() -> Self {
@@ -1215,4 +1234,9 @@ impl crate::Inventory {
             backing: std::sync::Arc::new(InventoryBacking::furnace()),
         }
     }
+    pub fn hopper() -> Self {
+        Self {
+            backing: std::sync::Arc::new(InventoryBacking::hopper()),
+        }
+    }
 }
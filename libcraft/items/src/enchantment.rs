@@ -61,6 +61,67 @@ impl Enchantment {
     }
 }
 
+/// The enchantments applied to an item, at most one of each kind.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Enchantments(Vec<Enchantment>);
+
+impl Enchantments {
+    /// Creates an empty set of enchantments.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Gets the level of the given enchantment, or 0 if it is not applied.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn get(&self, kind: EnchantmentKind) -> u8 {
+        self.0
+            .iter()
+            .find(|enchantment| enchantment.kind() == kind)
+            .map_or(0, |enchantment| enchantment.level() as u8)
+    }
+
+    /// Applies the given enchantment, replacing the level of an
+    /// enchantment of the same kind. A level of 0 removes it.
+    pub fn set(&mut self, kind: EnchantmentKind, level: u32) {
+        if level == 0 {
+            self.0.retain(|enchantment| enchantment.kind() != kind);
+        } else if let Some(enchantment) = self.0.iter_mut().find(|e| e.kind() == kind) {
+            enchantment.set_level(level);
+        } else {
+            self.0.push(Enchantment::new(kind, level));
+        }
+    }
+
+    /// Whether the given enchantment is applied.
+    #[must_use]
+    pub fn has(&self, kind: EnchantmentKind) -> bool {
+        self.get(kind) > 0
+    }
+
+    /// Returns an iterator over the applied enchantments.
+    pub fn iter(&self) -> impl Iterator<Item = &Enchantment> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<Enchantment>> for Enchantments {
+    fn from(enchantments: Vec<Enchantment>) -> Self {
+        let mut result = Self::new();
+        for enchantment in enchantments {
+            result.set(enchantment.kind(), enchantment.level());
+        }
+        result
+    }
+}
+
 /// Kind of an enchantment.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -105,3 +166,25 @@ pub enum EnchantmentKind {
     Thorns,
     Unbreaking,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enchantments_keep_one_level_per_kind() {
+        let mut enchantments = Enchantments::from(vec![
+            Enchantment::new(EnchantmentKind::Efficiency, 2),
+            Enchantment::new(EnchantmentKind::Efficiency, 5),
+        ]);
+        assert_eq!(enchantments.get(EnchantmentKind::Efficiency), 5);
+        assert_eq!(enchantments.iter().count(), 1);
+        assert_eq!(enchantments.get(EnchantmentKind::SilkTouch), 0);
+
+        enchantments.set(EnchantmentKind::SilkTouch, 1);
+        assert!(enchantments.has(EnchantmentKind::SilkTouch));
+        enchantments.set(EnchantmentKind::Efficiency, 0);
+        assert!(!enchantments.has(EnchantmentKind::Efficiency));
+        assert_eq!(enchantments.iter().count(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Enchantment, EnchantmentKind, Item};
+use crate::{Enchantment, EnchantmentKind, Enchantments, Item};
 use core::fmt::Display;
 use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
@@ -31,6 +31,9 @@ pub struct ItemStack {
 /// * Item damage (Optional)
 /// * Item repair cost (Optional)
 /// * Item enchantments
+/// * Item dye color (Optional)
+/// * Suspicious stew effect (Optional)
+/// * Potion type (Optional)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 pub struct ItemStackMeta {
@@ -47,7 +50,18 @@ pub struct ItemStackMeta {
     repair_cost: Option<u32>,
 
     /// The enchantments applied to this `ItemStack`.
-    enchantments: Vec<Enchantment>,
+    enchantments: Enchantments,
+
+    /// The RGB color this `ItemStack` is dyed, such as for leather armor.
+    color: Option<u32>,
+
+    /// The status effect given by eating this suspicious stew,
+    /// as the effect's protocol id and its duration in ticks.
+    stew_effect: Option<(u8, u32)>,
+
+    /// The identifier of the potion this `ItemStack` holds,
+    /// such as `minecraft:water`.
+    potion: Option<String>,
 }
 
 impl ItemStack {
@@ -65,7 +79,10 @@ impl ItemStack {
                 lore: "".to_owned(),
                 damage: None,
                 repair_cost: None,
-                enchantments: vec![],
+                enchantments: Enchantments::new(),
+                color: None,
+                stew_effect: None,
+                potion: None,
             }),
         })
     }
@@ -118,6 +135,71 @@ impl ItemStack {
         self.item
     }
 
+    /// Returns the level of the given enchantment, if this `ItemStack` has it.
+    #[must_use]
+    pub fn enchantment_level(&self, ench: EnchantmentKind) -> Option<u32> {
+        self.meta.as_ref()?.get_enchantment_level(ench)
+    }
+
+    /// Returns the enchantments applied to this `ItemStack`.
+    #[must_use]
+    pub fn enchantments(&self) -> &Enchantments {
+        static NONE: Enchantments = Enchantments::new();
+        self.meta.as_ref().map_or(&NONE, |meta| &meta.enchantments)
+    }
+
+    /// Sets the level of the given enchantment, adding it if necessary.
+    pub fn set_enchantment_level(&mut self, ench: EnchantmentKind, level: u32) {
+        let item = self.item;
+        self.meta
+            .get_or_insert_with(|| ItemStackMeta::new(item))
+            .set_enchantment_level(ench, level);
+    }
+
+    /// Returns the RGB color this `ItemStack` is dyed, if any.
+    #[must_use]
+    pub fn color(&self) -> Option<u32> {
+        self.meta.as_ref()?.color
+    }
+
+    /// Dyes this `ItemStack` the given RGB color, or washes
+    /// its dye off when `color` is `None`.
+    pub fn set_color(&mut self, color: Option<u32>) {
+        let item = self.item;
+        self.meta
+            .get_or_insert_with(|| ItemStackMeta::new(item))
+            .color = color;
+    }
+
+    /// Returns the status effect given by eating this suspicious
+    /// stew, as the effect's protocol id and its duration in ticks.
+    #[must_use]
+    pub fn stew_effect(&self) -> Option<(u8, u32)> {
+        self.meta.as_ref()?.stew_effect
+    }
+
+    /// Sets the status effect given by eating this suspicious stew.
+    pub fn set_stew_effect(&mut self, effect: Option<(u8, u32)>) {
+        let item = self.item;
+        self.meta
+            .get_or_insert_with(|| ItemStackMeta::new(item))
+            .stew_effect = effect;
+    }
+
+    /// Returns the identifier of the potion this `ItemStack` holds, if any.
+    #[must_use]
+    pub fn potion(&self) -> Option<&str> {
+        self.meta.as_ref()?.potion.as_deref()
+    }
+
+    /// Sets the identifier of the potion this `ItemStack` holds.
+    pub fn set_potion(&mut self, potion: Option<String>) {
+        let item = self.item;
+        self.meta
+            .get_or_insert_with(|| ItemStackMeta::new(item))
+            .potion = potion;
+    }
+
     /// Returns the number of items in this `ItemStack`.
     #[must_use]
     pub const fn count(&self) -> u32 {
@@ -309,20 +391,18 @@ impl ItemStack {
     /// If this function returns `true`, then the item is broken.
     #[allow(clippy::missing_panics_doc)]
     pub fn damage(&mut self, amount: i32) -> bool {
-        if self.meta.is_none() {
-            return false;
-        }
-        match &mut self.meta.clone().unwrap().damage {
-            Some(damage) => {
-                *damage += amount;
-                if let Some(durability) = self.item.durability() {
-                    // Convert to a larger type for a safe conversion
-                    i64::from(*damage) >= i64::from(durability)
-                } else {
-                    false
-                }
-            }
-            None => false,
+        let durability = self.item.durability();
+        let meta = match self.meta.as_mut() {
+            Some(meta) => meta,
+            None => return false,
+        };
+        let damage = meta.damage.get_or_insert(0);
+        *damage += amount;
+        if let Some(durability) = durability {
+            // Convert to a larger type for a safe conversion
+            i64::from(*damage) >= i64::from(durability)
+        } else {
+            false
         }
     }
 
@@ -380,7 +460,10 @@ impl ItemStackMeta {
             lore: "".to_owned(),
             damage: None,
             repair_cost: None,
-            enchantments: vec![],
+            enchantments: Enchantments::new(),
+            color: None,
+            stew_effect: None,
+            potion: None,
         }
     }
 
@@ -393,11 +476,13 @@ impl ItemStackMeta {
     }
     /// Change the level of the given enchantment in-place or add it at the end of the list.
     pub fn set_enchantment_level(&mut self, ench: EnchantmentKind, level: u32) {
-        if let Some(enchant) = self.enchantments.iter_mut().find(|e| e.kind() == ench) {
-            enchant.set_level(level);
-        } else {
-            self.enchantments.push(Enchantment::new(ench, level));
-        }
+        self.enchantments.set(ench, level);
+    }
+
+    /// The enchantments applied to the item.
+    #[must_use]
+    pub fn enchantments(&self) -> &Enchantments {
+        &self.enchantments
     }
 }
 
@@ -485,7 +570,7 @@ impl ItemStackBuilder {
     /// Set the item's enchantment metadata to `enchantments`
     #[must_use]
     pub fn enchantments(mut self, enchantments: Vec<Enchantment>) -> Self {
-        self.get_or_init_meta().enchantments = enchantments;
+        self.get_or_init_meta().enchantments = enchantments.into();
         self
     }
 
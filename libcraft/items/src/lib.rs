@@ -10,7 +10,7 @@ mod inventory_slot;
 mod item;
 mod item_stack;
 
-pub use enchantment::{Enchantment, EnchantmentKind};
+pub use enchantment::{Enchantment, EnchantmentKind, Enchantments};
 pub use inventory_slot::InventorySlot;
 pub use item::*;
 pub use item_stack::{ItemStack, ItemStackBuilder, ItemStackError, ItemStackMeta};
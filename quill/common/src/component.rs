@@ -173,6 +173,7 @@ host_component_enum! {
         Player = 205,
         FishingBobber = 206,
         PiglinBrute = 207,
+        Warden = 208,
 
         // `bincode` components
         Gamemode = 1000,
@@ -206,6 +207,9 @@ host_component_enum! {
         FlyingAbilityEvent = 1028,
         BuildingAbilityEvent = 1029,
         InvulnerabilityEvent = 1030,
+        Tameable = 1031,
+        MovementSpeed = 1032,
+        Velocity = 1033,
     }
 }
 
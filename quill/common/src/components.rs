@@ -3,12 +3,13 @@
 //! See the [entities module](crate::entities) for entity-specific
 //! components.
 
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
 use serde::{Deserialize, Serialize};
 use smartstring::{LazyCompact, SmartString};
 
 use libcraft_core::Gamemode;
+use uuid::Uuid;
 
 /// Whether an entity is touching the ground.
 #[derive(
@@ -258,10 +259,50 @@ impl PreviousGamemode {
 }
 
 /// Represents an entity's health
-#[derive(
-    Copy, Clone, Debug, PartialEq, Serialize, Deserialize, derive_more::Deref, derive_more::DerefMut,
-)]
-pub struct Health(pub f32);
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Health {
+    pub current: f32,
+    /// Most health the entity can heal up to
+    pub max: f32,
+    /// Ticks left in which the entity only takes damage
+    /// exceeding `last_damage_amount`
+    pub invulnerable_ticks: u32,
+    /// Damage last dealt to the entity
+    pub last_damage_amount: f32,
+}
+
+impl Health {
+    /// Full health of `max`.
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            invulnerable_ticks: 0,
+            last_damage_amount: 0.0,
+        }
+    }
+
+    /// Whether the entity was hurt too recently to take
+    /// damage that does not exceed the last.
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable_ticks > 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.current >= self.max
+    }
+
+    /// Restores up to `amount` of health without going over `max`
+    /// and returns the health restored. The dead cannot be healed.
+    pub fn heal(&mut self, amount: f32) -> f32 {
+        if self.current <= 0.0 {
+            return 0.0;
+        }
+        let healed = (self.max - self.current).max(0.0).min(amount);
+        self.current += healed;
+        healed
+    }
+}
 bincode_component_impl!(Health);
 
 /// A component on players that tracks if they are sprinting or not.
@@ -284,3 +325,139 @@ impl Sprinting {
     }
 }
 bincode_component_impl!(Sprinting);
+
+/// Whether an entity has been tamed, and by whom.
+///
+/// The owner is stored as the player's UUID rather than as an
+/// entity handle, so ownership survives the owner logging out.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tameable {
+    pub tamed: bool,
+    pub owner: Option<Uuid>,
+}
+
+impl Tameable {
+    /// Tames the entity for the given owner.
+    pub fn tame(&mut self, owner: Uuid) {
+        self.tamed = true;
+        self.owner = Some(owner);
+    }
+
+    /// Gives a tamed entity to a new owner. Returns
+    /// `false` if the entity is not tamed.
+    pub fn transfer(&mut self, new_owner: Uuid) -> bool {
+        if !self.tamed {
+            return false;
+        }
+        self.owner = Some(new_owner);
+        true
+    }
+
+    /// Makes the entity wild again.
+    pub fn untame(&mut self) {
+        self.tamed = false;
+        self.owner = None;
+    }
+
+    pub fn is_owned_by(&self, player: Uuid) -> bool {
+        self.tamed && self.owner == Some(player)
+    }
+}
+
+bincode_component_impl!(Tameable);
+
+/// An entity's movement speed.
+///
+/// `value` is the effective speed: `base_value` multiplied by
+/// every modifier currently speeding the entity up or slowing it down.
+/// Modifiers are keyed by their cause and only last until
+/// the speed is next recomputed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MovementSpeed {
+    pub value: f64,
+    pub base_value: f64,
+    #[serde(skip)]
+    modifiers: BTreeMap<&'static str, f64>,
+}
+
+impl MovementSpeed {
+    pub fn new(base_value: f64) -> Self {
+        Self {
+            value: base_value,
+            base_value,
+            modifiers: BTreeMap::new(),
+        }
+    }
+
+    /// Multiplies the speed by `multiplier` because of `cause`,
+    /// replacing any earlier multiplier from the same cause.
+    pub fn set_modifier(&mut self, cause: &'static str, multiplier: f64) {
+        self.modifiers.insert(cause, multiplier);
+    }
+
+    pub fn remove_modifier(&mut self, cause: &'static str) -> Option<f64> {
+        self.modifiers.remove(cause)
+    }
+
+    pub fn modifier(&self, cause: &str) -> Option<f64> {
+        self.modifiers.get(cause).copied()
+    }
+
+    /// Sets `value` from `base_value` and the current modifiers,
+    /// then clears the modifiers so that causes which no longer
+    /// apply stop affecting the speed.
+    pub fn recompute(&mut self) -> f64 {
+        self.value = self.base_value * self.modifiers.values().product::<f64>();
+        self.modifiers.clear();
+        self.value
+    }
+}
+
+bincode_component_impl!(MovementSpeed);
+
+/// An entity's velocity, in blocks per tick.
+///
+/// Systems moving entities set the velocity, and
+/// the physics step moves the entity by it each tick.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Velocity {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Velocity {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+bincode_component_impl!(Velocity);
+
+#[cfg(test)]
+mod tests {
+    use crate::Component;
+
+    use super::*;
+
+    #[test]
+    fn tameable_ownership_transfer_round_trips() {
+        let alice = Uuid::from_u128(1);
+        let bob = Uuid::from_u128(2);
+
+        let mut tameable = Tameable::default();
+        assert!(!tameable.transfer(alice));
+        tameable.tame(alice);
+        assert!(tameable.is_owned_by(alice));
+
+        assert!(tameable.transfer(bob));
+        assert_eq!(tameable.owner, Some(bob));
+        assert!(!tameable.is_owned_by(alice));
+
+        let mut bytes = Vec::new();
+        tameable.to_bytes(&mut bytes);
+        let (decoded, _) = Tameable::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, tameable);
+        assert!(decoded.is_owned_by(bob));
+    }
+}
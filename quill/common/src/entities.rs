@@ -214,3 +214,5 @@ pub mod player;
 pub use player::Player;
 pub mod fishing_bobber;
 pub use fishing_bobber::FishingBobber;
+pub mod warden;
+pub use warden::Warden;
@@ -0,0 +1,19 @@
+use bytemuck::{Pod, Zeroable};
+/// Marker component for warden entities.
+///
+/// # Example
+/// A system that queries for all wardens:
+/// ```no_run
+/// use quill::{Game, Position, entities::Warden};
+/// # struct MyPlugin;
+/// fn print_entities_system(_plugin: &mut MyPlugin, game: &mut Game) {
+///     for (entity, (position, _)) in game.query::<(&Position, &Warden)>() {
+///         println!("Found a warden with position {:?}", position);
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+pub struct Warden;
+
+pod_component_impl!(Warden);
@@ -327,4 +327,7 @@ pub enum EntityInit {
 
     /// Spawn a fishing bobber.
     FishingBobber,
+
+    /// Spawn a warden.
+    Warden,
 }